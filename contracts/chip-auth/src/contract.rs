@@ -0,0 +1,86 @@
+use soroban_sdk::{contractimpl, contracttype, crypto::Hash, Address, Bytes, BytesN, Env, Val};
+use merch_crypto::soroban::{chip_message_hash, widen_nonce};
+use crate::{ChipAuth, ChipAuthArgs, ChipAuthClient, ChipAuthContract};
+
+#[contracttype]
+pub enum DataKey {
+    Nonce(Address, BytesN<65>),
+}
+
+#[contractimpl]
+impl ChipAuthContract for ChipAuth {
+    fn verify(
+        e: &Env,
+        caller: Address,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: u32,
+        public_key: BytesN<65>,
+        nonce: u64,
+    ) -> bool {
+        caller.require_auth();
+
+        if !Self::check(e, caller.clone(), message, signature, recovery_id, public_key.clone(), nonce) {
+            return false;
+        }
+
+        e.storage()
+            .persistent()
+            .set(&DataKey::Nonce(caller, public_key), &nonce);
+        true
+    }
+
+    fn check(
+        e: &Env,
+        caller: Address,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: u32,
+        public_key: BytesN<65>,
+        nonce: u64,
+    ) -> bool {
+        // Verify nonce is monotonic increasing
+        if nonce <= Self::get_nonce(e, caller, public_key.clone()) {
+            return false;
+        }
+
+        let message_hash = chip_message_hash(e, &message, nonce);
+
+        signature_matches(e, &message_hash, &signature, recovery_id, &public_key)
+    }
+
+    fn get_nonce(e: &Env, caller: Address, public_key: BytesN<65>) -> u64 {
+        e.storage()
+            .persistent()
+            .get::<_, Val>(&DataKey::Nonce(caller, public_key))
+            .map(|nonce| widen_nonce(e, nonce))
+            .unwrap_or(0u64)
+    }
+}
+
+/// Check that the signature of message_hash recovers to public_key
+/// Inputs the host would reject (recovery ID out of range, zero or high S, R off the curve)
+/// are reported as a mismatch instead of trapping
+fn signature_matches(
+    e: &Env,
+    message_hash: &Hash<32>,
+    signature: &BytesN<64>,
+    recovery_id: u32,
+    public_key: &BytesN<65>,
+) -> bool {
+    if recovery_id > 3 {
+        return false;
+    }
+
+    let signature_bytes = signature.to_array();
+    let mut r = [0u8; 32];
+    let mut s = [0u8; 32];
+    r.copy_from_slice(&signature_bytes[..32]);
+    s.copy_from_slice(&signature_bytes[32..]);
+    if !merch_crypto::is_low_s(&s) || !merch_crypto::is_valid_r(&r, recovery_id) {
+        return false;
+    }
+
+    let recovered = e.crypto().secp256k1_recover(message_hash, signature, recovery_id);
+    recovered == *public_key
+}
@@ -0,0 +1,67 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractmeta, Address, Bytes, BytesN, Env};
+
+contractmeta!(key = "Description", val = "NFC chip signature verification");
+contractmeta!(key = "Version", val = env!("CARGO_PKG_VERSION"));
+
+mod contract;
+
+#[cfg(test)]
+mod test;
+
+#[contract]
+pub struct ChipAuth;
+
+pub trait ChipAuthContract {
+    /// Verify that an NFC chip signed `message` and consume `nonce`.
+    ///
+    /// The signed payload is `sha256(message || nonce.to_xdr())`. Nonces are
+    /// tracked per `caller`, so each consumer contract has its own namespace
+    /// and a signature consumed by one can still be used by another.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `caller` - Account or contract consuming the nonce.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks.
+    ///
+    /// # Returns
+    ///
+    /// Whether the signature is valid for a fresh nonce. The nonce is only
+    /// consumed on success.
+    fn verify(e: &Env, caller: Address, message: Bytes, signature: BytesN<64>, recovery_id: u32, public_key: BytesN<65>, nonce: u64) -> bool;
+
+    /// Read-only counterpart of `verify`: does not require authorization and
+    /// does not consume the nonce.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `caller` - Account or contract whose nonce namespace is checked.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks.
+    ///
+    /// # Returns
+    ///
+    /// Whether `verify` would succeed.
+    fn check(e: &Env, caller: Address, message: Bytes, signature: BytesN<64>, recovery_id: u32, public_key: BytesN<65>, nonce: u64) -> bool;
+
+    /// Returns the last nonce `caller` consumed for a chip, 0 if none.
+    ///
+    /// Nonces consumed before the nonce was widened to u64 read the same.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `caller` - Account or contract owning the nonce namespace.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    fn get_nonce(e: &Env, caller: Address, public_key: BytesN<65>) -> u64;
+}
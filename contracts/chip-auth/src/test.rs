@@ -0,0 +1,172 @@
+//! Tests for chip-auth
+//!
+//! Signatures are produced with a k256 software key standing in for the NFC
+//! chip, see the stellar-merch-shop tests for the hardware vectors.
+
+extern crate std;
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+use crate::{ChipAuth, ChipAuthClient};
+
+const TEST_MESSAGE: &[u8] = b"open sesame";
+
+struct SoftwareChip {
+    signing_key: k256::ecdsa::SigningKey,
+}
+
+impl SoftwareChip {
+    fn new(seed: u32) -> Self {
+        let mut secret = [0u8; 32];
+        secret[28..].copy_from_slice(&(seed + 1).to_be_bytes());
+        SoftwareChip { signing_key: k256::ecdsa::SigningKey::from_slice(&secret).unwrap() }
+    }
+
+    fn public_key(&self, e: &Env) -> BytesN<65> {
+        let point = self.signing_key.verifying_key().to_encoded_point(false);
+        BytesN::from_array(e, point.as_bytes().try_into().unwrap())
+    }
+
+    // Returns the message, signature and recovery ID over message || nonce
+    fn sign(&self, e: &Env, nonce: u64) -> (Bytes, BytesN<64>, u32) {
+        let message = Bytes::from_slice(e, TEST_MESSAGE);
+        let mut builder = message.clone();
+        builder.append(&nonce.to_xdr(e));
+        let message_hash: BytesN<32> = e.crypto().sha256(&builder).into();
+
+        let (signature, recovery_id) = self
+            .signing_key
+            .sign_prehash_recoverable(&message_hash.to_array())
+            .unwrap();
+        (message, BytesN::from_array(e, &signature.to_bytes().into()), recovery_id.to_byte() as u32)
+    }
+}
+
+/// A partner contract gating an action on possession of a chip
+#[contract]
+struct Door;
+
+#[contractimpl]
+impl Door {
+    pub fn open(
+        e: Env,
+        chip_auth: Address,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: u32,
+        public_key: BytesN<65>,
+        nonce: u64,
+    ) -> bool {
+        ChipAuthClient::new(&e, &chip_auth).verify(
+            &e.current_contract_address(),
+            &message,
+            &signature,
+            &recovery_id,
+            &public_key,
+            &nonce,
+        )
+    }
+}
+
+#[test]
+fn test_verify() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let caller = Address::generate(&e);
+    let client = ChipAuthClient::new(&e, &e.register(ChipAuth, ()));
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+
+    let (message, signature, recovery_id) = chip.sign(&e, 1);
+    assert!(client.check(&caller, &message, &signature, &recovery_id, &public_key, &1));
+    assert_eq!(client.get_nonce(&caller, &public_key), 0);
+
+    assert!(client.verify(&caller, &message, &signature, &recovery_id, &public_key, &1));
+    assert_eq!(client.get_nonce(&caller, &public_key), 1);
+
+    // Replays are refused
+    assert!(!client.verify(&caller, &message, &signature, &recovery_id, &public_key, &1));
+
+    // Another chip's key, a wrong message or an out of range recovery ID
+    let other_key = SoftwareChip::new(2).public_key(&e);
+    let (message, signature, recovery_id) = chip.sign(&e, 2);
+    assert!(!client.verify(&caller, &message, &signature, &recovery_id, &other_key, &2));
+    assert!(!client.verify(&caller, &Bytes::from_slice(&e, b"close"), &signature, &recovery_id, &public_key, &2));
+    assert!(!client.verify(&caller, &message, &signature, &4, &public_key, &2));
+
+    // An R the host cannot lift to a curve point, zero or past the curve order
+    let mut bytes = signature.to_array();
+    for r in [[0u8; 32], merch_crypto::SECP256K1_ORDER, [0xff; 32]] {
+        bytes[..32].copy_from_slice(&r);
+        assert!(!client.verify(&caller, &message, &BytesN::from_array(&e, &bytes), &recovery_id, &public_key, &2));
+    }
+    // An R + n past the field prime for the recovery IDs 2 and 3
+    bytes[..32].copy_from_slice(&signature.to_array()[..32]);
+    assert!(!client.verify(&caller, &message, &BytesN::from_array(&e, &bytes), &2, &public_key, &2));
+
+    // Failed attempts leave the nonce untouched
+    assert_eq!(client.get_nonce(&caller, &public_key), 1);
+}
+
+#[test]
+fn test_verify_requires_caller_auth() {
+    let e = Env::default();
+
+    let caller = Address::generate(&e);
+    let client = ChipAuthClient::new(&e, &e.register(ChipAuth, ()));
+    let chip = SoftwareChip::new(1);
+
+    let (message, signature, recovery_id) = chip.sign(&e, 1);
+    let result = client.try_verify(&caller, &message, &signature, &recovery_id, &chip.public_key(&e), &1);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cross_contract_consumer() {
+    let e = Env::default();
+
+    let chip_auth = e.register(ChipAuth, ());
+    let client = ChipAuthClient::new(&e, &chip_auth);
+    let front_door = DoorClient::new(&e, &e.register(Door, ()));
+    let back_door = DoorClient::new(&e, &e.register(Door, ()));
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+
+    // The consumer contract is the direct invoker, no mocked auth needed
+    let (message, signature, recovery_id) = chip.sign(&e, 1);
+    assert!(front_door.open(&chip_auth, &message, &signature, &recovery_id, &public_key, &1));
+    assert!(!front_door.open(&chip_auth, &message, &signature, &recovery_id, &public_key, &1));
+
+    // Each consumer has its own nonce namespace
+    assert!(back_door.open(&chip_auth, &message, &signature, &recovery_id, &public_key, &1));
+    assert_eq!(client.get_nonce(&front_door.address, &public_key), 1);
+    assert_eq!(client.get_nonce(&back_door.address, &public_key), 1);
+}
+
+#[test]
+fn test_u64_nonce() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let caller = Address::generate(&e);
+    let chip_auth = e.register(ChipAuth, ());
+    let client = ChipAuthClient::new(&e, &chip_auth);
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+
+    // Nonces consumed before the widening are stored as a u32
+    let nonce_key = crate::contract::DataKey::Nonce(caller.clone(), public_key.clone());
+    e.as_contract(&chip_auth, || e.storage().persistent().set(&nonce_key, &u32::MAX));
+    assert_eq!(client.get_nonce(&caller, &public_key), u32::MAX as u64);
+
+    let (message, signature, recovery_id) = chip.sign(&e, u32::MAX as u64);
+    assert!(!client.verify(&caller, &message, &signature, &recovery_id, &public_key, &(u32::MAX as u64)));
+
+    let nonce = u32::MAX as u64 + 1;
+    let (message, signature, recovery_id) = chip.sign(&e, nonce);
+    assert!(client.verify(&caller, &message, &signature, &recovery_id, &public_key, &nonce));
+    assert_eq!(client.get_nonce(&caller, &public_key), nonce);
+    let stored: Option<u64> = e.as_contract(&chip_auth, || e.storage().persistent().get(&nonce_key));
+    assert_eq!(stored, Some(nonce));
+}
@@ -0,0 +1,103 @@
+//! Small on-chain attributes of a token, such as its size or color
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Env, Map, String};
+use crate::{contract, errors, events, NFCtoNFTContract, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Maximum length of an attribute key, in bytes
+pub const MAX_ATTRIBUTE_KEY_LENGTH: u32 = 32;
+/// Maximum length of an attribute value, in bytes
+pub const MAX_ATTRIBUTE_VALUE_LENGTH: u32 = 64;
+/// Maximum number of attributes of a token
+pub const MAX_ATTRIBUTES: u32 = 20;
+
+#[contracttype]
+pub enum AttributeStorageKey {
+    Attributes(u64),
+}
+
+pub trait Attributes {
+    /// Sets the attribute `key` of `token_id` token, replacing its value if
+    /// it is already set.
+    ///
+    /// Keys are up to `MAX_ATTRIBUTE_KEY_LENGTH` bytes, values up to
+    /// `MAX_ATTRIBUTE_VALUE_LENGTH` bytes, and a token holds up to
+    /// `MAX_ATTRIBUTES` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `key` - Name of the attribute.
+    /// * `value` - Value of the attribute.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update"]`
+    /// * data - `[token_id: u64]`
+    fn set_attribute(e: &Env, token_id: u64, key: String, value: String);
+
+    /// Returns the attribute `key` of `token_id` token, if set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `key` - Name of the attribute.
+    fn get_attribute(e: &Env, token_id: u64, key: String) -> Option<String>;
+
+    /// Returns every attribute of `token_id` token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn attributes(e: &Env, token_id: u64) -> Map<String, String>;
+}
+
+#[contractimpl]
+impl Attributes for StellarMerchShop {
+    fn set_attribute(e: &Env, token_id: u64, key: String, value: String) {
+        contract::require_admin(e);
+
+        if key.len() > MAX_ATTRIBUTE_KEY_LENGTH || value.len() > MAX_ATTRIBUTE_VALUE_LENGTH {
+            panic_with_error!(e, &errors::NonFungibleTokenError::AttributeTooLong);
+        }
+
+        let mut attributes = Self::attributes(e, token_id);
+        if !attributes.contains_key(key.clone()) && attributes.len() >= MAX_ATTRIBUTES {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+        }
+        attributes.set(key, value);
+        e.storage()
+            .persistent()
+            .set(&AttributeStorageKey::Attributes(token_id), &attributes);
+
+        events::MetadataUpdate { token_id }.publish(e);
+    }
+
+    fn get_attribute(e: &Env, token_id: u64, key: String) -> Option<String> {
+        Self::attributes(e, token_id).get(key)
+    }
+
+    fn attributes(e: &Env, token_id: u64) -> Map<String, String> {
+        // Verify token exists
+        StellarMerchShop::public_key(e, token_id);
+
+        stored_attributes(e, token_id)
+    }
+}
+
+/// Attributes of an existing token_id
+pub(crate) fn stored_attributes(e: &Env, token_id: u64) -> Map<String, String> {
+    e.storage()
+        .persistent()
+        .get(&AttributeStorageKey::Attributes(token_id))
+        .unwrap_or_else(|| Map::new(e))
+}
+
+/// Remove the attributes of the burned token_id
+pub(crate) fn remove_attributes(e: &Env, token_id: u64) {
+    e.storage()
+        .persistent()
+        .remove(&AttributeStorageKey::Attributes(token_id));
+}
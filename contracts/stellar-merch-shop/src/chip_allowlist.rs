@@ -0,0 +1,133 @@
+//! Allowlist of the chips that can be minted
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, BytesN, Env, Vec};
+use crate::{chip_registry, contract, errors, events, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Maximum number of public keys accepted by `add_allowed_chips`
+pub const MAX_ALLOWLIST_BATCH: u32 = 25;
+
+#[contracttype]
+pub enum AllowlistStorageKey {
+    AllowlistEnabled,
+    AllowedChip(BytesN<65>),
+}
+
+pub trait ChipAllowlist {
+    /// Adds chips to the allowlist, up to `MAX_ALLOWLIST_BATCH` per call.
+    ///
+    /// A key no chip can have fails the whole call with `InvalidPublicKey`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_keys` - The chips' public keys (uncompressed SEC1 format, 65 bytes).
+    ///
+    /// # Events
+    ///
+    /// For each chip:
+    ///
+    /// * topics - `["chip_allowed", public_key: BytesN<65>]`
+    /// * data - `[allowed: bool]`
+    fn add_allowed_chips(e: &Env, public_keys: Vec<BytesN<65>>);
+
+    /// Removes a chip from the allowlist. Tokens already minted are kept.
+    ///
+    /// A chip that was neither minted nor revoked also leaves
+    /// `registered_chips`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    ///
+    /// # Events
+    ///
+    /// * topics - `["chip_allowed", public_key: BytesN<65>]`
+    /// * data - `[allowed: bool]`
+    fn remove_allowed_chip(e: &Env, public_key: BytesN<65>);
+
+    /// Enables or disables the allowlist.
+    ///
+    /// When enabled, minting a chip that is not on the allowlist fails with
+    /// `ChipNotAllowed`. Disabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `enabled` - Whether the allowlist is enforced.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["allowlist_enabled"]`
+    /// * data - `[enabled: bool]`
+    fn set_allowlist_enabled(e: &Env, enabled: bool);
+
+    /// Returns whether the allowlist is enforced.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn allowlist_enabled(e: &Env) -> bool;
+
+    /// Returns whether the chip is on the allowlist, whether it is enforced
+    /// or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    fn is_chip_allowed(e: &Env, public_key: BytesN<65>) -> bool;
+}
+
+#[contractimpl]
+impl ChipAllowlist for StellarMerchShop {
+    fn add_allowed_chips(e: &Env, public_keys: Vec<BytesN<65>>) {
+        contract::require_admin(e);
+
+        if public_keys.len() > MAX_ALLOWLIST_BATCH {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+        }
+        for public_key in public_keys.iter() {
+            contract::require_valid_public_key(e, &public_key);
+            e.storage().persistent().set(&AllowlistStorageKey::AllowedChip(public_key.clone()), &true);
+            chip_registry::add_chip(e, &public_key);
+            events::ChipAllowed { public_key, allowed: true }.publish(e);
+        }
+    }
+
+    fn remove_allowed_chip(e: &Env, public_key: BytesN<65>) {
+        contract::require_admin(e);
+
+        e.storage().persistent().remove(&AllowlistStorageKey::AllowedChip(public_key.clone()));
+        chip_registry::remove_unused_chip(e, &public_key);
+
+        events::ChipAllowed { public_key, allowed: false }.publish(e);
+    }
+
+    fn set_allowlist_enabled(e: &Env, enabled: bool) {
+        contract::require_admin(e);
+
+        e.storage().instance().set(&AllowlistStorageKey::AllowlistEnabled, &enabled);
+
+        events::AllowlistEnabled { enabled }.publish(e);
+    }
+
+    fn allowlist_enabled(e: &Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&AllowlistStorageKey::AllowlistEnabled)
+            .unwrap_or(false)
+    }
+
+    fn is_chip_allowed(e: &Env, public_key: BytesN<65>) -> bool {
+        e.storage().persistent().has(&AllowlistStorageKey::AllowedChip(public_key))
+    }
+}
+
+/// Check the chip can be minted under the allowlist, if enforced
+pub(crate) fn check_allowed(e: &Env, public_key: &BytesN<65>) -> Result<(), errors::NonFungibleTokenError> {
+    if StellarMerchShop::allowlist_enabled(e) && !StellarMerchShop::is_chip_allowed(e, public_key.clone()) {
+        return Err(errors::NonFungibleTokenError::ChipNotAllowed);
+    }
+    Ok(())
+}
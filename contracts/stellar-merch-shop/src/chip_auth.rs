@@ -0,0 +1,69 @@
+//! Optional delegation of chip signature checks to a chip-auth contract
+
+use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env};
+
+/// Interface of the standalone chip-auth contract
+#[contractclient(name = "ChipAuthClient")]
+pub trait ChipAuthInterface {
+    fn verify(
+        e: Env,
+        caller: Address,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: u32,
+        public_key: BytesN<65>,
+        nonce: u64,
+    ) -> bool;
+
+    fn check(
+        e: Env,
+        caller: Address,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: u32,
+        public_key: BytesN<65>,
+        nonce: u64,
+    ) -> bool;
+
+    fn get_nonce(e: Env, caller: Address, public_key: BytesN<65>) -> u64;
+}
+
+/// Verify the chip signature with chip-auth, consuming the nonce in this contract's namespace
+pub(crate) fn verify(
+    e: &Env,
+    chip_auth: &Address,
+    message: &Bytes,
+    signature: &BytesN<64>,
+    recovery_id: u32,
+    public_key: &BytesN<65>,
+    nonce: u64,
+) -> bool {
+    ChipAuthClient::new(e, chip_auth).verify(
+        &e.current_contract_address(),
+        message,
+        signature,
+        &recovery_id,
+        public_key,
+        &nonce,
+    )
+}
+
+/// Read-only counterpart of verify
+pub(crate) fn check(
+    e: &Env,
+    chip_auth: &Address,
+    message: &Bytes,
+    signature: &BytesN<64>,
+    recovery_id: u32,
+    public_key: &BytesN<65>,
+    nonce: u64,
+) -> bool {
+    ChipAuthClient::new(e, chip_auth).check(
+        &e.current_contract_address(),
+        message,
+        signature,
+        &recovery_id,
+        public_key,
+        &nonce,
+    )
+}
@@ -0,0 +1,114 @@
+//! Chip model and firmware information for recalls
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, BytesN, Env, Symbol, Vec};
+use crate::{contract, errors, events, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChipInfo {
+    pub model: Symbol,
+    pub firmware: u32,
+    pub batch: u32,
+}
+
+#[contracttype]
+pub enum ChipInfoStorageKey {
+    ChipInfo(BytesN<65>),
+    BatchTokenCount(u32),
+    BatchToken(u32, u32),
+}
+
+pub trait ChipInfoRegistry {
+    /// Returns the model and firmware information recorded for a chip, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    fn chip_info(e: &Env, public_key: BytesN<65>) -> Option<ChipInfo>;
+
+    /// Returns up to `limit` token ids whose chip belongs to `batch`, in mint
+    /// order, starting at position `start`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `batch` - Production batch of the chips.
+    /// * `start` - Position of the first token id to return.
+    /// * `limit` - Maximum number of token ids to return.
+    fn tokens_by_batch(e: &Env, batch: u32, start: u32, limit: u32) -> Vec<u64>;
+
+    /// Records a firmware update applied to a chip in the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `firmware` - The new firmware revision.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["chip_firmware_updated", public_key: BytesN<65>]`
+    /// * data - `[previous_firmware: u32, firmware: u32]`
+    fn update_chip_firmware(e: &Env, public_key: BytesN<65>, firmware: u32);
+}
+
+#[contractimpl]
+impl ChipInfoRegistry for StellarMerchShop {
+    fn chip_info(e: &Env, public_key: BytesN<65>) -> Option<ChipInfo> {
+        e.storage()
+            .persistent()
+            .get(&ChipInfoStorageKey::ChipInfo(public_key))
+    }
+
+    fn tokens_by_batch(e: &Env, batch: u32, start: u32, limit: u32) -> Vec<u64> {
+        let count: u32 = e
+            .storage()
+            .persistent()
+            .get(&ChipInfoStorageKey::BatchTokenCount(batch))
+            .unwrap_or(0);
+        let end = start.saturating_add(limit).min(count);
+
+        let mut token_ids = Vec::new(e);
+        for index in start..end {
+            let token_id: u64 = e
+                .storage()
+                .persistent()
+                .get(&ChipInfoStorageKey::BatchToken(batch, index))
+                .unwrap();
+            token_ids.push_back(token_id);
+        }
+        token_ids
+    }
+
+    fn update_chip_firmware(e: &Env, public_key: BytesN<65>, firmware: u32) {
+        contract::require_admin(e);
+
+        let mut chip_info = Self::chip_info(e, public_key.clone())
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NonExistentToken));
+        let previous_firmware = chip_info.firmware;
+        chip_info.firmware = firmware;
+        e.storage()
+            .persistent()
+            .set(&ChipInfoStorageKey::ChipInfo(public_key.clone()), &chip_info);
+
+        events::ChipFirmwareUpdated { public_key, previous_firmware, firmware }.publish(e);
+    }
+}
+
+/// Store the chip information of a freshly minted token and index it by batch
+pub(crate) fn record_chip_info(e: &Env, public_key: &BytesN<65>, token_id: u64, chip_info: &ChipInfo) {
+    e.storage()
+        .persistent()
+        .set(&ChipInfoStorageKey::ChipInfo(public_key.clone()), chip_info);
+
+    let count_key = ChipInfoStorageKey::BatchTokenCount(chip_info.batch);
+    let count: u32 = e.storage().persistent().get(&count_key).unwrap_or(0);
+    e.storage()
+        .persistent()
+        .set(&ChipInfoStorageKey::BatchToken(chip_info.batch, count), &token_id);
+    let count = count
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    e.storage().persistent().set(&count_key, &count);
+}
@@ -0,0 +1,126 @@
+//! Registry of every chip known to the contract, for inventory reconciliation
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, BytesN, Env, Vec};
+use crate::{errors, NFCtoNFTContract, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Maximum number of public keys returned by one `registered_chips` call
+pub const MAX_REGISTERED_CHIPS_LIMIT: u32 = 100;
+
+#[contracttype]
+pub enum ChipRegistryStorageKey {
+    ChipCount,
+    RegisteredChip(u64),
+    ChipPosition(BytesN<65>),
+}
+
+pub trait ChipRegistry {
+    /// Returns up to `limit` public keys of registered chips, starting at
+    /// position `start`.
+    ///
+    /// A chip is registered when it is allowlisted, minted or bound to a
+    /// token with `rebind_chip`, and stays listed once burned or revoked:
+    /// `is_revoked` flags the revoked ones. Only a chip removed from the
+    /// allowlist before it was minted or revoked leaves the registry, the
+    /// last chip then takes its position.
+    ///
+    /// `limit` is capped to `MAX_REGISTERED_CHIPS_LIMIT`, and a `start` past
+    /// the last chip returns an empty list.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `start` - Position of the first public key to return.
+    /// * `limit` - Maximum number of public keys to return.
+    fn registered_chips(e: &Env, start: u32, limit: u32) -> Vec<BytesN<65>>;
+
+    /// Returns the number of registered chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn registered_chip_count(e: &Env) -> u64;
+}
+
+#[contractimpl]
+impl ChipRegistry for StellarMerchShop {
+    fn registered_chips(e: &Env, start: u32, limit: u32) -> Vec<BytesN<65>> {
+        let end = u64::from(start)
+            .saturating_add(u64::from(limit.min(MAX_REGISTERED_CHIPS_LIMIT)))
+            .min(Self::registered_chip_count(e));
+
+        let mut public_keys = Vec::new(e);
+        for position in u64::from(start)..end {
+            let public_key: BytesN<65> = e
+                .storage()
+                .persistent()
+                .get(&ChipRegistryStorageKey::RegisteredChip(position))
+                .unwrap();
+            public_keys.push_back(public_key);
+        }
+        public_keys
+    }
+
+    fn registered_chip_count(e: &Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&ChipRegistryStorageKey::ChipCount)
+            .unwrap_or(0)
+    }
+}
+
+/// Append public_key to the registry, unless already registered
+pub(crate) fn add_chip(e: &Env, public_key: &BytesN<65>) {
+    let position_key = ChipRegistryStorageKey::ChipPosition(public_key.clone());
+    if e.storage().persistent().has(&position_key) {
+        return;
+    }
+
+    let count = StellarMerchShop::registered_chip_count(e);
+    let next_count = count
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    e.storage()
+        .persistent()
+        .set(&ChipRegistryStorageKey::RegisteredChip(count), public_key);
+    e.storage().persistent().set(&position_key, &count);
+    e.storage()
+        .instance()
+        .set(&ChipRegistryStorageKey::ChipCount, &next_count);
+}
+
+/// Remove public_key from the registry if it was never minted nor revoked,
+/// the last chip takes its position
+pub(crate) fn remove_unused_chip(e: &Env, public_key: &BytesN<65>) {
+    if StellarMerchShop::token_id_opt(e, public_key.clone()).is_some() || StellarMerchShop::is_revoked(e, public_key.clone()) {
+        return;
+    }
+    let position_key = ChipRegistryStorageKey::ChipPosition(public_key.clone());
+    let Some(position) = e.storage().persistent().get::<_, u64>(&position_key) else {
+        return;
+    };
+    let last = StellarMerchShop::registered_chip_count(e)
+        .checked_sub(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+
+    if position != last {
+        let last_public_key: BytesN<65> = e
+            .storage()
+            .persistent()
+            .get(&ChipRegistryStorageKey::RegisteredChip(last))
+            .unwrap();
+        e.storage()
+            .persistent()
+            .set(&ChipRegistryStorageKey::RegisteredChip(position), &last_public_key);
+        e.storage()
+            .persistent()
+            .set(&ChipRegistryStorageKey::ChipPosition(last_public_key), &position);
+    }
+
+    e.storage()
+        .persistent()
+        .remove(&ChipRegistryStorageKey::RegisteredChip(last));
+    e.storage().persistent().remove(&position_key);
+    e.storage()
+        .instance()
+        .set(&ChipRegistryStorageKey::ChipCount, &last);
+}
@@ -0,0 +1,133 @@
+//! Per-chip operation counters for support triage
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Bytes, BytesN, Env};
+use crate::contract::{ChipKey, ChipOperation};
+use crate::{contract, errors, events, NFCtoNFTContract, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ChipStats {
+    pub mints: u32,
+    pub claims: u32,
+    pub transfers: u32,
+}
+
+#[contracttype]
+pub enum ChipStatsStorageKey {
+    ChipStats(BytesN<65>),
+    ScanCount(BytesN<65>),
+}
+
+pub trait ChipStatistics {
+    /// Returns how many times a chip was used to mint, claim and transfer.
+    ///
+    /// Only successful operations are counted. Transfers include the ones
+    /// without the chip, such as `admin_transfer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    fn chip_stats(e: &Env, public_key: BytesN<65>) -> ChipStats;
+
+    /// Records a tap of a minted chip, claimed or not, and returns its
+    /// scan count.
+    ///
+    /// The chip signs `build_message_hash(Scan, message, None, nonce, expiration_ledger)`,
+    /// which consumes the nonce like any other chip operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["scan", public_key: BytesN<65>]`
+    /// * data - `[count: u32]`
+    fn record_scan(e: &Env, public_key: BytesN<65>, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, nonce: u64, expiration_ledger: u32) -> u32;
+
+    /// Returns how many times a chip was scanned with `record_scan`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    fn scan_count(e: &Env, public_key: BytesN<65>) -> u32;
+}
+
+#[contractimpl]
+impl ChipStatistics for StellarMerchShop {
+    fn chip_stats(e: &Env, public_key: BytesN<65>) -> ChipStats {
+        e.storage()
+            .persistent()
+            .get(&ChipStatsStorageKey::ChipStats(public_key))
+            .unwrap_or_default()
+    }
+
+    fn record_scan(
+        e: &Env,
+        public_key: BytesN<65>,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) -> u32 {
+        // Verify the chip was minted
+        StellarMerchShop::token_id(e, public_key.clone());
+
+        contract::verify_chip_signature(e, ChipOperation::Scan, message, signature, recovery_id, ChipKey::Ecdsa(public_key.clone()), nonce, expiration_ledger, None);
+
+        let count = Self::scan_count(e, public_key.clone())
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+        e.storage()
+            .persistent()
+            .set(&ChipStatsStorageKey::ScanCount(public_key.clone()), &count);
+
+        events::Scan { public_key, count }.publish(e);
+        count
+    }
+
+    fn scan_count(e: &Env, public_key: BytesN<65>) -> u32 {
+        e.storage()
+            .persistent()
+            .get(&ChipStatsStorageKey::ScanCount(public_key))
+            .unwrap_or(0)
+    }
+}
+
+fn update_stats(e: &Env, public_key: &BytesN<65>, update: impl FnOnce(&mut ChipStats) -> Option<()>) {
+    let mut stats = StellarMerchShop::chip_stats(e, public_key.clone());
+    update(&mut stats).unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    e.storage()
+        .persistent()
+        .set(&ChipStatsStorageKey::ChipStats(public_key.clone()), &stats);
+}
+
+pub(crate) fn record_mint(e: &Env, public_key: &BytesN<65>) {
+    update_stats(e, public_key, |stats| {
+        stats.mints = stats.mints.checked_add(1)?;
+        Some(())
+    });
+}
+
+pub(crate) fn record_claim(e: &Env, public_key: &BytesN<65>) {
+    update_stats(e, public_key, |stats| {
+        stats.claims = stats.claims.checked_add(1)?;
+        Some(())
+    });
+}
+
+pub(crate) fn record_transfer(e: &Env, public_key: &BytesN<65>) {
+    update_stats(e, public_key, |stats| {
+        stats.transfers = stats.transfers.checked_add(1)?;
+        Some(())
+    });
+}
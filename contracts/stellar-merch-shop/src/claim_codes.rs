@@ -0,0 +1,102 @@
+//! One-time claim codes as an alternative to chip signatures for the first claim
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Bytes, BytesN, Env};
+use crate::{contract, errors, events, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimCode {
+    pub code_hash: BytesN<32>,
+    pub expiry_ledger: u32,
+    pub redeemed: bool,
+}
+
+#[contracttype]
+pub enum ClaimCodeStorageKey {
+    ClaimCode(u64),
+}
+
+pub trait ClaimCodes {
+    /// Issues a one-time claim code for the unclaimed `token_id` token.
+    ///
+    /// The code itself is sent to the customer off-chain, only its hash is
+    /// stored. Issuing again replaces the previous code.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `code_hash` - SHA-256 hash of the claim code.
+    /// * `expiry_ledger` - First ledger at which the code can no longer be redeemed.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["claim_code_issued", token_id: u64]`
+    /// * data - `[expiry_ledger: u32]`
+    fn issue_claim_code(e: &Env, token_id: u64, code_hash: BytesN<32>, expiry_ledger: u32);
+
+    /// Claims `token_id` token with a claim code instead of a chip signature.
+    ///
+    /// The code is consumed. Later transfers still require the chip.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `claimant` - Account of the claimant.
+    /// * `token_id` - Token id as a number.
+    /// * `code_preimage` - The claim code.
+    ///
+    /// # Returns
+    ///
+    /// The token ID that was claimed.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["claim", claimant: Address, public_key: BytesN<65>, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[token_id: u64]`
+    fn claim_with_code(e: &Env, claimant: Address, token_id: u64, code_preimage: Bytes) -> u64;
+}
+
+#[contractimpl]
+impl ClaimCodes for StellarMerchShop {
+    fn issue_claim_code(e: &Env, token_id: u64, code_hash: BytesN<32>, expiry_ledger: u32) {
+        contract::require_admin(e);
+
+        // Verify the token exists and is still up for claim
+        if contract::token_data(e, token_id).owner.is_some() {
+            panic_with_error!(e, &errors::NonFungibleTokenError::TokenAlreadyMinted);
+        }
+
+        let claim_code = ClaimCode { code_hash, expiry_ledger, redeemed: false };
+        e.storage().persistent().set(&ClaimCodeStorageKey::ClaimCode(token_id), &claim_code);
+
+        events::ClaimCodeIssued { token_id, expiry_ledger }.publish(e);
+    }
+
+    fn claim_with_code(e: &Env, claimant: Address, token_id: u64, code_preimage: Bytes) -> u64 {
+        let key = ClaimCodeStorageKey::ClaimCode(token_id);
+        let mut claim_code: ClaimCode = e
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::InvalidClaimCode));
+
+        if claim_code.redeemed {
+            panic_with_error!(e, &errors::NonFungibleTokenError::ClaimCodeRedeemed);
+        }
+        if e.ledger().sequence() >= claim_code.expiry_ledger {
+            panic_with_error!(e, &errors::NonFungibleTokenError::ClaimCodeExpired);
+        }
+        if BytesN::from(e.crypto().sha256(&code_preimage)) != claim_code.code_hash {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidClaimCode);
+        }
+
+        // Keep the redeemed code around so reuse is reported as such
+        claim_code.redeemed = true;
+        e.storage().persistent().set(&key, &claim_code);
+
+        contract::assign_claim(e, &claimant, token_id, claimant.clone(), None);
+
+        token_id
+    }
+}
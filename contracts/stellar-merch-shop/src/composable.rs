@@ -0,0 +1,167 @@
+//! Composable tokens: accessory tokens attached to a parent token
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Env, Vec};
+use crate::{contract, errors, events, merchants, NonFungibleToken, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Maximum number of children a parent token can carry
+pub const MAX_CHILDREN: u32 = 10;
+
+#[contracttype]
+pub enum ComposableStorageKey {
+    Children(u64),
+    Parent(u64),
+}
+
+pub trait Composable {
+    /// Attach `child_id` token to `parent_id` token.
+    ///
+    /// Both tokens must be owned by the same account. Attachments are one level
+    /// deep: a child cannot carry children of its own and a parent cannot be
+    /// attached to another token. A soulbound token cannot be attached, and
+    /// owner transfers of the parent are refused while a child could not be
+    /// transferred on its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `parent_id` - Token id of the parent.
+    /// * `child_id` - Token id of the accessory.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["attach", parent_id: u64, child_id: u64]`
+    /// * data - `[owner: Address]`
+    fn attach(e: &Env, parent_id: u64, child_id: u64);
+
+    /// Detach `child_id` token from `parent_id` token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `parent_id` - Token id of the parent.
+    /// * `child_id` - Token id of the accessory.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["detach", parent_id: u64, child_id: u64]`
+    /// * data - `[owner: Address]`
+    fn detach(e: &Env, parent_id: u64, child_id: u64);
+
+    /// Returns the token ids attached to `parent_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `parent_id` - Token id of the parent.
+    fn children_of(e: &Env, parent_id: u64) -> Vec<u64>;
+
+    /// Returns the parent of `child_id`, if attached.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `child_id` - Token id of the accessory.
+    fn parent_of(e: &Env, child_id: u64) -> Option<u64>;
+}
+
+#[contractimpl]
+impl Composable for StellarMerchShop {
+    fn attach(e: &Env, parent_id: u64, child_id: u64) {
+        let owner = StellarMerchShop::owner_of(e, parent_id);
+        owner.require_auth();
+
+        if StellarMerchShop::owner_of(e, child_id) != owner {
+            panic_with_error!(e, &errors::NonFungibleTokenError::IncorrectOwner);
+        }
+        contract::require_not_soulbound(e, child_id);
+
+        // Keep the hierarchy one level deep so cycles are impossible
+        if parent_id == child_id
+            || Self::parent_of(e, child_id).is_some()
+            || Self::parent_of(e, parent_id).is_some()
+            || !Self::children_of(e, child_id).is_empty()
+        {
+            panic_with_error!(e, &errors::NonFungibleTokenError::TokenAttached);
+        }
+
+        let mut children = Self::children_of(e, parent_id);
+        if children.len() >= MAX_CHILDREN {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+        }
+        children.push_back(child_id);
+
+        e.storage().persistent().set(&ComposableStorageKey::Children(parent_id), &children);
+        e.storage().persistent().set(&ComposableStorageKey::Parent(child_id), &parent_id);
+
+        events::Attach { parent_id, child_id, owner }.publish(e);
+    }
+
+    fn detach(e: &Env, parent_id: u64, child_id: u64) {
+        let owner = StellarMerchShop::owner_of(e, parent_id);
+        owner.require_auth();
+
+        let mut children = Self::children_of(e, parent_id);
+        let index = children
+            .first_index_of(child_id)
+            .unwrap_or_else(|| panic_with_error!(e, &errors::NonFungibleTokenError::NonExistentToken));
+        children.remove(index);
+
+        if children.is_empty() {
+            e.storage().persistent().remove(&ComposableStorageKey::Children(parent_id));
+        } else {
+            e.storage().persistent().set(&ComposableStorageKey::Children(parent_id), &children);
+        }
+        e.storage().persistent().remove(&ComposableStorageKey::Parent(child_id));
+
+        events::Detach { parent_id, child_id, owner }.publish(e);
+    }
+
+    fn children_of(e: &Env, parent_id: u64) -> Vec<u64> {
+        e.storage()
+            .persistent()
+            .get(&ComposableStorageKey::Children(parent_id))
+            .unwrap_or(Vec::new(e))
+    }
+
+    fn parent_of(e: &Env, child_id: u64) -> Option<u64> {
+        e.storage()
+            .persistent()
+            .get(&ComposableStorageKey::Parent(child_id))
+    }
+}
+
+/// Panic if `token_id` is attached to a parent, attached tokens only move with their parent
+pub(crate) fn require_detached(e: &Env, token_id: u64) {
+    if e.storage().persistent().has(&ComposableStorageKey::Parent(token_id)) {
+        panic_with_error!(e, &errors::NonFungibleTokenError::TokenAttached);
+    }
+}
+
+/// Panic if `token_id` has children attached
+pub(crate) fn require_no_children(e: &Env, token_id: u64) {
+    if !StellarMerchShop::children_of(e, token_id).is_empty() {
+        panic_with_error!(e, &errors::NonFungibleTokenError::TokenAttached);
+    }
+}
+
+/// Panic unless every child of `parent_id` passes the checks of an owner
+/// transfer, children move with their parent
+pub(crate) fn require_children_transferable(e: &Env, parent_id: u64, chip_signed: bool) {
+    for child_id in StellarMerchShop::children_of(e, parent_id).iter() {
+        contract::require_not_soulbound(e, child_id);
+        contract::require_security_level(e, child_id, chip_signed);
+        contract::require_cooldown_elapsed(e, child_id);
+    }
+}
+
+/// Move the ownership of every child of `parent_id` along with the parent
+pub(crate) fn move_children(e: &Env, from: &Address, to: &Address, parent_id: u64) {
+    for child_id in StellarMerchShop::children_of(e, parent_id).iter() {
+        contract::update_owner(e, from, to, child_id);
+
+        let merchant_id = merchants::token_merchant(e, child_id);
+        let sku = contract::sku_of(e, &contract::token_data(e, child_id).public_key);
+        events::Transfer { from: from.clone(), to: to.clone(), merchant_id, sku, token_id: child_id }.publish(e);
+        contract::publish_activity(e, events::Operation::Transfer, child_id, Some(from.clone()), Some(to.clone()), None);
+    }
+}
@@ -1,255 +1,1252 @@
 //! NFC - NFT binding
 
-use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Bytes, BytesN, Env, String};
-use soroban_sdk::xdr::ToXdr;
-use crate::{errors, events, NFCtoNFTContract, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+use soroban_sdk::{contractimpl, contracttype, crypto::Hash, panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Val, Vec};
+use merch_crypto::soroban::{chip_message_hash, chip_message_preimage};
+use crate::chip_info::{self, ChipInfo};
+use crate::series::{self, Series};
+use crate::{attributes, chip_allowlist, chip_auth, chip_registry, chip_stats, errors, events, merchants, onchain_metadata, owner_index, purchase_orders, receiver, sales, token_index, token_ttl, NFCtoNFTContract, NonFungibleToken, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+#[cfg(feature = "composable")]
+use crate::composable;
+#[cfg(feature = "escrow")]
+use crate::escrow;
+#[cfg(feature = "partner-mint")]
+use crate::partner_mint;
+#[cfg(feature = "provenance")]
+use crate::{ownership_history, recent_claims};
+#[cfg(feature = "reservations")]
+use crate::reservation;
 
 #[contracttype]
 pub enum DataKey {
     Admin,
     NextTokenId,
+    /// Moved to the default series by `migrate`
     MaxTokens,
+    BuildInfo,
+    TransferCooldown,
+    NonceWindow,
+    ClaimedCount,
+    CheckpointInterval,
+    ChipAuth,
+    ActivityEvents,
+    MaxMessageLength,
+    PendingAdmin,
+    BurnedCount,
+    ClaimDeadline,
+    /// Next token id `migrate` moves to the `Token` layout
+    TokenMigrationCursor,
+    /// Number of tokens moved to the `Token` layout, by `migrate` or on read
+    MigratedTokenCount,
+    OnchainMetadata,
+}
+
+/// Interface version returned by `version`, bumped whenever a release
+/// changes the entry points or the storage layout
+///
+/// The `upgrade-fixture` feature builds the next version, the code the wasm
+/// upgrade test switches to.
+pub const CONTRACT_VERSION: u32 = 3 + cfg!(feature = "upgrade-fixture") as u32;
+
+/// Crate version, also published as the `Version` contract meta entry
+pub const BUILD_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Output of `git describe` passed through the `GIT_DESCRIBE` environment variable at build time
+pub const BUILD_GIT_DESCRIBE: &str = match option_env!("GIT_DESCRIBE") {
+    Some(describe) => describe,
+    None => "unknown",
+};
+
+/// Cargo profile the contract was built with
+pub const BUILD_PROFILE: &str = if cfg!(debug_assertions) { "debug" } else { "release" };
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuildInfo {
+    pub version: String,
+    pub git_describe: String,
+    pub profile: String,
 }
 
 #[contracttype]
 pub enum NFTStorageKey {
     ChipNonceByPublicKey(BytesN<65>),
+    /// Moved to `Token` by `migrate`
     Owner(u64),
+    /// Moved to `Token` by `migrate`
     PublicKey(u64),
+    Token(u64),
     TokenIdByPublicKey(BytesN<65>),
     Balance(Address),
+    LastTransferLedger(u64),
     Name,
     Symbol,
+    /// Moved to the default series by `migrate`
     URI,
+    UnclaimedURI,
+    Approval(u64),
+    ApprovalForAll(Address, Address),
+    /// Transfers left to an operator approved with `approve_operator_limited`
+    OperatorAllowance(Address, Address),
+    /// Only recipient of the token's approval, set by `approve_for_recipient`
+    ApprovalRecipient(u64),
+    TokenURI(u64),
+    Revoked(BytesN<65>),
+    Soulbound(u64),
+    ChipCurve(BytesN<65>),
+    SecurityLevel(u64),
 }
 
-#[contractimpl]
-impl NFCtoNFTContract for StellarMerchShop {
+/// Elliptic curve a chip signs with
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CurveType {
+    Secp256k1,
+    Secp256r1,
+}
 
-    fn __constructor(e: &Env, admin: Address, name: String, symbol: String, uri: String, max_tokens: u64) {
-        e.storage().instance().set(&DataKey::Admin, &admin);
+/// Factors an owner-initiated transfer of a token must present
+///
+/// Every chip-signed transfer also requires the owner's authorization, so
+/// there is no chip-only level: requiring the chip means requiring both.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SecurityLevel {
+    /// The owner's authorization is enough, `transfer` and `transfer_from` are allowed
+    OwnerOnly,
+    /// The owner's authorization and a chip signature, only the chip-signed transfers are allowed
+    Both,
+}
+
+/// Public key of a chip, whatever scheme it signs with
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ChipKey {
+    /// Uncompressed SEC1 key, secp256k1 unless registered with `set_chip_curve`
+    Ecdsa(BytesN<65>),
+    Ed25519(BytesN<32>),
+}
+
+/// Operation a chip signature authorizes, its tag is part of the signed
+/// message so a signature produced for one operation cannot authorize another
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ChipOperation {
+    Mint = 0,
+    Claim = 1,
+    Transfer = 2,
+    Burn = 3,
+    Redeem = 4,
+    Scan = 5,
+}
+
+/// Chip and owner of a token, read together on every hot path
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenData {
+    pub owner: Option<Address>,
+    pub public_key: BytesN<65>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenApproval {
+    pub approved: Address,
+    pub live_until_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintPreview {
+    pub would_succeed: bool,
+    pub error_code: u32,
+    pub predicted_token_id: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenInfo {
+    pub token_id: u64,
+    pub owner: Option<Address>,
+    pub public_key: BytesN<65>,
+    pub uri: String,
+    pub claimed: bool,
+}
 
-        e.storage().instance().set(&NFTStorageKey::Name, &name);
-        e.storage().instance().set(&NFTStorageKey::Symbol, &symbol);
-        e.storage().instance().set(&NFTStorageKey::URI, &uri);
+/// Maximum number of items accepted by `verify_batch`
+pub const MAX_VERIFY_BATCH: u32 = 20;
 
-        e.storage().instance().set(&DataKey::MaxTokens, &max_tokens);
-        e.storage().instance().set(&DataKey::NextTokenId, &0u64);
+/// Maximum number of public keys accepted by `register_chips`, each chip
+/// touches over a dozen ledger entries and a transaction's footprint is
+/// limited to 100: four chips of a series use 85 entries, five already need 105
+pub const MAX_REGISTER_BATCH: u32 = 4;
+
+/// Maximum number of legacy tokens `migrate` rewrites per call, each one
+/// writes three entries, six when added to the owner index, and a batch of
+/// claimed tokens has to fit in the 100 ledger entries of a transaction
+pub const MAX_MIGRATE_BATCH: u64 = 7;
+
+/// Maximum number of token ids accepted by `tokens_info`
+pub const MAX_TOKENS_INFO_BATCH: u32 = 50;
+
+/// Default maximum length in bytes of a signed message
+pub const DEFAULT_MAX_MESSAGE_LENGTH: u32 = 256;
+
+/// Upper bound the admin can raise the maximum message length to
+pub const MAX_MESSAGE_LENGTH_CEILING: u32 = 1024;
+
+/// Maximum length in bytes of the base URI
+pub const MAX_BASE_URI_LENGTH: u32 = 200;
+
+/// Maximum length in bytes of a token URI override
+pub const MAX_TOKEN_URI_LENGTH: u32 = 200;
+
+/// Maximum length in bytes of the collection and series names
+pub const MAX_NAME_LENGTH: u32 = 64;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifyItem {
+    pub message: Bytes,
+    pub signature: BytesN<64>,
+    pub recovery_id: Option<u32>,
+    pub public_key: BytesN<65>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProductVerification {
+    pub is_genuine: bool,
+    pub token_id: Option<u64>,
+    pub owner: Option<Address>,
+    pub revoked: bool,
+}
+
+#[contractimpl]
+impl NFCtoNFTContract for StellarMerchShop {
+
+    fn __constructor(
+        e: &Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        uri: String,
+        max_tokens: u64,
+        activity_events: bool,
+        onchain_metadata: bool,
+    ) {
+        initialize(e, &admin, &name, &symbol, &uri, max_tokens, activity_events, onchain_metadata)
+            .unwrap_or_else(|error| panic_with_error!(e, error));
     }
 
     fn upgrade(e: &Env, wasm_hash: BytesN<32>) {
-        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        let admin = require_admin(e);
 
         e.deployer().update_current_contract_wasm(wasm_hash.clone());
 
         events::Upgrade { admin, wasm_hash: wasm_hash.into() }.publish(e);
     }
 
+    fn migrate(e: &Env) -> bool {
+        require_admin(e);
+
+        series::migrate_legacy_collection(e);
+        store_build_info(e);
+        migrate_token_data(e)
+    }
+
+    fn migration_progress(e: &Env) -> (u64, u64) {
+        let migrated: u64 = e.storage().instance().get(&DataKey::MigratedTokenCount).unwrap_or(0);
+        let next_token_id: u64 = e.storage().instance().get(&DataKey::NextTokenId).unwrap_or(0);
+        let cursor: u64 = e.storage().instance().get(&DataKey::TokenMigrationCursor).unwrap_or(0);
+
+        (migrated, next_token_id.saturating_sub(cursor))
+    }
+
+    fn version(_e: &Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
+    fn build_info(e: &Env) -> BuildInfo {
+        e.storage()
+            .instance()
+            .get(&DataKey::BuildInfo)
+            .unwrap()
+    }
+
+    fn transfer_admin(e: &Env, new_admin: Address) {
+        require_admin(e);
+
+        e.storage().instance().set(&DataKey::PendingAdmin, &new_admin);
+
+        events::AdminProposed { admin: Self::get_admin(e), pending_admin: new_admin }.publish(e);
+    }
+
+    fn accept_admin(e: &Env) {
+        let admin = Self::pending_admin(e)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NoPendingAdmin));
+        // The new admin proves it controls the account before taking over
+        admin.require_auth();
+
+        let previous_admin = Self::get_admin(e);
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().remove(&DataKey::PendingAdmin);
+
+        events::AdminChanged { previous_admin, admin }.publish(e);
+    }
+
+    fn get_admin(e: &Env) -> Address {
+        e.storage().instance().get(&DataKey::Admin).unwrap()
+    }
+
+    fn pending_admin(e: &Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
     fn mint(
         e: &Env,
+        series_id: u32,
         message: Bytes,
         signature: BytesN<64>,
-        recovery_id: u32,
+        recovery_id: Option<u32>,
         public_key: BytesN<65>,
-        nonce: u32,
+        nonce: u64,
+        expiration_ledger: u32,
+        chip_info: Option<ChipInfo>,
+        po_number: Option<u32>,
     ) -> u64 {
-        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        merchants::require_series_admin(e, series_id);
 
-        verify_chip_signature(e, message, signature, recovery_id, public_key.clone(), nonce);
+        mint_chip(e, series_id, message, signature, recovery_id, ChipKey::Ecdsa(public_key), nonce, expiration_ledger, None, chip_info, po_number)
+    }
 
-        let public_key_lookup = NFTStorageKey::TokenIdByPublicKey(public_key.clone());
-        if e.storage().persistent().has(&public_key_lookup) {
-            panic_with_error!(&e, &errors::NonFungibleTokenError::TokenAlreadyMinted);
-        }
+    fn mint_der(
+        e: &Env,
+        series_id: u32,
+        message: Bytes,
+        der_signature: Bytes,
+        public_key: BytesN<65>,
+        nonce: u64,
+        expiration_ledger: u32,
+        chip_info: Option<ChipInfo>,
+        po_number: Option<u32>,
+    ) -> u64 {
+        let signature = der_signature_bytes(e, &der_signature);
+        Self::mint(e, series_id, message, signature, None, public_key, nonce, expiration_ledger, chip_info, po_number)
+    }
 
-        let token_id: u64 = e
-            .storage()
-            .instance()
-            .get(&DataKey::NextTokenId)
-            .unwrap();
-        let max_tokens: u64 = e
-            .storage()
-            .instance()
-            .get(&DataKey::MaxTokens)
-            .unwrap();
+    fn mint_ed25519(
+        e: &Env,
+        series_id: u32,
+        message: Bytes,
+        signature: BytesN<64>,
+        public_key: BytesN<32>,
+        nonce: u64,
+        expiration_ledger: u32,
+        chip_info: Option<ChipInfo>,
+        po_number: Option<u32>,
+    ) -> u64 {
+        merchants::require_series_admin(e, series_id);
+
+        mint_chip(e, series_id, message, signature, None, ChipKey::Ed25519(public_key), nonce, expiration_ledger, None, chip_info, po_number)
+    }
 
-        if token_id >= max_tokens {
-            panic_with_error!(&e, &errors::NonFungibleTokenError::TokenIDsAreDepleted);
+    fn simulate_mint(
+        e: &Env,
+        series_id: u32,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) -> MintPreview {
+        let chip_key = ChipKey::Ecdsa(public_key.clone());
+        let preview = check_chip_signature(e, ChipOperation::Mint, &message, &signature, recovery_id, &chip_key, nonce, expiration_ledger, None)
+            .and_then(|_| check_mintable(e, series_id, &public_key));
+        match preview {
+            Ok(token_id) => MintPreview { would_succeed: true, error_code: 0, predicted_token_id: token_id },
+            Err(error) => MintPreview { would_succeed: false, error_code: error as u32, predicted_token_id: 0 },
         }
+    }
 
-        e.storage().instance().set(&DataKey::NextTokenId, &(token_id + 1));
-        e.storage().persistent().set(&public_key_lookup, &token_id);
-        e.storage().persistent().set(&NFTStorageKey::PublicKey(token_id), &public_key);
+    fn verify(
+        e: &Env,
+        operation: ChipOperation,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        recipient: Option<Address>,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) -> bool {
+        check_chip_signature(e, operation, &message, &signature, recovery_id, &ChipKey::Ecdsa(public_key), nonce, expiration_ledger, recipient.as_ref()).is_ok()
+    }
 
-        events::Mint { token_id }.publish(&e);
+    fn claim(
+        e: &Env,
+        claimant: Address,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) -> u64 {
+        claim_chip(e, claimant, message, signature, recovery_id, ChipKey::Ecdsa(public_key), nonce, expiration_ledger)
+    }
 
-        token_id
+    fn claim_der(
+        e: &Env,
+        claimant: Address,
+        message: Bytes,
+        der_signature: Bytes,
+        public_key: BytesN<65>,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) -> u64 {
+        let signature = der_signature_bytes(e, &der_signature);
+        Self::claim(e, claimant, message, signature, None, public_key, nonce, expiration_ledger)
     }
 
-    fn claim(
+    fn claim_ed25519(
+        e: &Env,
+        claimant: Address,
+        message: Bytes,
+        signature: BytesN<64>,
+        public_key: BytesN<32>,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) -> u64 {
+        claim_chip(e, claimant, message, signature, None, ChipKey::Ed25519(public_key), nonce, expiration_ledger)
+    }
+
+    fn claim_with_donation(
         e: &Env,
         claimant: Address,
         message: Bytes,
         signature: BytesN<64>,
-        recovery_id: u32,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        nonce: u64,
+        expiration_ledger: u32,
+        donation: i128,
+    ) -> u64 {
+        let token_id = Self::claim(e, claimant.clone(), message, signature, recovery_id, public_key, nonce, expiration_ledger);
+        sales::collect_donation(e, token_id, &claimant, donation);
+        token_id
+    }
+
+    fn mint_and_claim(
+        e: &Env,
+        to: Address,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
         public_key: BytesN<65>,
-        nonce: u32,
+        nonce: u64,
+        expiration_ledger: u32,
     ) -> u64 {
-        verify_chip_signature(e, message, signature, recovery_id, public_key.clone(), nonce);
+        require_admin(e);
+        to.require_auth();
 
-        // Look up token_id from public_key
-        let token_id = Self::token_id(e, public_key.clone());
+        let token_id = mint_chip(e, series::DEFAULT_SERIES_ID, message, signature, recovery_id, ChipKey::Ecdsa(public_key), nonce, expiration_ledger, Some(&to), None, None);
+        assign_claim(e, &to, token_id, to.clone(), Some(nonce));
 
-        // Verify token is not already claimed
-        if e.storage().persistent().has(&NFTStorageKey::Owner(token_id)) {
-            panic_with_error!(e, &errors::NonFungibleTokenError::TokenAlreadyMinted);
+        token_id
+    }
+
+    fn register_chips(e: &Env, series_id: u32, public_keys: Vec<BytesN<65>>) -> Vec<u64> {
+        merchants::require_series_admin(e, series_id);
+
+        if public_keys.len() > MAX_REGISTER_BATCH {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+        }
+
+        let mut token_ids = Vec::new(e);
+        for public_key in public_keys.iter() {
+            require_valid_public_key(e, &public_key);
+            token_ids.push_back(register_chip(e, series_id, public_key, None, None, None, None));
         }
+        token_ids
+    }
 
-        e.storage().persistent().set(&NFTStorageKey::Owner(token_id), &claimant);
+    fn set_claim_deadline(e: &Env, ledger_timestamp: u64) {
+        require_admin(e);
 
-        let claimant_balance = Self::balance(e, claimant.clone());
-        e.storage().persistent().set(&NFTStorageKey::Balance(claimant.clone()), &(claimant_balance + 1));
+        e.storage().instance().set(&DataKey::ClaimDeadline, &ledger_timestamp);
 
-        events::Claim { claimant, token_id }.publish(&e);
+        events::ClaimDeadlineUpdated { ledger_timestamp }.publish(e);
+    }
 
-        token_id
+    fn claim_deadline(e: &Env) -> u64 {
+        e.storage().instance().get(&DataKey::ClaimDeadline).unwrap_or(0)
+    }
+
+    fn reclaim_unclaimed(e: &Env, token_id: u64) {
+        require_admin(e);
+
+        if !claim_window_closed(e) {
+            panic_with_error!(e, &errors::NonFungibleTokenError::ClaimWindowOpen);
+        }
+        let token = token_data(e, token_id);
+        if token.owner.is_some() {
+            panic_with_error!(e, &errors::NonFungibleTokenError::TokenAlreadyClaimed);
+        }
+        let public_key = token.public_key;
+
+        // The activity event reads the public key, publish it before the token is gone
+        publish_activity(e, events::Operation::Burn, token_id, None, None, None);
+
+        remove_token(e, token_id);
+        // Clearing the registration lets the chip be minted again
+        e.storage().persistent().remove(&NFTStorageKey::TokenIdByPublicKey(public_key.clone()));
+
+        events::Reclaim { token_id, public_key }.publish(e);
+    }
+
+    fn chip_transfer(
+        e: &Env,
+        from: Address,
+        to: Address,
+        token_id: u64,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) {
+        transfer_chip(e, from, to, token_id, message, signature, recovery_id, ChipKey::Ecdsa(public_key), nonce, expiration_ledger);
+    }
+
+    fn chip_transfer_der(
+        e: &Env,
+        from: Address,
+        to: Address,
+        token_id: u64,
+        message: Bytes,
+        der_signature: Bytes,
+        public_key: BytesN<65>,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) {
+        let signature = der_signature_bytes(e, &der_signature);
+        Self::chip_transfer(e, from, to, token_id, message, signature, None, public_key, nonce, expiration_ledger)
     }
 
-    fn transfer(
+    fn chip_transfer_ed25519(
         e: &Env,
         from: Address,
         to: Address,
         token_id: u64,
         message: Bytes,
         signature: BytesN<64>,
-        recovery_id: u32,
+        public_key: BytesN<32>,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) {
+        transfer_chip(e, from, to, token_id, message, signature, None, ChipKey::Ed25519(public_key), nonce, expiration_ledger);
+    }
+
+    fn burn(
+        e: &Env,
+        from: Address,
+        token_id: u64,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
         public_key: BytesN<65>,
-        nonce: u32,
+        nonce: u64,
+        expiration_ledger: u32,
     ) {
         from.require_auth();
 
-        verify_chip_signature(e, message, signature, recovery_id, public_key.clone(), nonce);
+        verify_token_chip_signature(e, ChipOperation::Burn, token_id, message, signature, recovery_id, ChipKey::Ecdsa(public_key.clone()), nonce, expiration_ledger, &from);
+
+        burn_token(e, &from, token_id, Some(nonce));
+        // Clearing the registration lets the chip be minted again
+        e.storage().persistent().remove(&NFTStorageKey::TokenIdByPublicKey(public_key));
+    }
+
+    fn burn_from_owner(e: &Env, owner: Address, token_id: u64) {
+        owner.require_auth();
+
+        // The chip registration is kept as a tombstone so a dead chip cannot be minted again
+        burn_token(e, &owner, token_id, None);
+    }
 
-        // Verify the chip public_key corresponds to that specific token_id
-        let token_id_public_key: BytesN<65> = Self::public_key(e, token_id);
+    fn admin_transfer(e: &Env, token_id: u64, to: Address, reason: u32) {
+        let admin = require_admin(e);
 
-        if token_id_public_key != public_key {
-            panic_with_error!(&e, &errors::NonFungibleTokenError::InvalidSignature);
+        if reason == 0 {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidReasonCode);
         }
 
-        let owner = Self::owner_of(e, token_id);
-        if owner != from || from == to {
+        let from = Self::owner_of(e, token_id);
+        if from == to {
             panic_with_error!(e, &errors::NonFungibleTokenError::IncorrectOwner);
         }
+        #[cfg(feature = "composable")]
+        composable::require_detached(e, token_id);
+        #[cfg(feature = "escrow")]
+        escrow::require_not_escrowed(e, token_id);
 
-        e.storage().persistent().set(&NFTStorageKey::Owner(token_id), &to);
+        update_owner(e, &from, &to, token_id);
+        #[cfg(feature = "composable")]
+        composable::move_children(e, &from, &to, token_id);
+        chip_stats::record_transfer(e, &Self::public_key(e, token_id));
 
-        let from_balance = Self::balance(e, from.clone());
-        e.storage().persistent().set(&NFTStorageKey::Balance(from.clone()), &(from_balance - 1));
-        let to_balance = Self::balance(e, to.clone());
-        e.storage().persistent().set(&NFTStorageKey::Balance(to.clone()), &(to_balance + 1));
+        events::AdminTransfer { from: from.clone(), to: to.clone(), token_id, reason }.publish(e);
+        publish_activity(e, events::Operation::AdminTransfer, token_id, Some(from.clone()), Some(to.clone()), None);
 
-        events::Transfer { from, to, token_id }.publish(e);
+        receiver::check_received(e, &admin, &from, &to, token_id);
     }
 
-    fn get_nonce(e: &Env, public_key: BytesN<65>) -> u32 {
-        let nonce_key = NFTStorageKey::ChipNonceByPublicKey(public_key);
-        e.storage()
-            .persistent()
-            .get(&nonce_key)
-            .unwrap_or(0u32)  // Default to 0 if not set (first use)
-    }
+    fn approve_operator_limited(e: &Env, owner: Address, operator: Address, remaining: u32, live_until_ledger: u32) {
+        owner.require_auth();
 
-    fn balance(e: &Env, owner: Address) -> u32 {
-        e.storage()
-            .persistent()
-            .get(&NFTStorageKey::Balance(owner))
-            .unwrap_or(0u32)
+        let approval_key = NFTStorageKey::ApprovalForAll(owner.clone(), operator.clone());
+        let allowance_key = NFTStorageKey::OperatorAllowance(owner.clone(), operator.clone());
+        if remaining == 0 || live_until_ledger < e.ledger().sequence() {
+            e.storage().persistent().remove(&approval_key);
+            e.storage().persistent().remove(&allowance_key);
+        } else {
+            e.storage().persistent().set(&approval_key, &live_until_ledger);
+            e.storage().persistent().set(&allowance_key, &remaining);
+        }
+
+        events::ApproveOperatorLimited { owner, operator, remaining, live_until_ledger }.publish(e);
     }
 
-    fn owner_of(e: &Env, token_id: u64) -> Address {
-        // Verify the token exists (this will panic if it doesn't)
-        Self::public_key(e, token_id);
+    fn operator_allowance(e: &Env, owner: Address, operator: Address) -> Option<u32> {
+        if !Self::is_approved_for_all(e, owner.clone(), operator.clone()) {
+            return None;
+        }
 
-        // Token exists, now check if it has an owner
-        e.storage().persistent()
-        .get(&NFTStorageKey::Owner(token_id))
-        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::TokenNotClaimed))
+        e.storage().persistent().get(&NFTStorageKey::OperatorAllowance(owner, operator))
     }
 
-    fn name(e: &Env) -> String {
-            e.storage()
-            .instance()
-            .get(&NFTStorageKey::Name)
-            .unwrap()
+    fn approve_for_recipient(e: &Env, approver: Address, approved: Address, token_id: u64, recipient: Address, live_until_ledger: u32) {
+        set_approval(e, &approver, &approved, token_id, live_until_ledger, Some(&recipient));
+
+        events::ApproveForRecipient { approver, token_id, approved, recipient, live_until_ledger }.publish(e);
     }
 
-    fn symbol(e: &Env) -> String {
-            e.storage()
-            .instance()
-            .get(&NFTStorageKey::Symbol)
-            .unwrap()
+    fn approved_recipient(e: &Env, token_id: u64) -> Option<Address> {
+        Self::get_approved(e, token_id)?;
+
+        e.storage().persistent().get(&NFTStorageKey::ApprovalRecipient(token_id))
     }
 
-    fn token_uri(e: &Env, token_id: u64) -> String {
-        // Verify token exists (this will panic if it doesn't)
+    fn set_soulbound(e: &Env, token_id: u64, soulbound: bool) {
+        require_admin(e);
+
+        // Verify token exists
         Self::public_key(e, token_id);
 
-        let base_uri: String = e
-            .storage()
-            .instance()
-            .get(&NFTStorageKey::URI)
-            .unwrap();
+        if soulbound {
+            e.storage().persistent().set(&NFTStorageKey::Soulbound(token_id), &true);
+        } else {
+            e.storage().persistent().remove(&NFTStorageKey::Soulbound(token_id));
+        }
 
-        // Construct URI: {base_uri}/{token_id}
-        let mut uri_bytes = Bytes::new(e);
-        uri_bytes.append(&Bytes::from(base_uri));
-        uri_bytes.append(&Bytes::from_slice(e, b"/"));
-        uri_bytes.append(&u64_to_decimal_bytes(e, token_id));
+        events::SoulboundUpdated { token_id, soulbound }.publish(e);
+    }
 
-        String::from(uri_bytes)
+    fn is_soulbound(e: &Env, token_id: u64) -> bool {
+        e.storage().persistent().has(&NFTStorageKey::Soulbound(token_id))
     }
 
-    fn token_id(e: &Env, public_key: BytesN<65>) -> u64 {
-        let public_key_lookup = NFTStorageKey::TokenIdByPublicKey(public_key);
-        e.storage()
-            .persistent()
-            .get::<NFTStorageKey, u64>(&public_key_lookup)
-            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NonExistentToken))
+    fn set_security_level(e: &Env, token_id: u64, level: SecurityLevel) {
+        require_admin(e);
+
+        // Verify token exists
+        Self::public_key(e, token_id);
+
+        if level == SecurityLevel::OwnerOnly {
+            e.storage().persistent().remove(&NFTStorageKey::SecurityLevel(token_id));
+        } else {
+            e.storage().persistent().set(&NFTStorageKey::SecurityLevel(token_id), &level);
+        }
+
+        events::SecurityLevelUpdated { token_id, level }.publish(e);
     }
 
-    fn public_key(e: &Env, token_id: u64) -> BytesN<65> {
+    fn security_level(e: &Env, token_id: u64) -> SecurityLevel {
         e.storage()
             .persistent()
-            .get(&NFTStorageKey::PublicKey(token_id))
-            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NonExistentToken))
+            .get(&NFTStorageKey::SecurityLevel(token_id))
+            .unwrap_or(SecurityLevel::OwnerOnly)
     }
-}
 
-/// Convert an u64 to its decimal string representation as Bytes
-/// Implementation inspired by OpenZeppelin's token_id_to_string
-pub(crate) fn u64_to_decimal_bytes(e: &Env, mut value: u64) -> Bytes {
-    if value == 0 {
-        return Bytes::from_slice(e, b"0");
-    }
+    fn rebind_chip(e: &Env, token_id: u64, public_key: BytesN<65>) {
+        require_admin(e);
+        require_valid_public_key(e, &public_key);
 
-    // Count digits (equivalent to log10(value) + 1 in no_std)
-    let mut temp = value;
-    let mut length = 0;
-    while temp > 0 {
-        length += 1;
-        temp /= 10;
+        let mut token = token_data(e, token_id);
+        if let Some(owner) = &token.owner {
+            owner.require_auth();
+        }
+        let previous_public_key = token.public_key.clone();
+        if e.storage().persistent().has(&NFTStorageKey::TokenIdByPublicKey(public_key.clone())) {
+            panic_with_error!(e, &errors::NonFungibleTokenError::TokenAlreadyMinted);
+        }
+
+        // The new chip starts from its own nonce, the old one keeps its nonce
+        // so its past signatures cannot be replayed if it is bound again
+        e.storage().persistent().remove(&NFTStorageKey::TokenIdByPublicKey(previous_public_key.clone()));
+        e.storage().persistent().set(&NFTStorageKey::TokenIdByPublicKey(public_key.clone()), &token_id);
+        token.public_key = public_key.clone();
+        e.storage().persistent().set(&NFTStorageKey::Token(token_id), &token);
+        chip_registry::add_chip(e, &public_key);
+        token_ttl::extend_token_entries(e, token_id, &public_key, token_ttl::MINT_TTL_LEDGERS);
+
+        events::ChipRebound { token_id, previous_public_key, public_key }.publish(e);
     }
 
-    // Allocate buffer with max size (20 for u64)
-    let mut buffer = [0u8; 20];
+    fn set_transfer_cooldown(e: &Env, ledgers: u32) {
+        require_admin(e);
 
-    // Fill from right to left (most significant digit first)
-    let mut i = length;
-    while value > 0 {
-        i -= 1;
+        e.storage().instance().set(&DataKey::TransferCooldown, &ledgers);
+
+        events::TransferCooldownUpdated { ledgers }.publish(e);
+    }
+
+    fn transfer_cooldown(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::TransferCooldown)
+            .unwrap_or(0u32)
+    }
+
+    fn set_nonce_window(e: &Env, window: u32) {
+        require_admin(e);
+
+        e.storage().instance().set(&DataKey::NonceWindow, &window);
+
+        events::NonceWindowUpdated { window }.publish(e);
+    }
+
+    fn nonce_window(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::NonceWindow)
+            .unwrap_or(0u32)
+    }
+
+    fn set_chip_auth(e: &Env, chip_auth: Option<Address>) {
+        require_admin(e);
+
+        match &chip_auth {
+            Some(chip_auth) => e.storage().instance().set(&DataKey::ChipAuth, chip_auth),
+            None => e.storage().instance().remove(&DataKey::ChipAuth),
+        }
+
+        events::ChipAuthUpdated { chip_auth }.publish(e);
+    }
+
+    fn chip_auth(e: &Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::ChipAuth)
+    }
+
+    fn set_max_message_length(e: &Env, length: u32) {
+        require_admin(e);
+
+        if length == 0 || length > MAX_MESSAGE_LENGTH_CEILING {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+        }
+        e.storage().instance().set(&DataKey::MaxMessageLength, &length);
+
+        events::MaxMessageLengthUpdated { length }.publish(e);
+    }
+
+    fn max_message_length(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MaxMessageLength)
+            .unwrap_or(DEFAULT_MAX_MESSAGE_LENGTH)
+    }
+
+    fn set_max_tokens(e: &Env, max_tokens: u64) {
+        require_admin(e);
+
+        let mut series = Self::series_info(e, series::DEFAULT_SERIES_ID);
+        if max_tokens < series.minted {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+        }
+        let previous_max_tokens = series.max_tokens;
+        series.max_tokens = max_tokens;
+        series::set_series_info(e, series::DEFAULT_SERIES_ID, &series);
+
+        events::MaxTokensUpdated { previous_max_tokens, max_tokens }.publish(e);
+    }
+
+    fn max_tokens(e: &Env) -> u64 {
+        Self::series_info(e, series::DEFAULT_SERIES_ID).max_tokens
+    }
+
+    fn remaining_supply(e: &Env) -> u64 {
+        let series = Self::series_info(e, series::DEFAULT_SERIES_ID);
+        series.max_tokens.saturating_sub(series.minted)
+    }
+
+    fn total_supply(e: &Env) -> u64 {
+        Self::minted_count(e).saturating_sub(Self::burned_count(e))
+    }
+
+    fn minted_count(e: &Env) -> u64 {
+        // NextTokenId doubles as the mint counter
+        e.storage().instance().get(&DataKey::NextTokenId).unwrap()
+    }
+
+    fn claimed_count(e: &Env) -> u64 {
+        e.storage().instance().get(&DataKey::ClaimedCount).unwrap_or(0)
+    }
+
+    fn burned_count(e: &Env) -> u64 {
+        e.storage().instance().get(&DataKey::BurnedCount).unwrap_or(0)
+    }
+
+    fn set_checkpoint_interval(e: &Env, mints: u32) {
+        require_admin(e);
+
+        e.storage().instance().set(&DataKey::CheckpointInterval, &mints);
+
+        events::CheckpointIntervalUpdated { mints }.publish(e);
+    }
+
+    fn checkpoint_interval(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::CheckpointInterval)
+            .unwrap_or(0u32)
+    }
+
+    fn verify_batch(e: &Env, items: Vec<VerifyItem>) -> Vec<bool> {
+        if items.len() > MAX_VERIFY_BATCH {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+        }
+
+        let mut results = Vec::new(e);
+        for item in items.iter() {
+            let message_hash = e.crypto().sha256(&item.message);
+            results.push_back(signature_matches(e, &message_hash, &item.signature, item.recovery_id, &item.public_key));
+        }
+        results
+    }
+
+    fn verify_product(
+        e: &Env,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+    ) -> ProductVerification {
+        if let Err(error) = check_message_length(e, &message) {
+            panic_with_error!(e, error);
+        }
+
+        let message_hash = e.crypto().sha256(&message);
+        let token_id: Option<u64> = e
+            .storage()
+            .persistent()
+            .get(&NFTStorageKey::TokenIdByPublicKey(public_key.clone()));
+
+        match token_id {
+            Some(token_id) if signature_matches(e, &message_hash, &signature, recovery_id, &public_key) => {
+                ProductVerification {
+                    is_genuine: true,
+                    token_id: Some(token_id),
+                    owner: token_data_opt(e, token_id).and_then(|token| token.owner),
+                    revoked: Self::is_revoked(e, public_key),
+                }
+            }
+            _ => ProductVerification { is_genuine: false, token_id: None, owner: None, revoked: false },
+        }
+    }
+
+    fn revoke_chip(e: &Env, public_key: BytesN<65>) {
+        require_admin(e);
+
+        e.storage().persistent().set(&NFTStorageKey::Revoked(public_key.clone()), &true);
+
+        events::ChipRevoked { public_key, revoked: true }.publish(e);
+    }
+
+    fn unrevoke_chip(e: &Env, public_key: BytesN<65>) {
+        require_admin(e);
+
+        e.storage().persistent().remove(&NFTStorageKey::Revoked(public_key.clone()));
+
+        events::ChipRevoked { public_key, revoked: false }.publish(e);
+    }
+
+    fn is_revoked(e: &Env, public_key: BytesN<65>) -> bool {
+        e.storage().persistent().has(&NFTStorageKey::Revoked(public_key))
+    }
+
+    fn set_chip_curve(e: &Env, public_key: BytesN<65>, curve: CurveType) {
+        require_admin(e);
+        require_valid_public_key(e, &public_key);
+
+        let curve_key = NFTStorageKey::ChipCurve(public_key.clone());
+        match curve {
+            // Most chips, left out of storage
+            CurveType::Secp256k1 => e.storage().persistent().remove(&curve_key),
+            CurveType::Secp256r1 => e.storage().persistent().set(&curve_key, &curve),
+        }
+
+        events::ChipCurveUpdated { public_key, curve }.publish(e);
+    }
+
+    fn chip_curve(e: &Env, public_key: BytesN<65>) -> CurveType {
+        e.storage()
+            .persistent()
+            .get(&NFTStorageKey::ChipCurve(public_key))
+            .unwrap_or(CurveType::Secp256k1)
+    }
+
+    fn get_nonce(e: &Env, public_key: BytesN<65>) -> u64 {
+        let nonce_key = NFTStorageKey::ChipNonceByPublicKey(public_key);
+        e.storage()
+            .persistent()
+            .get::<_, Val>(&nonce_key)
+            .inspect(|_| token_ttl::extend_entry(e, &nonce_key))
+            .map(|nonce| merch_crypto::soroban::widen_nonce(e, nonce))
+            .unwrap_or(0u64)  // No nonce consumed yet, first use must sign with 1
+    }
+
+    fn message_domain(e: &Env) -> Bytes {
+        e.current_contract_address().to_xdr(e)
+    }
+
+    fn build_message_hash(e: &Env, operation: ChipOperation, message: Bytes, recipient: Option<Address>, nonce: u64, expiration_ledger: u32) -> BytesN<32> {
+        message_hash(e, operation, &message, recipient.as_ref(), nonce, expiration_ledger).into()
+    }
+
+    fn owner_of_opt(e: &Env, token_id: u64) -> Option<Address> {
+        token_data_opt(e, token_id).and_then(|token| token.owner)
+    }
+
+    fn exists(e: &Env, token_id: u64) -> bool {
+        let storage = e.storage().persistent();
+        storage.has(&NFTStorageKey::Token(token_id)) || storage.has(&NFTStorageKey::PublicKey(token_id))
+    }
+
+    fn is_claimed(e: &Env, token_id: u64) -> bool {
+        Self::owner_of_opt(e, token_id).is_some()
+    }
+
+    fn token_info(e: &Env, token_id: u64) -> TokenInfo {
+        token_info_opt(e, token_id)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NonExistentToken))
+    }
+
+    fn tokens_info(e: &Env, token_ids: Vec<u64>) -> Vec<TokenInfo> {
+        if token_ids.len() > MAX_TOKENS_INFO_BATCH {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+        }
+
+        let mut tokens_info = Vec::new(e);
+        for token_id in token_ids.iter() {
+            if let Some(token_info) = token_info_opt(e, token_id) {
+                tokens_info.push_back(token_info);
+            }
+        }
+        tokens_info
+    }
+
+    fn token_uri_opt(e: &Env, token_id: u64) -> Option<String> {
+        // Verify token exists
+        let token = token_data_opt(e, token_id)?;
+
+        // Keep the product under wraps until someone claims it
+        let unclaimed_uri = Self::unclaimed_uri(e);
+        if !unclaimed_uri.is_empty() && token.owner.is_none() {
+            return Some(unclaimed_uri);
+        }
+
+        // Special editions carry their own metadata
+        let uri = e.storage().persistent().get(&NFTStorageKey::TokenURI(token_id)).unwrap_or_else(|| {
+            let base_uri = Self::series_info(e, series::token_series(e, token_id)).base_uri;
+
+            // Construct URI: {base_uri}/{token_id}
+            let mut uri_bytes = Bytes::from(base_uri);
+            if uri_bytes.last() != Some(b'/') {
+                uri_bytes.append(&Bytes::from_slice(e, b"/"));
+            }
+            uri_bytes.append(&u64_to_decimal_bytes(e, token_id));
+            String::from(uri_bytes)
+        });
+
+        // Self-contained deployments serve the metadata itself, the URI becoming its image
+        if onchain_metadata::enabled(e) {
+            return Some(onchain_metadata::token_data_uri(e, token_id, &uri));
+        }
+        Some(uri)
+    }
+
+    fn set_base_uri(e: &Env, uri: String) {
+        require_admin(e);
+
+        if let Err(error) = check_base_uri(&uri) {
+            panic_with_error!(e, error);
+        }
+        let mut series = Self::series_info(e, series::DEFAULT_SERIES_ID);
+        series.base_uri = uri.clone();
+        series::set_series_info(e, series::DEFAULT_SERIES_ID, &series);
+
+        events::BaseUriUpdated { series_id: series::DEFAULT_SERIES_ID, base_uri: uri }.publish(e);
+    }
+
+    fn base_uri(e: &Env) -> String {
+        Self::series_info(e, series::DEFAULT_SERIES_ID).base_uri
+    }
+
+    fn set_token_uri(e: &Env, token_id: u64, uri: String) {
+        merchants::require_token_admin(e, token_id);
+
+        if uri.is_empty() || uri.len() > MAX_TOKEN_URI_LENGTH {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidUri);
+        }
+        e.storage().persistent().set(&NFTStorageKey::TokenURI(token_id), &uri);
+
+        events::MetadataUpdate { token_id }.publish(e);
+    }
+
+    fn clear_token_uri(e: &Env, token_id: u64) {
+        merchants::require_token_admin(e, token_id);
+
+        e.storage().persistent().remove(&NFTStorageKey::TokenURI(token_id));
+
+        events::MetadataUpdate { token_id }.publish(e);
+    }
+
+    fn set_unclaimed_uri(e: &Env, uri: String) {
+        require_admin(e);
+
+        if let Err(error) = check_base_uri(&uri) {
+            panic_with_error!(e, error);
+        }
+        e.storage().instance().set(&NFTStorageKey::UnclaimedURI, &uri);
+
+        events::UnclaimedUriUpdated { uri }.publish(e);
+    }
+
+    fn unclaimed_uri(e: &Env) -> String {
+        e.storage()
+            .instance()
+            .get(&NFTStorageKey::UnclaimedURI)
+            .unwrap_or(String::from_str(e, ""))
+    }
+
+    fn token_id(e: &Env, public_key: BytesN<65>) -> u64 {
+        Self::token_id_opt(e, public_key)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NonExistentToken))
+    }
+
+    fn token_id_opt(e: &Env, public_key: BytesN<65>) -> Option<u64> {
+        let public_key_lookup = NFTStorageKey::TokenIdByPublicKey(public_key);
+        e.storage()
+            .persistent()
+            .get::<NFTStorageKey, u64>(&public_key_lookup)
+            .inspect(|_| token_ttl::extend_entry(e, &public_key_lookup))
+    }
+
+    fn public_key(e: &Env, token_id: u64) -> BytesN<65> {
+        Self::public_key_opt(e, token_id)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NonExistentToken))
+    }
+
+    fn public_key_opt(e: &Env, token_id: u64) -> Option<BytesN<65>> {
+        token_data_opt(e, token_id).map(|token| token.public_key)
+    }
+
+    fn chip_key(e: &Env, token_id: u64) -> ChipKey {
+        chip_key_of(e, Self::public_key(e, token_id))
+    }
+
+    fn chip_public_key(e: &Env, chip_key: ChipKey) -> BytesN<65> {
+        chip_public_key(e, &chip_key)
+    }
+}
+
+/// Read the chip and owner of token_id
+/// Tokens minted before `TokenData` are moved to the `Token` entry on the way,
+/// so a legacy entry is never read twice nor overwritten by `migrate` later
+pub(crate) fn token_data_opt(e: &Env, token_id: u64) -> Option<TokenData> {
+    let token_key = NFTStorageKey::Token(token_id);
+    e.storage()
+        .persistent()
+        .get(&token_key)
+        .inspect(|_| token_ttl::extend_entry(e, &token_key))
+        .or_else(|| migrate_token(e, token_id))
+}
+
+/// Read the chip and owner of an existing token_id
+pub(crate) fn token_data(e: &Env, token_id: u64) -> TokenData {
+    token_data_opt(e, token_id)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NonExistentToken))
+}
+
+/// Rewrite the per-token entries of contracts deployed before `TokenData`
+/// Returns true once every legacy token is moved, callers repeat `migrate` until then
+fn migrate_token_data(e: &Env) -> bool {
+    let next_token_id: u64 = e.storage().instance().get(&DataKey::NextTokenId).unwrap_or(0);
+    let start: u64 = e.storage().instance().get(&DataKey::TokenMigrationCursor).unwrap_or(0);
+    let end = next_token_id.min(start.saturating_add(MAX_MIGRATE_BATCH));
+
+    for token_id in start..end {
+        migrate_token(e, token_id);
+    }
+    e.storage().instance().set(&DataKey::TokenMigrationCursor, &end);
+
+    end == next_token_id
+}
+
+/// Move the legacy entries of token_id to a `Token` entry, shared by migrate and token_data_opt
+/// Burned tokens, tokens minted since the upgrade and tokens already moved have no legacy entry
+fn migrate_token(e: &Env, token_id: u64) -> Option<TokenData> {
+    let storage = e.storage().persistent();
+    let public_key: BytesN<65> = storage.get(&NFTStorageKey::PublicKey(token_id))?;
+    let owner: Option<Address> = storage.get(&NFTStorageKey::Owner(token_id));
+    // Tokens claimed before the owner index are missing from tokens_of
+    if let Some(owner) = &owner {
+        owner_index::backfill_token(e, owner, token_id);
+    }
+
+    let token = TokenData { owner, public_key };
+    let token_key = NFTStorageKey::Token(token_id);
+    storage.set(&token_key, &token);
+    storage.extend_ttl(&token_key, token_ttl::MINT_TTL_LEDGERS, token_ttl::MINT_TTL_LEDGERS);
+    storage.remove(&NFTStorageKey::PublicKey(token_id));
+    storage.remove(&NFTStorageKey::Owner(token_id));
+    increment_counter(e, &DataKey::MigratedTokenCount);
+
+    Some(token)
+}
+
+#[contractimpl]
+impl NonFungibleToken for StellarMerchShop {
+    fn balance(e: &Env, owner: Address) -> u32 {
+        let balance_key = NFTStorageKey::Balance(owner);
+        e.storage()
+            .persistent()
+            .get(&balance_key)
+            .inspect(|_| token_ttl::extend_entry(e, &balance_key))
+            .unwrap_or(0u32)
+    }
+
+    fn owner_of(e: &Env, token_id: u64) -> Address {
+        // Panics if the token doesn't exist, then check if it has an owner
+        token_data(e, token_id)
+            .owner
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::TokenNotClaimed))
+    }
+
+    fn transfer(e: &Env, from: Address, to: Address, token_id: u64) {
+        from.require_auth();
+
+        require_transfer_owner(e, &from, &to, token_id);
+        sales::collect_transfer_fee(e, &from);
+        transfer_token(e, from.clone(), from, to, token_id, None);
+    }
+
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u64) {
+        spender.require_auth();
+
+        require_transfer_owner(e, &from, &to, token_id);
+        if Self::is_approved_for_all(e, from.clone(), spender.clone()) {
+            use_operator_allowance(e, &from, &spender);
+        } else if Self::get_approved(e, token_id) == Some(spender.clone()) {
+            require_allowed_recipient(e, token_id, &to);
+        } else {
+            panic_with_error!(e, &errors::NonFungibleTokenError::Unauthorized);
+        }
+
+        sales::collect_transfer_fee(e, &spender);
+        transfer_token(e, spender, from, to, token_id, None);
+    }
+
+    fn approve(e: &Env, approver: Address, approved: Address, token_id: u64, live_until_ledger: u32) {
+        set_approval(e, &approver, &approved, token_id, live_until_ledger, None);
+
+        events::Approve { approver, token_id, approved, live_until_ledger }.publish(e);
+    }
+
+    fn approve_for_all(e: &Env, owner: Address, operator: Address, live_until_ledger: u32) {
+        owner.require_auth();
+
+        let approval_key = NFTStorageKey::ApprovalForAll(owner.clone(), operator.clone());
+        if live_until_ledger < e.ledger().sequence() {
+            e.storage().persistent().remove(&approval_key);
+        } else {
+            e.storage().persistent().set(&approval_key, &live_until_ledger);
+        }
+        e.storage().persistent().remove(&NFTStorageKey::OperatorAllowance(owner.clone(), operator.clone()));
+
+        events::ApproveForAll { owner, operator, live_until_ledger }.publish(e);
+    }
+
+    fn get_approved(e: &Env, token_id: u64) -> Option<Address> {
+        e.storage()
+            .persistent()
+            .get::<_, TokenApproval>(&NFTStorageKey::Approval(token_id))
+            .filter(|approval| e.ledger().sequence() <= approval.live_until_ledger)
+            .map(|approval| approval.approved)
+    }
+
+    fn is_approved_for_all(e: &Env, owner: Address, operator: Address) -> bool {
+        e.storage()
+            .persistent()
+            .get::<_, u32>(&NFTStorageKey::ApprovalForAll(owner, operator))
+            .is_some_and(|live_until_ledger| e.ledger().sequence() <= live_until_ledger)
+    }
+
+    fn name(e: &Env) -> String {
+            e.storage()
+            .instance()
+            .get(&NFTStorageKey::Name)
+            .unwrap()
+    }
+
+    fn symbol(e: &Env) -> String {
+            e.storage()
+            .instance()
+            .get(&NFTStorageKey::Symbol)
+            .unwrap()
+    }
+
+    fn token_uri(e: &Env, token_id: u64) -> String {
+        Self::token_uri_opt(e, token_id)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NonExistentToken))
+    }
+}
+
+/// Convert an u64 to its decimal string representation as Bytes
+/// Implementation inspired by OpenZeppelin's token_id_to_string
+pub(crate) fn u64_to_decimal_bytes(e: &Env, mut value: u64) -> Bytes {
+    if value == 0 {
+        return Bytes::from_slice(e, b"0");
+    }
+
+    // Count digits (equivalent to log10(value) + 1 in no_std)
+    let mut temp = value;
+    let mut length = 0;
+    while temp > 0 {
+        length += 1;
+        temp /= 10;
+    }
+
+    // Allocate buffer with max size (20 for u64)
+    let mut buffer = [0u8; 20];
+
+    // Fill from right to left (most significant digit first)
+    let mut i = length;
+    while value > 0 {
+        i -= 1;
         buffer[i] = b'0' + (value % 10) as u8;
         value /= 10;
     }
@@ -257,40 +1254,894 @@ pub(crate) fn u64_to_decimal_bytes(e: &Env, mut value: u64) -> Bytes {
     Bytes::from_slice(e, &buffer[..length])
 }
 
-/// Common function to verify chip signature
-/// Verifies that the signature was created by the chip with the given public_key
-/// Also handles nonce verification and updates the stored nonce for the public_key
-fn verify_chip_signature(
+/// Require the admin's authorization
+pub(crate) fn require_admin(e: &Env) -> Address {
+    let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
+    admin.require_auth();
+    admin
+}
+
+/// Set up the collection, only once: configuration changes after deployment
+/// go through dedicated setters so counters are never reset
+pub(crate) fn initialize(
+    e: &Env,
+    admin: &Address,
+    name: &String,
+    symbol: &String,
+    uri: &String,
+    max_tokens: u64,
+    activity_events: bool,
+    onchain_metadata: bool,
+) -> Result<(), errors::NonFungibleTokenError> {
+    if e.storage().instance().has(&DataKey::Admin) {
+        return Err(errors::NonFungibleTokenError::AlreadyInitialized);
+    }
+    check_base_uri(uri)?;
+    check_name(name)?;
+
+    e.storage().instance().set(&DataKey::Admin, admin);
+
+    e.storage().instance().set(&NFTStorageKey::Name, name);
+    e.storage().instance().set(&NFTStorageKey::Symbol, symbol);
+
+    series::store_series(e, series::DEFAULT_SERIES_ID, name, uri, max_tokens)?;
+    e.storage().instance().set(&DataKey::NextTokenId, &0u64);
+    e.storage().instance().set(&DataKey::ActivityEvents, &activity_events);
+    e.storage().instance().set(&DataKey::OnchainMetadata, &onchain_metadata);
+
+    store_build_info(e);
+
+    Ok(())
+}
+
+/// Validate the base URI, shared by the constructor, set_base_uri and set_unclaimed_uri
+pub(crate) fn check_base_uri(uri: &String) -> Result<(), errors::NonFungibleTokenError> {
+    if uri.len() > MAX_BASE_URI_LENGTH {
+        return Err(errors::NonFungibleTokenError::BaseUriMaxLenExceeded);
+    }
+    Ok(())
+}
+
+/// Validate a collection or series name, whose length bounds the on-chain metadata
+pub(crate) fn check_name(name: &String) -> Result<(), errors::NonFungibleTokenError> {
+    if name.len() > MAX_NAME_LENGTH {
+        return Err(errors::NonFungibleTokenError::NameTooLong);
+    }
+    Ok(())
+}
+
+/// Record the build metadata of the running code
+fn store_build_info(e: &Env) {
+    let build_info = BuildInfo {
+        version: String::from_str(e, BUILD_VERSION),
+        git_describe: String::from_str(e, BUILD_GIT_DESCRIBE),
+        profile: String::from_str(e, BUILD_PROFILE),
+    };
+    e.storage().instance().set(&DataKey::BuildInfo, &build_info);
+}
+
+/// Mint the chip once its signature is verified, shared by mint, mint_and_claim and partner_mint
+/// The signature is bound to recipient when the token is claimed on mint
+pub(crate) fn mint_chip(
     e: &Env,
+    series_id: u32,
     message: Bytes,
     signature: BytesN<64>,
-    recovery_id: u32,
+    recovery_id: Option<u32>,
+    chip_key: ChipKey,
+    nonce: u64,
+    expiration_ledger: u32,
+    recipient: Option<&Address>,
+    chip_info: Option<ChipInfo>,
+    po_number: Option<u32>,
+) -> u64 {
+    let public_key = chip_public_key(e, &chip_key);
+    verify_chip_signature(e, ChipOperation::Mint, message, signature, recovery_id, chip_key, nonce, expiration_ledger, recipient);
+    register_chip(e, series_id, public_key, recipient, chip_info, po_number, Some(nonce))
+}
+
+/// Assign the next token_id to the chip, shared by mint_chip and register_chips
+/// `recipient` is the account the token is claimed for right after, `nonce` the chip
+/// nonce consumed by the mint, if it was signed
+fn register_chip(
+    e: &Env,
+    series_id: u32,
     public_key: BytesN<65>,
-    nonce: u32,
+    recipient: Option<&Address>,
+    chip_info: Option<ChipInfo>,
+    po_number: Option<u32>,
+    nonce: Option<u64>,
+) -> u64 {
+    let token_id = check_mintable(e, series_id, &public_key).unwrap_or_else(|error| panic_with_error!(e, error));
+
+    let next_token_id = token_id
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    e.storage().instance().set(&DataKey::NextTokenId, &next_token_id);
+    e.storage().persistent().set(&NFTStorageKey::TokenIdByPublicKey(public_key.clone()), &token_id);
+    e.storage()
+        .persistent()
+        .set(&NFTStorageKey::Token(token_id), &TokenData { owner: None, public_key: public_key.clone() });
+    token_index::add_token(e, token_id);
+    chip_registry::add_chip(e, &public_key);
+    series::record_mint(e, series_id, token_id);
+    if let Some(chip_info) = chip_info {
+        chip_info::record_chip_info(e, &public_key, token_id, &chip_info);
+    }
+    if let Some(po_number) = po_number {
+        purchase_orders::record_po(e, token_id, po_number);
+    }
+    token_ttl::extend_token_entries(e, token_id, &public_key, token_ttl::MINT_TTL_LEDGERS);
+    chip_stats::record_mint(e, &public_key);
+
+    let merchant_id = merchants::series_merchant(e, series_id);
+    let sku = sku_of(e, &public_key);
+    events::Mint { token_id, public_key, merchant_id, sku, to: recipient.cloned(), po_number }.publish(e);
+    publish_activity(e, events::Operation::Mint, token_id, None, None, nonce);
+
+    let checkpoint_interval = StellarMerchShop::checkpoint_interval(e);
+    if checkpoint_interval != 0 && next_token_id.is_multiple_of(checkpoint_interval as u64) {
+        events::Checkpoint {
+            next_token_id,
+            total_supply: StellarMerchShop::total_supply(e),
+            claimed_count: StellarMerchShop::claimed_count(e),
+            ledger: e.ledger().sequence(),
+        }
+        .publish(e);
+    }
+
+    token_id
+}
+
+/// Claim the chip's token for claimant once its signature is verified, shared by claim and claim_ed25519
+fn claim_chip(
+    e: &Env,
+    claimant: Address,
+    message: Bytes,
+    signature: BytesN<64>,
+    recovery_id: Option<u32>,
+    chip_key: ChipKey,
+    nonce: u64,
+    expiration_ledger: u32,
+) -> u64 {
+    claimant.require_auth();
+
+    let public_key = chip_public_key(e, &chip_key);
+    verify_chip_signature(e, ChipOperation::Claim, message, signature, recovery_id, chip_key, nonce, expiration_ledger, Some(&claimant));
+
+    // Look up token_id from public_key
+    let token_id = StellarMerchShop::token_id(e, public_key);
+
+    sales::collect_payment(e, token_id, &claimant);
+    assign_claim(e, &claimant, token_id, claimant.clone(), Some(nonce));
+
+    token_id
+}
+
+/// Move token_id to `to` once the chip signature is verified, shared by chip_transfer and chip_transfer_ed25519
+fn transfer_chip(
+    e: &Env,
+    from: Address,
+    to: Address,
+    token_id: u64,
+    message: Bytes,
+    signature: BytesN<64>,
+    recovery_id: Option<u32>,
+    chip_key: ChipKey,
+    nonce: u64,
+    expiration_ledger: u32,
 ) {
-    let nonce_key = NFTStorageKey::ChipNonceByPublicKey(public_key.clone());
-    let stored_nonce: u32 = e.storage()
+    from.require_auth();
+
+    verify_token_chip_signature(e, ChipOperation::Transfer, token_id, message, signature, recovery_id, chip_key, nonce, expiration_ledger, &to);
+
+    require_transfer_owner(e, &from, &to, token_id);
+    transfer_token(e, from.clone(), from, to, token_id, Some(nonce));
+}
+
+/// Approve `approved` for token_id on behalf of its owner, shared by approve
+/// and approve_for_recipient
+/// `recipient` restricts the account the token can be sent to, a
+/// `live_until_ledger` of 0 revokes the approval
+fn set_approval(e: &Env, approver: &Address, approved: &Address, token_id: u64, live_until_ledger: u32, recipient: Option<&Address>) {
+    approver.require_auth();
+
+    if StellarMerchShop::owner_of(e, token_id) != *approver {
+        panic_with_error!(e, &errors::NonFungibleTokenError::InvalidApprover);
+    }
+
+    let approval_key = NFTStorageKey::Approval(token_id);
+    let recipient_key = NFTStorageKey::ApprovalRecipient(token_id);
+    if live_until_ledger == 0 {
+        e.storage().persistent().remove(&approval_key);
+        e.storage().persistent().remove(&recipient_key);
+        return;
+    }
+
+    if live_until_ledger < e.ledger().sequence() {
+        panic_with_error!(e, &errors::NonFungibleTokenError::InvalidLiveUntilLedger);
+    }
+    let approval = TokenApproval { approved: approved.clone(), live_until_ledger };
+    e.storage().persistent().set(&approval_key, &approval);
+    match recipient {
+        Some(recipient) => e.storage().persistent().set(&recipient_key, recipient),
+        None => e.storage().persistent().remove(&recipient_key),
+    }
+}
+
+/// Panic if the approval of token_id is restricted to another recipient than `to`
+fn require_allowed_recipient(e: &Env, token_id: u64, to: &Address) {
+    let recipient: Option<Address> = e.storage().persistent().get(&NFTStorageKey::ApprovalRecipient(token_id));
+    if recipient.is_some_and(|recipient| recipient != *to) {
+        panic_with_error!(e, &errors::NonFungibleTokenError::RecipientNotAllowed);
+    }
+}
+
+/// Use up one transfer of a limited operator approval, revoking it after the last one
+fn use_operator_allowance(e: &Env, owner: &Address, operator: &Address) {
+    let allowance_key = NFTStorageKey::OperatorAllowance(owner.clone(), operator.clone());
+    let Some(remaining) = e.storage().persistent().get::<_, u32>(&allowance_key) else {
+        return;
+    };
+
+    if remaining > 1 {
+        e.storage().persistent().set(&allowance_key, &(remaining - 1));
+    } else {
+        e.storage().persistent().remove(&allowance_key);
+        e.storage().persistent().remove(&NFTStorageKey::ApprovalForAll(owner.clone(), operator.clone()));
+    }
+}
+
+/// Check that `from` owns token_id and sends it to someone else, shared by every owner-initiated transfer
+fn require_transfer_owner(e: &Env, from: &Address, to: &Address, token_id: u64) {
+    if StellarMerchShop::owner_of(e, token_id) != *from || from == to {
+        panic_with_error!(e, &errors::NonFungibleTokenError::IncorrectOwner);
+    }
+}
+
+/// Move token_id once the caller checked the ownership and authorization, shared by
+/// chip_transfer, transfer and transfer_from
+/// `operator` is the account that authorized the transfer, `nonce` the chip nonce
+/// consumed by the transfer, if it was signed
+fn transfer_token(e: &Env, operator: Address, from: Address, to: Address, token_id: u64, nonce: Option<u64>) {
+    #[cfg(feature = "composable")]
+    {
+        composable::require_detached(e, token_id);
+        composable::require_children_transferable(e, token_id, nonce.is_some());
+    }
+    require_not_soulbound(e, token_id);
+    require_security_level(e, token_id, nonce.is_some());
+    require_cooldown_elapsed(e, token_id);
+
+    update_owner(e, &from, &to, token_id);
+    #[cfg(feature = "composable")]
+    composable::move_children(e, &from, &to, token_id);
+    let public_key = StellarMerchShop::public_key(e, token_id);
+    chip_stats::record_transfer(e, &public_key);
+
+    let merchant_id = merchants::token_merchant(e, token_id);
+    let sku = sku_of(e, &public_key);
+    events::Transfer { from: from.clone(), to: to.clone(), merchant_id, sku, token_id }.publish(e);
+    publish_activity(e, events::Operation::Transfer, token_id, Some(from.clone()), Some(to.clone()), nonce);
+
+    receiver::check_received(e, &operator, &from, &to, token_id);
+}
+
+/// Move `token_id` from `from` to `to`, clear its approval and update both balances
+pub(crate) fn update_owner(e: &Env, from: &Address, to: &Address, token_id: u64) {
+    let mut token = token_data(e, token_id);
+    token.owner = Some(to.clone());
+    e.storage().persistent().set(&NFTStorageKey::Token(token_id), &token);
+    e.storage().persistent().remove(&NFTStorageKey::Approval(token_id));
+    e.storage().persistent().remove(&NFTStorageKey::ApprovalRecipient(token_id));
+    e.storage().persistent().set(&NFTStorageKey::LastTransferLedger(token_id), &e.ledger().sequence());
+    #[cfg(feature = "provenance")]
+    ownership_history::record_owner(e, token_id, to);
+    owner_index::remove_token(e, from, token_id);
+    owner_index::add_token(e, to, token_id);
+
+    decrement_balance(e, from);
+    increment_balance(e, to);
+}
+
+/// Destroy token_id owned by from, shared by burn and burn_from_owner
+/// The chip registration, TokenIdByPublicKey, is left to the caller
+fn burn_token(e: &Env, from: &Address, token_id: u64, nonce: Option<u64>) {
+    if StellarMerchShop::owner_of(e, token_id) != *from {
+        panic_with_error!(e, &errors::NonFungibleTokenError::IncorrectOwner);
+    }
+    #[cfg(feature = "composable")]
+    {
+        composable::require_detached(e, token_id);
+        composable::require_no_children(e, token_id);
+    }
+
+    // The activity event reads the public key, publish it before the token is gone
+    publish_activity(e, events::Operation::Burn, token_id, Some(from.clone()), None, nonce);
+
+    remove_owner(e, from, token_id);
+    remove_token(e, token_id);
+
+    events::Burn { from: from.clone(), token_id }.publish(e);
+}
+
+/// Delete the entries of token_id once its owner, if any, is removed, shared by burn and reclaim_unclaimed
+/// The chip registration is left to the caller
+fn remove_token(e: &Env, token_id: u64) {
+    e.storage().persistent().remove(&NFTStorageKey::Token(token_id));
+    e.storage().persistent().remove(&NFTStorageKey::TokenURI(token_id));
+    e.storage().persistent().remove(&NFTStorageKey::Soulbound(token_id));
+    e.storage().persistent().remove(&NFTStorageKey::SecurityLevel(token_id));
+    attributes::remove_attributes(e, token_id);
+    series::remove_token(e, token_id);
+    token_index::remove_token(e, token_id);
+    increment_counter(e, &DataKey::BurnedCount);
+}
+
+/// Add one to a collection counter kept in instance storage
+fn increment_counter(e: &Env, key: &DataKey) {
+    let count: u64 = e.storage().instance().get(key).unwrap_or(0);
+    let count = count
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    e.storage().instance().set(key, &count);
+}
+
+/// Add one token to the balance of owner
+fn increment_balance(e: &Env, owner: &Address) {
+    let balance = StellarMerchShop::balance(e, owner.clone())
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    e.storage().persistent().set(&NFTStorageKey::Balance(owner.clone()), &balance);
+    token_ttl::extend_entry(e, &NFTStorageKey::Balance(owner.clone()));
+}
+
+/// Remove one token from the balance of owner, an empty balance means storage is inconsistent
+fn decrement_balance(e: &Env, owner: &Address) {
+    let balance = StellarMerchShop::balance(e, owner.clone())
+        .checked_sub(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    e.storage().persistent().set(&NFTStorageKey::Balance(owner.clone()), &balance);
+    token_ttl::extend_entry(e, &NFTStorageKey::Balance(owner.clone()));
+}
+
+/// Take token_id away from its owner when it is burned
+/// The owner goes along with the `Token` entry in remove_token
+pub(crate) fn remove_owner(e: &Env, from: &Address, token_id: u64) {
+    e.storage().persistent().remove(&NFTStorageKey::Approval(token_id));
+    e.storage().persistent().remove(&NFTStorageKey::ApprovalRecipient(token_id));
+    e.storage().persistent().remove(&NFTStorageKey::LastTransferLedger(token_id));
+    #[cfg(feature = "provenance")]
+    ownership_history::clear_history(e, token_id);
+    owner_index::remove_token(e, from, token_id);
+
+    decrement_balance(e, from);
+}
+
+/// Give the unclaimed token_id to its first owner
+/// `operator` is the account that authorized the claim
+pub(crate) fn assign_claim(e: &Env, operator: &Address, token_id: u64, claimant: Address, nonce: Option<u64>) {
+    // Verify token is not already claimed
+    let mut token = token_data(e, token_id);
+    if token.owner.is_some() {
+        panic_with_error!(e, &errors::NonFungibleTokenError::TokenAlreadyMinted);
+    }
+    if claim_window_closed(e) {
+        panic_with_error!(e, &errors::NonFungibleTokenError::ClaimWindowClosed);
+    }
+    #[cfg(feature = "reservations")]
+    reservation::consume_reservation(e, token_id, &claimant);
+
+    token.owner = Some(claimant.clone());
+    e.storage().persistent().set(&NFTStorageKey::Token(token_id), &token);
+    e.storage().persistent().set(&NFTStorageKey::LastTransferLedger(token_id), &e.ledger().sequence());
+    #[cfg(feature = "provenance")]
+    ownership_history::record_owner(e, token_id, &claimant);
+    owner_index::add_token(e, &claimant, token_id);
+
+    increment_balance(e, &claimant);
+
+    increment_counter(e, &DataKey::ClaimedCount);
+
+    #[cfg(feature = "provenance")]
+    recent_claims::record_claim(e, token_id, &claimant);
+    let public_key = token.public_key;
+    chip_stats::record_claim(e, &public_key);
+
+    // token_uri switches from the placeholder to the real metadata
+    if !StellarMerchShop::unclaimed_uri(e).is_empty() {
+        events::MetadataUpdate { token_id }.publish(e);
+    }
+
+    let merchant_id = merchants::token_merchant(e, token_id);
+    let sku = sku_of(e, &public_key);
+    events::Claim { claimant: claimant.clone(), public_key, merchant_id, sku, token_id }.publish(e);
+    publish_activity(e, events::Operation::Claim, token_id, None, Some(claimant.clone()), nonce);
+
+    // Claims come from the collection itself
+    receiver::check_received(e, operator, &e.current_contract_address(), &claimant, token_id);
+}
+
+/// Publish the consolidated ActivityV1 event, if enabled at construction
+pub(crate) fn publish_activity(
+    e: &Env,
+    operation: events::Operation,
+    token_id: u64,
+    from: Option<Address>,
+    to: Option<Address>,
+    nonce: Option<u64>,
+) {
+    if !e.storage().instance().get(&DataKey::ActivityEvents).unwrap_or(false) {
+        return;
+    }
+
+    let public_key = StellarMerchShop::public_key(e, token_id);
+    let sku = sku_of(e, &public_key);
+
+    events::ActivityV1 {
+        operation,
+        token_id,
+        public_key,
+        from,
+        to,
+        sku,
+        nonce,
+        ledger: e.ledger().sequence(),
+        version: events::ACTIVITY_SCHEMA_VERSION,
+    }
+    .publish(e);
+}
+
+/// SKU the chip with `public_key` is allocated to, `None` without partner mints
+#[cfg_attr(not(feature = "partner-mint"), allow(unused_variables))]
+pub(crate) fn sku_of(e: &Env, public_key: &BytesN<65>) -> Option<u32> {
+    #[cfg(feature = "partner-mint")]
+    return partner_mint::sku_of(e, public_key);
+    #[cfg(not(feature = "partner-mint"))]
+    None
+}
+
+/// Panic if token_id changed hands less than the transfer cooldown ago
+pub(crate) fn require_cooldown_elapsed(e: &Env, token_id: u64) {
+    let cooldown = StellarMerchShop::transfer_cooldown(e);
+    if cooldown == 0 {
+        return;
+    }
+
+    let last_transfer_ledger: Option<u32> = e
+        .storage()
         .persistent()
-        .get(&nonce_key)
-        .unwrap_or(0u32);
+        .get(&NFTStorageKey::LastTransferLedger(token_id));
+    if last_transfer_ledger.is_some_and(|ledger| e.ledger().sequence() < ledger.saturating_add(cooldown)) {
+        panic_with_error!(e, &errors::NonFungibleTokenError::TransferCooldownActive);
+    }
+}
+
+/// Whether the claim deadline, if any, has passed
+fn claim_window_closed(e: &Env) -> bool {
+    let claim_deadline = StellarMerchShop::claim_deadline(e);
+    claim_deadline != 0 && e.ledger().timestamp() > claim_deadline
+}
+
+/// Tokens at the Both security level only move with a chip signature on top of the owner's authorization
+pub(crate) fn require_security_level(e: &Env, token_id: u64, chip_signed: bool) {
+    if !chip_signed && StellarMerchShop::security_level(e, token_id) == SecurityLevel::Both {
+        panic_with_error!(e, &errors::NonFungibleTokenError::DualAuthRequired);
+    }
+}
+
+/// Soulbound tokens stay with their owner, only the admin and recovery paths can move them
+pub(crate) fn require_not_soulbound(e: &Env, token_id: u64) {
+    if StellarMerchShop::is_soulbound(e, token_id) {
+        panic_with_error!(e, &errors::NonFungibleTokenError::TokenSoulbound);
+    }
+}
+
+/// Verify the chip signature for operation bound to recipient and that the chip's public_key corresponds to that specific token_id
+pub(crate) fn verify_token_chip_signature(
+    e: &Env,
+    operation: ChipOperation,
+    token_id: u64,
+    message: Bytes,
+    signature: BytesN<64>,
+    recovery_id: Option<u32>,
+    chip_key: ChipKey,
+    nonce: u64,
+    expiration_ledger: u32,
+    recipient: &Address,
+) {
+    let public_key = chip_public_key(e, &chip_key);
+    verify_chip_signature(e, operation, message, signature, recovery_id, chip_key, nonce, expiration_ledger, Some(recipient));
+
+    let token_id_public_key: BytesN<65> = StellarMerchShop::public_key(e, token_id);
+    if token_id_public_key != public_key {
+        panic_with_error!(e, &errors::NonFungibleTokenError::InvalidSignature);
+    }
+}
+
+/// Common function to verify chip signature
+/// Verifies that the signature was created by the chip with the given chip_key
+/// Also handles nonce verification and updates the stored nonce for the chip
+/// The recovery ID only applies to ECDSA chips
+pub(crate) fn verify_chip_signature(
+    e: &Env,
+    operation: ChipOperation,
+    message: Bytes,
+    signature: BytesN<64>,
+    recovery_id: Option<u32>,
+    chip_key: ChipKey,
+    nonce: u64,
+    expiration_ledger: u32,
+    recipient: Option<&Address>,
+) {
+    // chip-auth consumes its own nonce, only the local one is checked here
+    let result = authenticate_chip(e, operation, &message, &signature, recovery_id, &chip_key, nonce, expiration_ledger, recipient, chip_auth::verify);
+    if let Err(error) = result {
+        panic_with_error!(e, error);
+    }
+
+    store_nonce(e, &chip_public_key(e, &chip_key), nonce);
+}
+
+/// Signatures of a revoked chip authorize nothing
+fn check_not_revoked(e: &Env, public_key: &BytesN<65>) -> Result<(), errors::NonFungibleTokenError> {
+    if StellarMerchShop::is_revoked(e, public_key.clone()) {
+        return Err(errors::NonFungibleTokenError::ChipRevoked);
+    }
+    Ok(())
+}
+
+/// Read-only part of verify_chip_signature: nonce and signature checks
+fn check_chip_signature(
+    e: &Env,
+    operation: ChipOperation,
+    message: &Bytes,
+    signature: &BytesN<64>,
+    recovery_id: Option<u32>,
+    chip_key: &ChipKey,
+    nonce: u64,
+    expiration_ledger: u32,
+    recipient: Option<&Address>,
+) -> Result<(), errors::NonFungibleTokenError> {
+    check_verifiable_curve(e, chip_key)?;
+    authenticate_chip(e, operation, message, signature, recovery_id, chip_key, nonce, expiration_ledger, recipient, chip_auth::check)
+}
+
+/// Refuse P-256 chips on read-only checks: the host aborts on a well-formed
+/// signature that does not match, which a simulation cannot report as false
+fn check_verifiable_curve(e: &Env, chip_key: &ChipKey) -> Result<(), errors::NonFungibleTokenError> {
+    if let ChipKey::Ecdsa(public_key) = chip_key
+        && StellarMerchShop::chip_curve(e, public_key.clone()) == CurveType::Secp256r1
+    {
+        return Err(errors::NonFungibleTokenError::UnverifiableCurve);
+    }
+
+    Ok(())
+}
+
+/// Nonce and signature checks shared by check_chip_signature and verify_chip_signature
+/// `chip_auth_verify` is the chip-auth call used when one is configured, consuming its nonce or not
+fn authenticate_chip(
+    e: &Env,
+    operation: ChipOperation,
+    message: &Bytes,
+    signature: &BytesN<64>,
+    recovery_id: Option<u32>,
+    chip_key: &ChipKey,
+    nonce: u64,
+    expiration_ledger: u32,
+    recipient: Option<&Address>,
+    chip_auth_verify: fn(&Env, &Address, &Bytes, &BytesN<64>, u32, &BytesN<65>, u64) -> bool,
+) -> Result<(), errors::NonFungibleTokenError> {
+    check_chip_key(chip_key)?;
+    let public_key = &chip_public_key(e, chip_key);
+    check_not_revoked(e, public_key)?;
+    check_message_length(e, message)?;
+    check_nonce(e, public_key, nonce)?;
+    check_expiration(e, expiration_ledger)?;
+
+    if let ChipKey::Ed25519(public_key) = chip_key {
+        // Ed25519 signs the preimage itself, there is no hash to build
+        let preimage = chip_message_preimage(e, &signed_message(e, operation, message, recipient, expiration_ledger), nonce);
+        e.crypto().ed25519_verify(public_key, &preimage, signature);
+        return Ok(());
+    }
+
+    let message_hash = message_hash(e, operation, message, recipient, nonce, expiration_ledger);
+    match StellarMerchShop::chip_auth(e) {
+        // chip-auth only knows secp256k1 chips
+        Some(chip_auth) if StellarMerchShop::chip_curve(e, public_key.clone()) == CurveType::Secp256k1 => {
+            let (signature, recovery_id) = resolve_recovery_id(e, &message_hash, signature, recovery_id, public_key)?;
+            let message = signed_message(e, operation, message, recipient, expiration_ledger);
+            if !chip_auth_verify(e, &chip_auth, &message, &signature, recovery_id, public_key, nonce) {
+                return Err(errors::NonFungibleTokenError::InvalidSignature);
+            }
+            Ok(())
+        }
+        // Verify signature recovers to the public_key
+        _ => check_signature(e, &message_hash, signature, recovery_id, public_key),
+    }
+}
+
+/// Reject keys no chip can have before any cryptography: ECDSA keys must be
+/// uncompressed SEC1 points, whether on the curve is left to the signature check
+fn check_chip_key(chip_key: &ChipKey) -> Result<(), errors::NonFungibleTokenError> {
+    let valid = match chip_key {
+        ChipKey::Ecdsa(public_key) => public_key.get(0) == Some(SEC1_UNCOMPRESSED_PREFIX),
+        ChipKey::Ed25519(public_key) => public_key.to_array() != [0u8; 32],
+    };
+    if !valid {
+        return Err(errors::NonFungibleTokenError::InvalidPublicKey);
+    }
+
+    Ok(())
+}
+
+/// Panic unless public_key, in the form of chip_public_key, is one a chip can have
+/// Registration paths call it as no signature vouches for the key there
+pub(crate) fn require_valid_public_key(e: &Env, public_key: &BytesN<65>) {
+    if let Err(error) = check_chip_key(&chip_key_of(e, public_key.clone())) {
+        panic_with_error!(e, error);
+    }
+}
+
+/// secp256k1_recover only accepts recovery IDs 0 to 3
+fn check_recovery_id(recovery_id: u32) -> Result<(), errors::NonFungibleTokenError> {
+    if recovery_id > 3 {
+        return Err(errors::NonFungibleTokenError::InvalidRecoveryId);
+    }
+
+    Ok(())
+}
+
+/// Reject empty messages, a client bug, and messages over the configured maximum before any hashing
+fn check_message_length(e: &Env, message: &Bytes) -> Result<(), errors::NonFungibleTokenError> {
+    if message.is_empty() || message.len() > StellarMerchShop::max_message_length(e) {
+        return Err(errors::NonFungibleTokenError::MalformedMessage);
+    }
+
+    Ok(())
+}
+
+/// Verify nonce is strictly greater than the last consumed one, and within the window if any
+fn check_nonce(e: &Env, public_key: &BytesN<65>, nonce: u64) -> Result<(), errors::NonFungibleTokenError> {
+    let stored_nonce = StellarMerchShop::get_nonce(e, public_key.clone());
 
-    // Verify nonce is monotonic increasing
+    let nonce_window = StellarMerchShop::nonce_window(e);
     if nonce <= stored_nonce {
-        panic_with_error!(&e, &errors::NonFungibleTokenError::InvalidSignature);
+        return Err(errors::NonFungibleTokenError::NonceTooLow);
+    }
+    if nonce_window != 0 && nonce - stored_nonce > u64::from(nonce_window) {
+        return Err(errors::NonFungibleTokenError::NonceTooFarAhead);
     }
 
-    // Build message hash with nonce
-    let mut builder: Bytes = Bytes::new(&e);
-    builder.append(&message.clone());
-    builder.append(&nonce.clone().to_xdr(&e));
-    let message_hash = e.crypto().sha256(&builder);
+    Ok(())
+}
 
-    // Verify signature recovers to the public_key
-    let recovered = e.crypto().secp256k1_recover(&message_hash, &signature, recovery_id);
-    if recovered != public_key {
-        panic_with_error!(&e, &errors::NonFungibleTokenError::InvalidSignature);
+/// Reject signatures used after their expiration ledger, 0 meaning no expiry
+fn check_expiration(e: &Env, expiration_ledger: u32) -> Result<(), errors::NonFungibleTokenError> {
+    if expiration_ledger != 0 && e.ledger().sequence() > expiration_ledger {
+        return Err(errors::NonFungibleTokenError::SignatureExpired);
     }
-    
-    // Update stored nonce for this public_key
+
+    Ok(())
+}
+
+/// Update stored nonce for this public_key
+fn store_nonce(e: &Env, public_key: &BytesN<65>, nonce: u64) {
+    let nonce_key = NFTStorageKey::ChipNonceByPublicKey(public_key.clone());
     e.storage().persistent().set(&nonce_key, &nonce);
+    token_ttl::extend_entry(e, &nonce_key);
+
+    events::NonceUsed { public_key: public_key.clone(), nonce }.publish(e);
+}
+
+/// Summary of token_id, or None if it does not exist
+fn token_info_opt(e: &Env, token_id: u64) -> Option<TokenInfo> {
+    let TokenData { owner, public_key } = token_data_opt(e, token_id)?;
+    Some(TokenInfo {
+        token_id,
+        claimed: owner.is_some(),
+        owner,
+        public_key,
+        uri: StellarMerchShop::token_uri(e, token_id),
+    })
+}
+
+/// Read-only validation of a mint once the chip signature is checked, shared by mint and simulate_mint
+/// Returns the token_id the chip would be minted as
+fn check_mintable(e: &Env, series_id: u32, public_key: &BytesN<65>) -> Result<u64, errors::NonFungibleTokenError> {
+    let public_key_lookup = NFTStorageKey::TokenIdByPublicKey(public_key.clone());
+    if e.storage().persistent().has(&public_key_lookup) {
+        return Err(errors::NonFungibleTokenError::TokenAlreadyMinted);
+    }
+    chip_allowlist::check_allowed(e, public_key)?;
+    series::check_mintable(e, series_id)?;
+
+    // Token ids are shared by every series
+    let token_id: u64 = e
+        .storage()
+        .instance()
+        .get(&DataKey::NextTokenId)
+        .unwrap();
+
+    Ok(token_id)
+}
+
+/// Message covered by the chip signature: the contract address is prepended so a
+/// signature cannot be replayed on another deployment, and the recipient is bound
+/// so a pending claim or transfer cannot be replayed with another address
+fn signed_message(e: &Env, operation: ChipOperation, message: &Bytes, recipient: Option<&Address>, expiration_ledger: u32) -> Bytes {
+    merch_crypto::soroban::signed_message(e, &StellarMerchShop::message_domain(e), operation as u8, message, recipient, expiration_ledger)
+}
+
+/// Hash the chip signs for message, shared by build_message_hash and the signature checks
+fn message_hash(e: &Env, operation: ChipOperation, message: &Bytes, recipient: Option<&Address>, nonce: u64, expiration_ledger: u32) -> Hash<32> {
+    chip_message_hash(e, &signed_message(e, operation, message, recipient, expiration_ledger), nonce)
+}
+
+/// Leading zero bytes of an Ed25519 key in its 65 bytes form
+const ED25519_KEY_PADDING: usize = 33;
+
+/// First byte of an uncompressed SEC1 public key
+const SEC1_UNCOMPRESSED_PREFIX: u8 = 0x04;
+
+/// Public key the chip's entries are stored and published under
+/// Ed25519 keys are left padded with zeros, which no SEC1 encoding starts with,
+/// so every chip shares the nonce, lookup and token entries of ECDSA chips
+pub(crate) fn chip_public_key(e: &Env, chip_key: &ChipKey) -> BytesN<65> {
+    match chip_key {
+        ChipKey::Ecdsa(public_key) => public_key.clone(),
+        ChipKey::Ed25519(public_key) => {
+            let mut padded_key = [0u8; 65];
+            padded_key[ED25519_KEY_PADDING..].copy_from_slice(&public_key.to_array());
+            BytesN::from_array(e, &padded_key)
+        }
+    }
+}
+
+/// Inverse of chip_public_key
+pub(crate) fn chip_key_of(e: &Env, public_key: BytesN<65>) -> ChipKey {
+    let padded_key = public_key.to_array();
+    if padded_key[..ED25519_KEY_PADDING] != [0u8; ED25519_KEY_PADDING] {
+        return ChipKey::Ecdsa(public_key);
+    }
+
+    let mut ed25519_key = [0u8; 32];
+    ed25519_key.copy_from_slice(&padded_key[ED25519_KEY_PADDING..]);
+    ChipKey::Ed25519(BytesN::from_array(e, &ed25519_key))
+}
+
+/// The chip emits high S signatures freely but the host only recovers low S ones
+/// Substituting n - s for s mirrors R's y coordinate, so the recovery ID parity flips
+fn normalize_signature(e: &Env, signature: &BytesN<64>, recovery_id: u32) -> (BytesN<64>, u32) {
+    let mut signature_bytes = signature.to_array();
+    let mut s = [0u8; 32];
+    s.copy_from_slice(&signature_bytes[32..]);
+    let s_normalized = merch_crypto::normalize_s(&s);
+    if s_normalized == s {
+        return (signature.clone(), recovery_id);
+    }
+
+    signature_bytes[32..].copy_from_slice(&s_normalized);
+    (BytesN::from_array(e, &signature_bytes), recovery_id ^ 1)
+}
+
+/// Longest DER encoding of a secp256k1 signature: both integers padded to 33 bytes
+const MAX_DER_SIGNATURE_LENGTH: u32 = 72;
+
+/// 64 bytes R || S signature of a DER encoded chip signature
+fn der_signature_bytes(e: &Env, der_signature: &Bytes) -> BytesN<64> {
+    if der_signature.len() > MAX_DER_SIGNATURE_LENGTH {
+        panic_with_error!(e, errors::NonFungibleTokenError::MalformedSignature);
+    }
+    let mut der = [0u8; MAX_DER_SIGNATURE_LENGTH as usize];
+    let der = &mut der[..der_signature.len() as usize];
+    der_signature.copy_into_slice(der);
+
+    let signature_bytes = merch_crypto::parse_der_signature(der)
+        .unwrap_or_else(|_| panic_with_error!(e, errors::NonFungibleTokenError::MalformedSignature));
+    // The host traps on a zero R or S instead of failing the recovery
+    if signature_bytes[..32] == [0u8; 32] || signature_bytes[32..] == [0u8; 32] {
+        panic_with_error!(e, errors::NonFungibleTokenError::MalformedSignature);
+    }
+    BytesN::from_array(e, &signature_bytes)
+}
+
+/// Normalized signature and the recovery ID to use with it
+/// Without a recovery ID from the caller, it is found by recovering against public_key:
+/// only 0 and 1 are tried as 2 and 3 need R's x coordinate above n, which a chip never
+/// produces in practice, so at most two recoveries
+/// Signatures the host would reject by trapping are reported as InvalidSignature
+fn resolve_recovery_id(
+    e: &Env,
+    message_hash: &Hash<32>,
+    signature: &BytesN<64>,
+    recovery_id: Option<u32>,
+    public_key: &BytesN<65>,
+) -> Result<(BytesN<64>, u32), errors::NonFungibleTokenError> {
+    if let Some(recovery_id) = recovery_id {
+        check_recovery_id(recovery_id)?;
+    }
+
+    let (signature, normalized_recovery_id) = normalize_signature(e, signature, recovery_id.unwrap_or(0));
+    let mut r = [0u8; 32];
+    let mut s = [0u8; 32];
+    r.copy_from_slice(&signature.to_array()[..32]);
+    s.copy_from_slice(&signature.to_array()[32..]);
+    if !merch_crypto::is_low_s(&s) || !merch_crypto::is_valid_r(&r, normalized_recovery_id) {
+        return Err(errors::NonFungibleTokenError::InvalidSignature);
+    }
+
+    match recovery_id {
+        Some(_) => Ok((signature, normalized_recovery_id)),
+        None => (0..2)
+            .find(|&recovery_id| e.crypto().secp256k1_recover(message_hash, &signature, recovery_id) == *public_key)
+            .map(|recovery_id| (signature, recovery_id))
+            .ok_or(errors::NonFungibleTokenError::SignatureRecoveryMismatch),
+    }
+}
+
+/// Check that the signature of message_hash recovers to public_key
+/// High S signatures are normalized first; inputs the host would still
+/// reject (recovery ID out of range, zero S) are reported as errors instead of trapping
+fn check_signature(
+    e: &Env,
+    message_hash: &Hash<32>,
+    signature: &BytesN<64>,
+    recovery_id: Option<u32>,
+    public_key: &BytesN<65>,
+) -> Result<(), errors::NonFungibleTokenError> {
+    if StellarMerchShop::chip_curve(e, public_key.clone()) == CurveType::Secp256r1 {
+        return check_p256_signature(e, message_hash, signature, public_key);
+    }
+
+    let (signature, resolved_recovery_id) = resolve_recovery_id(e, message_hash, signature, recovery_id, public_key)?;
+    if recovery_id.is_none() {
+        // Already recovered to public_key
+        return Ok(());
+    }
+
+    let recovered = e.crypto().secp256k1_recover(message_hash, &signature, resolved_recovery_id);
+    if recovered != *public_key {
+        return Err(errors::NonFungibleTokenError::SignatureRecoveryMismatch);
+    }
+
+    Ok(())
+}
+
+/// Check the P-256 signature of message_hash against public_key, nothing to recover
+/// Malformed signatures are reported as errors, the host traps on a well-formed one
+/// that does not match
+fn check_p256_signature(
+    e: &Env,
+    message_hash: &Hash<32>,
+    signature: &BytesN<64>,
+    public_key: &BytesN<65>,
+) -> Result<(), errors::NonFungibleTokenError> {
+    let mut signature_bytes = signature.to_array();
+    let mut r = [0u8; 32];
+    let mut s = [0u8; 32];
+    r.copy_from_slice(&signature_bytes[..32]);
+    s.copy_from_slice(&signature_bytes[32..]);
+    let s = merch_crypto::normalize_s_p256(&s);
+    if !merch_crypto::is_valid_p256_signature(&r, &s) {
+        return Err(errors::NonFungibleTokenError::InvalidSignature);
+    }
+
+    signature_bytes[32..].copy_from_slice(&s);
+    e.crypto().secp256r1_verify(public_key, message_hash, &BytesN::from_array(e, &signature_bytes));
+    Ok(())
+}
+
+/// Boolean form of check_signature for read-only verification, false for P-256 chips
+pub(crate) fn signature_matches(
+    e: &Env,
+    message_hash: &Hash<32>,
+    signature: &BytesN<64>,
+    recovery_id: Option<u32>,
+    public_key: &BytesN<65>,
+) -> bool {
+    check_verifiable_curve(e, &ChipKey::Ecdsa(public_key.clone())).is_ok()
+        && check_signature(e, message_hash, signature, recovery_id, public_key).is_ok()
 }
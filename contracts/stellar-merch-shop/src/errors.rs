@@ -9,6 +9,10 @@ pub enum NonFungibleTokenError {
     /// Indicates an error related to the ownership over a particular token.
     /// Used in transfers.
     IncorrectOwner = 201,
+    /// Indicates a failure with the `approver` of a token to be approved.
+    InvalidApprover = 203,
+    /// Indicates an expiration ledger in the past.
+    InvalidLiveUntilLedger = 204,
     /// Indicates overflow when adding two values
     MathOverflow = 205,
     /// Indicates all possible `token_id`s are already in use.
@@ -17,10 +21,109 @@ pub enum NonFungibleTokenError {
     InvalidAmount = 207,
     /// Indicates the token was already minted.
     TokenAlreadyMinted = 210,
+    /// Indicates the base URI is longer than `MAX_BASE_URI_LENGTH`.
+    BaseUriMaxLenExceeded = 211,
     /// Indicates the royalty amount is higher than 10_000 (100%) basis points.
     InvalidRoyaltyAmount = 212,
     /// Indicates an invalid signature
     InvalidSignature = 214,
     /// Indicates the token exists but has not been claimed yet
     TokenNotClaimed = 215,
+    /// Indicates a missing or invalid reason code for an admin action
+    InvalidReasonCode = 216,
+    /// Indicates the token is attached to a parent token or has children
+    /// attached, or the attachment would nest tokens more than one level deep
+    TokenAttached = 217,
+    /// Indicates there is no open escrow for the token
+    EscrowNotFound = 218,
+    /// Indicates the escrow timeout has not been reached yet
+    EscrowNotExpired = 219,
+    /// Indicates the escrow timeout has been reached
+    EscrowExpired = 220,
+    /// Indicates the token is held in escrow
+    TokenInEscrow = 221,
+    /// Indicates a status change that the workflow does not allow
+    InvalidStatusTransition = 222,
+    /// Indicates the token changed hands too recently to be transferred again
+    TransferCooldownActive = 223,
+    /// Indicates the token is reserved for another claimant
+    ReservedForAnother = 224,
+    /// Indicates a missing or incorrect claim code
+    InvalidClaimCode = 225,
+    /// Indicates the claim code was already redeemed
+    ClaimCodeRedeemed = 226,
+    /// Indicates the claim code expired
+    ClaimCodeExpired = 227,
+    /// Indicates the contract was already initialized
+    AlreadyInitialized = 228,
+    /// Indicates there is no treasury proposal to accept
+    NoPendingTreasury = 229,
+    /// Indicates the partner has no mint allowance left for the SKU
+    MintAllowanceExhausted = 230,
+    /// Indicates the chip is not allocated to the requested SKU
+    ChipSkuMismatch = 231,
+    /// Indicates an empty signed message or one over the maximum length
+    MalformedMessage = 232,
+    /// Indicates an empty URI or one over the maximum length
+    InvalidUri = 233,
+    /// Indicates the account is not the recovery address of the owner
+    RecoveryNotConfigured = 234,
+    /// Indicates there is no announced recovery for the owner
+    NoPendingRecovery = 235,
+    /// Indicates the recovery delay has not passed yet
+    RecoveryDelayActive = 236,
+    /// Indicates the spender has no valid approval for the token
+    Unauthorized = 237,
+    /// Indicates the nonce was not greater than the last consumed one
+    NonceTooLow = 238,
+    /// Indicates the signature recovers to another public key
+    SignatureRecoveryMismatch = 239,
+    /// Indicates a recovery ID outside of 0-3
+    InvalidRecoveryId = 240,
+    /// Indicates a signature that is not valid DER
+    MalformedSignature = 241,
+    /// Indicates a signature used after its expiration ledger
+    SignatureExpired = 242,
+    /// Indicates there is no admin transfer to accept
+    NoPendingAdmin = 243,
+    /// Indicates the chip is not on the allowlist
+    ChipNotAllowed = 244,
+    /// Indicates the chip was revoked
+    ChipRevoked = 245,
+    /// Indicates the token is soulbound and cannot be transferred
+    TokenSoulbound = 246,
+    /// Indicates the perk of the token was already redeemed
+    AlreadyRedeemed = 247,
+    /// Indicates an attribute key or value over the maximum length
+    AttributeTooLong = 248,
+    /// Indicates a series that does not exist
+    SeriesNotFound = 249,
+    /// Indicates a series id that is already taken
+    SeriesAlreadyExists = 250,
+    /// Indicates a claim after the claim deadline
+    ClaimWindowClosed = 251,
+    /// Indicates the claim deadline has not passed yet
+    ClaimWindowOpen = 252,
+    /// Indicates the recipient contract refused the token
+    ReceiverRejected = 253,
+    /// Indicates a public key that is neither an uncompressed SEC1 point
+    /// nor a non-zero Ed25519 key
+    InvalidPublicKey = 254,
+    /// Indicates the token requires a chip signature and the owner's authorization to move
+    DualAuthRequired = 255,
+    /// Indicates the nonce is further ahead of the last consumed one than the nonce window
+    NonceTooFarAhead = 256,
+    /// Indicates a merchant that does not exist
+    MerchantNotFound = 257,
+    /// Indicates a read-only check of a `Secp256r1` chip, the host aborts
+    /// instead of failing on a signature that does not match
+    UnverifiableCurve = 258,
+    /// Indicates the token already has an owner
+    TokenAlreadyClaimed = 259,
+    /// Indicates a transfer to another account than the one the approval is restricted to
+    RecipientNotAllowed = 260,
+    /// Indicates a collection or series name longer than `MAX_NAME_LENGTH`
+    NameTooLong = 261,
+    /// Indicates a donation while no donation address is set
+    DonationAddressNotSet = 262,
 }
\ No newline at end of file
@@ -0,0 +1,206 @@
+//! Escrowed transfers released on receipt confirmation
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Bytes, BytesN, Env};
+use crate::contract::{ChipKey, ChipOperation};
+use crate::{chip_stats, contract, errors, events, receiver, NonFungibleToken, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+#[cfg(feature = "composable")]
+use crate::composable;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Escrow {
+    pub from: Address,
+    pub to: Address,
+    pub expiration_ledger: u32,
+}
+
+#[contracttype]
+pub enum EscrowStorageKey {
+    Escrow(u64),
+}
+
+pub trait EscrowTransfer {
+    /// Parks `token_id` token in contract custody until `to` confirms receipt.
+    ///
+    /// The chip signature proves the physical item is handed over. If `to`
+    /// never confirms, `from` can take the token back once `timeout_ledgers`
+    /// ledgers have passed.
+    ///
+    /// The chip signs `build_message_hash(Transfer, message, Some(to), nonce, 0)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    /// * `timeout_ledgers` - Number of ledgers `to` has to confirm receipt.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["escrow_opened", from: Address, to: Address]`
+    /// * data - `[token_id: u64, expiration_ledger: u32]`
+    fn escrow_transfer(e: &Env, from: Address, to: Address, token_id: u64, timeout_ledgers: u32, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>, nonce: u64);
+
+    /// Completes the escrowed transfer of `token_id` token to `to`.
+    ///
+    /// Only possible before the escrow timeout.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["escrow_completed", from: Address, to: Address]`
+    /// * data - `[token_id: u64]`
+    fn confirm_receipt(e: &Env, to: Address, token_id: u64);
+
+    /// Returns the escrowed `token_id` token to `from`.
+    ///
+    /// Only possible once the escrow timeout is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["escrow_reclaimed", from: Address]`
+    /// * data - `[token_id: u64]`
+    fn reclaim_escrow(e: &Env, from: Address, token_id: u64);
+
+    /// Returns the open escrow for `token_id`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn escrow_of(e: &Env, token_id: u64) -> Option<Escrow>;
+}
+
+#[contractimpl]
+impl EscrowTransfer for StellarMerchShop {
+    fn escrow_transfer(
+        e: &Env,
+        from: Address,
+        to: Address,
+        token_id: u64,
+        timeout_ledgers: u32,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        nonce: u64,
+    ) {
+        from.require_auth();
+
+        contract::verify_token_chip_signature(e, ChipOperation::Transfer, token_id, message, signature, recovery_id, ChipKey::Ecdsa(public_key.clone()), nonce, 0, &to);
+
+        let owner = StellarMerchShop::owner_of(e, token_id);
+        if owner != from || from == to {
+            panic_with_error!(e, &errors::NonFungibleTokenError::IncorrectOwner);
+        }
+        #[cfg(feature = "composable")]
+        {
+            composable::require_detached(e, token_id);
+            composable::require_children_transferable(e, token_id, true);
+        }
+        contract::require_not_soulbound(e, token_id);
+        contract::require_cooldown_elapsed(e, token_id);
+
+        let expiration_ledger = e
+            .ledger()
+            .sequence()
+            .checked_add(timeout_ledgers)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+
+        // The contract holds the token until the escrow is settled
+        let custody = e.current_contract_address();
+        contract::update_owner(e, &from, &custody, token_id);
+        #[cfg(feature = "composable")]
+        composable::move_children(e, &from, &custody, token_id);
+        chip_stats::record_transfer(e, &public_key);
+
+        let escrow = Escrow { from: from.clone(), to: to.clone(), expiration_ledger };
+        e.storage().persistent().set(&EscrowStorageKey::Escrow(token_id), &escrow);
+
+        events::EscrowOpened { from: from.clone(), to, token_id, expiration_ledger }.publish(e);
+        contract::publish_activity(e, events::Operation::EscrowOpened, token_id, Some(from), Some(custody), Some(nonce));
+    }
+
+    fn confirm_receipt(e: &Env, to: Address, token_id: u64) {
+        to.require_auth();
+
+        let escrow = get_escrow(e, token_id);
+        if escrow.to != to {
+            panic_with_error!(e, &errors::NonFungibleTokenError::IncorrectOwner);
+        }
+        if e.ledger().sequence() >= escrow.expiration_ledger {
+            panic_with_error!(e, &errors::NonFungibleTokenError::EscrowExpired);
+        }
+
+        e.storage().persistent().remove(&EscrowStorageKey::Escrow(token_id));
+
+        let custody = e.current_contract_address();
+        contract::update_owner(e, &custody, &to, token_id);
+        #[cfg(feature = "composable")]
+        composable::move_children(e, &custody, &to, token_id);
+
+        events::EscrowCompleted { from: escrow.from, to: to.clone(), token_id }.publish(e);
+        contract::publish_activity(e, events::Operation::EscrowCompleted, token_id, Some(custody.clone()), Some(to.clone()), None);
+
+        receiver::check_received(e, &to, &custody, &to, token_id);
+    }
+
+    fn reclaim_escrow(e: &Env, from: Address, token_id: u64) {
+        from.require_auth();
+
+        let escrow = get_escrow(e, token_id);
+        if escrow.from != from {
+            panic_with_error!(e, &errors::NonFungibleTokenError::IncorrectOwner);
+        }
+        if e.ledger().sequence() < escrow.expiration_ledger {
+            panic_with_error!(e, &errors::NonFungibleTokenError::EscrowNotExpired);
+        }
+
+        e.storage().persistent().remove(&EscrowStorageKey::Escrow(token_id));
+
+        let custody = e.current_contract_address();
+        contract::update_owner(e, &custody, &from, token_id);
+        #[cfg(feature = "composable")]
+        composable::move_children(e, &custody, &from, token_id);
+
+        events::EscrowReclaimed { from: from.clone(), token_id }.publish(e);
+        contract::publish_activity(e, events::Operation::EscrowReclaimed, token_id, Some(custody.clone()), Some(from.clone()), None);
+
+        receiver::check_received(e, &from, &custody, &from, token_id);
+    }
+
+    fn escrow_of(e: &Env, token_id: u64) -> Option<Escrow> {
+        e.storage()
+            .persistent()
+            .get(&EscrowStorageKey::Escrow(token_id))
+    }
+}
+
+fn get_escrow(e: &Env, token_id: u64) -> Escrow {
+    StellarMerchShop::escrow_of(e, token_id)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::EscrowNotFound))
+}
+
+/// Panic if `token_id` is held in escrow
+pub(crate) fn require_not_escrowed(e: &Env, token_id: u64) {
+    if e.storage().persistent().has(&EscrowStorageKey::Escrow(token_id)) {
+        panic_with_error!(e, &errors::NonFungibleTokenError::TokenInEscrow);
+    }
+}
@@ -1,4 +1,5 @@
-use soroban_sdk::{Address, contractevent, Bytes};
+use soroban_sdk::{Address, contractevent, contracttype, Bytes, BytesN, String};
+use crate::contract::{CurveType, SecurityLevel};
 
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -14,9 +15,32 @@ pub struct Transfer {
     pub from: Address,
     #[topic]
     pub to: Address,
+    #[topic]
+    pub merchant_id: u32,
+    #[topic]
+    pub sku: Option<u32>,
+    pub token_id: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Burn {
+    #[topic]
+    pub from: Address,
     pub token_id: u64,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransfer {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub token_id: u64,
+    pub reason: u32,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Approve {
@@ -28,6 +52,18 @@ pub struct Approve {
     pub live_until_ledger: u32,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApproveForRecipient {
+    #[topic]
+    pub approver: Address,
+    #[topic]
+    pub token_id: u64,
+    pub approved: Address,
+    pub recipient: Address,
+    pub live_until_ledger: u32,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ApproveForAll {
@@ -37,11 +73,38 @@ pub struct ApproveForAll {
     pub live_until_ledger: u32,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApproveOperatorLimited {
+    #[topic]
+    pub owner: Address,
+    pub operator: Address,
+    pub remaining: u32,
+    pub live_until_ledger: u32,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Mint {
     #[topic]
     pub token_id: u64,
+    #[topic]
+    pub public_key: BytesN<65>,
+    #[topic]
+    pub merchant_id: u32,
+    #[topic]
+    pub sku: Option<u32>,
+    pub to: Option<Address>,
+    pub po_number: Option<u32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    pub next_token_id: u64,
+    pub total_supply: u64,
+    pub claimed_count: u64,
+    pub ledger: u32,
 }
 
 #[contractevent]
@@ -49,5 +112,488 @@ pub struct Mint {
 pub struct Claim {
     #[topic]
     pub claimant: Address,
+    #[topic]
+    pub public_key: BytesN<65>,
+    #[topic]
+    pub merchant_id: u32,
+    #[topic]
+    pub sku: Option<u32>,
+    pub token_id: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NonceUsed {
+    #[topic]
+    pub public_key: BytesN<65>,
+    pub nonce: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataUpdate {
+    pub token_id: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attach {
+    #[topic]
+    pub parent_id: u64,
+    #[topic]
+    pub child_id: u64,
+    pub owner: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Detach {
+    #[topic]
+    pub parent_id: u64,
+    #[topic]
+    pub child_id: u64,
+    pub owner: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowOpened {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub token_id: u64,
+    pub expiration_ledger: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowCompleted {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub token_id: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReclaimed {
+    #[topic]
+    pub from: Address,
+    pub token_id: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WarrantyClaimOpened {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+    pub issue_hash: BytesN<32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WarrantyClaimResolved {
+    #[topic]
+    pub token_id: u64,
+    pub approved: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WarrantyClaimFulfilled {
+    #[topic]
+    pub token_id: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reclaim {
+    #[topic]
+    pub token_id: u64,
+    pub public_key: BytesN<65>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Redeem {
+    #[topic]
+    pub token_id: u64,
+    pub owner: Address,
+    pub timestamp: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Scan {
+    #[topic]
+    pub public_key: BytesN<65>,
+    pub count: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChipFirmwareUpdated {
+    #[topic]
+    pub public_key: BytesN<65>,
+    pub previous_firmware: u32,
+    pub firmware: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeriesCreated {
+    #[topic]
+    pub series_id: u32,
+    pub name: String,
+    pub base_uri: String,
+    pub max_tokens: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxTokensUpdated {
+    pub previous_max_tokens: u64,
+    pub max_tokens: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChipRebound {
+    #[topic]
+    pub token_id: u64,
+    pub previous_public_key: BytesN<65>,
+    pub public_key: BytesN<65>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposed {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub pending_admin: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminChanged {
+    #[topic]
+    pub previous_admin: Address,
+    #[topic]
+    pub admin: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryChanged {
+    #[topic]
+    pub previous_treasury: Address,
+    #[topic]
+    pub treasury: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Withdraw {
+    #[topic]
+    pub token: Address,
+    #[topic]
+    pub to: Address,
+    #[topic]
+    pub merchant_id: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryInitiated {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub recovery: Address,
+    pub executable_ledger: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryCancelled {
+    #[topic]
+    pub owner: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Recovery {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub recovery: Address,
+    pub token_id: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryAddressSet {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub recovery: Address,
+    pub delay_ledgers: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BaseUriUpdated {
+    #[topic]
+    pub series_id: u32,
+    pub base_uri: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnclaimedUriUpdated {
+    pub uri: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChipRevoked {
+    #[topic]
+    pub public_key: BytesN<65>,
+    pub revoked: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChipCurveUpdated {
+    #[topic]
+    pub public_key: BytesN<65>,
+    pub curve: CurveType,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SoulboundUpdated {
+    #[topic]
+    pub token_id: u64,
+    pub soulbound: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerchantCreated {
+    #[topic]
+    pub merchant_id: u32,
+    pub admin: Address,
+    pub name: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerchantAdminUpdated {
+    #[topic]
+    pub merchant_id: u32,
+    pub admin: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiverHookUpdated {
+    #[topic]
+    pub receiver: Address,
+    pub enabled: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SecurityLevelUpdated {
+    #[topic]
+    pub token_id: u64,
+    pub level: SecurityLevel,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimDeadlineUpdated {
+    pub ledger_timestamp: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferCooldownUpdated {
+    pub ledgers: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NonceWindowUpdated {
+    pub window: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChipAuthUpdated {
+    pub chip_auth: Option<Address>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckpointIntervalUpdated {
+    pub mints: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxMessageLengthUpdated {
+    pub length: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChipAllowed {
+    #[topic]
+    pub public_key: BytesN<65>,
+    pub allowed: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowlistEnabled {
+    pub enabled: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoyaltyUpdated {
+    #[topic]
+    pub token_id: Option<u64>,
+    pub receiver: Address,
+    pub basis_points: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceUpdated {
+    #[topic]
+    pub payment_token: Address,
+    #[topic]
+    pub merchant_id: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferFeeUpdated {
+    #[topic]
+    pub payment_token: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Donation {
+    #[topic]
+    pub donor: Address,
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub payment_token: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeExemptionUpdated {
+    #[topic]
+    pub account: Address,
+    pub exempt: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChipSkuUpdated {
+    #[topic]
+    pub public_key: BytesN<65>,
+    pub sku: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintAllowanceUpdated {
+    #[topic]
+    pub partner: Address,
+    #[topic]
+    pub sku: u32,
+    pub quantity: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimCodeIssued {
+    #[topic]
+    pub token_id: u64,
+    pub expiry_ledger: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reserved {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub claimant: Address,
+    pub expiry_ledger: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReservationReleased {
+    #[topic]
+    pub token_id: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimDelegated {
+    #[topic]
+    pub token_id: u64,
+    #[topic]
+    pub delegate: Address,
+}
+
+/// Schema version of ActivityV1, bump it on any change to the event shape
+pub const ACTIVITY_SCHEMA_VERSION: u32 = 1;
+
+/// Token state change reported by ActivityV1
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operation {
+    Mint,
+    Claim,
+    Transfer,
+    AdminTransfer,
+    EscrowOpened,
+    EscrowCompleted,
+    EscrowReclaimed,
+    Burn,
+}
+
+/// Consolidated view of a token state change for indexers
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityV1 {
+    #[topic]
+    pub operation: Operation,
+    #[topic]
     pub token_id: u64,
+    pub public_key: BytesN<65>,
+    pub from: Option<Address>,
+    pub to: Option<Address>,
+    pub sku: Option<u32>,
+    pub nonce: Option<u64>,
+    pub ledger: u32,
+    pub version: u32,
 }
@@ -1,46 +1,358 @@
 #![no_std]
 #![allow(dead_code)]
 
-use soroban_sdk::{contract, contractmeta, Env, Address, String, BytesN, Bytes};
+use soroban_sdk::{contract, contractmeta, Env, Address, String, BytesN, Bytes, Vec};
 
 contractmeta!(key = "Description", val = "Stellar Merch Shop");
+contractmeta!(key = "Version", val = env!("CARGO_PKG_VERSION"));
 
 mod contract;
+mod attributes;
+mod chip_allowlist;
+mod chip_auth;
+mod chip_info;
+mod chip_registry;
+mod chip_stats;
+#[cfg(feature = "claim-codes")]
+mod claim_codes;
+#[cfg(feature = "composable")]
+mod composable;
+#[cfg(feature = "escrow")]
+mod escrow;
+#[cfg(feature = "locales")]
+mod locales;
+mod merchants;
+mod onchain_metadata;
+mod owner_index;
+#[cfg(feature = "provenance")]
+mod ownership_history;
+#[cfg(feature = "partner-mint")]
+mod partner_mint;
+mod purchase_orders;
+mod receiver;
+#[cfg(feature = "recovery")]
+mod recovery;
+#[cfg(feature = "provenance")]
+mod recent_claims;
+mod redemption;
+#[cfg(feature = "reservations")]
+mod reservation;
+mod royalty;
+mod sales;
+mod series;
+mod token_index;
+mod token_ttl;
+mod treasury;
+#[cfg(feature = "warranty")]
+mod warranty;
 
 #[cfg(test)]
 mod test;
 mod errors;
 mod events;
 
+pub use chip_info::ChipInfo;
+pub use contract::{BuildInfo, ChipKey, ChipOperation, CurveType, MintPreview, ProductVerification, SecurityLevel, TokenInfo, VerifyItem};
+
 #[contract]
 pub struct StellarMerchShop;
 
 pub trait NFCtoNFTContract {
 
-    fn __constructor(e: &Env, admin: Address, name: String, symbol: String, uri: String, max_tokens: u64);
+    /// Initialize the collection.
+    ///
+    /// The collection starts with a single series, `DEFAULT_SERIES_ID`,
+    /// named after the collection. Other drops are added with `create_series`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `admin` - Account administering the collection.
+    /// * `name` - Name of the collection, up to `MAX_NAME_LENGTH` bytes.
+    /// * `symbol` - Symbol of the collection.
+    /// * `uri` - Base URI of the default series metadata, up to `MAX_BASE_URI_LENGTH` bytes.
+    /// * `max_tokens` - Maximum number of tokens the default series can mint.
+    /// * `activity_events` - Whether to also emit the consolidated
+    ///   `ActivityV1` event on every token state change, for indexers.
+    /// * `onchain_metadata` - Whether `token_uri` returns the metadata
+    ///   itself as a `data:application/json;base64,` URI, so no metadata
+    ///   host is needed.
+    fn __constructor(
+        e: &Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        uri: String,
+        max_tokens: u64,
+        activity_events: bool,
+        onchain_metadata: bool,
+    );
 
+    /// Replace the contract code, keeping its storage.
+    ///
+    /// The new code must already be uploaded to the network. Call `migrate`
+    /// once the upgrade is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `wasm_hash` - Hash of the uploaded wasm to switch to.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["upgrade"]`
+    /// * data - `[admin: Address, wasm_hash: Bytes]`
     fn upgrade(e: &Env, wasm_hash: BytesN<32>);
 
+    /// Refresh stored state after an upgrade.
+    ///
+    /// `upgrade` swaps the code once the invocation returns, so anything
+    /// derived from the new code (such as the build metadata) is recorded by
+    /// calling this function afterwards. Contracts deployed before series
+    /// get their collection moved to the default series.
+    ///
+    /// Tokens minted before `TokenData` are rewritten to a single `Token`
+    /// entry, up to `MAX_MIGRATE_BATCH` token ids per call, and the claimed
+    /// ones missing from the owner index are added to `tokens_of`. Tokens
+    /// read in the meantime are rewritten by that read already.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Returns
+    ///
+    /// True once every token is rewritten, call again until then.
+    fn migrate(e: &Env) -> bool;
+
+    /// Returns the progress of the move of tokens minted before `TokenData`.
+    ///
+    /// Any call reading a token still in the legacy layout rewrites it
+    /// right away, `migrate` goes through the remaining token ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Returns
+    ///
+    /// The number of tokens rewritten so far, on read or by `migrate`, and
+    /// the number of token ids `migrate` still has to go through.
+    fn migration_progress(e: &Env) -> (u64, u64);
+
+    /// Returns the build metadata of the live code.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Returns
+    ///
+    /// The crate version, `git describe` output and build profile.
+    fn build_info(e: &Env) -> BuildInfo;
+
+    /// Returns the interface version of the live code.
+    ///
+    /// Clients and indexers compare it to the version they support to
+    /// detect an upgrade.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn version(e: &Env) -> u32;
+
+    /// Proposes `new_admin` as the administrator of the collection.
+    ///
+    /// The current admin keeps its rights until `new_admin` calls
+    /// `accept_admin`. Proposing again replaces the pending address, which
+    /// is how a mistaken proposal is cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `new_admin` - Account proposed as the admin.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["admin_proposed", admin: Address, pending_admin: Address]`
+    /// * data - `[]`
+    fn transfer_admin(e: &Env, new_admin: Address);
+
+    /// Accepts the pending admin proposal, signed by the proposed admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["admin_changed", previous_admin: Address, admin: Address]`
+    /// * data - `[]`
+    fn accept_admin(e: &Env);
+
+    /// Returns the admin of the collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn get_admin(e: &Env) -> Address;
+
+    /// Returns the proposed admin awaiting acceptance, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn pending_admin(e: &Env) -> Option<Address>;
+
     /// Mint NFT using NFC chip signature.
     ///
     /// This function verifies that the provided signature was created by an Infineon
     /// NFC chip by recovering the chip's public key. The public key is converted to
     /// a SEP-50 compliant u64 token_id.
     ///
+    /// The token has no owner yet: it stays unclaimed, with `owner_of`
+    /// failing with `TokenNotClaimed`, until the buyer scans the chip and
+    /// calls `claim`.
+    ///
+    /// Every function taking a chip signature fails with `InvalidPublicKey`
+    /// before any cryptography when `public_key` is not an uncompressed SEC1
+    /// key, 0x04 followed by both coordinates.
+    ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
-    /// * `to` - Account of the token's owner.
+    /// * `series_id` - Series the token is minted in, `DEFAULT_SERIES_ID` for the collection created by the constructor.
     /// * `message` - The message that was signed without the nonce.
     /// * `signature` - 64-byte ECDSA signature from NFC chip.
-    /// * `recovery_id` - Recovery ID (0-3) for signature recovery.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    /// * `chip_info` - Optional chip model, firmware and production batch.
+    /// * `po_number` - Optional purchase order the chip was produced under.
+    ///
+    /// # Returns
+    ///
+    /// The u64 token_id (SEP-50 compliant) if signature is valid.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", token_id: u64, public_key: BytesN<65>, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[to: Option<Address>, po_number: Option<u32>]`
+    fn mint(e: &Env, series_id: u32, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32, chip_info: Option<ChipInfo>, po_number: Option<u32>) -> u64;
+
+    /// Same as `mint` with the DER encoded signature returned by the chip.
+    ///
+    /// R and S are extracted in-contract, S is normalized and the recovery
+    /// ID is found against `public_key`. Malformed DER fails with
+    /// `MalformedSignature`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `series_id` - Series the token is minted in.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `der_signature` - DER encoded ECDSA signature from NFC chip.
     /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
-    /// * `nonce` - A nonce to prevent replay attacks.
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    /// * `chip_info` - Optional chip model, firmware and production batch.
+    /// * `po_number` - Optional purchase order the chip was produced under.
+    ///
+    /// # Returns
+    ///
+    /// The u64 token_id (SEP-50 compliant) if signature is valid.
+    fn mint_der(e: &Env, series_id: u32, message: Bytes, der_signature: Bytes, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32, chip_info: Option<ChipInfo>, po_number: Option<u32>) -> u64;
+
+    /// Same as `mint` for a chip signing with an Ed25519 key.
+    ///
+    /// The chip signs the preimage of `build_message_hash` itself instead of
+    /// its hash. The chip is recorded under `chip_public_key`, the public key
+    /// every other function takes. A signature that does not match aborts in
+    /// the host instead of failing with a contract error.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `series_id` - Series the token is minted in.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte Ed25519 signature from NFC chip.
+    /// * `public_key` - The chip's Ed25519 public key (32 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    /// * `chip_info` - Optional chip model, firmware and production batch.
+    /// * `po_number` - Optional purchase order the chip was produced under.
     ///
     /// # Returns
     ///
     /// The u64 token_id (SEP-50 compliant) if signature is valid.
-    fn mint(e: &Env, message: Bytes, signature: BytesN<64>, recovery_id: u32, public_key: BytesN<65>, nonce: u32) -> u64;
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", token_id: u64, public_key: BytesN<65>, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[to: Option<Address>, po_number: Option<u32>]`
+    fn mint_ed25519(e: &Env, series_id: u32, message: Bytes, signature: BytesN<64>, public_key: BytesN<32>, nonce: u64, expiration_ledger: u32, chip_info: Option<ChipInfo>, po_number: Option<u32>) -> u64;
+
+    /// Dry-run of `mint` without any state change.
+    ///
+    /// Performs every validation of `mint` (nonce, signature, duplicate chip,
+    /// supply) read-only, so wallets can preview the result before signing.
+    /// Admin authorization is not checked. `Secp256r1` chips are refused with
+    /// `UnverifiableCurve`, see `set_chip_curve`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `series_id` - Series the token is minted in.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    ///
+    /// # Returns
+    ///
+    /// Whether `mint` would succeed, the `NonFungibleTokenError` code it would
+    /// fail with (0 on success) and the token_id it would mint (0 on failure).
+    fn simulate_mint(e: &Env, series_id: u32, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32) -> MintPreview;
+
+    /// Checks whether the chip signature would be accepted by the entry
+    /// point of `operation`, without consuming the nonce.
+    ///
+    /// Builds the same hash as `build_message_hash` and runs the same
+    /// recovery check as the entry point. Never fails: any rejection,
+    /// including a nonce already used, an expired signature or a revoked
+    /// chip, returns false. Meant to be simulated before submitting the real
+    /// transaction. `Secp256r1` chips always return false, see
+    /// `set_chip_curve`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operation` - Operation the signature authorizes.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `recipient` - Account the signature is bound to, if any, see `build_message_hash`.
+    /// * `nonce` - The nonce the chip signed with, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    ///
+    /// # Returns
+    ///
+    /// Whether the signature is valid for `nonce`.
+    fn verify(
+        e: &Env,
+        operation: ChipOperation,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        recipient: Option<Address>,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) -> bool;
 
     /// Claim NFT using NFC chip signature.
     ///
@@ -48,131 +360,1220 @@ pub trait NFCtoNFTContract {
     /// NFC chip by recovering the chip's public key. The public key is converted to
     /// a SEP-50 compliant u64 token_id.
     ///
+    /// `claimant` must authorize the call, so a relayer cannot claim tokens
+    /// into an account that did not consent.
+    ///
+    /// Claims fail with `ClaimWindowClosed` after `claim_deadline`. When the
+    /// token's merchant set a `price`, it is transferred from `claimant` to
+    /// the treasury, or to the contract for a merchant created with
+    /// `create_merchant`, and a `receipt` is issued.
+    ///
+    /// The chip signs `build_message_hash(Claim, message, Some(claimant), nonce, expiration_ledger)`.
+    ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
     /// * `claimant` - Account of the claimant.
     /// * `message` - The message that was signed without the nonce.
     /// * `signature` - 64-byte ECDSA signature from NFC chip.
-    /// * `recovery_id` - Recovery ID (0-3) for signature recovery.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
     /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
-    /// * `nonce` - A nonce to prevent replay attacks.
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
     ///
     /// # Returns
     ///
     /// The u64 token_id (SEP-50 compliant) if signature is valid.
-    fn claim(e: &Env, claimant: Address, message: Bytes, signature: BytesN<64>, recovery_id: u32, public_key: BytesN<65>, nonce: u32) -> u64;
-
-    /// Transfers `token_id` token from `from` to `to` using NFC chip signature.
     ///
-    /// This function verifies that the provided signature was created by an Infineon
-    /// NFC chip whose public key corresponds to the token being transferred.
+    /// # Events
     ///
-    /// WARNING: Note that the caller is responsible to confirm that the
-    /// recipient is capable of receiving the `Non-Fungible` or else the NFT
-    /// may be permanently lost.
+    /// * topics - `["claim", claimant: Address, public_key: BytesN<65>, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[token_id: u64]`
+    fn claim(e: &Env, claimant: Address, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32) -> u64;
+
+    /// Same as `claim` with the DER encoded signature returned by the chip.
     ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
-    /// * `from` - Account of the sender.
-    /// * `to` - Account of the recipient.
-    /// * `token_id` - Token id as a number.
+    /// * `claimant` - Account of the claimant.
     /// * `message` - The message that was signed without the nonce.
-    /// * `signature` - 64-byte ECDSA signature from NFC chip.
-    /// * `recovery_id` - Recovery ID (0-3) for signature recovery.
+    /// * `der_signature` - DER encoded ECDSA signature from NFC chip.
     /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
-    /// * `nonce` - A nonce to prevent replay attacks.
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    ///
+    /// # Returns
+    ///
+    /// The u64 token_id (SEP-50 compliant) if signature is valid.
+    fn claim_der(e: &Env, claimant: Address, message: Bytes, der_signature: Bytes, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32) -> u64;
+
+    /// Same as `claim` for a chip signing with an Ed25519 key, see `mint_ed25519`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `claimant` - Account of the claimant.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte Ed25519 signature from NFC chip.
+    /// * `public_key` - The chip's Ed25519 public key (32 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    ///
+    /// # Returns
+    ///
+    /// The u64 token_id (SEP-50 compliant) if signature is valid.
     ///
     /// # Events
     ///
-    /// * topics - `["transfer", from: Address, to: Address]`
-    /// * data - `[token_id: BytesN<65>]`
-    fn transfer(e: &Env, from: Address, to: Address, token_id: u64, message: Bytes, signature: BytesN<64>, recovery_id: u32, public_key: BytesN<65>, nonce: u32);
+    /// * topics - `["claim", claimant: Address, public_key: BytesN<65>, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[token_id: u64]`
+    fn claim_ed25519(e: &Env, claimant: Address, message: Bytes, signature: BytesN<64>, public_key: BytesN<32>, nonce: u64, expiration_ledger: u32) -> u64;
 
-    /// Returns the current nonce for the given `public_key`.
+    /// Same as `claim`, with a donation on top of the price.
+    ///
+    /// A positive `donation` is transferred from `claimant` to the donation
+    /// address, see `set_donation_address`, in the payment token of the
+    /// token's merchant. It fails with `DonationAddressNotSet` when no
+    /// donation address is set and with `InvalidAmount` when the merchant
+    /// has no price, so no payment token.
     ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
+    /// * `claimant` - Account of the claimant, paying the price and the donation.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
     /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    /// * `donation` - Amount donated in the smallest unit of the payment token, 0 for none.
     ///
     /// # Returns
     ///
-    /// The current nonce for this chip's public_key (defaults to 0 if not set).
-    fn get_nonce(e: &Env, public_key: BytesN<65>) -> u32;
+    /// The u64 token_id (SEP-50 compliant) if signature is valid.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["claim", claimant: Address, public_key: BytesN<65>, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[token_id: u64]`
+    ///
+    /// * topics - `["donation", donor: Address, token_id: u64, payment_token: Address]`
+    /// * data - `[amount: i128]`
+    fn claim_with_donation(
+        e: &Env,
+        claimant: Address,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        nonce: u64,
+        expiration_ledger: u32,
+        donation: i128,
+    ) -> u64;
 
-    /// Returns the number of tokens in `owner`'s account.
+    /// Mint and claim the chip's NFT to `to` with a single chip signature.
+    ///
+    /// Performs the registration of `mint` and the ownership assignment of
+    /// `claim` atomically, so a shop session needs one chip tap and one
+    /// nonce. Both the admin and `to` must authorize the call.
+    ///
+    /// The chip signs `build_message_hash(Mint, message, Some(to), nonce, expiration_ledger)`.
     ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
-    /// * `owner` - Account of the token's owner.
-    fn balance(e: &Env, owner: Address) -> u32;
+    /// * `to` - Account of the token's owner.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    ///
+    /// # Returns
+    ///
+    /// The u64 token_id (SEP-50 compliant) if signature is valid.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", token_id: u64, public_key: BytesN<65>, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[to: Option<Address>, po_number: Option<u32>]`
+    ///
+    /// * topics - `["claim", claimant: Address, public_key: BytesN<65>, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[token_id: u64]`
+    fn mint_and_claim(e: &Env, to: Address, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32) -> u64;
 
-    /// Returns the address of the owner of the given `token_id`.
+    /// Register chips known from the manufacturer, without their signature.
+    ///
+    /// Each public key gets the next token id with the same bookkeeping as
+    /// `mint`, and the tokens are then claimed with `claim` as usual. Up to
+    /// `MAX_REGISTER_BATCH` keys per call. A key already registered fails
+    /// the whole call with `TokenAlreadyMinted`, a key no chip can have,
+    /// see `chip_public_key`, with `InvalidPublicKey`.
     ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
-    /// * `token_id` - Token id as a number.
+    /// * `series_id` - Series the tokens are minted in.
+    /// * `public_keys` - The chips' public keys (uncompressed SEC1 format, 65 bytes).
     ///
-    /// # Notes
+    /// # Returns
     ///
-    /// If the token does not exist, this function is expected to panic.
-    fn owner_of(e: &Env, token_id: u64) -> Address;
+    /// The token ids assigned to the chips, in order.
+    ///
+    /// # Events
+    ///
+    /// For each chip:
+    ///
+    /// * topics - `["mint", token_id: u64, public_key: BytesN<65>, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[to: Option<Address>, po_number: Option<u32>]`
+    fn register_chips(e: &Env, series_id: u32, public_keys: Vec<BytesN<65>>) -> Vec<u64>;
 
-    /// Returns the token collection name.
+    /// Sets the ledger timestamp after which tokens can no longer be
+    /// claimed, 0 for no deadline.
+    ///
+    /// Claims after the deadline fail with `ClaimWindowClosed`, and the
+    /// tokens left unclaimed can be cleared with `reclaim_unclaimed`.
     ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
-    fn name(e: &Env) -> String;
+    /// * `ledger_timestamp` - Last ledger timestamp a token can be claimed at.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["claim_deadline_updated"]`
+    /// * data - `[ledger_timestamp: u64]`
+    fn set_claim_deadline(e: &Env, ledger_timestamp: u64);
 
-    /// Returns the token collection symbol.
+    /// Returns the ledger timestamp after which tokens can no longer be
+    /// claimed, 0 for no deadline.
     ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
-    fn symbol(e: &Env) -> String;
+    fn claim_deadline(e: &Env) -> u64;
 
-    /// Returns the Uniform Resource Identifier (URI) for `token_id` token.
+    /// Deletes `token_id` token, left unclaimed past the claim deadline,
+    /// so its chip can be minted again in a new drop.
+    ///
+    /// Fails with `ClaimWindowOpen` while tokens can still be claimed and
+    /// with `TokenAlreadyClaimed` once the token has an owner. The
+    /// chip keeps its nonce, so its previous signatures cannot be replayed.
     ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
     /// * `token_id` - Token id as a number.
     ///
-    /// # Notes
+    /// # Events
     ///
-    /// If the token does not exist, this function is expected to panic.
-    fn token_uri(e: &Env, token_id: u64) -> String;
+    /// * topics - `["reclaim", token_id: u64]`
+    /// * data - `[public_key: BytesN<65>]`
+    fn reclaim_unclaimed(e: &Env, token_id: u64);
 
-    /// Returns the token ID for the given chip public key.
+    /// Transfers `token_id` token from `from` to `to` using NFC chip signature.
+    ///
+    /// This function verifies that the provided signature was created by an Infineon
+    /// NFC chip whose public key corresponds to the token being transferred.
+    ///
+    /// WARNING: Note that the caller is responsible to confirm that the
+    /// recipient is capable of receiving the `Non-Fungible` or else the NFT
+    /// may be permanently lost. Contract recipients are asked through the
+    /// receiver hook, see `transfer`.
+    ///
+    /// `from` must authorize the call: the chip signature proves possession
+    /// of the item, the authorization proves the owner consents. Owners who
+    /// lost their wallet go through `admin_transfer`.
+    ///
+    /// The chip signs `build_message_hash(Transfer, message, Some(to), nonce, expiration_ledger)`.
     ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
     /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
     ///
-    /// # Returns
+    /// # Events
     ///
-    /// The token ID associated with this public key, or panics if not found.
-    fn token_id(e: &Env, public_key: BytesN<65>) -> u64;
+    /// * topics - `["transfer", from: Address, to: Address, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[token_id: BytesN<65>]`
+    fn chip_transfer(e: &Env, from: Address, to: Address, token_id: u64, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32);
 
-    /// Returns the chip public key for the given token ID.
+    /// Same as `chip_transfer` with the DER encoded signature returned by the chip.
     ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
     /// * `token_id` - Token id as a number.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `der_signature` - DER encoded ECDSA signature from NFC chip.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    fn chip_transfer_der(e: &Env, from: Address, to: Address, token_id: u64, message: Bytes, der_signature: Bytes, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32);
+
+    /// Same as `chip_transfer` for a chip signing with an Ed25519 key, see `mint_ed25519`.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// The chip's public key associated with this token ID.
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte Ed25519 signature from NFC chip.
+    /// * `public_key` - The chip's Ed25519 public key (32 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
     ///
-    /// # Notes
+    /// # Events
     ///
-    /// If the token does not exist, this function is expected to panic.
-    fn public_key(e: &Env, token_id: u64) -> BytesN<65>;
+    /// * topics - `["transfer", from: Address, to: Address, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[token_id: BytesN<65>]`
+    fn chip_transfer_ed25519(e: &Env, from: Address, to: Address, token_id: u64, message: Bytes, signature: BytesN<64>, public_key: BytesN<32>, nonce: u64, expiration_ledger: u32);
+
+    /// Destroys `token_id` token using NFC chip signature.
+    ///
+    /// Verifies the chip signature like `chip_transfer` and that `from` owns the
+    /// token. The chip's registration is cleared, so the same chip can be
+    /// minted again as a new token with a nonce greater than `get_nonce`.
+    ///
+    /// The chip signs `build_message_hash(Burn, message, Some(from), nonce, expiration_ledger)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the owner.
+    /// * `token_id` - Token id as a number.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u64]`
+    fn burn(e: &Env, from: Address, token_id: u64, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32);
+
+    /// Destroys `token_id` token without chip signature.
+    ///
+    /// For items whose chip is dead or destroyed: only `owner`'s
+    /// authorization is required. Unlike `burn`, the chip stays registered
+    /// to the burned token so it can never be minted again.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the owner.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: u64]`
+    fn burn_from_owner(e: &Env, owner: Address, token_id: u64);
+
+    /// Transfers `token_id` token to `to` on behalf of the admin.
+    ///
+    /// Recovery path for customers who lost access to their wallet but proved
+    /// ownership of the physical item through support. The reason code is
+    /// mandatory so every forced transfer can be audited. A contract `to` is
+    /// asked through the receiver hook with the admin as the operator, see
+    /// `transfer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `to` - Account of the recipient.
+    /// * `reason` - Support reason code, must not be 0.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["admin_transfer", from: Address, to: Address]`
+    /// * data - `[token_id: u64, reason: u32]`
+    fn admin_transfer(e: &Env, token_id: u64, to: Address, reason: u32);
+
+    /// Approve `operator` to transfer tokens of `owner` with `transfer_from`
+    /// at most `remaining` times until `live_until_ledger` (inclusive).
+    ///
+    /// Each transfer by the operator uses up one transfer and the approval
+    /// is revoked after the last one. A `remaining` of 0 or a
+    /// `live_until_ledger` in the past revokes the approval, as does
+    /// `approve_for_all`, which replaces it with an unlimited one.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account granting the approval.
+    /// * `operator` - Account approved for the tokens of `owner`.
+    /// * `remaining` - Number of transfers the operator may make.
+    /// * `live_until_ledger` - Last ledger at which the approval is valid.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve_operator_limited", owner: Address]`
+    /// * data - `[operator: Address, remaining: u32, live_until_ledger: u32]`
+    fn approve_operator_limited(e: &Env, owner: Address, operator: Address, remaining: u32, live_until_ledger: u32);
+
+    /// Returns the number of transfers `operator` may still make for
+    /// `owner`, or `None` if its approval is not limited or expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account owning the tokens.
+    /// * `operator` - Account to check.
+    fn operator_allowance(e: &Env, owner: Address, operator: Address) -> Option<u32>;
+
+    /// Approves `approved` for `token_id` token until `live_until_ledger`,
+    /// only to transfer it to `recipient`.
+    ///
+    /// Works like `approve`, which replaces it with an unrestricted
+    /// approval. `transfer_from` to any other account fails with
+    /// `RecipientNotAllowed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - Owner of the token.
+    /// * `approved` - Account approved for the token.
+    /// * `token_id` - Token id as a number.
+    /// * `recipient` - Only account the token can be transferred to.
+    /// * `live_until_ledger` - Last ledger the approval is valid for.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve_for_recipient", approver: Address, token_id: u64]`
+    /// * data - `[approved: Address, recipient: Address, live_until_ledger: u32]`
+    fn approve_for_recipient(e: &Env, approver: Address, approved: Address, token_id: u64, recipient: Address, live_until_ledger: u32);
+
+    /// Returns the only account `token_id` token can be transferred to by
+    /// its approved account, or `None` if the approval is not restricted or
+    /// there is no approval.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn approved_recipient(e: &Env, token_id: u64) -> Option<Address>;
+
+    /// Locks `token_id` token to its owner, or unlocks it.
+    ///
+    /// A soulbound token cannot be transferred, escrowed or moved with
+    /// `transfer_from`, and fails with `TokenSoulbound`. It can still be
+    /// claimed and burned, and moved by `admin_transfer` or a recovery.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `soulbound` - Whether the token is locked to its owner.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["soulbound_updated", token_id: u64]`
+    /// * data - `[soulbound: bool]`
+    fn set_soulbound(e: &Env, token_id: u64, soulbound: bool);
+
+    /// Returns whether `token_id` token is locked to its owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn is_soulbound(e: &Env, token_id: u64) -> bool;
+
+    /// Sets the factors an owner-initiated transfer of `token_id` must present.
+    ///
+    /// At `Both`, `transfer` and `transfer_from` fail with `DualAuthRequired`
+    /// and the token only moves with `chip_transfer`, its DER and Ed25519
+    /// variants or `escrow_transfer`, which need the chip signature and the
+    /// owner's authorization. `admin_transfer` and recoveries are not
+    /// owner-initiated and stay allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `level` - Factors required to transfer the token.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["security_level_updated", token_id: u64]`
+    /// * data - `[level: SecurityLevel]`
+    fn set_security_level(e: &Env, token_id: u64, level: SecurityLevel);
+
+    /// Returns the factors an owner-initiated transfer of `token_id` must
+    /// present, `OwnerOnly` unless set with `set_security_level`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn security_level(e: &Env, token_id: u64) -> SecurityLevel;
+
+    /// Binds `token_id` token to a replacement chip.
+    ///
+    /// Signatures of the previous chip no longer authorize anything for the
+    /// token, and the new chip signs from its own nonce. Both the admin and
+    /// the owner, once the token is claimed, must authorize the call. A key
+    /// no chip can have fails with `InvalidPublicKey`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `public_key` - The new chip's public key (uncompressed SEC1 format, 65 bytes).
+    ///
+    /// # Events
+    ///
+    /// * topics - `["chip_rebound", token_id: u64]`
+    /// * data - `[previous_public_key: BytesN<65>, public_key: BytesN<65>]`
+    fn rebind_chip(e: &Env, token_id: u64, public_key: BytesN<65>);
+
+    /// Sets the number of ledgers a token must stay with its owner before it
+    /// can be transferred again.
+    ///
+    /// Applies to owner-initiated transfers; admin transfers are exempt.
+    /// A cooldown of 0 disables the check.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `ledgers` - Cooldown in ledgers.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer_cooldown_updated"]`
+    /// * data - `[ledgers: u32]`
+    fn set_transfer_cooldown(e: &Env, ledgers: u32);
+
+    /// Returns the transfer cooldown in ledgers (0 when disabled).
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn transfer_cooldown(e: &Env) -> u32;
+
+    /// Sets how far ahead of the stored nonce a chip nonce may be.
+    ///
+    /// A nonce is accepted if it lies in `(stored, stored + window]`, and the
+    /// stored nonce then jumps to it. Nonces past the window fail with
+    /// `NonceTooFarAhead`. A window of 1 only accepts the next nonce, a window
+    /// of 0 removes the upper bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `window` - Number of nonces accepted ahead of the stored one.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["nonce_window_updated"]`
+    /// * data - `[window: u32]`
+    fn set_nonce_window(e: &Env, window: u32);
+
+    /// Returns the nonce acceptance window (0 when unbounded).
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn nonce_window(e: &Env) -> u32;
+
+    /// Sets the chip-auth contract chip signatures are verified with.
+    ///
+    /// When set, signature recovery and nonce accounting are delegated to
+    /// `chip_auth` under this contract's namespace. The local nonce and
+    /// nonce window are still enforced, so switching back and forth cannot
+    /// replay a consumed signature. `None` restores the internal path.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `chip_auth` - Address of the chip-auth contract, if any.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["chip_auth_updated"]`
+    /// * data - `[chip_auth: Option<Address>]`
+    fn set_chip_auth(e: &Env, chip_auth: Option<Address>);
+
+    /// Returns the chip-auth contract signatures are delegated to, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn chip_auth(e: &Env) -> Option<Address>;
+
+    /// Sets every how many mints a `Checkpoint` event summarizing the
+    /// collection is emitted. An interval of 0 disables checkpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `mints` - Number of mints between two checkpoints.
+    ///
+    /// # Events
+    ///
+    /// Emitted by `mint`:
+    ///
+    /// * topics - `["checkpoint"]`
+    /// * data - `[next_token_id: u64, total_supply: u64, claimed_count: u64, ledger: u32]`
+    fn set_checkpoint_interval(e: &Env, mints: u32);
+
+    /// Returns the number of mints between two checkpoints (0 when disabled).
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn checkpoint_interval(e: &Env) -> u32;
+
+    /// Sets the maximum length in bytes of a signed message.
+    ///
+    /// Chip signatures over longer or empty messages are rejected before
+    /// hashing. Defaults to `DEFAULT_MAX_MESSAGE_LENGTH` and cannot exceed
+    /// `MAX_MESSAGE_LENGTH_CEILING`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `length` - Maximum message length in bytes.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["max_message_length_updated"]`
+    /// * data - `[length: u32]`
+    fn set_max_message_length(e: &Env, length: u32);
+
+    /// Returns the maximum length in bytes of a signed message.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn max_message_length(e: &Env) -> u32;
+
+    /// Sets the maximum number of tokens the default series can mint.
+    ///
+    /// The cap can be raised to extend a run, or lowered down to the
+    /// number of tokens already minted to close it. Lower values are
+    /// rejected with `InvalidAmount`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `max_tokens` - New maximum number of tokens.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["max_tokens_updated"]`
+    /// * data - `[previous_max_tokens: u64, max_tokens: u64]`
+    fn set_max_tokens(e: &Env, max_tokens: u64);
+
+    /// Returns the maximum number of tokens the default series can mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn max_tokens(e: &Env) -> u64;
+
+    /// Returns the number of tokens the default series can still mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn remaining_supply(e: &Env) -> u64;
+
+    /// Returns the number of tokens in circulation, minted and not burned.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn total_supply(e: &Env) -> u64;
+
+    /// Returns the number of tokens minted in every series, burned ones included.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn minted_count(e: &Env) -> u64;
+
+    /// Returns the number of tokens claimed, burned ones included.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn claimed_count(e: &Env) -> u64;
+
+    /// Returns the number of tokens burned.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn burned_count(e: &Env) -> u64;
+
+    /// Verifies a batch of chip signatures without consuming any nonce.
+    ///
+    /// Each item is checked against `sha256(message)`, so the message is the
+    /// full challenge the chip signed. Uses the same recovery check as the
+    /// mint, claim and transfer paths. Items of `Secp256r1` chips are always
+    /// false, see `set_chip_curve`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `items` - Up to `MAX_VERIFY_BATCH` messages with their signature,
+    ///   recovery ID and the chip's public key.
+    ///
+    /// # Returns
+    ///
+    /// Whether each item's signature recovers to its public key, in order.
+    fn verify_batch(e: &Env, items: Vec<VerifyItem>) -> Vec<bool>;
+
+    /// Checks that a product carries a genuine chip registered with this
+    /// contract, without authorization and without consuming any nonce.
+    ///
+    /// The signature is checked against `sha256(message)`, where the message
+    /// is a free-form challenge chosen by the verifier. Empty challenges or
+    /// challenges over `max_message_length` fail with `MalformedMessage`.
+    /// `Secp256r1` chips are never reported genuine, see `set_chip_curve`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `message` - The challenge signed by the chip.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    ///
+    /// # Returns
+    ///
+    /// Whether the chip is genuine and, if so, its token id, owner and
+    /// whether it was revoked.
+    fn verify_product(e: &Env, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>) -> ProductVerification;
+
+    /// Revokes a compromised chip.
+    ///
+    /// Signatures of a revoked chip are rejected with `ChipRevoked`, so it
+    /// can no longer mint, claim or transfer. Ownership is left intact and
+    /// the owner can still hand the token over with `approve` and
+    /// `transfer_from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    ///
+    /// # Events
+    ///
+    /// * topics - `["chip_revoked", public_key: BytesN<65>]`
+    /// * data - `[revoked: bool]`
+    fn revoke_chip(e: &Env, public_key: BytesN<65>);
+
+    /// Lifts the revocation of a chip.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    ///
+    /// # Events
+    ///
+    /// * topics - `["chip_revoked", public_key: BytesN<65>]`
+    /// * data - `[revoked: bool]`
+    fn unrevoke_chip(e: &Env, public_key: BytesN<65>);
+
+    /// Returns whether the chip is revoked.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    fn is_revoked(e: &Env, public_key: BytesN<65>) -> bool;
+
+    /// Sets the curve the chip signs with, `Secp256k1` by default.
+    ///
+    /// Signatures of a `Secp256r1` chip are verified against its public key,
+    /// so their recovery ID is ignored. Such chips are always verified by
+    /// this contract, even when `chip_auth` is set. The host aborts on a
+    /// `Secp256r1` signature that does not match instead of failing, so the
+    /// read-only checks (`verify`, `verify_batch`, `verify_product` and
+    /// `simulate_mint`) refuse these chips rather than abort.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `curve` - Curve of the chip's key.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["chip_curve_updated", public_key: BytesN<65>]`
+    /// * data - `[curve: CurveType]`
+    fn set_chip_curve(e: &Env, public_key: BytesN<65>, curve: CurveType);
+
+    /// Returns the curve the chip signs with.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    fn chip_curve(e: &Env, public_key: BytesN<65>) -> CurveType;
+
+    /// Returns the last nonce consumed for the given `public_key`.
+    ///
+    /// Signatures must use a strictly greater nonce, so a chip never used
+    /// before (nonce 0) signs its first message with nonce 1. Clients sign
+    /// with `get_nonce + 1`; skipping ahead is allowed within `nonce_window`.
+    /// Nonces are u64 so a chip can sign with its own signature counter.
+    /// Chips last used before the nonce was widened have it stored as a u32,
+    /// which reads the same and is rewritten as a u64 by the next signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    ///
+    /// # Returns
+    ///
+    /// The last nonce consumed by this chip's public_key (0 if never used).
+    ///
+    /// # Events
+    ///
+    /// Emitted by every entry point consuming a chip signature:
+    ///
+    /// * topics - `["nonce_used", public_key: BytesN<65>]`
+    /// * data - `[nonce: u64]`
+    fn get_nonce(e: &Env, public_key: BytesN<65>) -> u64;
+
+    /// Returns the domain prepended to every message signed by a chip.
+    ///
+    /// It is the XDR of this contract's address, so a signature is only
+    /// valid for the deployment it was produced for.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn message_domain(e: &Env) -> Bytes;
+
+    /// Returns the hash the chip must sign for `message` and `nonce`.
+    ///
+    /// The preimage is `message_domain() || operation as u8 || message
+    /// || recipient.to_xdr() || expiration_ledger.to_xdr() || nonce.to_xdr()`,
+    /// the expiration ledger being left out when 0 so signatures without
+    /// expiry keep the same hash.
+    /// `operation` is the entry point the signature authorizes, so a
+    /// signature collected for a claim cannot be spent on a transfer or a burn.
+    /// `recipient` is the claimant of `claim`, the `to` of `chip_transfer`,
+    /// `escrow_transfer` and `partner_mint`, and `None` for `mint`, so a
+    /// signature cannot be replayed for another address.
+    ///
+    /// The contract verifies signatures against this same hash, so clients
+    /// should call it rather than rebuild the preimage.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operation` - Operation the signature authorizes.
+    /// * `message` - The message to sign without the nonce.
+    /// * `recipient` - Account the signature is bound to, if any.
+    /// * `nonce` - The nonce to sign with.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    fn build_message_hash(e: &Env, operation: ChipOperation, message: Bytes, recipient: Option<Address>, nonce: u64, expiration_ledger: u32) -> BytesN<32>;
+
+    /// Returns the address of the owner of the given `token_id`, or `None` if
+    /// the token does not exist or has not been claimed.
+    ///
+    /// Non-panicking counterpart of `owner_of`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn owner_of_opt(e: &Env, token_id: u64) -> Option<Address>;
+
+    /// Returns whether `token_id` token has been minted and not burned.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn exists(e: &Env, token_id: u64) -> bool;
+
+    /// Returns whether `token_id` token has been claimed and not burned.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn is_claimed(e: &Env, token_id: u64) -> bool;
+
+    /// Returns the owner, public key, URI and claim status of `token_id`
+    /// token in a single call.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Notes
+    ///
+    /// If the token does not exist, this function is expected to panic.
+    fn token_info(e: &Env, token_id: u64) -> TokenInfo;
+
+    /// Batch counterpart of `token_info`, up to `MAX_TOKENS_INFO_BATCH` ids.
+    ///
+    /// Ids of tokens that do not exist are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_ids` - Token ids as numbers.
+    fn tokens_info(e: &Env, token_ids: Vec<u64>) -> Vec<TokenInfo>;
+
+    /// Returns the URI for `token_id` token, or `None` if it does not exist.
+    ///
+    /// Non-panicking counterpart of `token_uri`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn token_uri_opt(e: &Env, token_id: u64) -> Option<String>;
+
+    /// Sets the base URI the default series builds token URIs from, as
+    /// `{base_uri}/{token_id}`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `uri` - The base URI, up to `MAX_BASE_URI_LENGTH` bytes.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["base_uri_updated", series_id: u32]`
+    /// * data - `[base_uri: String]`
+    fn set_base_uri(e: &Env, uri: String);
+
+    /// Returns the base URI the default series builds token URIs from.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn base_uri(e: &Env) -> String;
+
+    /// Sets a URI override for `token_id` token, returned by `token_uri`
+    /// instead of the one built from the base URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `uri` - Metadata URI of the token, up to `MAX_TOKEN_URI_LENGTH` bytes.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update"]`
+    /// * data - `[token_id: u64]`
+    fn set_token_uri(e: &Env, token_id: u64, uri: String);
+
+    /// Removes the URI override of `token_id` token, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update"]`
+    /// * data - `[token_id: u64]`
+    fn clear_token_uri(e: &Env, token_id: u64);
+
+    /// Sets the placeholder URI returned by `token_uri` for tokens that have
+    /// not been claimed yet. An empty URI disables the placeholder.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `uri` - The placeholder URI, up to `MAX_BASE_URI_LENGTH` bytes.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["unclaimed_uri_updated"]`
+    /// * data - `[uri: String]`
+    fn set_unclaimed_uri(e: &Env, uri: String);
+
+    /// Returns the placeholder URI of unclaimed tokens, empty if unset.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn unclaimed_uri(e: &Env) -> String;
+
+    /// Returns the token ID for the given chip public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    ///
+    /// # Returns
+    ///
+    /// The token ID associated with this public key, or panics if not found.
+    fn token_id(e: &Env, public_key: BytesN<65>) -> u64;
+
+    /// Returns the token ID for the given chip public key, or `None` if the
+    /// chip was not minted.
+    ///
+    /// Non-panicking counterpart of `token_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    fn token_id_opt(e: &Env, public_key: BytesN<65>) -> Option<u64>;
+
+    /// Returns the chip public key for the given token ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Returns
+    ///
+    /// The chip's public key associated with this token ID.
+    ///
+    /// # Notes
+    ///
+    /// If the token does not exist, this function is expected to panic.
+    fn public_key(e: &Env, token_id: u64) -> BytesN<65>;
+
+    /// Returns the chip public key for the given token ID, or `None` if the
+    /// token does not exist.
+    ///
+    /// Non-panicking counterpart of `public_key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn public_key_opt(e: &Env, token_id: u64) -> Option<BytesN<65>>;
+
+    /// Returns the chip key for the given token ID, telling ECDSA and
+    /// Ed25519 chips apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Notes
+    ///
+    /// If the token does not exist, this function is expected to panic.
+    fn chip_key(e: &Env, token_id: u64) -> ChipKey;
+
+    /// Returns the public key `chip_key` is recorded under, as taken by
+    /// `get_nonce`, `token_id`, `revoke_chip` and the other chip functions.
+    ///
+    /// ECDSA keys are returned as is. Ed25519 keys are left padded with
+    /// zeros to 65 bytes, which no SEC1 encoded key starts with.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `chip_key` - The chip's key.
+    fn chip_public_key(e: &Env, chip_key: ChipKey) -> BytesN<65>;
+}
+
+/// Standard non-fungible token interface (SEP-50) expected by wallets and
+/// marketplaces, sharing its storage with the chip-signed functions.
+pub trait NonFungibleToken {
+    /// Returns the number of tokens in `owner`'s account.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the token's owner.
+    fn balance(e: &Env, owner: Address) -> u32;
+
+    /// Returns the address of the owner of the given `token_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Notes
+    ///
+    /// If the token does not exist, this function is expected to panic.
+    /// A minted token that has not been claimed yet panics with
+    /// `TokenNotClaimed`.
+    fn owner_of(e: &Env, token_id: u64) -> Address;
+
+    /// Transfers `token_id` token from `from` to `to` without a chip
+    /// signature.
+    ///
+    /// Standard entry point for wallets and marketplaces: only `from`'s
+    /// authorization is required. Transfers proving possession of the item
+    /// go through `chip_transfer`.
+    ///
+    /// WARNING: Note that the caller is responsible to confirm that the
+    /// recipient is capable of receiving the `Non-Fungible` or else the NFT
+    /// may be permanently lost.
+    ///
+    /// A contract recipient must export `on_nft_received(operator, from,
+    /// token_id) -> bool`, called once the token is moved: the transfer fails
+    /// with `ReceiverRejected` if it returns false, panics or does not export
+    /// the hook. Accounts, contracts that opted out with `set_receiver_hook`
+    /// and recipients that authorized the call themselves are not checked.
+    /// Every path giving a token to a new owner applies the same check.
+    ///
+    /// `from` pays the transfer fee, if any, see `set_transfer_fee`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[token_id: u64]`
+    fn transfer(e: &Env, from: Address, to: Address, token_id: u64);
+
+    /// Transfer `token_id` token from `from` to `to` on behalf of an approved
+    /// `spender`, without a chip signature.
+    ///
+    /// `spender` must be approved for the token or approved for all tokens
+    /// of `from`. The single-token approval is consumed and its recipient
+    /// restriction applies, a limited operator approval uses up one
+    /// transfer. `spender` pays the transfer fee, if any. Contract
+    /// recipients are called with `spender` as the operator, see `transfer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - Account approved for the token.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[token_id: u64]`
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u64);
+
+    /// Approves `approved` for `token_id` token until `live_until_ledger`.
+    ///
+    /// The approval is cleared whenever the token changes hands. A
+    /// `live_until_ledger` of 0 revokes the current approval. It replaces a
+    /// restricted approval from `approve_for_recipient`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - Owner of the token.
+    /// * `approved` - Account approved for the token.
+    /// * `token_id` - Token id as a number.
+    /// * `live_until_ledger` - Last ledger the approval is valid for.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", approver: Address, token_id: u64]`
+    /// * data - `[approved: Address, live_until_ledger: u32]`
+    fn approve(e: &Env, approver: Address, approved: Address, token_id: u64, live_until_ledger: u32);
+
+    /// Approve `operator` to transfer all tokens of `owner` until
+    /// `live_until_ledger` (inclusive).
+    ///
+    /// A `live_until_ledger` in the past revokes the approval. It replaces
+    /// a limited approval from `approve_operator_limited`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account granting the approval.
+    /// * `operator` - Account approved for all tokens of `owner`.
+    /// * `live_until_ledger` - Last ledger at which the approval is valid.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve_for_all", owner: Address]`
+    /// * data - `[operator: Address, live_until_ledger: u32]`
+    fn approve_for_all(e: &Env, owner: Address, operator: Address, live_until_ledger: u32);
+
+    /// Returns the account approved for `token_id` token, or `None` if there
+    /// is no approval or it expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn get_approved(e: &Env, token_id: u64) -> Option<Address>;
+
+    /// Returns whether `operator` is currently approved for all tokens of
+    /// `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account owning the tokens.
+    /// * `operator` - Account to check.
+    fn is_approved_for_all(e: &Env, owner: Address, operator: Address) -> bool;
+
+    /// Returns the token collection name.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn name(e: &Env) -> String;
+
+    /// Returns the token collection symbol.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn symbol(e: &Env) -> String;
+
+    /// Returns the Uniform Resource Identifier (URI) for `token_id` token.
+    ///
+    /// Unclaimed tokens get the placeholder URI if one is set. Otherwise
+    /// the token URI override is returned, falling back to
+    /// `{base_uri}/{token_id}` with the base URI of the token's series.
+    ///
+    /// With on-chain metadata, that URI becomes the `image` of a JSON
+    /// document returned as a `data:application/json;base64,` URI. The
+    /// document is named `{name} #{token_id}` after the collection,
+    /// described by the name of the token's series and lists the token's
+    /// attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Notes
+    ///
+    /// If the token does not exist, this function is expected to panic.
+    fn token_uri(e: &Env, token_id: u64) -> String;
 }
\ No newline at end of file
@@ -0,0 +1,100 @@
+//! Locale-specific token URIs
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Env, String, Symbol, Vec};
+use crate::{contract, errors, events, NFCtoNFTContract, NonFungibleToken, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Maximum number of locales a token can carry
+pub const MAX_LOCALES: u32 = 8;
+
+/// Maximum length in bytes of a locale URI
+pub const MAX_LOCALE_URI_LENGTH: u32 = 200;
+
+#[contracttype]
+pub enum LocaleStorageKey {
+    LocaleUri(u64, Symbol),
+    Locales(u64),
+}
+
+pub trait Locales {
+    /// Sets the metadata URI of `token_id` token for `locale`.
+    ///
+    /// Setting an existing locale again replaces its URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `locale` - Language tag, e.g. `fr` or `de_CH`.
+    /// * `uri` - Metadata URI for the locale, up to `MAX_LOCALE_URI_LENGTH` bytes.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update"]`
+    /// * data - `[token_id: u64]`
+    fn set_locale_uri(e: &Env, token_id: u64, locale: Symbol, uri: String);
+
+    /// Returns the metadata URI of `token_id` token for `locale`, falling
+    /// back to `token_uri` if the locale is not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `locale` - Language tag.
+    fn token_uri_localized(e: &Env, token_id: u64, locale: Symbol) -> String;
+
+    /// Returns the locales `token_id` token has a URI for.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn locales_of(e: &Env, token_id: u64) -> Vec<Symbol>;
+}
+
+#[contractimpl]
+impl Locales for StellarMerchShop {
+    fn set_locale_uri(e: &Env, token_id: u64, locale: Symbol, uri: String) {
+        contract::require_admin(e);
+
+        // Verify token exists
+        StellarMerchShop::public_key(e, token_id);
+
+        if uri.is_empty() || uri.len() > MAX_LOCALE_URI_LENGTH {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidUri);
+        }
+
+        let mut locales = Self::locales_of(e, token_id);
+        if !locales.contains(&locale) {
+            if locales.len() >= MAX_LOCALES {
+                panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+            }
+            locales.push_back(locale.clone());
+            e.storage().persistent().set(&LocaleStorageKey::Locales(token_id), &locales);
+        }
+        e.storage().persistent().set(&LocaleStorageKey::LocaleUri(token_id, locale), &uri);
+
+        events::MetadataUpdate { token_id }.publish(e);
+    }
+
+    fn token_uri_localized(e: &Env, token_id: u64, locale: Symbol) -> String {
+        let token_uri = StellarMerchShop::token_uri(e, token_id);
+
+        // Keep the product under wraps until someone claims it
+        if !StellarMerchShop::unclaimed_uri(e).is_empty() && StellarMerchShop::owner_of_opt(e, token_id).is_none() {
+            return token_uri;
+        }
+
+        e.storage()
+            .persistent()
+            .get(&LocaleStorageKey::LocaleUri(token_id, locale))
+            .unwrap_or(token_uri)
+    }
+
+    fn locales_of(e: &Env, token_id: u64) -> Vec<Symbol> {
+        e.storage()
+            .persistent()
+            .get(&LocaleStorageKey::Locales(token_id))
+            .unwrap_or(Vec::new(e))
+    }
+}
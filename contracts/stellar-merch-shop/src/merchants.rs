@@ -0,0 +1,220 @@
+//! Merchants hosted by one deployment, each administering its own series of tokens
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Env, String};
+use crate::contract::{self, DataKey, NFTStorageKey};
+use crate::series::{self, Series};
+use crate::{errors, events, NFCtoNFTContract, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Merchant of the deployment itself, administered by the contract admin
+pub const DEFAULT_MERCHANT_ID: u32 = 0;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerchantInfo {
+    pub admin: Address,
+    pub name: String,
+}
+
+#[contracttype]
+pub enum MerchantStorageKey {
+    /// Only set for merchants created with create_merchant
+    Merchant(u32),
+    NextMerchantId,
+}
+
+pub trait Merchants {
+    /// Creates a merchant, such as a partner brand, administered by its own
+    /// account.
+    ///
+    /// The merchant owns a new series whose id is the merchant id: its admin,
+    /// and not the contract admin, mints and registers chips in it, manages
+    /// the URI and royalty of its tokens, sets their price and withdraws the
+    /// payments, see `withdraw`. Token ids stay shared by every
+    /// merchant. Series created with `create_series` and everything else
+    /// belong to `DEFAULT_MERCHANT_ID`, so deployments predating merchants
+    /// need no migration.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `merchant_admin` - Account administering the merchant.
+    /// * `name` - Name of the merchant, also the name of its series, up to `MAX_NAME_LENGTH` bytes.
+    /// * `base_uri` - Base URI of the merchant metadata, up to `MAX_BASE_URI_LENGTH` bytes.
+    /// * `max_tokens` - Maximum number of tokens the merchant can mint.
+    ///
+    /// # Returns
+    ///
+    /// The id of the merchant and of its series.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["merchant_created", merchant_id: u32]`
+    /// * data - `[admin: Address, name: String]`
+    fn create_merchant(e: &Env, merchant_admin: Address, name: String, base_uri: String, max_tokens: u64) -> u32;
+
+    /// Hands the administration of a merchant to another account. Requires
+    /// the current merchant admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `merchant_id` - Id of a merchant created with `create_merchant`.
+    /// * `new_admin` - Account administering the merchant from now on.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["merchant_admin_updated", merchant_id: u32]`
+    /// * data - `[admin: Address]`
+    fn set_merchant_admin(e: &Env, merchant_id: u32, new_admin: Address);
+
+    /// Sets the base URI of a merchant's series. Requires the merchant admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `merchant_id` - Id of a merchant created with `create_merchant`.
+    /// * `uri` - The base URI, up to `MAX_BASE_URI_LENGTH` bytes.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["base_uri_updated", series_id: u32]`
+    /// * data - `[base_uri: String]`
+    fn set_merchant_base_uri(e: &Env, merchant_id: u32, uri: String);
+
+    /// Returns the admin and name of a merchant, the contract admin and
+    /// collection name for `DEFAULT_MERCHANT_ID`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `merchant_id` - Id of the merchant.
+    fn merchant_info(e: &Env, merchant_id: u32) -> MerchantInfo;
+
+    /// Returns the merchant `token_id` token was minted by.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn merchant_of(e: &Env, token_id: u64) -> u32;
+}
+
+#[contractimpl]
+impl Merchants for StellarMerchShop {
+    fn create_merchant(e: &Env, merchant_admin: Address, name: String, base_uri: String, max_tokens: u64) -> u32 {
+        contract::require_admin(e);
+
+        // Skip the ids already taken by series created with create_series
+        let mut merchant_id: u32 = e
+            .storage()
+            .instance()
+            .get(&MerchantStorageKey::NextMerchantId)
+            .unwrap_or(DEFAULT_MERCHANT_ID + 1);
+        while series::series_info_opt(e, merchant_id).is_some() {
+            merchant_id = merchant_id
+                .checked_add(1)
+                .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+        }
+        if let Err(error) = series::store_series(e, merchant_id, &name, &base_uri, max_tokens) {
+            panic_with_error!(e, error);
+        }
+
+        let merchant = MerchantInfo { admin: merchant_admin.clone(), name: name.clone() };
+        e.storage().instance().set(&MerchantStorageKey::Merchant(merchant_id), &merchant);
+        let next_merchant_id = merchant_id
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+        e.storage().instance().set(&MerchantStorageKey::NextMerchantId, &next_merchant_id);
+
+        events::MerchantCreated { merchant_id, admin: merchant_admin, name }.publish(e);
+        merchant_id
+    }
+
+    fn set_merchant_admin(e: &Env, merchant_id: u32, new_admin: Address) {
+        let mut merchant = hosted_merchant(e, merchant_id);
+        merchant.admin.require_auth();
+
+        merchant.admin = new_admin.clone();
+        e.storage().instance().set(&MerchantStorageKey::Merchant(merchant_id), &merchant);
+
+        events::MerchantAdminUpdated { merchant_id, admin: new_admin }.publish(e);
+    }
+
+    fn set_merchant_base_uri(e: &Env, merchant_id: u32, uri: String) {
+        hosted_merchant(e, merchant_id).admin.require_auth();
+
+        if let Err(error) = contract::check_base_uri(&uri) {
+            panic_with_error!(e, error);
+        }
+        let mut series = StellarMerchShop::series_info(e, merchant_id);
+        series.base_uri = uri.clone();
+        series::set_series_info(e, merchant_id, &series);
+
+        events::BaseUriUpdated { series_id: merchant_id, base_uri: uri }.publish(e);
+    }
+
+    fn merchant_info(e: &Env, merchant_id: u32) -> MerchantInfo {
+        if merchant_id == DEFAULT_MERCHANT_ID {
+            return MerchantInfo {
+                admin: e.storage().instance().get(&DataKey::Admin).unwrap(),
+                name: e.storage().instance().get(&NFTStorageKey::Name).unwrap(),
+            };
+        }
+        hosted_merchant(e, merchant_id)
+    }
+
+    fn merchant_of(e: &Env, token_id: u64) -> u32 {
+        // Verify token exists
+        StellarMerchShop::public_key(e, token_id);
+
+        token_merchant(e, token_id)
+    }
+}
+
+fn hosted_merchant(e: &Env, merchant_id: u32) -> MerchantInfo {
+    e.storage()
+        .instance()
+        .get(&MerchantStorageKey::Merchant(merchant_id))
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MerchantNotFound))
+}
+
+/// Merchant owning the series, the series id itself for merchants created with create_merchant
+pub(crate) fn series_merchant(e: &Env, series_id: u32) -> u32 {
+    if e.storage().instance().has(&MerchantStorageKey::Merchant(series_id)) {
+        series_id
+    } else {
+        DEFAULT_MERCHANT_ID
+    }
+}
+
+/// Require the admin of the merchant owning the series, the contract admin for the default merchant
+pub(crate) fn require_series_admin(e: &Env, series_id: u32) -> Address {
+    let merchant_id = series_merchant(e, series_id);
+    if merchant_id == DEFAULT_MERCHANT_ID {
+        return contract::require_admin(e);
+    }
+    let admin = hosted_merchant(e, merchant_id).admin;
+    admin.require_auth();
+    admin
+}
+
+/// Merchant that minted token_id
+pub(crate) fn token_merchant(e: &Env, token_id: u64) -> u32 {
+    series_merchant(e, series::token_series(e, token_id))
+}
+
+/// Require the admin of merchant_id, which must exist
+pub(crate) fn require_merchant_admin(e: &Env, merchant_id: u32) -> Address {
+    if merchant_id != DEFAULT_MERCHANT_ID {
+        hosted_merchant(e, merchant_id);
+    }
+    require_series_admin(e, merchant_id)
+}
+
+/// Require the admin of the merchant that minted token_id, which must exist
+pub(crate) fn require_token_admin(e: &Env, token_id: u64) -> Address {
+    // Verify token exists
+    StellarMerchShop::public_key(e, token_id);
+
+    require_series_admin(e, series::token_series(e, token_id))
+}
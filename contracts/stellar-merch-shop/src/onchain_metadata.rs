@@ -0,0 +1,130 @@
+//! Token metadata built on-chain and returned as a `data:` URI, for
+//! deployments that do not rely on any metadata host
+
+use soroban_sdk::{Bytes, Env, String};
+use crate::contract::{self, DataKey, NFTStorageKey};
+use crate::{attributes, series};
+
+const DATA_URI_PREFIX: &[u8] = b"data:application/json;base64,";
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Bytes encoded per chunk, a multiple of 3 so only the last chunk is padded
+const BASE64_CHUNK: u32 = 192;
+
+/// Bytes of a stored string escaped per chunk
+const ESCAPE_CHUNK: u32 = 64;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Whether token_uri returns the metadata itself instead of a link to it
+pub(crate) fn enabled(e: &Env) -> bool {
+    e.storage().instance().get(&DataKey::OnchainMetadata).unwrap_or(false)
+}
+
+/// Metadata of token_id as `data:application/json;base64,...`
+///
+/// Every field is capped (names by `MAX_NAME_LENGTH`, the image by the URI
+/// caps, the attributes by the attribute caps), so is the URI
+pub(crate) fn token_data_uri(e: &Env, token_id: u64, image: &String) -> String {
+    let mut uri = Bytes::from_slice(e, DATA_URI_PREFIX);
+    uri.append(&base64_encode(e, &token_json(e, token_id, image)));
+    String::from(uri)
+}
+
+/// `{"name","description","image","attributes"}` of token_id, named after
+/// the collection and described by its series
+fn token_json(e: &Env, token_id: u64, image: &String) -> Bytes {
+    let collection: String = e.storage().instance().get(&NFTStorageKey::Name).unwrap();
+    let series = series::series_info_opt(e, series::token_series(e, token_id)).unwrap();
+
+    let mut json = Bytes::from_slice(e, b"{\"name\":");
+    let mut name = Bytes::from(collection);
+    name.extend_from_slice(b" #");
+    name.append(&contract::u64_to_decimal_bytes(e, token_id));
+    push_json_string(&mut json, &name);
+    json.extend_from_slice(b",\"description\":");
+    push_json_string(&mut json, &Bytes::from(series.name));
+    json.extend_from_slice(b",\"image\":");
+    push_json_string(&mut json, &Bytes::from(image.clone()));
+
+    json.extend_from_slice(b",\"attributes\":[");
+    for (index, (key, value)) in attributes::stored_attributes(e, token_id).iter().enumerate() {
+        if index > 0 {
+            json.push_back(b',');
+        }
+        json.extend_from_slice(b"{\"trait_type\":");
+        push_json_string(&mut json, &Bytes::from(key));
+        json.extend_from_slice(b",\"value\":");
+        push_json_string(&mut json, &Bytes::from(value));
+        json.push_back(b'}');
+    }
+    json.extend_from_slice(b"]}");
+    json
+}
+
+/// Append value as a JSON string, escaping quotes, backslashes and control characters
+fn push_json_string(json: &mut Bytes, value: &Bytes) {
+    let mut input = [0u8; ESCAPE_CHUNK as usize];
+    // Control characters take 6 bytes once escaped
+    let mut output = [0u8; ESCAPE_CHUNK as usize * 6];
+
+    json.push_back(b'"');
+    let mut start = 0;
+    while start < value.len() {
+        let end = start.saturating_add(ESCAPE_CHUNK).min(value.len());
+        let chunk = &mut input[..(end - start) as usize];
+        value.slice(start..end).copy_into_slice(chunk);
+
+        let mut written = 0;
+        for &byte in chunk.iter() {
+            match byte {
+                b'"' | b'\\' => {
+                    output[written..written + 2].copy_from_slice(&[b'\\', byte]);
+                    written += 2;
+                }
+                0x00..=0x1f => {
+                    let hex = [HEX_DIGITS[(byte >> 4) as usize], HEX_DIGITS[(byte & 0xf) as usize]];
+                    output[written..written + 6].copy_from_slice(&[b'\\', b'u', b'0', b'0', hex[0], hex[1]]);
+                    written += 6;
+                }
+                _ => {
+                    output[written] = byte;
+                    written += 1;
+                }
+            }
+        }
+        json.extend_from_slice(&output[..written]);
+        start = end;
+    }
+    json.push_back(b'"');
+}
+
+/// Standard base64 with padding, no_std and without allocation
+fn base64_encode(e: &Env, data: &Bytes) -> Bytes {
+    let mut input = [0u8; BASE64_CHUNK as usize];
+    let mut output = [0u8; BASE64_CHUNK as usize / 3 * 4];
+
+    let mut encoded = Bytes::new(e);
+    let mut start = 0;
+    while start < data.len() {
+        let end = start.saturating_add(BASE64_CHUNK).min(data.len());
+        let chunk = &mut input[..(end - start) as usize];
+        data.slice(start..end).copy_into_slice(chunk);
+
+        let mut written = 0;
+        for group in chunk.chunks(3) {
+            let triple = (group[0] as u32) << 16
+                | (*group.get(1).unwrap_or(&0) as u32) << 8
+                | *group.get(2).unwrap_or(&0) as u32;
+            output[written] = BASE64_ALPHABET[(triple >> 18) as usize & 63];
+            output[written + 1] = BASE64_ALPHABET[(triple >> 12) as usize & 63];
+            output[written + 2] = if group.len() > 1 { BASE64_ALPHABET[(triple >> 6) as usize & 63] } else { b'=' };
+            output[written + 3] = if group.len() > 2 { BASE64_ALPHABET[triple as usize & 63] } else { b'=' };
+            written += 4;
+        }
+        encoded.extend_from_slice(&output[..written]);
+        start = end;
+    }
+    encoded
+}
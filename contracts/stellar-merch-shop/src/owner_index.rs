@@ -0,0 +1,122 @@
+//! Index of the tokens held by each owner
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Env, Vec};
+use crate::{errors, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Maximum number of token ids returned by one `tokens_of` call
+pub const MAX_TOKENS_OF_LIMIT: u32 = 100;
+
+#[contracttype]
+pub enum OwnerIndexStorageKey {
+    HeldCount(Address),
+    HeldToken(Address, u32),
+    TokenPosition(u64),
+}
+
+pub trait OwnerIndex {
+    /// Returns up to `limit` token ids held by `owner`, starting at position
+    /// `start`. Positions are not stable across transfers.
+    ///
+    /// `limit` is capped to `MAX_TOKENS_OF_LIMIT`, and a `start` past the
+    /// last token returns an empty list.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the token's owner.
+    /// * `start` - Position of the first token id to return.
+    /// * `limit` - Maximum number of token ids to return.
+    fn tokens_of(e: &Env, owner: Address, start: u32, limit: u32) -> Vec<u64>;
+}
+
+#[contractimpl]
+impl OwnerIndex for StellarMerchShop {
+    fn tokens_of(e: &Env, owner: Address, start: u32, limit: u32) -> Vec<u64> {
+        let count = token_count(e, &owner);
+        let end = start.saturating_add(limit.min(MAX_TOKENS_OF_LIMIT)).min(count);
+
+        let mut token_ids = Vec::new(e);
+        for position in start..end {
+            let token_id: u64 = e
+                .storage()
+                .persistent()
+                .get(&OwnerIndexStorageKey::HeldToken(owner.clone(), position))
+                .unwrap();
+            token_ids.push_back(token_id);
+        }
+        token_ids
+    }
+}
+
+fn token_count(e: &Env, owner: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&OwnerIndexStorageKey::HeldCount(owner.clone()))
+        .unwrap_or(0)
+}
+
+/// Append token_id to the tokens of owner
+pub(crate) fn add_token(e: &Env, owner: &Address, token_id: u64) {
+    let count = token_count(e, owner);
+    let next_count = count
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    e.storage()
+        .persistent()
+        .set(&OwnerIndexStorageKey::HeldToken(owner.clone(), count), &token_id);
+    e.storage()
+        .persistent()
+        .set(&OwnerIndexStorageKey::TokenPosition(token_id), &count);
+    e.storage()
+        .persistent()
+        .set(&OwnerIndexStorageKey::HeldCount(owner.clone()), &next_count);
+}
+
+/// Append token_id to the tokens of owner unless already indexed, for
+/// tokens claimed before the index existed
+pub(crate) fn backfill_token(e: &Env, owner: &Address, token_id: u64) {
+    if !e.storage().persistent().has(&OwnerIndexStorageKey::TokenPosition(token_id)) {
+        add_token(e, owner, token_id);
+    }
+}
+
+/// Remove token_id from the tokens of owner, the last token takes its position
+///
+/// Tokens claimed before the index existed and not yet backfilled by
+/// `migrate` are not indexed, there is nothing to remove.
+pub(crate) fn remove_token(e: &Env, owner: &Address, token_id: u64) {
+    let Some(position) = e
+        .storage()
+        .persistent()
+        .get::<_, u32>(&OwnerIndexStorageKey::TokenPosition(token_id))
+    else {
+        return;
+    };
+    let last = token_count(e, owner)
+        .checked_sub(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+
+    if position != last {
+        let last_token_id: u64 = e
+            .storage()
+            .persistent()
+            .get(&OwnerIndexStorageKey::HeldToken(owner.clone(), last))
+            .unwrap();
+        e.storage()
+            .persistent()
+            .set(&OwnerIndexStorageKey::HeldToken(owner.clone(), position), &last_token_id);
+        e.storage()
+            .persistent()
+            .set(&OwnerIndexStorageKey::TokenPosition(last_token_id), &position);
+    }
+
+    e.storage()
+        .persistent()
+        .remove(&OwnerIndexStorageKey::HeldToken(owner.clone(), last));
+    e.storage()
+        .persistent()
+        .remove(&OwnerIndexStorageKey::TokenPosition(token_id));
+    e.storage()
+        .persistent()
+        .set(&OwnerIndexStorageKey::HeldCount(owner.clone()), &last);
+}
@@ -0,0 +1,55 @@
+//! Bounded ownership history per token
+
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Vec};
+use crate::{StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Number of owners kept per token
+pub const OWNERSHIP_HISTORY_CAPACITY: u32 = 8;
+
+#[contracttype]
+pub enum OwnershipHistoryStorageKey {
+    OwnershipHistory(u64),
+}
+
+pub trait OwnershipHistory {
+    /// Returns the latest owners of `token_id` token with the ledger at which
+    /// they received it, newest first.
+    ///
+    /// At most `OWNERSHIP_HISTORY_CAPACITY` entries are kept, older owners are
+    /// dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn ownership_history(e: &Env, token_id: u64) -> Vec<(Address, u32)>;
+}
+
+#[contractimpl]
+impl OwnershipHistory for StellarMerchShop {
+    fn ownership_history(e: &Env, token_id: u64) -> Vec<(Address, u32)> {
+        e.storage()
+            .persistent()
+            .get(&OwnershipHistoryStorageKey::OwnershipHistory(token_id))
+            .unwrap_or(Vec::new(e))
+    }
+}
+
+/// Record `owner` as the newest owner of `token_id`, dropping the oldest entry once full
+pub(crate) fn record_owner(e: &Env, token_id: u64, owner: &Address) {
+    let mut history = StellarMerchShop::ownership_history(e, token_id);
+    history.push_front((owner.clone(), e.ledger().sequence()));
+    if history.len() > OWNERSHIP_HISTORY_CAPACITY {
+        history.pop_back();
+    }
+    e.storage()
+        .persistent()
+        .set(&OwnershipHistoryStorageKey::OwnershipHistory(token_id), &history);
+}
+
+/// Forget the owners of the burned `token_id`
+pub(crate) fn clear_history(e: &Env, token_id: u64) {
+    e.storage()
+        .persistent()
+        .remove(&OwnershipHistoryStorageKey::OwnershipHistory(token_id));
+}
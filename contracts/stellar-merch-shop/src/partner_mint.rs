@@ -0,0 +1,182 @@
+//! Mints triggered by approved partner contracts within a per-SKU allowance
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Bytes, BytesN, Env};
+use crate::contract::ChipKey;
+use crate::{contract, errors, events, series, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+pub enum PartnerMintStorageKey {
+    ChipSku(BytesN<65>),
+    MintAllowance(Address, u32),
+}
+
+pub trait PartnerMint {
+    /// Allocates the chip with `public_key` to `sku`.
+    ///
+    /// Partners can only mint chips allocated to a SKU they have an
+    /// allowance for.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `sku` - SKU of the product the chip is embedded in.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["chip_sku_updated", public_key: BytesN<65>]`
+    /// * data - `[sku: u32]`
+    fn set_chip_sku(e: &Env, public_key: BytesN<65>, sku: u32);
+
+    /// Returns the SKU the chip with `public_key` is allocated to, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    fn chip_sku(e: &Env, public_key: BytesN<65>) -> Option<u32>;
+
+    /// Allows `contract` to mint `quantity` chips of `sku`.
+    ///
+    /// Granting again replaces the remaining allowance, a quantity of 0
+    /// revokes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `contract` - Address of the partner contract.
+    /// * `sku` - SKU the partner may mint.
+    /// * `quantity` - Number of mints allowed.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint_allowance_updated", partner: Address, sku: u32]`
+    /// * data - `[quantity: u64]`
+    fn grant_mint_allowance(e: &Env, contract: Address, sku: u32, quantity: u64);
+
+    /// Returns the number of `sku` chips `contract` may still mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `contract` - Address of the partner contract.
+    /// * `sku` - SKU of the allowance.
+    fn mint_allowance(e: &Env, contract: Address, sku: u32) -> u64;
+
+    /// Mint a chip of `sku` on behalf of `partner` directly to `to`.
+    ///
+    /// `partner` must authorize the call, which a contract does implicitly
+    /// when it invokes this function itself. One unit of its `sku` allowance
+    /// is consumed. A contract `to` is asked through the receiver hook with
+    /// `partner` as the operator, see `transfer`.
+    ///
+    /// The chip signs `build_message_hash(Mint, message, Some(to), nonce, 0)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `partner` - Address of the partner contract.
+    /// * `to` - Account of the token's owner.
+    /// * `sku` - SKU the chip is allocated to.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    ///
+    /// # Returns
+    ///
+    /// The u64 token_id of the minted chip.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", token_id: u64, public_key: BytesN<65>, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[to: Option<Address>, po_number: Option<u32>]`
+    ///
+    /// * topics - `["claim", claimant: Address, public_key: BytesN<65>, merchant_id: u32, sku: Option<u32>]`
+    /// * data - `[token_id: u64]`
+    fn partner_mint(
+        e: &Env,
+        partner: Address,
+        to: Address,
+        sku: u32,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        nonce: u64,
+    ) -> u64;
+}
+
+#[contractimpl]
+impl PartnerMint for StellarMerchShop {
+    fn set_chip_sku(e: &Env, public_key: BytesN<65>, sku: u32) {
+        contract::require_admin(e);
+        contract::require_valid_public_key(e, &public_key);
+
+        e.storage().persistent().set(&PartnerMintStorageKey::ChipSku(public_key.clone()), &sku);
+
+        events::ChipSkuUpdated { public_key, sku }.publish(e);
+    }
+
+    fn chip_sku(e: &Env, public_key: BytesN<65>) -> Option<u32> {
+        sku_of(e, &public_key)
+    }
+
+    fn grant_mint_allowance(e: &Env, contract: Address, sku: u32, quantity: u64) {
+        contract::require_admin(e);
+
+        set_allowance(e, &contract, sku, quantity);
+    }
+
+    fn mint_allowance(e: &Env, contract: Address, sku: u32) -> u64 {
+        e.storage()
+            .persistent()
+            .get(&PartnerMintStorageKey::MintAllowance(contract, sku))
+            .unwrap_or(0u64)
+    }
+
+    fn partner_mint(
+        e: &Env,
+        partner: Address,
+        to: Address,
+        sku: u32,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        nonce: u64,
+    ) -> u64 {
+        partner.require_auth();
+
+        let allowance = Self::mint_allowance(e, partner.clone(), sku);
+        if allowance == 0 {
+            panic_with_error!(e, &errors::NonFungibleTokenError::MintAllowanceExhausted);
+        }
+        if Self::chip_sku(e, public_key.clone()) != Some(sku) {
+            panic_with_error!(e, &errors::NonFungibleTokenError::ChipSkuMismatch);
+        }
+        set_allowance(e, &partner, sku, allowance - 1);
+
+        let token_id = contract::mint_chip(e, series::DEFAULT_SERIES_ID, message, signature, recovery_id, ChipKey::Ecdsa(public_key), nonce, 0, Some(&to), None, None);
+        contract::assign_claim(e, &partner, token_id, to, None);
+
+        token_id
+    }
+}
+
+/// SKU the chip with `public_key` is allocated to, if any
+pub(crate) fn sku_of(e: &Env, public_key: &BytesN<65>) -> Option<u32> {
+    e.storage().persistent().get(&PartnerMintStorageKey::ChipSku(public_key.clone()))
+}
+
+fn set_allowance(e: &Env, contract: &Address, sku: u32, quantity: u64) {
+    let key = PartnerMintStorageKey::MintAllowance(contract.clone(), sku);
+    if quantity == 0 {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &quantity);
+    }
+
+    events::MintAllowanceUpdated { partner: contract.clone(), sku, quantity }.publish(e);
+}
@@ -0,0 +1,78 @@
+//! Purchase order under which each chip was produced
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Env, Vec};
+use crate::{errors, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+pub enum PurchaseOrderStorageKey {
+    TokenPo(u64),
+    PoTokenCount(u32),
+    PoToken(u32, u32),
+}
+
+pub trait PurchaseOrders {
+    /// Returns the purchase order `token_id` token was minted under, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn po_of(e: &Env, token_id: u64) -> Option<u32>;
+
+    /// Returns up to `limit` token ids minted under purchase order `po`, in
+    /// mint order, starting at position `start`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `po` - Purchase order number.
+    /// * `start` - Position of the first token id to return.
+    /// * `limit` - Maximum number of token ids to return.
+    fn tokens_by_po(e: &Env, po: u32, start: u32, limit: u32) -> Vec<u64>;
+}
+
+#[contractimpl]
+impl PurchaseOrders for StellarMerchShop {
+    fn po_of(e: &Env, token_id: u64) -> Option<u32> {
+        e.storage()
+            .persistent()
+            .get(&PurchaseOrderStorageKey::TokenPo(token_id))
+    }
+
+    fn tokens_by_po(e: &Env, po: u32, start: u32, limit: u32) -> Vec<u64> {
+        let count: u32 = e
+            .storage()
+            .persistent()
+            .get(&PurchaseOrderStorageKey::PoTokenCount(po))
+            .unwrap_or(0);
+        let end = start.saturating_add(limit).min(count);
+
+        let mut token_ids = Vec::new(e);
+        for index in start..end {
+            let token_id: u64 = e
+                .storage()
+                .persistent()
+                .get(&PurchaseOrderStorageKey::PoToken(po, index))
+                .unwrap();
+            token_ids.push_back(token_id);
+        }
+        token_ids
+    }
+}
+
+/// Link a freshly minted token to its purchase order and index it
+pub(crate) fn record_po(e: &Env, token_id: u64, po: u32) {
+    e.storage()
+        .persistent()
+        .set(&PurchaseOrderStorageKey::TokenPo(token_id), &po);
+
+    let count_key = PurchaseOrderStorageKey::PoTokenCount(po);
+    let count: u32 = e.storage().persistent().get(&count_key).unwrap_or(0);
+    e.storage()
+        .persistent()
+        .set(&PurchaseOrderStorageKey::PoToken(po, count), &token_id);
+    let count = count
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    e.storage().persistent().set(&count_key, &count);
+}
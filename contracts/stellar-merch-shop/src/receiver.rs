@@ -0,0 +1,87 @@
+//! Receiver hook letting contract recipients accept or refuse incoming tokens
+
+use soroban_sdk::{contractclient, contractimpl, contracttype, panic_with_error, Address, Env, Executable};
+use crate::{errors, events, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+pub enum ReceiverStorageKey {
+    OptOut(Address),
+}
+
+/// Interface contract recipients export to handle incoming tokens
+#[contractclient(name = "NftReceiverClient")]
+pub trait NftReceiverInterface {
+    fn on_nft_received(e: Env, operator: Address, from: Address, token_id: u64) -> bool;
+}
+
+pub trait ReceiverHook {
+    /// Opts `receiver` in or out of the receiver hook. Requires `receiver`.
+    ///
+    /// Contracts are asked through `on_nft_received` whether they accept
+    /// each incoming token, see `transfer`. Contracts that opted out receive
+    /// tokens like accounts do.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - Contract exporting `on_nft_received`.
+    /// * `enabled` - Whether incoming tokens go through the hook.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["receiver_hook_updated", receiver: Address]`
+    /// * data - `[enabled: bool]`
+    fn set_receiver_hook(e: &Env, receiver: Address, enabled: bool);
+
+    /// Returns whether incoming tokens of `receiver` go through the receiver
+    /// hook, true unless it opted out.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - Account or contract receiving tokens.
+    fn receiver_hook(e: &Env, receiver: Address) -> bool;
+}
+
+#[contractimpl]
+impl ReceiverHook for StellarMerchShop {
+    fn set_receiver_hook(e: &Env, receiver: Address, enabled: bool) {
+        receiver.require_auth();
+
+        let key = ReceiverStorageKey::OptOut(receiver.clone());
+        if enabled {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, &true);
+        }
+
+        events::ReceiverHookUpdated { receiver, enabled }.publish(e);
+    }
+
+    fn receiver_hook(e: &Env, receiver: Address) -> bool {
+        !e.storage().persistent().has(&ReceiverStorageKey::OptOut(receiver))
+    }
+}
+
+/// Ask a contract recipient whether it accepts token_id, once the token is moved,
+/// shared by every path giving a token to a new owner
+/// Contracts that opted out with set_receiver_hook are not asked, nor those that
+/// authorized the operation themselves, such as a contract wallet claiming its
+/// token. The host reports a missing hook like a panic, so a contract without
+/// it refuses until it opts out
+pub(crate) fn check_received(e: &Env, operator: &Address, from: &Address, to: &Address, token_id: u64) {
+    if to == operator
+        || !matches!(to.executable(), Some(Executable::Wasm(_)))
+        || !StellarMerchShop::receiver_hook(e, to.clone())
+    {
+        return;
+    }
+
+    let accepted = matches!(
+        NftReceiverClient::new(e, to).try_on_nft_received(operator, from, &token_id),
+        Ok(Ok(true))
+    );
+    if !accepted {
+        panic_with_error!(e, errors::NonFungibleTokenError::ReceiverRejected);
+    }
+}
@@ -0,0 +1,78 @@
+//! Bounded log of the most recent claims
+
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Vec};
+use crate::{StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Number of claims kept in the log
+pub const RECENT_CLAIMS_CAPACITY: u32 = 20;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecentClaim {
+    pub token_id: u64,
+    pub ledger: u32,
+    pub claimant: Address,
+}
+
+#[contracttype]
+pub enum RecentClaimsStorageKey {
+    /// Total number of claims recorded, the next slot is this value modulo the capacity
+    RecentClaimsCursor,
+    RecentClaimSlot(u32),
+}
+
+pub trait RecentClaims {
+    /// Returns the most recent claims, newest first.
+    ///
+    /// At most `RECENT_CLAIMS_CAPACITY` entries are kept, older claims are
+    /// overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn recent_claims(e: &Env) -> Vec<RecentClaim>;
+}
+
+#[contractimpl]
+impl RecentClaims for StellarMerchShop {
+    fn recent_claims(e: &Env) -> Vec<RecentClaim> {
+        let cursor = get_cursor(e);
+        let count = cursor.min(RECENT_CLAIMS_CAPACITY);
+
+        let mut claims = Vec::new(e);
+        for i in 1..=count {
+            let slot = (cursor - i) % RECENT_CLAIMS_CAPACITY;
+            let claim: RecentClaim = e
+                .storage()
+                .persistent()
+                .get(&RecentClaimsStorageKey::RecentClaimSlot(slot))
+                .unwrap();
+            claims.push_back(claim);
+        }
+        claims
+    }
+}
+
+fn get_cursor(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&RecentClaimsStorageKey::RecentClaimsCursor)
+        .unwrap_or(0u32)
+}
+
+/// Record a claim, overwriting the oldest slot once the log is full
+pub(crate) fn record_claim(e: &Env, token_id: u64, claimant: &Address) {
+    let cursor = get_cursor(e);
+    let slot = cursor % RECENT_CLAIMS_CAPACITY;
+
+    let claim = RecentClaim { token_id, ledger: e.ledger().sequence(), claimant: claimant.clone() };
+    e.storage().persistent().set(&RecentClaimsStorageKey::RecentClaimSlot(slot), &claim);
+
+    // Wrap within a multiple of the capacity so the slot order is preserved
+    let next_cursor = if cursor == u32::MAX - (u32::MAX % RECENT_CLAIMS_CAPACITY) - 1 {
+        RECENT_CLAIMS_CAPACITY
+    } else {
+        cursor + 1
+    };
+    e.storage().instance().set(&RecentClaimsStorageKey::RecentClaimsCursor, &next_cursor);
+}
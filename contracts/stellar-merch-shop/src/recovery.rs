@@ -0,0 +1,189 @@
+//! Owner-designated recovery address pulling the owner's tokens after a delay
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Env};
+use crate::{chip_stats, contract, errors, events, owner_index::OwnerIndex, receiver, NFCtoNFTContract, NonFungibleToken, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryConfig {
+    pub recovery: Address,
+    pub delay_ledgers: u32,
+}
+
+#[contracttype]
+pub enum RecoveryStorageKey {
+    RecoveryAddress(Address),
+    PendingRecovery(Address),
+}
+
+pub trait Recovery {
+    /// Designates `recovery` as the account able to recover the tokens of
+    /// `owner`, `delay_ledgers` ledgers after announcing it.
+    ///
+    /// Replaces any previous recovery address and cancels a pending recovery.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the token's owner.
+    /// * `recovery` - Cold account receiving the tokens.
+    /// * `delay_ledgers` - Ledgers between the announcement and the recovery.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["recovery_address_set", owner: Address, recovery: Address]`
+    /// * data - `[delay_ledgers: u32]`
+    fn set_recovery_address(e: &Env, owner: Address, recovery: Address, delay_ledgers: u32);
+
+    /// Returns the recovery address of `owner` and its delay, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the token's owner.
+    fn recovery_of(e: &Env, owner: Address) -> Option<RecoveryConfig>;
+
+    /// Announces the recovery of the tokens of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `recovery` - Recovery address of `owner`.
+    /// * `owner` - Account of the token's owner.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["recovery_initiated", owner: Address, recovery: Address]`
+    /// * data - `[executable_ledger: u32]`
+    fn initiate_recovery(e: &Env, recovery: Address, owner: Address);
+
+    /// Cancels the pending recovery of the tokens of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the token's owner.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["recovery_cancelled", owner: Address]`
+    /// * data - `[]`
+    fn cancel_recovery(e: &Env, owner: Address);
+
+    /// Returns the ledger from which the pending recovery of `owner` can be
+    /// executed, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the token's owner.
+    fn pending_recovery(e: &Env, owner: Address) -> Option<u32>;
+
+    /// Moves every token of `owner` to `recovery` once the delay announced
+    /// by `initiate_recovery` has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `recovery` - Recovery address of `owner`.
+    /// * `owner` - Account of the token's owner.
+    ///
+    /// # Events
+    ///
+    /// For each token:
+    ///
+    /// * topics - `["recovery", owner: Address, recovery: Address]`
+    /// * data - `[token_id: u64]`
+    fn execute_recovery(e: &Env, recovery: Address, owner: Address);
+}
+
+#[contractimpl]
+impl Recovery for StellarMerchShop {
+    fn set_recovery_address(e: &Env, owner: Address, recovery: Address, delay_ledgers: u32) {
+        owner.require_auth();
+
+        if recovery == owner {
+            panic_with_error!(e, &errors::NonFungibleTokenError::IncorrectOwner);
+        }
+
+        let config = RecoveryConfig { recovery: recovery.clone(), delay_ledgers };
+        e.storage().persistent().set(&RecoveryStorageKey::RecoveryAddress(owner.clone()), &config);
+        e.storage().persistent().remove(&RecoveryStorageKey::PendingRecovery(owner.clone()));
+
+        events::RecoveryAddressSet { owner, recovery, delay_ledgers }.publish(e);
+    }
+
+    fn recovery_of(e: &Env, owner: Address) -> Option<RecoveryConfig> {
+        e.storage().persistent().get(&RecoveryStorageKey::RecoveryAddress(owner))
+    }
+
+    fn initiate_recovery(e: &Env, recovery: Address, owner: Address) {
+        recovery.require_auth();
+
+        let config = get_config(e, &recovery, &owner);
+        let executable_ledger = e
+            .ledger()
+            .sequence()
+            .checked_add(config.delay_ledgers)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+        e.storage()
+            .persistent()
+            .set(&RecoveryStorageKey::PendingRecovery(owner.clone()), &executable_ledger);
+
+        events::RecoveryInitiated { owner, recovery, executable_ledger }.publish(e);
+    }
+
+    fn cancel_recovery(e: &Env, owner: Address) {
+        owner.require_auth();
+
+        if Self::pending_recovery(e, owner.clone()).is_none() {
+            panic_with_error!(e, &errors::NonFungibleTokenError::NoPendingRecovery);
+        }
+        e.storage().persistent().remove(&RecoveryStorageKey::PendingRecovery(owner.clone()));
+
+        events::RecoveryCancelled { owner }.publish(e);
+    }
+
+    fn pending_recovery(e: &Env, owner: Address) -> Option<u32> {
+        e.storage().persistent().get(&RecoveryStorageKey::PendingRecovery(owner))
+    }
+
+    fn execute_recovery(e: &Env, recovery: Address, owner: Address) {
+        recovery.require_auth();
+
+        get_config(e, &recovery, &owner);
+        let executable_ledger = Self::pending_recovery(e, owner.clone())
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NoPendingRecovery));
+        if e.ledger().sequence() < executable_ledger {
+            panic_with_error!(e, &errors::NonFungibleTokenError::RecoveryDelayActive);
+        }
+        e.storage().persistent().remove(&RecoveryStorageKey::PendingRecovery(owner.clone()));
+
+        // Snapshot the holding first, moving tokens reorders the owner index.
+        // Attached children are owned by the owner too, so they move as well.
+        let balance = StellarMerchShop::balance(e, owner.clone());
+        for token_id in StellarMerchShop::tokens_of(e, owner.clone(), 0, balance).iter() {
+            contract::update_owner(e, &owner, &recovery, token_id);
+            chip_stats::record_transfer(e, &StellarMerchShop::public_key(e, token_id));
+
+            events::Recovery { owner: owner.clone(), recovery: recovery.clone(), token_id }.publish(e);
+            contract::publish_activity(
+                e,
+                events::Operation::Transfer,
+                token_id,
+                Some(owner.clone()),
+                Some(recovery.clone()),
+                None,
+            );
+            receiver::check_received(e, &recovery, &owner, &recovery, token_id);
+        }
+    }
+}
+
+/// Recovery configuration of owner, panics unless recovery is its recovery address
+fn get_config(e: &Env, recovery: &Address, owner: &Address) -> RecoveryConfig {
+    match StellarMerchShop::recovery_of(e, owner.clone()) {
+        Some(config) if config.recovery == *recovery => config,
+        _ => panic_with_error!(e, errors::NonFungibleTokenError::RecoveryNotConfigured),
+    }
+}
@@ -0,0 +1,94 @@
+//! One-time redemption of the physical perk attached to a token
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Bytes, BytesN, Env};
+use crate::contract::{ChipKey, ChipOperation};
+use crate::{contract, errors, events, NonFungibleToken, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+pub enum RedemptionStorageKey {
+    /// Ledger timestamp of the redemption
+    Redeemed(u64),
+}
+
+pub trait Redemption {
+    /// Redeems the perk of `token_id` token at the booth, once.
+    ///
+    /// The owner must authorize the call and the chip signs
+    /// `build_message_hash(Redeem, message, Some(owner), nonce, expiration_ledger)`,
+    /// proving the item is at hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `message` - The message that was signed without the nonce.
+    /// * `signature` - 64-byte ECDSA signature from NFC chip.
+    /// * `recovery_id` - Recovery ID (0-3) for signature recovery, found by the contract when `None`.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    /// * `nonce` - A nonce to prevent replay attacks, greater than `get_nonce`.
+    /// * `expiration_ledger` - Last ledger the signature is valid for, 0 for no expiry.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["redeem", token_id: u64]`
+    /// * data - `[owner: Address, timestamp: u64]`
+    fn redeem(e: &Env, token_id: u64, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32);
+
+    /// Returns whether the perk of `token_id` token was redeemed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn is_redeemed(e: &Env, token_id: u64) -> bool;
+
+    /// Returns the ledger timestamp the perk of `token_id` token was
+    /// redeemed at, if it was.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn redeemed_at(e: &Env, token_id: u64) -> Option<u64>;
+}
+
+#[contractimpl]
+impl Redemption for StellarMerchShop {
+    fn redeem(
+        e: &Env,
+        token_id: u64,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) {
+        let owner = StellarMerchShop::owner_of(e, token_id);
+        owner.require_auth();
+
+        if Self::is_redeemed(e, token_id) {
+            panic_with_error!(e, errors::NonFungibleTokenError::AlreadyRedeemed);
+        }
+        contract::verify_token_chip_signature(e, ChipOperation::Redeem, token_id, message, signature, recovery_id, ChipKey::Ecdsa(public_key), nonce, expiration_ledger, &owner);
+
+        let timestamp = e.ledger().timestamp();
+        e.storage()
+            .persistent()
+            .set(&RedemptionStorageKey::Redeemed(token_id), &timestamp);
+
+        events::Redeem { token_id, owner, timestamp }.publish(e);
+    }
+
+    fn is_redeemed(e: &Env, token_id: u64) -> bool {
+        e.storage()
+            .persistent()
+            .has(&RedemptionStorageKey::Redeemed(token_id))
+    }
+
+    fn redeemed_at(e: &Env, token_id: u64) -> Option<u64> {
+        e.storage()
+            .persistent()
+            .get(&RedemptionStorageKey::Redeemed(token_id))
+    }
+}
@@ -0,0 +1,153 @@
+//! Reservation of unclaimed tokens for a specific claimant
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Env};
+use crate::{contract, errors, events, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+pub enum ReservationStorageKey {
+    Reservation(u64),
+    ClaimDelegate(u64),
+}
+
+pub trait Reservation {
+    /// Reserves the unclaimed `token_id` token for `claimant`.
+    ///
+    /// Until `expiry_ledger` is reached or the reservation is released, only
+    /// `claimant` can claim the token. Reserving again replaces the previous
+    /// reservation.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `claimant` - Account the token is promised to.
+    /// * `expiry_ledger` - First ledger at which the reservation no longer applies.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["reserved", token_id: u64, claimant: Address]`
+    /// * data - `[expiry_ledger: u32]`
+    fn reserve_for(e: &Env, token_id: u64, claimant: Address, expiry_ledger: u32);
+
+    /// Releases the reservation of `token_id` token, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["reservation_released", token_id: u64]`
+    /// * data - `[]`
+    fn release_reservation(e: &Env, token_id: u64);
+
+    /// Returns the claimant and expiry ledger of the active reservation of
+    /// `token_id` token, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn reservation_of(e: &Env, token_id: u64) -> Option<(Address, u32)>;
+
+    /// Hands the claim of the reserved `token_id` token to `delegate`.
+    ///
+    /// Once delegated, `delegate` is the only account that can claim the
+    /// token. The reservation holder can re-delegate until the claim happens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `delegator` - Account holding the reservation.
+    /// * `token_id` - Token id as a number.
+    /// * `delegate` - Account allowed to claim the token.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["claim_delegated", token_id: u64, delegate: Address]`
+    /// * data - `[]`
+    fn delegate_claim(e: &Env, delegator: Address, token_id: u64, delegate: Address);
+
+    /// Returns the claim delegate of the reserved `token_id` token, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn claim_delegate_of(e: &Env, token_id: u64) -> Option<Address>;
+}
+
+#[contractimpl]
+impl Reservation for StellarMerchShop {
+    fn reserve_for(e: &Env, token_id: u64, claimant: Address, expiry_ledger: u32) {
+        contract::require_admin(e);
+
+        // Verify the token exists and is still up for claim
+        if contract::token_data(e, token_id).owner.is_some() {
+            panic_with_error!(e, &errors::NonFungibleTokenError::TokenAlreadyMinted);
+        }
+
+        e.storage()
+            .persistent()
+            .set(&ReservationStorageKey::Reservation(token_id), &(claimant.clone(), expiry_ledger));
+        e.storage().persistent().remove(&ReservationStorageKey::ClaimDelegate(token_id));
+
+        events::Reserved { token_id, claimant, expiry_ledger }.publish(e);
+    }
+
+    fn release_reservation(e: &Env, token_id: u64) {
+        contract::require_admin(e);
+
+        clear_reservation(e, token_id);
+
+        events::ReservationReleased { token_id }.publish(e);
+    }
+
+    fn reservation_of(e: &Env, token_id: u64) -> Option<(Address, u32)> {
+        e.storage()
+            .persistent()
+            .get::<_, (Address, u32)>(&ReservationStorageKey::Reservation(token_id))
+            .filter(|(_, expiry_ledger)| e.ledger().sequence() < *expiry_ledger)
+    }
+
+    fn delegate_claim(e: &Env, delegator: Address, token_id: u64, delegate: Address) {
+        delegator.require_auth();
+
+        if Self::reservation_of(e, token_id).is_none_or(|(reserved_for, _)| reserved_for != delegator) {
+            panic_with_error!(e, &errors::NonFungibleTokenError::IncorrectOwner);
+        }
+
+        e.storage()
+            .persistent()
+            .set(&ReservationStorageKey::ClaimDelegate(token_id), &delegate);
+
+        events::ClaimDelegated { token_id, delegate }.publish(e);
+    }
+
+    fn claim_delegate_of(e: &Env, token_id: u64) -> Option<Address> {
+        // A delegation only lives as long as the reservation it stems from
+        Self::reservation_of(e, token_id)?;
+
+        e.storage()
+            .persistent()
+            .get(&ReservationStorageKey::ClaimDelegate(token_id))
+    }
+}
+
+fn clear_reservation(e: &Env, token_id: u64) {
+    e.storage().persistent().remove(&ReservationStorageKey::Reservation(token_id));
+    e.storage().persistent().remove(&ReservationStorageKey::ClaimDelegate(token_id));
+}
+
+/// Panic if `token_id` is reserved for, or delegated to, someone other than
+/// `claimant`, and clear the reservation once it has been honoured
+pub(crate) fn consume_reservation(e: &Env, token_id: u64, claimant: &Address) {
+    if let Some((reserved_for, _)) = StellarMerchShop::reservation_of(e, token_id) {
+        let allowed = StellarMerchShop::claim_delegate_of(e, token_id).unwrap_or(reserved_for);
+        if allowed != *claimant {
+            panic_with_error!(e, &errors::NonFungibleTokenError::ReservedForAnother);
+        }
+    }
+    clear_reservation(e, token_id);
+}
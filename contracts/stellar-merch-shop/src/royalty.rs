@@ -0,0 +1,118 @@
+//! Royalties owed on secondary sales, in basis points of the sale price
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Env};
+use crate::treasury::Treasury;
+use crate::{contract, errors, events, merchants, NFCtoNFTContract, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Basis points of a full sale price (100%)
+pub const MAX_ROYALTY_BASIS_POINTS: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Royalty {
+    pub receiver: Address,
+    pub basis_points: u32,
+}
+
+#[contracttype]
+pub enum RoyaltyStorageKey {
+    DefaultRoyalty,
+    TokenRoyalty(u64),
+}
+
+pub trait Royalties {
+    /// Sets the royalty applied to every token without its own royalty.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - Account receiving the royalty.
+    /// * `basis_points` - Royalty in basis points, up to `MAX_ROYALTY_BASIS_POINTS`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["royalty_updated", token_id: Option<u64>]`
+    /// * data - `[receiver: Address, basis_points: u32]`
+    fn set_default_royalty(e: &Env, receiver: Address, basis_points: u32);
+
+    /// Sets the royalty of `token_id` token, which takes precedence over the
+    /// default royalty.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `receiver` - Account receiving the royalty.
+    /// * `basis_points` - Royalty in basis points, up to `MAX_ROYALTY_BASIS_POINTS`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["royalty_updated", token_id: Option<u64>]`
+    /// * data - `[receiver: Address, basis_points: u32]`
+    fn set_token_royalty(e: &Env, token_id: u64, receiver: Address, basis_points: u32);
+
+    /// Returns the receiver and amount of the royalty owed when `token_id`
+    /// token is sold for `sale_price`.
+    ///
+    /// Without any royalty set, the amount is 0 and the receiver is the
+    /// treasury.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `sale_price` - Price the token is sold for.
+    fn royalty_info(e: &Env, token_id: u64, sale_price: i128) -> (Address, i128);
+}
+
+#[contractimpl]
+impl Royalties for StellarMerchShop {
+    fn set_default_royalty(e: &Env, receiver: Address, basis_points: u32) {
+        contract::require_admin(e);
+
+        let royalty = new_royalty(e, receiver.clone(), basis_points);
+        e.storage().instance().set(&RoyaltyStorageKey::DefaultRoyalty, &royalty);
+
+        events::RoyaltyUpdated { token_id: None, receiver, basis_points }.publish(e);
+    }
+
+    fn set_token_royalty(e: &Env, token_id: u64, receiver: Address, basis_points: u32) {
+        merchants::require_token_admin(e, token_id);
+
+        let royalty = new_royalty(e, receiver.clone(), basis_points);
+        e.storage().persistent().set(&RoyaltyStorageKey::TokenRoyalty(token_id), &royalty);
+
+        events::RoyaltyUpdated { token_id: Some(token_id), receiver, basis_points }.publish(e);
+    }
+
+    fn royalty_info(e: &Env, token_id: u64, sale_price: i128) -> (Address, i128) {
+        // Verify token exists
+        StellarMerchShop::public_key(e, token_id);
+
+        if sale_price < 0 {
+            panic_with_error!(e, errors::NonFungibleTokenError::InvalidAmount);
+        }
+
+        let royalty = e
+            .storage()
+            .persistent()
+            .get::<_, Royalty>(&RoyaltyStorageKey::TokenRoyalty(token_id))
+            .or_else(|| e.storage().instance().get(&RoyaltyStorageKey::DefaultRoyalty));
+        let Some(royalty) = royalty else {
+            return (StellarMerchShop::treasury(e), 0);
+        };
+
+        let amount = sale_price
+            .checked_mul(royalty.basis_points as i128)
+            .map(|amount| amount / MAX_ROYALTY_BASIS_POINTS as i128)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+        (royalty.receiver, amount)
+    }
+}
+
+fn new_royalty(e: &Env, receiver: Address, basis_points: u32) -> Royalty {
+    if basis_points > MAX_ROYALTY_BASIS_POINTS {
+        panic_with_error!(e, errors::NonFungibleTokenError::InvalidRoyaltyAmount);
+    }
+    Royalty { receiver, basis_points }
+}
@@ -0,0 +1,383 @@
+//! Price charged on claim and flat fee charged on transfer, paid in a
+//! Stellar Asset Contract token, the receipts of the claim payments and the
+//! donations added to them
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, token, Address, Env};
+use crate::merchants::{self, DEFAULT_MERCHANT_ID};
+use crate::{contract, errors, events, treasury, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Price {
+    pub payment_token: Address,
+    pub amount: i128,
+}
+
+/// Proof of a payment collected by `claim`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Receipt {
+    pub token_id: u64,
+    pub merchant_id: u32,
+    pub payer: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+pub enum SalesStorageKey {
+    /// Price of the default merchant
+    Price,
+    /// Price of a merchant created with create_merchant
+    MerchantPrice(u32),
+    /// Number of receipts issued, the id of the next one
+    ReceiptCount,
+    Receipt(u64),
+    /// Id of the latest receipt of a token
+    LatestReceipt(u64),
+    TransferFee,
+    FeeExempt(Address),
+    DonationAddress,
+    /// Amount of a payment token donated on claims of a token
+    TokenDonations(u64, Address),
+    /// Amount of a payment token donated on every claim
+    Donations(Address),
+}
+
+pub trait Sales {
+    /// Sets the price `claim` charges the claimant of a merchant's tokens,
+    /// 0 for free claims. Requires the merchant admin.
+    ///
+    /// Payments for `DEFAULT_MERCHANT_ID` go to the treasury, see `treasury`.
+    /// Payments for other merchants are held by the contract until their
+    /// admin withdraws them, see `withdraw`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `merchant_id` - Id of the merchant.
+    /// * `payment_token` - Stellar Asset Contract of the token the price is paid in.
+    /// * `amount` - Price in the smallest unit of `payment_token`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["price_updated", payment_token: Address, merchant_id: u32]`
+    /// * data - `[amount: i128]`
+    fn set_price(e: &Env, merchant_id: u32, payment_token: Address, amount: i128);
+
+    /// Returns the payment token and the price of a claim of a merchant's
+    /// tokens.
+    ///
+    /// Without a price set, the amount is 0 and the payment token is the
+    /// contract itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `merchant_id` - Id of the merchant.
+    fn price(e: &Env, merchant_id: u32) -> (Address, i128);
+
+    /// Returns the receipt `receipt_id`, if it was issued.
+    ///
+    /// Each paid claim issues a receipt with the next id, starting at 0.
+    /// Free claims do not.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receipt_id` - Id of the receipt.
+    fn receipt(e: &Env, receipt_id: u64) -> Option<Receipt>;
+
+    /// Returns the id and the receipt of the latest payment for `token_id`
+    /// token, if it was ever paid for.
+    ///
+    /// Older receipts of the token stay available with `receipt`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn latest_receipt_for(e: &Env, token_id: u64) -> Option<(u64, Receipt)>;
+
+    /// Sets the flat fee `transfer` and `transfer_from` charge the account
+    /// initiating the transfer, paid to the treasury to fund storage rent
+    /// top-ups. 0 disables the fee.
+    ///
+    /// Admin transfers and fee-exempt accounts, see `set_fee_exempt`, do
+    /// not pay it. It is independent of the royalties.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `payment_token` - Stellar Asset Contract of the token the fee is paid in.
+    /// * `amount` - Fee in the smallest unit of `payment_token`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer_fee_updated", payment_token: Address]`
+    /// * data - `[amount: i128]`
+    fn set_transfer_fee(e: &Env, payment_token: Address, amount: i128);
+
+    /// Returns the payment token and the amount of the transfer fee.
+    ///
+    /// Without a fee set, the amount is 0 and the payment token is the
+    /// contract itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn transfer_fee(e: &Env) -> (Address, i128);
+
+    /// Exempts `account` from the transfer fee, or removes its exemption.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - Account initiating transfers, as owner or spender.
+    /// * `exempt` - Whether `account` transfers without paying the fee.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["fee_exemption_updated", account: Address]`
+    /// * data - `[exempt: bool]`
+    fn set_fee_exempt(e: &Env, account: Address, exempt: bool);
+
+    /// Returns whether `account` is exempt from the transfer fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - Account initiating transfers, as owner or spender.
+    fn is_fee_exempt(e: &Env, account: Address) -> bool;
+
+    /// Sets the account receiving the donations of `claim_with_donation`,
+    /// such as the charity a drop supports. `None` stops accepting
+    /// donations.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `donation_address` - Account receiving the donations.
+    fn set_donation_address(e: &Env, donation_address: Option<Address>);
+
+    /// Returns the account receiving the donations, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn donation_address(e: &Env) -> Option<Address>;
+
+    /// Returns the amount of `payment_token` donated on the claims of
+    /// `token_id` token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `payment_token` - Stellar Asset Contract of the token donated.
+    fn token_donations(e: &Env, token_id: u64, payment_token: Address) -> i128;
+
+    /// Returns the amount of `payment_token` donated on every claim.
+    ///
+    /// Totals are kept per payment token, amounts of different tokens are
+    /// never added up.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `payment_token` - Stellar Asset Contract of the token donated.
+    fn total_donations(e: &Env, payment_token: Address) -> i128;
+}
+
+#[contractimpl]
+impl Sales for StellarMerchShop {
+    fn set_price(e: &Env, merchant_id: u32, payment_token: Address, amount: i128) {
+        merchants::require_merchant_admin(e, merchant_id);
+
+        if amount < 0 {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+        }
+        e.storage()
+            .instance()
+            .set(&price_key(merchant_id), &Price { payment_token: payment_token.clone(), amount });
+
+        events::PriceUpdated { payment_token, merchant_id, amount }.publish(e);
+    }
+
+    fn price(e: &Env, merchant_id: u32) -> (Address, i128) {
+        match e.storage().instance().get::<_, Price>(&price_key(merchant_id)) {
+            Some(price) => (price.payment_token, price.amount),
+            None => (e.current_contract_address(), 0),
+        }
+    }
+
+    fn receipt(e: &Env, receipt_id: u64) -> Option<Receipt> {
+        e.storage().persistent().get(&SalesStorageKey::Receipt(receipt_id))
+    }
+
+    fn latest_receipt_for(e: &Env, token_id: u64) -> Option<(u64, Receipt)> {
+        let receipt_id: u64 = e.storage().persistent().get(&SalesStorageKey::LatestReceipt(token_id))?;
+        Self::receipt(e, receipt_id).map(|receipt| (receipt_id, receipt))
+    }
+
+    fn set_transfer_fee(e: &Env, payment_token: Address, amount: i128) {
+        contract::require_admin(e);
+
+        if amount < 0 {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+        }
+        if amount == 0 {
+            e.storage().instance().remove(&SalesStorageKey::TransferFee);
+        } else {
+            e.storage()
+                .instance()
+                .set(&SalesStorageKey::TransferFee, &Price { payment_token: payment_token.clone(), amount });
+        }
+
+        events::TransferFeeUpdated { payment_token, amount }.publish(e);
+    }
+
+    fn transfer_fee(e: &Env) -> (Address, i128) {
+        match e.storage().instance().get::<_, Price>(&SalesStorageKey::TransferFee) {
+            Some(fee) => (fee.payment_token, fee.amount),
+            None => (e.current_contract_address(), 0),
+        }
+    }
+
+    fn set_fee_exempt(e: &Env, account: Address, exempt: bool) {
+        contract::require_admin(e);
+
+        let exempt_key = SalesStorageKey::FeeExempt(account.clone());
+        if exempt {
+            e.storage().persistent().set(&exempt_key, &true);
+        } else {
+            e.storage().persistent().remove(&exempt_key);
+        }
+
+        events::FeeExemptionUpdated { account, exempt }.publish(e);
+    }
+
+    fn is_fee_exempt(e: &Env, account: Address) -> bool {
+        e.storage().persistent().has(&SalesStorageKey::FeeExempt(account))
+    }
+
+    fn set_donation_address(e: &Env, donation_address: Option<Address>) {
+        contract::require_admin(e);
+
+        match donation_address {
+            Some(donation_address) => e.storage().instance().set(&SalesStorageKey::DonationAddress, &donation_address),
+            None => e.storage().instance().remove(&SalesStorageKey::DonationAddress),
+        }
+    }
+
+    fn donation_address(e: &Env) -> Option<Address> {
+        e.storage().instance().get(&SalesStorageKey::DonationAddress)
+    }
+
+    fn token_donations(e: &Env, token_id: u64, payment_token: Address) -> i128 {
+        e.storage()
+            .persistent()
+            .get(&SalesStorageKey::TokenDonations(token_id, payment_token))
+            .unwrap_or(0)
+    }
+
+    fn total_donations(e: &Env, payment_token: Address) -> i128 {
+        e.storage()
+            .persistent()
+            .get(&SalesStorageKey::Donations(payment_token))
+            .unwrap_or(0)
+    }
+}
+
+/// The default merchant keeps the key it had before merchants existed
+fn price_key(merchant_id: u32) -> SalesStorageKey {
+    if merchant_id == DEFAULT_MERCHANT_ID {
+        SalesStorageKey::Price
+    } else {
+        SalesStorageKey::MerchantPrice(merchant_id)
+    }
+}
+
+/// Pull the price of token_id from claimant, if any, before the claim is assigned
+/// The default merchant is paid to the treasury, other merchants are paid to the
+/// contract which holds the funds for them
+pub(crate) fn collect_payment(e: &Env, token_id: u64, claimant: &Address) {
+    let merchant_id = merchants::token_merchant(e, token_id);
+    let (payment_token, amount) = StellarMerchShop::price(e, merchant_id);
+    if amount == 0 {
+        return;
+    }
+    if merchant_id == DEFAULT_MERCHANT_ID {
+        token::Client::new(e, &payment_token).transfer(claimant, treasury::fee_destination(e), &amount);
+    } else {
+        token::Client::new(e, &payment_token).transfer(claimant, e.current_contract_address(), &amount);
+        treasury::credit_merchant(e, merchant_id, &payment_token, amount);
+    }
+
+    issue_receipt(
+        e,
+        Receipt {
+            token_id,
+            merchant_id,
+            payer: claimant.clone(),
+            payment_token,
+            amount,
+            ledger: e.ledger().sequence(),
+            timestamp: e.ledger().timestamp(),
+        },
+    );
+}
+
+/// Pull the transfer fee from payer, the account initiating the transfer, before the token moves
+/// Exemptions are only read while a fee is set, free transfers keep their footprint
+pub(crate) fn collect_transfer_fee(e: &Env, payer: &Address) {
+    let (payment_token, amount) = StellarMerchShop::transfer_fee(e);
+    if amount == 0 || StellarMerchShop::is_fee_exempt(e, payer.clone()) {
+        return;
+    }
+    token::Client::new(e, &payment_token).transfer(payer, treasury::fee_destination(e), &amount);
+}
+
+/// Send the donation of donor, the claimant of token_id, to the donation address
+/// It is paid in the payment token of the token's merchant and rejected rather
+/// than kept by the contract when no donation address is set
+pub(crate) fn collect_donation(e: &Env, token_id: u64, donor: &Address, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+    }
+    if amount == 0 {
+        return;
+    }
+    let donation_address = StellarMerchShop::donation_address(e)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::DonationAddressNotSet));
+    let (payment_token, price) = StellarMerchShop::price(e, merchants::token_merchant(e, token_id));
+    if price == 0 {
+        panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+    }
+    token::Client::new(e, &payment_token).transfer(donor, &donation_address, &amount);
+
+    let token_total = StellarMerchShop::token_donations(e, token_id, payment_token.clone());
+    e.storage().persistent().set(
+        &SalesStorageKey::TokenDonations(token_id, payment_token.clone()),
+        &treasury::checked_add(e, token_total, amount),
+    );
+    let total = StellarMerchShop::total_donations(e, payment_token.clone());
+    e.storage()
+        .persistent()
+        .set(&SalesStorageKey::Donations(payment_token.clone()), &treasury::checked_add(e, total, amount));
+
+    events::Donation { donor: donor.clone(), token_id, payment_token, amount }.publish(e);
+}
+
+/// Store `receipt` under the next receipt id and point its token to it
+fn issue_receipt(e: &Env, receipt: Receipt) {
+    let receipt_id: u64 = e.storage().instance().get(&SalesStorageKey::ReceiptCount).unwrap_or(0);
+    let next_receipt_id = receipt_id
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    e.storage().instance().set(&SalesStorageKey::ReceiptCount, &next_receipt_id);
+    e.storage().persistent().set(&SalesStorageKey::LatestReceipt(receipt.token_id), &receipt_id);
+    e.storage().persistent().set(&SalesStorageKey::Receipt(receipt_id), &receipt);
+}
@@ -0,0 +1,179 @@
+//! Series of drops minted within one collection, each with its own metadata and cap
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Env, String};
+use crate::contract::{self, DataKey, NFTStorageKey};
+use crate::{errors, events, token_ttl, NFCtoNFTContract, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Series created by the constructor, the collection as a single drop
+pub const DEFAULT_SERIES_ID: u32 = 0;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeriesInfo {
+    pub name: String,
+    pub base_uri: String,
+    pub max_tokens: u64,
+    /// Number of tokens minted in the series, burned ones included
+    pub minted: u64,
+}
+
+#[contracttype]
+pub enum SeriesStorageKey {
+    Series(u32),
+    /// Only set for tokens outside of the default series
+    TokenSeries(u64),
+}
+
+pub trait Series {
+    /// Creates a new series, such as a yearly drop, with its own metadata
+    /// and cap.
+    ///
+    /// Token ids are shared by every series: the series only caps how many
+    /// tokens it mints and builds their URI as `{base_uri}/{token_id}`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `series_id` - Id of the new series, fails with `SeriesAlreadyExists` if taken.
+    /// * `name` - Name of the series, up to `MAX_NAME_LENGTH` bytes.
+    /// * `base_uri` - Base URI of the series metadata, up to `MAX_BASE_URI_LENGTH` bytes.
+    /// * `max_tokens` - Maximum number of tokens the series can mint.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["series_created", series_id: u32]`
+    /// * data - `[name: String, base_uri: String, max_tokens: u64]`
+    fn create_series(e: &Env, series_id: u32, name: String, base_uri: String, max_tokens: u64);
+
+    /// Returns the series `token_id` token was minted in.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn series_of(e: &Env, token_id: u64) -> u32;
+
+    /// Returns the metadata, cap and mint count of a series.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `series_id` - Id of the series.
+    fn series_info(e: &Env, series_id: u32) -> SeriesInfo;
+}
+
+#[contractimpl]
+impl Series for StellarMerchShop {
+    fn create_series(e: &Env, series_id: u32, name: String, base_uri: String, max_tokens: u64) {
+        contract::require_admin(e);
+
+        if e.storage().instance().has(&SeriesStorageKey::Series(series_id)) {
+            panic_with_error!(e, &errors::NonFungibleTokenError::SeriesAlreadyExists);
+        }
+        if let Err(error) = store_series(e, series_id, &name, &base_uri, max_tokens) {
+            panic_with_error!(e, error);
+        }
+
+        events::SeriesCreated { series_id, name, base_uri, max_tokens }.publish(e);
+    }
+
+    fn series_of(e: &Env, token_id: u64) -> u32 {
+        // Verify token exists
+        StellarMerchShop::public_key(e, token_id);
+
+        token_series(e, token_id)
+    }
+
+    fn series_info(e: &Env, series_id: u32) -> SeriesInfo {
+        series_info_opt(e, series_id)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::SeriesNotFound))
+    }
+}
+
+/// Create a series, shared by create_series and the constructor
+pub(crate) fn store_series(
+    e: &Env,
+    series_id: u32,
+    name: &String,
+    base_uri: &String,
+    max_tokens: u64,
+) -> Result<(), errors::NonFungibleTokenError> {
+    contract::check_base_uri(base_uri)?;
+    contract::check_name(name)?;
+
+    let series = SeriesInfo { name: name.clone(), base_uri: base_uri.clone(), max_tokens, minted: 0 };
+    e.storage().instance().set(&SeriesStorageKey::Series(series_id), &series);
+    Ok(())
+}
+
+pub(crate) fn series_info_opt(e: &Env, series_id: u32) -> Option<SeriesInfo> {
+    e.storage().instance().get(&SeriesStorageKey::Series(series_id))
+}
+
+pub(crate) fn set_series_info(e: &Env, series_id: u32, series: &SeriesInfo) {
+    e.storage().instance().set(&SeriesStorageKey::Series(series_id), series);
+}
+
+/// Series of an existing token_id
+pub(crate) fn token_series(e: &Env, token_id: u64) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&SeriesStorageKey::TokenSeries(token_id))
+        .unwrap_or(DEFAULT_SERIES_ID)
+}
+
+/// Read-only check that the series exists and can still mint
+pub(crate) fn check_mintable(e: &Env, series_id: u32) -> Result<SeriesInfo, errors::NonFungibleTokenError> {
+    let series = series_info_opt(e, series_id).ok_or(errors::NonFungibleTokenError::SeriesNotFound)?;
+    if series.minted >= series.max_tokens {
+        return Err(errors::NonFungibleTokenError::TokenIDsAreDepleted);
+    }
+    Ok(series)
+}
+
+/// Count the minted token_id in its series
+/// Tokens of the default series carry no entry, keeping the mint footprint unchanged
+pub(crate) fn record_mint(e: &Env, series_id: u32, token_id: u64) {
+    let mut series = check_mintable(e, series_id).unwrap_or_else(|error| panic_with_error!(e, error));
+    series.minted = series
+        .minted
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    set_series_info(e, series_id, &series);
+
+    if series_id != DEFAULT_SERIES_ID {
+        let key = SeriesStorageKey::TokenSeries(token_id);
+        e.storage().persistent().set(&key, &series_id);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, token_ttl::MINT_TTL_LEDGERS, token_ttl::MINT_TTL_LEDGERS);
+    }
+}
+
+/// Remove the series of the burned token_id
+pub(crate) fn remove_token(e: &Env, token_id: u64) {
+    e.storage()
+        .persistent()
+        .remove(&SeriesStorageKey::TokenSeries(token_id));
+}
+
+/// Move the single collection of contracts deployed before series to the default series
+pub(crate) fn migrate_legacy_collection(e: &Env) {
+    let instance = e.storage().instance();
+    if instance.has(&SeriesStorageKey::Series(DEFAULT_SERIES_ID)) {
+        return;
+    }
+
+    // A cap lowered below the tokens already minted leaves the series full
+    let max_tokens: u64 = instance.get(&DataKey::MaxTokens).unwrap();
+    let minted: u64 = instance.get(&DataKey::NextTokenId).unwrap();
+    let series = SeriesInfo {
+        name: instance.get(&NFTStorageKey::Name).unwrap(),
+        base_uri: instance.get(&NFTStorageKey::URI).unwrap(),
+        max_tokens,
+        minted: minted.min(max_tokens),
+    };
+    set_series_info(e, DEFAULT_SERIES_ID, &series);
+    instance.remove(&NFTStorageKey::URI);
+    instance.remove(&DataKey::MaxTokens);
+}
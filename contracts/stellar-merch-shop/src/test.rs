@@ -25,10 +25,19 @@
 //! ## Important Notes
 //!
 //! - Message hash = SHA256(message_bytes + nonce.to_xdr())
+//! - The contract signs over more than that (its address, the recipient), see
+//!   `build_message_hash`: the hardware vectors predate it and can only be
+//!   checked with `verify_product` and `verify_batch`
 //! - Soroban's to_xdr() for u32 uses type tag 0x00000003, NOT 0x00000004
-//! - Signatures must have S normalized (low S form) for Soroban's secp256k1_recover
-//! - The normalize_s() function handles this automatically
+//! - Soroban's secp256k1_recover only accepts low S signatures; the contract
+//!   normalizes high S ones (flipping the recovery ID parity) so raw chip
+//!   signatures can be passed as is
 //! - Recovery ID (0-3) is determined automatically by trying all possibilities
+//!
+//! ## Software Chips
+//!
+//! Tests needing more chips or nonces than the hardware vectors provide use
+//! `SoftwareChip`, which signs the same message hash with a deterministic k256 key.
 
 extern crate std;
 extern crate alloc;
@@ -36,10 +45,17 @@ extern crate alloc;
 use alloc::format;
 use alloc::vec::Vec;
 
-use soroban_sdk::{crypto::Hash, testutils::Address as _, Address, Bytes, BytesN, Env, String};
+use soroban_sdk::{contractclient, crypto::Hash, testutils::{storage::{Instance as _, Persistent as _}, Address as _, AuthorizedFunction, AuthorizedInvocation, Events as _, Ledger as _, MockAuth, MockAuthInvoke}, Address, Bytes, BytesN, Env, Event, IntoVal, Map, String, Symbol};
 use soroban_sdk::xdr::ToXdr;
 
-use crate::{StellarMerchShop, StellarMerchShopClient};
+use crate::attributes::MAX_ATTRIBUTE_VALUE_LENGTH;
+use crate::chip_stats::ChipStats;
+#[cfg(feature = "provenance")]
+use crate::recent_claims::RecentClaim;
+use crate::sales::Receipt;
+#[cfg(feature = "warranty")]
+use crate::warranty::WarrantyStatus;
+use crate::{errors, events, ChipInfo, ChipKey, ChipOperation, CurveType, MintPreview, ProductVerification, SecurityLevel, StellarMerchShop, StellarMerchShopClient, TokenInfo, VerifyItem};
 
 struct TestSignature {
     nonce: u32,
@@ -157,56 +173,11 @@ const TEST_SIGNATURES: &[TestSignature] = &[
 ];
 
 
-// Normalize s value for ECDSA signatures (required by Soroban, same as webapp)
-fn normalize_s(s: &[u8; 32]) -> [u8; 32] {
-    const HALF_ORDER: [u8; 32] = [
-        0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x5D, 0x57, 0x6E, 0x73, 0x57, 0xA4, 0x50, 0x1D,
-    ];
-    const CURVE_ORDER: [u8; 32] = [
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE,
-        0xBA, 0xAE, 0xDC, 0xE6, 0xAF, 0x48, 0xA0, 0x3B, 0xBF, 0xD2, 0x5E, 0x8C, 0xD0, 0x36, 0x41, 0x41,
-    ];
-
-    // Check if s > half_order
-    let mut s_greater_than_half = false;
-    for i in 0..32 {
-        if s[i] > HALF_ORDER[i] {
-            s_greater_than_half = true;
-            break;
-        } else if s[i] < HALF_ORDER[i] {
-            break;
-        }
-    }
-
-    if s_greater_than_half {
-        // s = n - s
-        let mut result = [0u8; 32];
-        let mut borrow = 0u16;
-        for i in (0..32).rev() {
-            let curve_byte = CURVE_ORDER[i] as u16;
-            let s_byte = s[i] as u16;
-            let total_to_subtract = s_byte + borrow;
-
-            if curve_byte >= total_to_subtract {
-                result[i] = (curve_byte - total_to_subtract) as u8;
-                borrow = 0;
-            } else {
-                result[i] = ((256u16 + curve_byte) - total_to_subtract) as u8;
-                borrow = 1;
-            }
-        }
-        result
-    } else {
-        *s
-    }
-}
-
 // Helper to create test signature with proper normalization and find recovery ID
 fn create_test_signature_and_recovery_id(e: &Env, message_hash: &Hash<32>, sig: &TestSignature) -> (BytesN<64>, u32) {
     let public_key = BytesN::from_array(e, &sig.public_key);
 
-    let s_normalized = normalize_s(&sig.sig_s);
+    let s_normalized = merch_crypto::normalize_s(&sig.sig_s);
     let mut sig_bytes = [0u8; 64];
     // Standard secp256k1 format is [R, S] where R and S are 32 bytes each
     sig_bytes[..32].copy_from_slice(&sig.sig_r);
@@ -245,6 +216,26 @@ fn calculate_message_hash(e: &Env, message: &[u8], nonce: u32) -> Hash<32> {
     e.crypto().sha256(&builder)
 }
 
+// Hash the contract expects: contract.to_xdr() || operation || message || recipient.to_xdr() || expiration_ledger.to_xdr() || nonce.to_xdr()
+fn calculate_contract_message_hash(e: &Env, operation: ChipOperation, contract: &Address, message: &[u8], recipient: Option<&Address>, nonce: u64, expiration_ledger: u32) -> Hash<32> {
+    e.crypto().sha256(&contract_message_preimage(e, operation, contract, message, recipient, nonce, expiration_ledger))
+}
+
+// Preimage of calculate_contract_message_hash, signed as is by Ed25519 chips
+fn contract_message_preimage(e: &Env, operation: ChipOperation, contract: &Address, message: &[u8], recipient: Option<&Address>, nonce: u64, expiration_ledger: u32) -> Bytes {
+    let mut builder = contract.clone().to_xdr(e);
+    builder.push_back(operation as u8);
+    builder.append(&Bytes::from_slice(e, message));
+    if let Some(recipient) = recipient {
+        builder.append(&recipient.clone().to_xdr(e));
+    }
+    if expiration_ledger != 0 {
+        builder.append(&expiration_ledger.to_xdr(e));
+    }
+    builder.append(&nonce.to_xdr(e));
+    builder
+}
+
 // Helper function to print message hash for manual signing
 fn print_message_hash_for_signing(e: &Env, message: &[u8], nonce: u32) {
     let message_bytes = Bytes::from_slice(e, message);
@@ -280,76 +271,20 @@ fn print_message_hash_for_signing(e: &Env, message: &[u8], nonce: u32) {
     std::println!();
 }
 
-// Helper function to parse DER signature and extract R and S
-// DER format: 0x30 [length] 0x02 [R length] [R bytes] 0x02 [S length] [S bytes]
+// Helper function to parse a hex DER signature and extract R and S
 fn parse_der_signature(der_hex: &str) -> ([u8; 32], [u8; 32]) {
-    // Parse hex string to bytes
     let clean_hex = der_hex.strip_prefix("0x").unwrap_or(der_hex);
     let mut der_bytes = Vec::new();
     for i in 0..(clean_hex.len() / 2) {
         let byte_str = &clean_hex[i * 2..i * 2 + 2];
-        let byte = u8::from_str_radix(byte_str, 16).expect("Invalid hex string");
-        der_bytes.push(byte);
-    }
-    
-    let mut pos = 1; // Skip 0x30 sequence tag
-    
-    if der_bytes[0] != 0x30 {
-        panic!("Invalid DER: expected sequence tag 0x30");
-    }
-    
-    let _seq_len = der_bytes[pos];
-    pos += 1;
-    
-    // Parse R component
-    if der_bytes[pos] != 0x02 {
-        panic!("Invalid DER: expected integer tag 0x02 for R");
-    }
-    pos += 1;
-    
-    let r_len = der_bytes[pos] as usize;
-    pos += 1;
-    
-    let mut r_bytes = der_bytes[pos..pos + r_len].to_vec();
-    pos += r_len;
-    
-    // Remove leading zero if present (for positive numbers)
-    if r_bytes.len() > 32 && r_bytes[0] == 0x00 {
-        r_bytes = r_bytes[1..].to_vec();
+        der_bytes.push(u8::from_str_radix(byte_str, 16).expect("Invalid hex string"));
     }
-    
-    // Pad to 32 bytes if needed
+
+    let signature = merch_crypto::parse_der_signature(&der_bytes).expect("Invalid DER signature");
     let mut sig_r = [0u8; 32];
-    if r_bytes.len() < 32 {
-        sig_r[32 - r_bytes.len()..].copy_from_slice(&r_bytes);
-    } else {
-        sig_r.copy_from_slice(&r_bytes[r_bytes.len() - 32..]);
-    }
-    
-    // Parse S component
-    if der_bytes[pos] != 0x02 {
-        panic!("Invalid DER: expected integer tag 0x02 for S");
-    }
-    pos += 1;
-    
-    let s_len = der_bytes[pos] as usize;
-    pos += 1;
-    
-    let mut s_bytes = der_bytes[pos..pos + s_len].to_vec();
-    
-    // Remove leading zero if present (for positive numbers)
-    if s_bytes.len() > 32 && s_bytes[0] == 0x00 {
-        s_bytes = s_bytes[1..].to_vec();
-    }
-    
-    // Pad to 32 bytes if needed
     let mut sig_s = [0u8; 32];
-    if s_bytes.len() < 32 {
-        sig_s[32 - s_bytes.len()..].copy_from_slice(&s_bytes);
-    } else {
-        sig_s.copy_from_slice(&s_bytes[s_bytes.len() - 32..]);
-    }
-    
+    sig_r.copy_from_slice(&signature[..32]);
+    sig_s.copy_from_slice(&signature[32..]);
     (sig_r, sig_s)
 }
 
@@ -392,6 +327,128 @@ fn format_signature_for_rust(sig_r: [u8; 32], sig_s: [u8; 32]) -> std::string::S
     result
 }
 
+// Software chip for tests needing more chips or nonces than the hardware vectors provide
+struct SoftwareChip {
+    signing_key: k256::ecdsa::SigningKey,
+}
+
+impl SoftwareChip {
+    fn new(seed: u32) -> Self {
+        let mut secret = [0u8; 32];
+        secret[28..].copy_from_slice(&(seed + 1).to_be_bytes());
+        SoftwareChip { signing_key: k256::ecdsa::SigningKey::from_slice(&secret).unwrap() }
+    }
+
+    fn public_key(&self, e: &Env) -> BytesN<65> {
+        let point = self.signing_key.verifying_key().to_encoded_point(false);
+        BytesN::from_array(e, point.as_bytes().try_into().unwrap())
+    }
+
+    // Returns the message, signature, recovery ID and public key like signed_arguments
+    fn sign(&self, e: &Env, operation: ChipOperation, contract: &Address, message: &[u8], nonce: u64) -> (Bytes, BytesN<64>, u32, BytesN<65>) {
+        self.sign_until(e, operation, contract, message, None, nonce, 0)
+    }
+
+    // Same as sign for a signature bound to recipient, as claim and transfer expect
+    fn sign_for(&self, e: &Env, operation: ChipOperation, contract: &Address, message: &[u8], recipient: &Address, nonce: u64) -> (Bytes, BytesN<64>, u32, BytesN<65>) {
+        self.sign_until(e, operation, contract, message, Some(recipient), nonce, 0)
+    }
+
+    // Same as sign_for with a signature expiring after expiration_ledger
+    fn sign_until(&self, e: &Env, operation: ChipOperation, contract: &Address, message: &[u8], recipient: Option<&Address>, nonce: u64, expiration_ledger: u32) -> (Bytes, BytesN<64>, u32, BytesN<65>) {
+        self.sign_hash(e, message, calculate_contract_message_hash(e, operation, contract, message, recipient, nonce, expiration_ledger))
+    }
+
+    fn sign_hash(&self, e: &Env, message: &[u8], message_hash: Hash<32>) -> (Bytes, BytesN<64>, u32, BytesN<65>) {
+        let message_hash: BytesN<32> = message_hash.into();
+        let (signature, recovery_id) = self
+            .signing_key
+            .sign_prehash_recoverable(&message_hash.to_array())
+            .unwrap();
+        let signature = BytesN::from_array(e, &signature.to_bytes().into());
+        (Bytes::from_slice(e, message), signature, recovery_id.to_byte() as u32, self.public_key(e))
+    }
+
+    fn mint(&self, e: &Env, client: &StellarMerchShopClient, nonce: u64) -> u64 {
+        let (message, signature, recovery_id, public_key) = self.sign(e, ChipOperation::Mint, &client.address, TEST_MESSAGE, nonce);
+        client.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &nonce, &0, &None, &None)
+    }
+
+    fn claim(&self, e: &Env, client: &StellarMerchShopClient, claimant: &Address, nonce: u64) -> u64 {
+        let (message, signature, recovery_id, public_key) = self.sign_for(e, ChipOperation::Claim, &client.address, TEST_MESSAGE, claimant, nonce);
+        client.claim(claimant, &message, &signature, &Some(recovery_id), &public_key, &nonce, &0)
+    }
+}
+
+// Software Ed25519 chip, see SoftwareChip
+struct Ed25519Chip {
+    signing_key: ed25519_dalek::SigningKey,
+}
+
+impl Ed25519Chip {
+    fn new(seed: u32) -> Self {
+        let mut secret = [0u8; 32];
+        secret[28..].copy_from_slice(&(seed + 1).to_be_bytes());
+        Ed25519Chip { signing_key: ed25519_dalek::SigningKey::from_bytes(&secret) }
+    }
+
+    fn public_key(&self, e: &Env) -> BytesN<32> {
+        BytesN::from_array(e, self.signing_key.verifying_key().as_bytes())
+    }
+
+    // Returns the message and signature, the chip signs the preimage rather than its hash
+    fn sign(&self, e: &Env, operation: ChipOperation, contract: &Address, recipient: Option<&Address>, nonce: u64) -> (Bytes, BytesN<64>) {
+        use ed25519_dalek::Signer;
+
+        let preimage: Vec<u8> = contract_message_preimage(e, operation, contract, TEST_MESSAGE, recipient, nonce, 0).iter().collect();
+        let signature = self.signing_key.sign(&preimage);
+        (Bytes::from_slice(e, TEST_MESSAGE), BytesN::from_array(e, &signature.to_bytes()))
+    }
+}
+
+// RFC 6979 A.2.5: P-256 key signing "sample" with SHA-256, S is high like a chip may return it
+const P256_VECTOR_PUBLIC_KEY: [u8; 65] = [
+    0x04, 0x60, 0xfe, 0xd4, 0xba, 0x25, 0x5a, 0x9d, 0x31, 0xc9, 0x61, 0xeb, 0x74, 0xc6, 0x35, 0x6d,
+    0x68, 0xc0, 0x49, 0xb8, 0x92, 0x3b, 0x61, 0xfa, 0x6c, 0xe6, 0x69, 0x62, 0x2e, 0x60, 0xf2, 0x9f,
+    0xb6, 0x79, 0x03, 0xfe, 0x10, 0x08, 0xb8, 0xbc, 0x99, 0xa4, 0x1a, 0xe9, 0xe9, 0x56, 0x28, 0xbc,
+    0x64, 0xf2, 0xf1, 0xb2, 0x0c, 0x2d, 0x7e, 0x9f, 0x51, 0x77, 0xa3, 0xc2, 0x94, 0xd4, 0x46, 0x22,
+    0x99,
+];
+
+const P256_VECTOR_SIGNATURE: [u8; 64] = [
+    0xef, 0xd4, 0x8b, 0x2a, 0xac, 0xb6, 0xa8, 0xfd, 0x11, 0x40, 0xdd, 0x9c, 0xd4, 0x5e, 0x81, 0xd6,
+    0x9d, 0x2c, 0x87, 0x7b, 0x56, 0xaa, 0xf9, 0x91, 0xc3, 0x4d, 0x0e, 0xa8, 0x4e, 0xaf, 0x37, 0x16,
+    0xf7, 0xcb, 0x1c, 0x94, 0x2d, 0x65, 0x7c, 0x41, 0xd4, 0x36, 0xc7, 0xa1, 0xb6, 0xe2, 0x9f, 0x65,
+    0xf3, 0xe9, 0x00, 0xdb, 0xb9, 0xaf, 0xf4, 0x06, 0x4d, 0xc4, 0xab, 0x2f, 0x84, 0x3a, 0xcd, 0xa8,
+];
+
+// Software P-256 chip, see SoftwareChip
+struct P256Chip {
+    signing_key: p256::ecdsa::SigningKey,
+}
+
+impl P256Chip {
+    fn new(seed: u32) -> Self {
+        let mut secret = [0u8; 32];
+        secret[28..].copy_from_slice(&(seed + 1).to_be_bytes());
+        P256Chip { signing_key: p256::ecdsa::SigningKey::from_slice(&secret).unwrap() }
+    }
+
+    fn public_key(&self, e: &Env) -> BytesN<65> {
+        let point = self.signing_key.verifying_key().to_encoded_point(false);
+        BytesN::from_array(e, point.as_bytes().try_into().unwrap())
+    }
+
+    // Returns the message and signature, P-256 has no recovery ID
+    fn sign(&self, e: &Env, operation: ChipOperation, contract: &Address, recipient: Option<&Address>, nonce: u64) -> (Bytes, BytesN<64>) {
+        use p256::ecdsa::signature::hazmat::PrehashSigner;
+
+        let message_hash: BytesN<32> = calculate_contract_message_hash(e, operation, contract, TEST_MESSAGE, recipient, nonce, 0).into();
+        let signature: p256::ecdsa::Signature = self.signing_key.sign_prehash(&message_hash.to_array()).unwrap();
+        (Bytes::from_slice(e, TEST_MESSAGE), BytesN::from_array(e, &signature.to_bytes().into()))
+    }
+}
+
 #[test]
 fn test_print_message_hash_for_signing() {
     let e = Env::default();
@@ -421,6 +478,20 @@ fn test_print_message_hash_for_signing() {
 }
 
 fn create_client<'a>(e: &Env, admin: &Address) -> StellarMerchShopClient<'a> {
+    create_client_with_max_tokens(e, admin, 10_000)
+}
+
+fn create_client_with_max_tokens<'a>(e: &Env, admin: &Address, max_tokens: u64) -> StellarMerchShopClient<'a> {
+    create_client_with_options(e, admin, max_tokens, false, false)
+}
+
+fn create_client_with_options<'a>(
+    e: &Env,
+    admin: &Address,
+    max_tokens: u64,
+    activity_events: bool,
+    onchain_metadata: bool,
+) -> StellarMerchShopClient<'a> {
     let address = e.register(
         StellarMerchShop,
         (
@@ -428,12 +499,31 @@ fn create_client<'a>(e: &Env, admin: &Address) -> StellarMerchShopClient<'a> {
             &String::from_str(e, "TestNFT"),
             &String::from_str(e, "TNFT"),
             &String::from_str(e, "ipfs://abcd"),
-            &10_000u64, // max_tokens
+            &max_tokens,
+            &activity_events,
+            &onchain_metadata,
         ),
     );
     StellarMerchShopClient::new(e, &address)
 }
 
+// Helper returning the message, signature, recovery ID and public key of a test signature
+fn signed_arguments(e: &Env, sig: &TestSignature) -> (Bytes, BytesN<64>, u32, BytesN<65>) {
+    let message_hash = calculate_message_hash(e, sig.message, sig.nonce);
+    let (signature, recovery_id) = create_test_signature_and_recovery_id(e, &message_hash, sig);
+    let message = Bytes::from_slice(e, sig.message);
+    let public_key = BytesN::from_array(e, &sig.public_key);
+    (message, signature, recovery_id, public_key)
+}
+
+// Helper to mint a token with nonce 1 and claim it with nonce 2
+fn mint_and_claim(e: &Env, client: &StellarMerchShopClient, chip: &SoftwareChip, claimant: &Address) -> u64 {
+    let token_id = chip.mint(e, client, 1);
+    chip.claim(e, client, claimant, 2);
+
+    token_id
+}
+
 #[test]
 fn test_metadata() {
     let e = Env::default();
@@ -449,6 +539,24 @@ fn test_metadata() {
     assert_eq!(symbol, String::from_str(&e, "TNFT"));
 }
 
+#[test]
+fn test_build_info() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let build_info = client.build_info();
+    assert_eq!(build_info.version, String::from_str(&e, env!("CARGO_PKG_VERSION")));
+    assert_eq!(build_info.profile, String::from_str(&e, crate::contract::BUILD_PROFILE));
+    assert_eq!(client.version(), crate::contract::CONTRACT_VERSION);
+
+    // Refreshing after an upgrade keeps the live code's metadata
+    client.migrate();
+    assert_eq!(client.build_info(), build_info);
+}
+
 
 #[test]
 fn test_claim() {
@@ -459,14 +567,14 @@ fn test_claim() {
     let claimant = Address::generate(&e);
     let client = create_client(&e, &admin);
 
-    // Chip 1, nonce 1 (mint)
+    // Chip 1, nonce 1 (mint): the hardware vectors predate the contract domain
     let mint_sig = &TEST_SIGNATURES[0];
-    let mint_message_hash = calculate_message_hash(&e, mint_sig.message, mint_sig.nonce);
-    let (mint_signature, mint_recovery_id) = create_test_signature_and_recovery_id(&e, &mint_message_hash, mint_sig);
-    let message = Bytes::from_slice(&e, mint_sig.message);
-    let public_key = BytesN::from_array(&e, &mint_sig.public_key);
+    let (message, signature, recovery_id, public_key) = signed_arguments(&e, mint_sig);
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &u64::from(mint_sig.nonce), &0, &None, &None);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
 
-    let token_id = client.mint(&message, &mint_signature, &mint_recovery_id, &public_key, &mint_sig.nonce);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
     assert_eq!(token_id, 0u64);
 
     // Verify token is unclaimed after mint
@@ -475,14 +583,7 @@ fn test_claim() {
     }));
     assert!(owner_result.is_err(), "Token should be unclaimed after mint");
 
-    // Chip 1, nonce 2 (claim)
-    let claim_sig = &TEST_SIGNATURES[1];
-    let claim_message_hash = calculate_message_hash(&e, claim_sig.message, claim_sig.nonce);
-    let (claim_signature, claim_recovery_id) = create_test_signature_and_recovery_id(&e, &claim_message_hash, claim_sig);
-    let message = Bytes::from_slice(&e, claim_sig.message);
-
-    // Claim the token
-    let claimed_token_id = client.claim(&claimant, &message, &claim_signature, &claim_recovery_id, &public_key, &claim_sig.nonce);
+    let claimed_token_id = chip.claim(&e, &client, &claimant, 2);
     assert_eq!(claimed_token_id, token_id, "Claim should return the same token ID");
 
     // Verify ownership was transferred
@@ -493,188 +594,4633 @@ fn test_claim() {
     let claimant_balance = client.balance(&claimant);
     assert_eq!(claimant_balance, 1u32, "Claimant should have balance of 1 after claiming");
 
-    let token_uri = client.token_uri(&0);
+    let token_uri = client.token_uri(&token_id);
     assert_eq!(token_uri, String::from_str(&e, "ipfs://abcd/0"));
 }
 
 #[test]
-#[should_panic]
-fn test_nonce_reuse_prevention() {
+fn test_claim_event() {
     let e = Env::default();
     e.mock_all_auths();
 
     let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
     let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+    chip.claim(&e, &client, &claimant, 2);
 
-    // Chip 1, nonce 1
-    let sig = &TEST_SIGNATURES[0];
-    let message_hash = calculate_message_hash(&e, sig.message, sig.nonce);
-    let (signature, recovery_id) = create_test_signature_and_recovery_id(&e, &message_hash, sig);
-    let message = Bytes::from_slice(&e, sig.message);
-    let public_key = BytesN::from_array(&e, &sig.public_key);
+    // Indexers filter claims by claimant, by chip, by merchant or by SKU, and follow the chip nonce
+    let nonce_topics: soroban_sdk::Vec<soroban_sdk::Val> = (Symbol::new(&e, "nonce_used"), chip.public_key(&e)).into_val(&e);
+    let nonce_data = soroban_sdk::Map::<Symbol, soroban_sdk::Val>::from_array(&e, [(Symbol::new(&e, "nonce"), 2u64.into_val(&e))]);
+    let topics: soroban_sdk::Vec<soroban_sdk::Val> = (Symbol::new(&e, "claim"), claimant.clone(), chip.public_key(&e), 0u32, None::<u32>).into_val(&e);
+    let data = soroban_sdk::Map::<Symbol, soroban_sdk::Val>::from_array(&e, [(Symbol::new(&e, "token_id"), token_id.into_val(&e))]);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        soroban_sdk::vec![
+            &e,
+            (client.address.clone(), nonce_topics, nonce_data.into_val(&e)),
+            (client.address.clone(), topics, data.into_val(&e)),
+        ]
+    );
+}
 
-    // First mint should succeed
-    let _token_id = client.mint(&message, &signature, &recovery_id, &public_key, &sig.nonce);
+#[test]
+fn test_event_lifecycle() {
+    let e = Env::default();
+    e.mock_all_auths();
 
-    // Second mint with same nonce should panic (nonce reuse prevention)
-    client.mint(&message, &signature, &recovery_id, &public_key, &sig.nonce);
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+    let base_uri = String::from_str(&e, "ipfs://efgh");
+    let placeholder = String::from_str(&e, "ipfs://placeholder");
+
+    // Every state change is reported, so an indexer never has to read storage back
+    let mut emitted = std::vec::Vec::new();
+    let mut record = || emitted.extend_from_slice(e.events().all().filter_by_contract(&client.address).events());
+
+    client.transfer_admin(&new_admin);
+    record();
+    client.accept_admin();
+    record();
+    client.set_base_uri(&base_uri);
+    record();
+    client.set_unclaimed_uri(&placeholder);
+    record();
+    let token_id = chip.mint(&e, &client, 1);
+    record();
+    chip.claim(&e, &client, &alice, 2);
+    record();
+    let (message, signature, recovery_id, _) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    record();
+    client.revoke_chip(&public_key);
+    record();
+    client.burn_from_owner(&bob, &token_id);
+    record();
+
+    assert_eq!(
+        emitted,
+        [
+            events::AdminProposed { admin: admin.clone(), pending_admin: new_admin.clone() }.to_xdr(&e, &client.address),
+            events::AdminChanged { previous_admin: admin.clone(), admin: new_admin.clone() }.to_xdr(&e, &client.address),
+            events::BaseUriUpdated { series_id: 0, base_uri }.to_xdr(&e, &client.address),
+            events::UnclaimedUriUpdated { uri: placeholder }.to_xdr(&e, &client.address),
+            events::NonceUsed { public_key: public_key.clone(), nonce: 1 }.to_xdr(&e, &client.address),
+            events::Mint { token_id, public_key: public_key.clone(), merchant_id: 0, sku: None, to: None, po_number: None }.to_xdr(&e, &client.address),
+            events::NonceUsed { public_key: public_key.clone(), nonce: 2 }.to_xdr(&e, &client.address),
+            events::MetadataUpdate { token_id }.to_xdr(&e, &client.address),
+            events::Claim { claimant: alice.clone(), public_key: public_key.clone(), merchant_id: 0, sku: None, token_id }.to_xdr(&e, &client.address),
+            events::NonceUsed { public_key: public_key.clone(), nonce: 3 }.to_xdr(&e, &client.address),
+            events::Transfer { from: alice.clone(), to: bob.clone(), merchant_id: 0, sku: None, token_id }.to_xdr(&e, &client.address),
+            events::ChipRevoked { public_key: public_key.clone(), revoked: true }.to_xdr(&e, &client.address),
+            events::Burn { from: bob.clone(), token_id }.to_xdr(&e, &client.address),
+        ]
+    );
 }
 
 #[test]
-fn test_u64_to_decimal_bytes() {
+fn test_claim_bound_to_claimant() {
     let e = Env::default();
+    e.mock_all_auths();
 
-    let test_cases: &[(u64, &str)] = &[
-        (0, "0"),
-        (1, "1"),
-        (9, "9"),
-        (10, "10"),
-        (99, "99"),
-        (100, "100"),
-        (999, "999"),
-        (1000, "1000"),
-        (9999, "9999"),
-        (10000, "10000"),
-        (12345, "12345"),
-        (99999, "99999"),
-        (100000, "100000"),
-        (999999, "999999"),
-    ];
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let attacker = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
 
-    for (value, expected_str) in test_cases.iter() {
-        let result = crate::contract::u64_to_decimal_bytes(&e, *value);
-        assert_eq!(result, Bytes::from_slice(&e, expected_str.as_bytes()));
-    }
+    // The attacker replays the pending claim with their own address
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &claimant, 2);
+    let result = client.try_claim(&attacker, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+    let result = client.try_claim(&attacker, &message, &signature, &None, &public_key, &2, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+
+    assert_eq!(client.claim(&claimant, &message, &signature, &Some(recovery_id), &public_key, &2, &0), token_id);
+    assert_eq!(client.owner_of(&token_id), claimant);
+
+    // Same for a transfer
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &claimant, 3);
+    let result = client.try_chip_transfer(&claimant, &attacker, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+
+    // The hash clients sign matches the contract's
+    let expected = calculate_contract_message_hash(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, Some(&claimant), 2, 0);
+    let message = Bytes::from_slice(&e, TEST_MESSAGE);
+    assert_eq!(client.build_message_hash(&ChipOperation::Claim, &message, &Some(claimant.clone()), &2, &0), BytesN::from(expected));
+    let expected = calculate_contract_message_hash(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, None, 1, 0);
+    assert_eq!(client.build_message_hash(&ChipOperation::Mint, &message, &None, &1, &0), BytesN::from(expected));
 }
 
 #[test]
-fn test_transfer() {
+fn test_signature_bound_to_operation() {
     let e = Env::default();
     e.mock_all_auths();
 
     let admin = Address::generate(&e);
-    let claimant = Address::generate(&e);
-    let recipient = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
     let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &alice);
 
-    // Chip 1, nonce 1 (mint)
-    let mint_sig = &TEST_SIGNATURES[0];
-    let mint_message_hash = calculate_message_hash(&e, mint_sig.message, mint_sig.nonce);
-    let (mint_signature, mint_recovery_id) = create_test_signature_and_recovery_id(&e, &mint_message_hash, mint_sig);
-    let message = Bytes::from_slice(&e, mint_sig.message);
-    let public_key = BytesN::from_array(&e, &mint_sig.public_key);
-    let token_id = client.mint(&message, &mint_signature, &mint_recovery_id, &public_key, &mint_sig.nonce);
-    assert_eq!(token_id, 0u64);
+    // A signature collected for a claim cannot move the token
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &bob, 3);
+    let result = client.try_chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
 
-    // Chip 1, nonce 2 (claim)
-    let claim_sig = &TEST_SIGNATURES[1];
-    let claim_message_hash = calculate_message_hash(&e, claim_sig.message, claim_sig.nonce);
-    let (claim_signature, claim_recovery_id) = create_test_signature_and_recovery_id(&e, &claim_message_hash, claim_sig);
-    let message = Bytes::from_slice(&e, claim_sig.message);
-    let claimed_token_id = client.claim(&claimant, &message, &claim_signature, &claim_recovery_id, &public_key, &claim_sig.nonce);
-    assert_eq!(claimed_token_id, token_id);
+    // Nor can a transfer signature burn it
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &alice, 3);
+    let result = client.try_burn(&alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+    assert_eq!(client.get_nonce(&public_key), 2);
 
-    // Verify initial ownership and balance
-    let owner = client.owner_of(&token_id);
-    assert_eq!(owner, claimant);
-    let claimant_balance_before = client.balance(&claimant);
-    assert_eq!(claimant_balance_before, 1u32);
-    let recipient_balance_before = client.balance(&recipient);
-    assert_eq!(recipient_balance_before, 0u32);
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(client.owner_of(&token_id), bob);
 
-    // Chip 1, nonce 3 (transfer)
-    let transfer_sig = &TEST_SIGNATURES[2];
-    let transfer_message_hash = calculate_message_hash(&e, transfer_sig.message, transfer_sig.nonce);
-    let (transfer_signature, transfer_recovery_id) = create_test_signature_and_recovery_id(&e, &transfer_message_hash, transfer_sig);
-    let message = Bytes::from_slice(&e, transfer_sig.message);
-    client.transfer(&claimant, &recipient, &token_id, &message, &transfer_signature, &transfer_recovery_id, &public_key, &transfer_sig.nonce);
+    // Every operation signs a distinct hash for the same message and nonce
+    let message = Bytes::from_slice(&e, TEST_MESSAGE);
+    let hash = |operation| client.build_message_hash(&operation, &message, &Some(bob.clone()), &4, &0);
+    assert_ne!(hash(ChipOperation::Claim), hash(ChipOperation::Transfer));
+    assert_ne!(hash(ChipOperation::Transfer), hash(ChipOperation::Burn));
+    assert_ne!(hash(ChipOperation::Burn), hash(ChipOperation::Redeem));
+}
 
-    // Verify ownership changed
-    let new_owner = client.owner_of(&token_id);
-    assert_eq!(new_owner, recipient, "Token should be owned by recipient after transfer");
+#[test]
+fn test_mint_and_claim_entry_point() {
+    let e = Env::default();
+    e.mock_all_auths();
 
-    // Verify balances updated
-    let claimant_balance_after = client.balance(&claimant);
-    assert_eq!(claimant_balance_after, 0u32, "Claimant balance should be 0 after transfer");
-    let recipient_balance_after = client.balance(&recipient);
-    assert_eq!(recipient_balance_after, 1u32, "Recipient balance should be 1 after transfer");
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client_with_max_tokens(&e, &admin, 2);
+    let chips = [SoftwareChip::new(1), SoftwareChip::new(2), SoftwareChip::new(3)];
+
+    // One signature bound to the buyer registers and assigns the token
+    let (message, signature, recovery_id, public_key) = chips[0].sign_for(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, &buyer, 1);
+    let token_id = client.mint_and_claim(&buyer, &message, &signature, &Some(recovery_id), &public_key, &1, &0);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [
+            events::NonceUsed { public_key: public_key.clone(), nonce: 1 }.to_xdr(&e, &client.address),
+            events::Mint { token_id, public_key: public_key.clone(), merchant_id: 0, sku: None, to: Some(buyer.clone()), po_number: None }.to_xdr(&e, &client.address),
+            events::Claim { claimant: buyer.clone(), public_key: public_key.clone(), merchant_id: 0, sku: None, token_id }.to_xdr(&e, &client.address),
+        ]
+    );
+    assert!(e.auths().iter().any(|(address, _)| *address == admin));
+    assert!(e.auths().iter().any(|(address, _)| *address == buyer));
+    assert_eq!(client.owner_of(&token_id), buyer);
+    assert_eq!(client.balance(&buyer), 1);
+    assert_eq!(client.token_id(&public_key), token_id);
+    assert_eq!(client.get_nonce(&public_key), 1);
+
+    // The chip was already registered
+    let (message, signature, recovery_id, public_key) = chips[0].sign_for(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, &buyer, 2);
+    let result = client.try_mint_and_claim(&buyer, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenAlreadyMinted.into())));
+
+    // MaxTokens still applies
+    chips[1].mint(&e, &client, 1);
+    let (message, signature, recovery_id, public_key) = chips[2].sign_for(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, &buyer, 1);
+    let result = client.try_mint_and_claim(&buyer, &message, &signature, &Some(recovery_id), &public_key, &1, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenIDsAreDepleted.into())));
 }
 
 #[test]
-fn test_multiple_chips_and_nfts() {
+fn test_register_chips() {
     let e = Env::default();
     e.mock_all_auths();
 
     let admin = Address::generate(&e);
-    let claimant1 = Address::generate(&e);
-    let claimant2 = Address::generate(&e);
+    let buyer = Address::generate(&e);
     let client = create_client(&e, &admin);
+    let chips: std::vec::Vec<SoftwareChip> = (1..=8).map(SoftwareChip::new).collect();
+    let mut public_keys: std::vec::Vec<BytesN<65>> = chips.iter().map(|chip| chip.public_key(&e)).collect();
 
-    // Chip 1: Mint NFT 1 (nonce 1) and claim it (nonce 2)
-    let mint1_sig = &TEST_SIGNATURES[0];
-    let mint1_message_hash = calculate_message_hash(&e, mint1_sig.message, mint1_sig.nonce);
-    let (mint1_signature, mint1_recovery_id) = create_test_signature_and_recovery_id(&e, &mint1_message_hash, mint1_sig);
-    let message = Bytes::from_slice(&e, mint1_sig.message);
-    let public_key_1 = BytesN::from_array(&e, &mint1_sig.public_key);
-    let token_id_1 = client.mint(&message, &mint1_signature, &mint1_recovery_id, &public_key_1, &mint1_sig.nonce);
-    assert_eq!(token_id_1, 0u64);
+    // Batches are kept within the ledger entries a transaction can write
+    let result = client.try_register_chips(&0, &soroban_sdk::Vec::from_slice(&e, &public_keys));
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
 
-    let claim1_sig = &TEST_SIGNATURES[1];
-    let claim1_message_hash = calculate_message_hash(&e, claim1_sig.message, claim1_sig.nonce);
-    let (claim1_signature, claim1_recovery_id) = create_test_signature_and_recovery_id(&e, &claim1_message_hash, claim1_sig);
-    let message = Bytes::from_slice(&e, claim1_sig.message);
-    let claimed_token_id_1 = client.claim(&claimant1, &message, &claim1_signature, &claim1_recovery_id, &public_key_1, &claim1_sig.nonce);
-    assert_eq!(claimed_token_id_1, token_id_1);
+    // A duplicate in the batch rolls back the whole call
+    public_keys[3] = public_keys[2].clone();
+    let result = client.try_register_chips(&0, &soroban_sdk::Vec::from_slice(&e, &public_keys[..4]));
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenAlreadyMinted.into())));
+    assert_eq!(client.minted_count(), 0);
+    assert_eq!(client.token_id_opt(&public_keys[0]), None);
 
-    // Chip 2: Mint NFT 2 (nonce 3) and claim it (nonce 4)
-    let mint2_sig = &TEST_SIGNATURES[3];
-    let mint2_message_hash = calculate_message_hash(&e, mint2_sig.message, mint2_sig.nonce);
-    let (mint2_signature, mint2_recovery_id) = create_test_signature_and_recovery_id(&e, &mint2_message_hash, mint2_sig);
-    let message = Bytes::from_slice(&e, mint2_sig.message);
-    let public_key_2 = BytesN::from_array(&e, &mint2_sig.public_key);
-    let token_id_2 = client.mint(&message, &mint2_signature, &mint2_recovery_id, &public_key_2, &mint2_sig.nonce);
-    assert_eq!(token_id_2, 1u64, "Second token should have ID 1");
+    public_keys[3] = chips[3].public_key(&e);
+    let token_ids = client.register_chips(&0, &soroban_sdk::Vec::from_slice(&e, &public_keys[..4]));
+    assert_eq!(token_ids, soroban_sdk::Vec::from_slice(&e, &[0u64, 1, 2, 3]));
+    let mint_events: std::vec::Vec<_> = (0..4u64)
+        .map(|token_id| {
+            let public_key = public_keys[token_id as usize].clone();
+            events::Mint { token_id, public_key, merchant_id: 0, sku: None, to: None, po_number: None }.to_xdr(&e, &client.address)
+        })
+        .collect();
+    assert_eq!(e.events().all().filter_by_contract(&client.address), mint_events.as_slice());
+    assert_eq!(client.token_id(&public_keys[3]), 3);
+    assert_eq!(client.get_nonce(&public_keys[3]), 0);
 
-    let claim2_sig = &TEST_SIGNATURES[4];
-    let claim2_message_hash = calculate_message_hash(&e, claim2_sig.message, claim2_sig.nonce);
-    let (claim2_signature, claim2_recovery_id) = create_test_signature_and_recovery_id(&e, &claim2_message_hash, claim2_sig);
-    let message = Bytes::from_slice(&e, claim2_sig.message);
-    let claimed_token_id_2 = client.claim(&claimant2, &message, &claim2_signature, &claim2_recovery_id, &public_key_2, &claim2_sig.nonce);
-    assert_eq!(claimed_token_id_2, token_id_2);
+    // Registered chips are claimed as if they were minted
+    chips[3].claim(&e, &client, &buyer, 1);
+    assert_eq!(client.owner_of(&3), buyer);
 
-    // Verify both NFTs exist independently
-    let owner1 = client.owner_of(&token_id_1);
-    assert_eq!(owner1, claimant1, "NFT 1 should be owned by claimant1");
-    
-    let owner2 = client.owner_of(&token_id_2);
-    assert_eq!(owner2, claimant2, "NFT 2 should be owned by claimant2");
+    // Eight chips take two full batches, the test host enforces the network limits
+    let token_ids = client.register_chips(&0, &soroban_sdk::Vec::from_slice(&e, &public_keys[4..]));
+    assert_eq!(token_ids, soroban_sdk::Vec::from_slice(&e, &[4u64, 5, 6, 7]));
+    assert_eq!(client.minted_count(), 8);
 
-    // Verify both public keys are stored correctly
-    let stored_public_key_1 = client.public_key(&token_id_1);
-    assert_eq!(stored_public_key_1, public_key_1, "NFT 1 should have Chip 1's public key");
-    
-    let stored_public_key_2 = client.public_key(&token_id_2);
-    assert_eq!(stored_public_key_2, public_key_2, "NFT 2 should have Chip 2's public key");
+    let result = client.try_register_chips(&0, &soroban_sdk::vec![&e, public_keys[0].clone()]);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenAlreadyMinted.into())));
 
-    // Verify token IDs are mapped correctly
-    let stored_token_id_1 = client.token_id(&public_key_1);
-    assert_eq!(stored_token_id_1, token_id_1, "Chip 1's public key should map to token ID 1");
-    
-    let stored_token_id_2 = client.token_id(&public_key_2);
-    assert_eq!(stored_token_id_2, token_id_2, "Chip 2's public key should map to token ID 2");
+    // MaxTokens still applies
+    let client = create_client_with_max_tokens(&e, &admin, 1);
+    let result = client.try_register_chips(&0, &soroban_sdk::vec![&e, public_keys[0].clone(), public_keys[1].clone()]);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenIDsAreDepleted.into())));
+}
 
-    // Verify balances are tracked separately
-    let balance1 = client.balance(&claimant1);
-    assert_eq!(balance1, 1u32, "Claimant1 should have balance of 1");
-    
-    let balance2 = client.balance(&claimant2);
-    assert_eq!(balance2, 1u32, "Claimant2 should have balance of 1");
+#[test]
+fn test_invalid_public_key() {
+    let e = Env::default();
+    e.mock_all_auths();
 
-    // Verify token URIs are different
-    let uri1 = client.token_uri(&token_id_1);
-    let uri2 = client.token_uri(&token_id_2);
-    assert_eq!(uri1, String::from_str(&e, "ipfs://abcd/0"));
-    assert_eq!(uri2, String::from_str(&e, "ipfs://abcd/1"));
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+
+    // A compressed SEC1 prefix and an all-zero key fail before any signature check
+    let mut compressed_key = chip.public_key(&e).to_array();
+    compressed_key[0] = 0x02;
+    let invalid = errors::NonFungibleTokenError::InvalidPublicKey;
+    for public_key in [BytesN::from_array(&e, &compressed_key), BytesN::from_array(&e, &[0u8; 65])] {
+        let (message, signature, recovery_id, _) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 2);
+        let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &2, &0, &None, &None);
+        assert_eq!(result, Err(Ok(invalid.into())));
+        assert!(!client.verify(&ChipOperation::Mint, &message, &signature, &Some(recovery_id), &public_key, &None, &2, &0));
+        let preview = client.simulate_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+        assert_eq!(preview.error_code, invalid as u32);
+
+        let (message, signature, recovery_id, _) = chip.sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &alice, 2);
+        let result = client.try_claim(&alice, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+        assert_eq!(result, Err(Ok(invalid.into())));
+        let result = client.try_chip_transfer(&bob, &alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+        assert_eq!(result, Err(Ok(invalid.into())));
+
+        // Registration paths have no signature to vouch for the key
+        let result = client.try_register_chips(&0, &soroban_sdk::vec![&e, public_key.clone()]);
+        assert_eq!(result, Err(Ok(invalid.into())));
+        assert_eq!(client.try_rebind_chip(&token_id, &public_key), Err(Ok(invalid.into())));
+        assert_eq!(client.try_add_allowed_chips(&soroban_sdk::vec![&e, public_key.clone()]), Err(Ok(invalid.into())));
+    }
+    assert_eq!(client.get_nonce(&chip.public_key(&e)), 1);
+    assert_eq!(client.minted_count(), 1);
+}
+
+#[test]
+fn test_chip_allowlist() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chips: std::vec::Vec<SoftwareChip> = (1..=4).map(SoftwareChip::new).collect();
+    let public_keys: std::vec::Vec<BytesN<65>> = chips.iter().map(|chip| chip.public_key(&e)).collect();
+    let mint = |chip: &SoftwareChip, nonce: u64| {
+        let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, nonce);
+        client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &nonce, &0, &None, &None)
+    };
+    let not_allowed = Err(Ok(errors::NonFungibleTokenError::ChipNotAllowed.into()));
+
+    client.add_allowed_chips(&soroban_sdk::vec![&e, public_keys[0].clone(), public_keys[1].clone()]);
+    assert!(client.is_chip_allowed(&public_keys[0]));
+    assert!(!client.is_chip_allowed(&public_keys[2]));
+
+    // Not enforced until enabled
+    assert!(!client.allowlist_enabled());
+    assert!(mint(&chips[2], 1).is_ok());
+
+    client.set_allowlist_enabled(&true);
+    assert_eq!(mint(&chips[3], 1), not_allowed);
+    assert!(mint(&chips[0], 1).is_ok());
+
+    let (message, signature, recovery_id, public_key) = chips[3].sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+    let preview = client.simulate_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0);
+    assert_eq!(preview.error_code, errors::NonFungibleTokenError::ChipNotAllowed as u32);
+
+    client.remove_allowed_chip(&public_keys[1]);
+    assert!(!client.is_chip_allowed(&public_keys[1]));
+    assert_eq!(mint(&chips[1], 1), not_allowed);
+
+    client.set_allowlist_enabled(&false);
+    assert!(mint(&chips[1], 1).is_ok());
+    assert!(mint(&chips[3], 1).is_ok());
 }
 
+#[test]
+fn test_registered_chips() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chips: std::vec::Vec<SoftwareChip> = (1..=7).map(SoftwareChip::new).collect();
+    let public_keys: std::vec::Vec<BytesN<65>> = chips.iter().map(|chip| chip.public_key(&e)).collect();
+    assert_eq!(client.registered_chip_count(), 0);
+
+    client.add_allowed_chips(&soroban_sdk::Vec::from_slice(&e, &public_keys));
+    assert_eq!(client.registered_chip_count(), 7);
+
+    // Minting an allowlisted chip does not register it twice
+    let token_id = chips[0].mint(&e, &client, 1);
+    assert_eq!(client.registered_chip_count(), 7);
+
+    // Minted and revoked chips stay registered once off the allowlist
+    client.revoke_chip(&public_keys[1]);
+    client.remove_allowed_chip(&public_keys[0]);
+    client.remove_allowed_chip(&public_keys[1]);
+    assert_eq!(client.registered_chip_count(), 7);
+    assert!(client.is_revoked(&public_keys[1]));
+
+    // An unused chip is unregistered, the last one takes its position
+    client.remove_allowed_chip(&public_keys[2]);
+    assert_eq!(client.registered_chip_count(), 6);
+    assert_eq!(
+        client.registered_chips(&0, &4),
+        soroban_sdk::vec![&e, public_keys[0].clone(), public_keys[1].clone(), public_keys[6].clone(), public_keys[3].clone()]
+    );
+    assert_eq!(client.registered_chips(&4, &4), soroban_sdk::vec![&e, public_keys[4].clone(), public_keys[5].clone()]);
+    assert_eq!(client.registered_chips(&6, &4), soroban_sdk::vec![&e]);
+    assert_eq!(client.registered_chips(&u32::MAX, &u32::MAX), soroban_sdk::vec![&e]);
+
+    // Burned tokens keep their chip registered, a rebound chip joins the registry
+    let new_chip = SoftwareChip::new(8);
+    client.rebind_chip(&token_id, &new_chip.public_key(&e));
+    assert_eq!(client.registered_chip_count(), 7);
+    assert_eq!(client.registered_chips(&6, &1), soroban_sdk::vec![&e, new_chip.public_key(&e)]);
+    client.set_claim_deadline(&1);
+    e.ledger().set_timestamp(2);
+    client.reclaim_unclaimed(&token_id);
+    assert!(!client.exists(&token_id));
+    assert_eq!(client.registered_chip_count(), 7);
+}
 
+#[test]
+fn test_claim_requires_claimant_auth() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &claimant, 2);
+
+    // A relayer submitting the claim without the claimant's signature
+    e.mock_auths(&[]);
+    let result = client.try_claim(&claimant, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+    let auth_error = soroban_sdk::Error::from_type_and_code(soroban_sdk::xdr::ScErrorType::Context, soroban_sdk::xdr::ScErrorCode::InvalidAction);
+    assert_eq!(result, Err(Ok(auth_error)));
+    assert_eq!(client.get_nonce(&public_key), 1);
+
+    let args: soroban_sdk::Vec<soroban_sdk::Val> = (claimant.clone(), message.clone(), signature.clone(), Some(recovery_id), public_key.clone(), 2u64, 0u32).into_val(&e);
+    e.mock_auths(&[MockAuth {
+        address: &claimant,
+        invoke: &MockAuthInvoke { contract: &client.address, fn_name: "claim", args: args.clone(), sub_invokes: &[] },
+    }]);
+    assert_eq!(client.claim(&claimant, &message, &signature, &Some(recovery_id), &public_key, &2, &0), token_id);
+    assert_eq!(
+        e.auths(),
+        [(claimant.clone(), AuthorizedInvocation {
+            function: AuthorizedFunction::Contract((client.address.clone(), Symbol::new(&e, "claim"), args)),
+            sub_invocations: std::vec![],
+        })]
+    );
+    assert_eq!(client.owner_of(&token_id), claimant);
+}
+
+#[test]
+fn test_transfer_requires_owner_auth() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &alice);
+
+    // Bob holds the item and a valid chip signature, but alice never agreed
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    e.mock_auths(&[]);
+    let result = client.try_chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    let auth_error = soroban_sdk::Error::from_type_and_code(soroban_sdk::xdr::ScErrorType::Context, soroban_sdk::xdr::ScErrorCode::InvalidAction);
+    assert_eq!(result, Err(Ok(auth_error)));
+    assert_eq!(client.owner_of(&token_id), alice);
+    assert_eq!(client.get_nonce(&public_key), 2);
+
+    e.mock_all_auths();
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(e.auths()[0].0, alice);
+    assert_eq!(client.owner_of(&token_id), bob);
+}
+
+#[test]
+fn test_message_domain() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client_a = create_client(&e, &admin);
+    let client_b = create_client(&e, &admin);
+    assert_eq!(client_a.message_domain(), client_a.address.clone().to_xdr(&e));
+    assert_ne!(client_a.message_domain(), client_b.message_domain());
+
+    // A signature harvested on deployment A does not verify on deployment B
+    let chip = SoftwareChip::new(1);
+    let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client_a.address, TEST_MESSAGE, 1);
+    let result = client_b.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+    assert_eq!(client_b.get_nonce(&public_key), 0);
+
+    client_a.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None);
+    assert_eq!(client_a.get_nonce(&public_key), 1);
+}
+
+#[test]
+fn test_signature_expiry() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+
+    // Valid up to and including the expiration ledger
+    let (message, signature, recovery_id, public_key) = chip.sign_until(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, None, 1, 110);
+    e.ledger().set_sequence_number(111);
+    let preview = client.simulate_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &110);
+    assert_eq!(preview.error_code, errors::NonFungibleTokenError::SignatureExpired as u32);
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &110, &None, &None);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureExpired.into())));
+    e.ledger().set_sequence_number(110);
+    let token_id = client.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &110, &None, &None);
+
+    // An expired claim does not consume the nonce
+    let (message, signature, recovery_id, public_key) = chip.sign_until(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, Some(&alice), 2, 120);
+    let expected = calculate_contract_message_hash(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, Some(&alice), 2, 120);
+    assert_eq!(client.build_message_hash(&ChipOperation::Claim, &message, &Some(alice.clone()), &2, &120), BytesN::from(expected));
+    e.ledger().set_sequence_number(121);
+    let result = client.try_claim(&alice, &message, &signature, &Some(recovery_id), &public_key, &2, &120);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureExpired.into())));
+    assert_eq!(client.get_nonce(&public_key), 1);
+
+    // The expiration ledger is signed, it cannot be dropped or extended
+    let result = client.try_claim(&alice, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+    let result = client.try_claim(&alice, &message, &signature, &Some(recovery_id), &public_key, &2, &200);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+
+    // 0 never expires
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &alice, 2);
+    e.ledger().set_sequence_number(1_000_000);
+    assert_eq!(client.claim(&alice, &message, &signature, &Some(recovery_id), &public_key, &2, &0), token_id);
+
+    let (message, signature, recovery_id, public_key) = chip.sign_until(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, Some(&bob), 3, 1_000_010);
+    e.ledger().set_sequence_number(1_000_005);
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &1_000_010);
+    assert_eq!(client.owner_of(&token_id), bob);
+}
+
+#[test]
+#[should_panic]
+fn test_nonce_reuse_prevention() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Chip 1, nonce 1
+    let (message, signature, recovery_id, public_key) = SoftwareChip::new(1).sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+
+    // First mint should succeed
+    let _token_id = client.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None);
+
+    // Second mint with same nonce should panic (nonce reuse prevention)
+    client.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None);
+}
+
+#[test]
+fn test_nonce_xdr_matches_host() {
+    let e = Env::default();
+
+    for nonce in [0u64, 1, 5, u32::MAX as u64, u64::MAX] {
+        assert_eq!(Bytes::from_array(&e, &merch_crypto::nonce_xdr(nonce)), nonce.to_xdr(&e));
+    }
+}
+
+#[test]
+fn test_message_hash_vector() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Canonical test message with nonce 0, before the domain is prepended
+    let message = Bytes::from_slice(&e, TEST_MESSAGE);
+    let expected = [
+        0x54, 0x18, 0xf7, 0x9f, 0x36, 0x0b, 0x27, 0x01, 0xe4, 0xae, 0x67, 0x3f, 0x73, 0x7a, 0x33, 0xf5,
+        0x3a, 0x5d, 0xa8, 0x47, 0xfc, 0xd4, 0xf7, 0x77, 0xc6, 0x6a, 0x46, 0xf1, 0x8d, 0x2e, 0x05, 0xb9,
+    ];
+    assert_eq!(BytesN::from(merch_crypto::soroban::chip_message_hash(&e, &message, 0)), BytesN::from_array(&e, &expected));
+
+    // build_message_hash is that hash over the domain separated message tagged with the operation
+    let mut domain_message = client.message_domain();
+    domain_message.push_back(ChipOperation::Mint as u8);
+    domain_message.append(&message);
+    assert_eq!(
+        client.build_message_hash(&ChipOperation::Mint, &message, &None, &0, &0),
+        BytesN::from(merch_crypto::soroban::chip_message_hash(&e, &domain_message, 0))
+    );
+}
+
+#[test]
+fn test_u64_to_decimal_bytes() {
+    let e = Env::default();
+
+    let test_cases: &[(u64, &str)] = &[
+        (0, "0"),
+        (1, "1"),
+        (7, "7"),
+        (9, "9"),
+        (10, "10"),
+        (42, "42"),
+        (99, "99"),
+        (100, "100"),
+        (999, "999"),
+        (1000, "1000"),
+        (9999, "9999"),
+        (10000, "10000"),
+        (12345, "12345"),
+        (99999, "99999"),
+        (100000, "100000"),
+        (999999, "999999"),
+        (u64::MAX, "18446744073709551615"),
+    ];
+
+    for (value, expected_str) in test_cases.iter() {
+        let result = crate::contract::u64_to_decimal_bytes(&e, *value);
+        assert_eq!(result, Bytes::from_slice(&e, expected_str.as_bytes()));
+    }
+}
+
+#[test]
+fn test_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Nonce 1 (mint) and nonce 2 (claim)
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &claimant);
+    assert_eq!(token_id, 0u64);
+
+    // Verify initial ownership and balance
+    let owner = client.owner_of(&token_id);
+    assert_eq!(owner, claimant);
+    let claimant_balance_before = client.balance(&claimant);
+    assert_eq!(claimant_balance_before, 1u32);
+    let recipient_balance_before = client.balance(&recipient);
+    assert_eq!(recipient_balance_before, 0u32);
+
+    // Nonce 3 (transfer)
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &recipient, 3);
+    client.chip_transfer(&claimant, &recipient, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+
+    // Verify ownership changed
+    let new_owner = client.owner_of(&token_id);
+    assert_eq!(new_owner, recipient, "Token should be owned by recipient after transfer");
+
+    // Verify balances updated
+    let claimant_balance_after = client.balance(&claimant);
+    assert_eq!(claimant_balance_after, 0u32, "Claimant balance should be 0 after transfer");
+    let recipient_balance_after = client.balance(&recipient);
+    assert_eq!(recipient_balance_after, 1u32, "Recipient balance should be 1 after transfer");
+}
+
+#[test]
+fn test_transfer_storage_footprint() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &claimant);
+
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &recipient, 3);
+    client.chip_transfer(&claimant, &recipient, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+
+    // Owner and public key share the Token entry, separate Owner and
+    // PublicKey entries took one more read for the same writes.
+    // The receiver hook reads whether the recipient is a contract, the chip
+    // curve picks the signature scheme, the merchant topic reads the token's
+    // series, the SKU topic reads the chip's SKU, the approval is cleared
+    // with its recipient restriction, composable tokens check their parent
+    // and children, provenance records the new owner
+    let composable = u32::from(cfg!(feature = "composable"));
+    let partner_mint = u32::from(cfg!(feature = "partner-mint"));
+    let provenance = u32::from(cfg!(feature = "provenance"));
+    let resources = e.cost_estimate().resources();
+    assert_eq!(resources.memory_read_entries, 21 + 2 * composable + partner_mint + provenance);
+    assert_eq!(resources.write_entries, 14 + provenance);
+
+    // Looking up the owner reads the instance and the Token entry, it used to take 3 entries
+    client.owner_of(&token_id);
+    assert_eq!(e.cost_estimate().resources().memory_read_entries, 2);
+}
+
+/// Standard non-fungible token interface as a generic wallet or marketplace sees it
+#[allow(dead_code)]
+#[contractclient(name = "StandardNftClient")]
+trait StandardNft {
+    fn balance(e: Env, owner: Address) -> u32;
+    fn owner_of(e: Env, token_id: u64) -> Address;
+    fn transfer(e: Env, from: Address, to: Address, token_id: u64);
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, token_id: u64);
+    fn approve(e: Env, approver: Address, approved: Address, token_id: u64, live_until_ledger: u32);
+    fn approve_for_all(e: Env, owner: Address, operator: Address, live_until_ledger: u32);
+    fn get_approved(e: Env, token_id: u64) -> Option<Address>;
+    fn is_approved_for_all(e: Env, owner: Address, operator: Address) -> bool;
+    fn name(e: Env) -> String;
+    fn symbol(e: Env) -> String;
+    fn token_uri(e: Env, token_id: u64) -> String;
+}
+
+#[test]
+fn test_standard_interface() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let nft = StandardNftClient::new(&e, &client.address);
+
+    // Tokens minted and claimed through the chip flow
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &alice);
+    let unclaimed_id = SoftwareChip::new(2).mint(&e, &client, 1);
+
+    assert_eq!(nft.name(), client.name());
+    assert_eq!(nft.symbol(), String::from_str(&e, "TNFT"));
+    assert_eq!(nft.token_uri(&token_id), String::from_str(&e, "ipfs://abcd/0"));
+    assert_eq!(nft.balance(&alice), 1);
+    assert_eq!(nft.owner_of(&token_id), alice);
+
+    // Only the owner authorizes a standard transfer, no chip signature
+    nft.transfer(&alice, &bob, &token_id);
+    assert_eq!(
+        e.auths(),
+        [(alice.clone(), AuthorizedInvocation {
+            function: AuthorizedFunction::Contract((client.address.clone(), Symbol::new(&e, "transfer"), (alice.clone(), bob.clone(), token_id).into_val(&e))),
+            sub_invocations: std::vec![],
+        })]
+    );
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address).events()[0],
+        events::Transfer { from: alice.clone(), to: bob.clone(), merchant_id: 0, sku: None, token_id }.to_xdr(&e, &client.address)
+    );
+    assert_eq!(nft.owner_of(&token_id), bob);
+    assert_eq!((nft.balance(&alice), nft.balance(&bob)), (0, 1));
+
+    let result = nft.try_transfer(&alice, &carol, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::IncorrectOwner.into())));
+    let result = nft.try_transfer(&alice, &carol, &unclaimed_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenNotClaimed.into())));
+
+    // Approvals work the same as through the chip contract interface
+    nft.approve(&bob, &carol, &token_id, &100);
+    assert_eq!(nft.get_approved(&token_id), Some(carol.clone()));
+    nft.transfer_from(&carol, &bob, &alice, &token_id);
+    assert_eq!(nft.owner_of(&token_id), alice);
+    assert_eq!(nft.get_approved(&token_id), None);
+
+    nft.approve_for_all(&alice, &carol, &100);
+    assert!(nft.is_approved_for_all(&alice, &carol));
+    nft.transfer_from(&carol, &alice, &bob, &token_id);
+    assert_eq!(nft.owner_of(&token_id), bob);
+
+    // The chip keeps working on the same storage
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &alice, 3);
+    client.chip_transfer(&bob, &alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(nft.owner_of(&token_id), alice);
+    assert_eq!((nft.balance(&alice), nft.balance(&bob)), (1, 0));
+}
+
+/// Contract recipient accepting every token and recording the last one
+#[soroban_sdk::contract]
+struct AcceptingReceiver;
+
+#[soroban_sdk::contractimpl]
+impl AcceptingReceiver {
+    pub fn on_nft_received(e: Env, operator: Address, from: Address, token_id: u64) -> bool {
+        e.storage().instance().set(&Symbol::new(&e, "received"), &(operator, from, token_id));
+        true
+    }
+
+    pub fn received(e: Env) -> Option<(Address, Address, u64)> {
+        e.storage().instance().get(&Symbol::new(&e, "received"))
+    }
+}
+
+/// Contract recipient refusing every token
+#[soroban_sdk::contract]
+struct RejectingReceiver;
+
+#[soroban_sdk::contractimpl]
+impl RejectingReceiver {
+    pub fn on_nft_received(_e: Env, _operator: Address, _from: Address, _token_id: u64) -> bool {
+        false
+    }
+}
+
+#[test]
+fn test_receiver_hook() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let accepting = AcceptingReceiverClient::new(&e, &e.register(AcceptingReceiver, ()));
+    let rejecting = e.register(RejectingReceiver, ());
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &alice);
+
+    // Contracts are asked unless they opt out
+    assert!(client.receiver_hook(&rejecting));
+
+    // A refusing contract keeps the token with its owner, whichever path moves it
+    let result = client.try_transfer(&alice, &rejecting, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ReceiverRejected.into())));
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &rejecting, 3);
+    let result = client.try_chip_transfer(&alice, &rejecting, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ReceiverRejected.into())));
+    let result = client.try_admin_transfer(&token_id, &rejecting, &1);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ReceiverRejected.into())));
+    assert_eq!(client.owner_of(&token_id), alice);
+    assert_eq!(client.balance(&rejecting), 0);
+
+    client.transfer(&alice, &accepting.address, &token_id);
+    assert_eq!(client.owner_of(&token_id), accepting.address);
+    assert_eq!(accepting.received(), Some((alice.clone(), alice.clone(), token_id)));
+
+    // Contracts without the hook refuse tokens until they opt out, then
+    // receive them like accounts
+    let other_shop = create_client(&e, &admin);
+    let result = client.try_transfer(&accepting.address, &other_shop.address, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ReceiverRejected.into())));
+    client.set_receiver_hook(&other_shop.address, &false);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::ReceiverHookUpdated { receiver: other_shop.address.clone(), enabled: false }.to_xdr(&e, &client.address)]
+    );
+    assert!(!client.receiver_hook(&other_shop.address));
+    client.transfer(&accepting.address, &other_shop.address, &token_id);
+    assert_eq!(client.owner_of(&token_id), other_shop.address);
+    client.transfer(&other_shop.address, &alice, &token_id);
+    client.set_receiver_hook(&other_shop.address, &true);
+    assert!(client.receiver_hook(&other_shop.address));
+    let result = client.try_transfer(&alice, &other_shop.address, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ReceiverRejected.into())));
+    assert_eq!(client.owner_of(&token_id), alice);
+
+    // The admin is the operator of a forced transfer
+    client.admin_transfer(&token_id, &accepting.address, &1);
+    assert_eq!(accepting.received(), Some((admin.clone(), alice.clone(), token_id)));
+
+    // A contract wallet authorizing its own claim is not asked
+    let claim_chip = SoftwareChip::new(2);
+    let claimed_id = claim_chip.mint(&e, &client, 1);
+    claim_chip.claim(&e, &client, &rejecting, 2);
+    assert_eq!(client.owner_of(&claimed_id), rejecting);
+
+    // Tokens claimed on behalf of a contract come from the collection itself
+    #[cfg(feature = "partner-mint")]
+    {
+        let program = LoyaltyProgramClient::new(&e, &e.register(LoyaltyProgram, ()));
+        let chips = [SoftwareChip::new(3), SoftwareChip::new(4)];
+        for chip in &chips {
+            client.set_chip_sku(&chip.public_key(&e), &7u32);
+        }
+        client.grant_mint_allowance(&program.address, &7u32, &2u64);
+
+        let (message, signature, recovery_id, public_key) = chips[0].sign_for(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, &rejecting, 1);
+        let result = program.try_complete_quest(&client.address, &rejecting, &7u32, &message, &signature, &Some(recovery_id), &public_key, &1);
+        assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ReceiverRejected.into())));
+
+        let (message, signature, recovery_id, public_key) = chips[1].sign_for(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, &accepting.address, 1);
+        let token_id = program.complete_quest(&client.address, &accepting.address, &7u32, &message, &signature, &Some(recovery_id), &public_key, &1);
+        assert_eq!(accepting.received(), Some((program.address.clone(), client.address.clone(), token_id)));
+    }
+}
+
+#[test]
+fn test_burn() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &alice);
+
+    // Only the owner can burn, with a signature bound to them
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Burn, &client.address, TEST_MESSAGE, &bob, 3);
+    let result = client.try_burn(&bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::IncorrectOwner.into())));
+    let result = client.try_burn(&alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Burn, &client.address, TEST_MESSAGE, &alice, 3);
+    client.burn(&alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [
+            events::NonceUsed { public_key: public_key.clone(), nonce: 3 }.to_xdr(&e, &client.address),
+            events::Burn { from: alice.clone(), token_id }.to_xdr(&e, &client.address),
+        ]
+    );
+
+    assert_eq!(client.try_owner_of(&token_id), Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+    assert_eq!(client.try_token_id(&public_key), Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+    assert_eq!(client.balance(&alice), 0);
+    assert_eq!(client.tokens_of(&alice, &0, &10).len(), 0);
+    #[cfg(feature = "provenance")]
+    assert_eq!(client.ownership_history(&token_id).len(), 0);
+
+    // The signature is spent and the token is gone
+    let result = client.try_burn(&alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Burn, &client.address, TEST_MESSAGE, &alice, 4);
+    let result = client.try_burn(&alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &4, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+
+    // The chip can be minted again as a new token, with a fresh nonce
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &3, &0, &None, &None);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+    let new_token_id = chip.mint(&e, &client, 4);
+    assert_eq!(new_token_id, token_id + 1);
+    assert_eq!(client.token_id(&public_key), new_token_id);
+}
+
+#[test]
+fn test_burn_from_owner() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &alice);
+
+    let result = client.try_burn_from_owner(&bob, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::IncorrectOwner.into())));
+    let result = client.try_burn_from_owner(&alice, &(token_id + 1));
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+
+    client.burn_from_owner(&alice, &token_id);
+    assert_eq!(e.auths()[0].0, alice);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::Burn { from: alice.clone(), token_id }.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.try_owner_of(&token_id), Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+    assert_eq!(client.balance(&alice), 0);
+    assert_eq!(client.tokens_of(&alice, &0, &10).len(), 0);
+
+    let result = client.try_burn_from_owner(&alice, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+
+    // The dead chip stays registered and cannot mint a fresh token
+    assert_eq!(client.token_id(&chip.public_key(&e)), token_id);
+    let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 3);
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &3, &0, &None, &None);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenAlreadyMinted.into())));
+}
+
+#[test]
+fn test_multiple_chips_and_nfts() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant1 = Address::generate(&e);
+    let claimant2 = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Chip 1: Mint NFT 1 (nonce 1) and claim it (nonce 2)
+    let chip1 = SoftwareChip::new(1);
+    let public_key_1 = chip1.public_key(&e);
+    let token_id_1 = chip1.mint(&e, &client, 1);
+    assert_eq!(token_id_1, 0u64);
+    let claimed_token_id_1 = chip1.claim(&e, &client, &claimant1, 2);
+    assert_eq!(claimed_token_id_1, token_id_1);
+
+    // Chip 2: Mint NFT 2 (nonce 3) and claim it (nonce 4)
+    let chip2 = SoftwareChip::new(2);
+    let public_key_2 = chip2.public_key(&e);
+    let token_id_2 = chip2.mint(&e, &client, 3);
+    assert_eq!(token_id_2, 1u64, "Second token should have ID 1");
+    let claimed_token_id_2 = chip2.claim(&e, &client, &claimant2, 4);
+    assert_eq!(claimed_token_id_2, token_id_2);
+
+    // Verify both NFTs exist independently
+    let owner1 = client.owner_of(&token_id_1);
+    assert_eq!(owner1, claimant1, "NFT 1 should be owned by claimant1");
+    
+    let owner2 = client.owner_of(&token_id_2);
+    assert_eq!(owner2, claimant2, "NFT 2 should be owned by claimant2");
+
+    // Verify both public keys are stored correctly
+    let stored_public_key_1 = client.public_key(&token_id_1);
+    assert_eq!(stored_public_key_1, public_key_1, "NFT 1 should have Chip 1's public key");
+    
+    let stored_public_key_2 = client.public_key(&token_id_2);
+    assert_eq!(stored_public_key_2, public_key_2, "NFT 2 should have Chip 2's public key");
+
+    // Verify token IDs are mapped correctly
+    let stored_token_id_1 = client.token_id(&public_key_1);
+    assert_eq!(stored_token_id_1, token_id_1, "Chip 1's public key should map to token ID 1");
+    
+    let stored_token_id_2 = client.token_id(&public_key_2);
+    assert_eq!(stored_token_id_2, token_id_2, "Chip 2's public key should map to token ID 2");
+
+    // Verify balances are tracked separately
+    let balance1 = client.balance(&claimant1);
+    assert_eq!(balance1, 1u32, "Claimant1 should have balance of 1");
+    
+    let balance2 = client.balance(&claimant2);
+    assert_eq!(balance2, 1u32, "Claimant2 should have balance of 1");
+
+    // Verify token URIs are different
+    let uri1 = client.token_uri(&token_id_1);
+    let uri2 = client.token_uri(&token_id_2);
+    assert_eq!(uri1, String::from_str(&e, "ipfs://abcd/0"));
+    assert_eq!(uri2, String::from_str(&e, "ipfs://abcd/1"));
+}
+
+#[test]
+fn test_admin_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let recovery = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Nonce 1 (mint) and nonce 2 (claim)
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &claimant);
+
+    // Support moves the token to the customer's new wallet
+    client.admin_transfer(&token_id, &recovery, &7u32);
+
+    let event = events::AdminTransfer {
+        from: claimant.clone(),
+        to: recovery.clone(),
+        token_id,
+        reason: 7,
+    };
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [event.to_xdr(&e, &client.address)]
+    );
+
+    assert_eq!(client.owner_of(&token_id), recovery);
+    assert_eq!(client.balance(&claimant), 0u32);
+    assert_eq!(client.balance(&recovery), 1u32);
+    assert_eq!(client.chip_stats(&chip.public_key(&e)), ChipStats { mints: 1, claims: 1, transfers: 1 });
+}
+
+#[test]
+fn test_admin_transfer_requires_reason() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let recovery = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let token_id = mint_and_claim(&e, &client, &SoftwareChip::new(1), &claimant);
+
+    let result = client.try_admin_transfer(&token_id, &recovery, &0u32);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidReasonCode.into())));
+    assert_eq!(client.owner_of(&token_id), claimant);
+}
+
+#[test]
+#[cfg(feature = "composable")]
+fn test_composable_tokens() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Chip 1 is the jacket, chip 2 the patch
+    let jacket = SoftwareChip::new(1);
+    let patch = SoftwareChip::new(2);
+    let parent_id = mint_and_claim(&e, &client, &jacket, &claimant);
+    let child_id = mint_and_claim(&e, &client, &patch, &claimant);
+
+    client.attach(&parent_id, &child_id);
+    assert_eq!(client.children_of(&parent_id), soroban_sdk::vec![&e, child_id]);
+    assert_eq!(client.parent_of(&child_id), Some(parent_id));
+
+    // The reverse attachment would nest tokens two levels deep
+    let result = client.try_attach(&child_id, &parent_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenAttached.into())));
+
+    // Chip 1, nonce 3: transferring the parent moves the patch along
+    let (message, signature, recovery_id, public_key) = jacket.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &recipient, 3);
+    client.chip_transfer(&claimant, &recipient, &parent_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+
+    assert_eq!(client.owner_of(&parent_id), recipient);
+    assert_eq!(client.owner_of(&child_id), recipient);
+    assert_eq!(client.balance(&claimant), 0u32);
+    assert_eq!(client.balance(&recipient), 2u32);
+
+    // Chip 1, nonce 4: the jacket cannot be burned with the patch attached
+    let (message, signature, recovery_id, public_key) = jacket.sign_for(&e, ChipOperation::Burn, &client.address, TEST_MESSAGE, &recipient, 4);
+    let result = client.try_burn(&recipient, &parent_id, &message, &signature, &Some(recovery_id), &public_key, &4, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenAttached.into())));
+
+    // Chip 2, nonce 3: the attached patch cannot move on its own
+    let (message, signature, recovery_id, public_key) = patch.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &claimant, 3);
+    let result = client.try_chip_transfer(&recipient, &claimant, &child_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenAttached.into())));
+
+    client.detach(&parent_id, &child_id);
+    assert_eq!(client.children_of(&parent_id).len(), 0);
+    assert_eq!(client.parent_of(&child_id), None);
+
+    // Once detached the patch moves independently of the jacket
+    client.chip_transfer(&recipient, &claimant, &child_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(client.owner_of(&child_id), claimant);
+    assert_eq!(client.owner_of(&parent_id), recipient);
+}
+
+#[test]
+#[cfg(feature = "composable")]
+fn test_composable_child_guards() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let jacket = SoftwareChip::new(1);
+    let patch = SoftwareChip::new(2);
+    let parent_id = mint_and_claim(&e, &client, &jacket, &alice);
+    let child_id = mint_and_claim(&e, &client, &patch, &alice);
+
+    // A soulbound token cannot ride along a transferable parent
+    client.set_soulbound(&child_id, &true);
+    let result = client.try_attach(&parent_id, &child_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenSoulbound.into())));
+
+    // Nor when it becomes soulbound once attached
+    client.set_soulbound(&child_id, &false);
+    client.attach(&parent_id, &child_id);
+    client.set_soulbound(&child_id, &true);
+    let result = client.try_transfer(&alice, &bob, &parent_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenSoulbound.into())));
+    let (message, signature, recovery_id, public_key) = jacket.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    let result = client.try_chip_transfer(&alice, &bob, &parent_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenSoulbound.into())));
+
+    // A dual-auth child needs a chip-signed transfer of the parent
+    client.set_soulbound(&child_id, &false);
+    client.set_security_level(&child_id, &SecurityLevel::Both);
+    let result = client.try_transfer(&alice, &bob, &parent_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::DualAuthRequired.into())));
+    client.chip_transfer(&alice, &bob, &parent_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(client.owner_of(&child_id), bob);
+}
+
+#[test]
+#[cfg(feature = "escrow")]
+fn test_escrow_confirm_receipt() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &seller);
+
+    // Nonce 3: hand over the item
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &buyer, 3);
+    e.ledger().set_sequence_number(100);
+    client.escrow_transfer(&seller, &buyer, &token_id, &50u32, &message, &signature, &Some(recovery_id), &public_key, &3);
+
+    // The contract holds the token while the escrow is open
+    assert_eq!(client.owner_of(&token_id), client.address);
+    assert_eq!(client.balance(&seller), 0u32);
+    assert_eq!(client.balance(&buyer), 0u32);
+    let escrow = client.escrow_of(&token_id).unwrap();
+    assert_eq!(escrow.expiration_ledger, 150u32);
+
+    // The seller cannot take the token back before the timeout
+    let result = client.try_reclaim_escrow(&seller, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::EscrowNotExpired.into())));
+
+    client.confirm_receipt(&buyer, &token_id);
+
+    assert_eq!(client.owner_of(&token_id), buyer);
+    assert_eq!(client.balance(&buyer), 1u32);
+    assert_eq!(client.balance(&client.address), 0u32);
+    assert_eq!(client.escrow_of(&token_id), None);
+
+    // Settled escrows cannot be reclaimed
+    e.ledger().set_sequence_number(200);
+    let result = client.try_reclaim_escrow(&seller, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::EscrowNotFound.into())));
+}
+
+#[test]
+#[cfg(feature = "escrow")]
+fn test_escrow_timeout_reclaim() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &seller);
+
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &buyer, 3);
+    e.ledger().set_sequence_number(100);
+    client.escrow_transfer(&seller, &buyer, &token_id, &50u32, &message, &signature, &Some(recovery_id), &public_key, &3);
+
+    e.ledger().set_sequence_number(149);
+    let result = client.try_reclaim_escrow(&seller, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::EscrowNotExpired.into())));
+
+    // After the timeout only the seller can settle the escrow
+    e.ledger().set_sequence_number(150);
+    let result = client.try_confirm_receipt(&buyer, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::EscrowExpired.into())));
+
+    client.reclaim_escrow(&seller, &token_id);
+
+    assert_eq!(client.owner_of(&token_id), seller);
+    assert_eq!(client.balance(&seller), 1u32);
+    assert_eq!(client.balance(&buyer), 0u32);
+    assert_eq!(client.balance(&client.address), 0u32);
+    assert_eq!(client.escrow_of(&token_id), None);
+}
+
+// Helper building a verify_batch item from a test signature, the challenge being message || nonce.to_xdr()
+fn verify_item(e: &Env, sig: &TestSignature) -> VerifyItem {
+    let (message, signature, recovery_id, public_key) = signed_arguments(e, sig);
+    let mut challenge = message;
+    challenge.append(&sig.nonce.to_xdr(e));
+    VerifyItem { message: challenge, signature, recovery_id: Some(recovery_id), public_key }
+}
+
+#[test]
+fn test_verify_batch() {
+    let e = Env::default();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Valid signatures from both chips
+    let chip1 = verify_item(&e, &TEST_SIGNATURES[0]);
+    let chip2 = verify_item(&e, &TEST_SIGNATURES[3]);
+
+    // Signature over a different challenge
+    let mut wrong_message = verify_item(&e, &TEST_SIGNATURES[0]);
+    wrong_message.message = verify_item(&e, &TEST_SIGNATURES[1]).message;
+
+    // Signature from chip 1 presented with chip 2's key
+    let mut wrong_key = verify_item(&e, &TEST_SIGNATURES[0]);
+    wrong_key.public_key = chip2.public_key.clone();
+
+    // Out of range recovery ID
+    let mut wrong_recovery_id = verify_item(&e, &TEST_SIGNATURES[0]);
+    wrong_recovery_id.recovery_id = Some(4);
+
+    // High S form, rejected by the host
+    let mut high_s = verify_item(&e, &TEST_SIGNATURES[0]);
+    let mut sig_bytes = [0u8; 64];
+    sig_bytes[..32].copy_from_slice(&TEST_SIGNATURES[0].sig_r);
+    sig_bytes[32..].copy_from_slice(&[0xff; 32]);
+    high_s.signature = BytesN::from_array(&e, &sig_bytes);
+
+    let items = soroban_sdk::vec![&e, chip1, wrong_message, chip2, wrong_key, wrong_recovery_id, high_s];
+    assert_eq!(
+        client.verify_batch(&items),
+        soroban_sdk::vec![&e, true, false, true, false, false, false]
+    );
+
+    // Nothing was consumed
+    assert_eq!(client.get_nonce(&BytesN::from_array(&e, &CHIP1_PUBLIC_KEY)), 0u64);
+}
+
+#[test]
+fn test_verify_batch_size_limit() {
+    let e = Env::default();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let mut items = soroban_sdk::Vec::new(&e);
+    for _ in 0..=crate::contract::MAX_VERIFY_BATCH {
+        items.push_back(verify_item(&e, &TEST_SIGNATURES[0]));
+    }
+
+    let result = client.try_verify_batch(&items);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+}
+
+#[test]
+fn test_verify() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+
+    // Accepted without consuming the nonce, so the mint with it still goes through
+    let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+    assert!(client.verify(&ChipOperation::Mint, &message, &signature, &Some(recovery_id), &public_key, &None, &1, &0));
+    assert!(client.verify(&ChipOperation::Mint, &message, &signature, &None, &public_key, &None, &1, &0));
+    assert_eq!(client.get_nonce(&public_key), 0);
+    assert_eq!(client.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None), 0);
+
+    // Every rejection is a plain false, never an error
+    assert!(!client.verify(&ChipOperation::Mint, &message, &signature, &Some(recovery_id), &public_key, &None, &1, &0));
+    let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 2);
+    assert!(!client.verify(&ChipOperation::Mint, &message, &signature, &Some(recovery_id), &public_key, &None, &3, &0));
+    assert!(!client.verify(&ChipOperation::Mint, &message, &signature, &Some(7), &public_key, &None, &2, &0));
+    assert!(!client.verify(&ChipOperation::Mint, &Bytes::new(&e), &signature, &Some(recovery_id), &public_key, &None, &2, &0));
+    assert!(!client.verify(&ChipOperation::Mint, &message, &BytesN::from_array(&e, &[0u8; 64]), &None, &public_key, &None, &2, &0));
+    // R off the curve, which the host traps on
+    let mut off_curve = [0u8; 64];
+    off_curve[31] = 5;
+    off_curve[63] = 1;
+    assert!(!client.verify(&ChipOperation::Mint, &message, &BytesN::from_array(&e, &off_curve), &None, &public_key, &None, &2, &0));
+    assert!(!client.verify(&ChipOperation::Mint, &message, &signature, &Some(2), &public_key, &None, &2, &0));
+    assert!(!client.verify(&ChipOperation::Mint, &message, &signature, &Some(recovery_id), &SoftwareChip::new(2).public_key(&e), &None, &2, &0));
+    assert!(client.verify(&ChipOperation::Mint, &message, &signature, &Some(recovery_id), &public_key, &None, &2, &0));
+
+    client.revoke_chip(&public_key);
+    assert!(!client.verify(&ChipOperation::Mint, &message, &signature, &Some(recovery_id), &public_key, &None, &2, &0));
+    assert_eq!(client.get_nonce(&public_key), 1);
+
+    // The operation, recipient and expiry are bound like in the entry points
+    let alice = Address::generate(&e);
+    let (message, signature, recovery_id, public_key) = SoftwareChip::new(2).sign_until(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, Some(&alice), 1, 100);
+    let verify = |operation: ChipOperation, recipient: Option<Address>, expiration_ledger: u32| {
+        client.verify(&operation, &message, &signature, &Some(recovery_id), &public_key, &recipient, &1, &expiration_ledger)
+    };
+    assert!(verify(ChipOperation::Claim, Some(alice.clone()), 100));
+    assert!(!verify(ChipOperation::Transfer, Some(alice.clone()), 100));
+    assert!(!verify(ChipOperation::Claim, None, 100));
+    assert!(!verify(ChipOperation::Claim, Some(admin.clone()), 100));
+    assert!(!verify(ChipOperation::Claim, Some(alice.clone()), 0));
+    e.ledger().set_sequence_number(101);
+    assert!(!verify(ChipOperation::Claim, Some(alice.clone()), 100));
+}
+
+#[test]
+fn test_p256_chip() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Known vector, only valid once the key is registered as P-256
+    let vector = VerifyItem {
+        message: Bytes::from_slice(&e, b"sample"),
+        signature: BytesN::from_array(&e, &P256_VECTOR_SIGNATURE),
+        recovery_id: None,
+        public_key: BytesN::from_array(&e, &P256_VECTOR_PUBLIC_KEY),
+    };
+    assert_eq!(client.chip_curve(&vector.public_key), CurveType::Secp256k1);
+    assert_eq!(client.verify_batch(&soroban_sdk::vec![&e, vector.clone()]), soroban_sdk::vec![&e, false]);
+    client.set_chip_curve(&vector.public_key, &CurveType::Secp256r1);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::ChipCurveUpdated { public_key: vector.public_key.clone(), curve: CurveType::Secp256r1 }.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.chip_curve(&vector.public_key), CurveType::Secp256r1);
+    // Read-only checks refuse P-256 chips rather than let the host abort on a mismatch
+    let mut mismatch = vector.clone();
+    mismatch.message = Bytes::from_slice(&e, b"other");
+    assert_eq!(client.verify_batch(&soroban_sdk::vec![&e, vector, mismatch]), soroban_sdk::vec![&e, false, false]);
+
+    // Full lifecycle of a P-256 chip, the recovery ID is ignored
+    let chip = P256Chip::new(1);
+    let public_key = chip.public_key(&e);
+    let (message, signature) = chip.sign(&e, ChipOperation::Mint, &client.address, None, 1);
+    let result = client.try_mint(&0, &message, &signature, &None, &public_key, &1, &0, &None, &None);
+    assert!(result.is_err());
+    client.set_chip_curve(&public_key, &CurveType::Secp256r1);
+    assert!(!client.verify(&ChipOperation::Mint, &message, &signature, &None, &public_key, &None, &1, &0));
+    assert!(!client.verify(&ChipOperation::Mint, &message, &signature, &None, &public_key, &None, &2, &0));
+    let preview = client.simulate_mint(&0, &message, &signature, &None, &public_key, &1, &0);
+    assert_eq!(preview.error_code, errors::NonFungibleTokenError::UnverifiableCurve as u32);
+    let token_id = client.mint(&0, &message, &signature, &Some(3), &public_key, &1, &0, &None, &None);
+    let verification = client.verify_product(&message, &signature, &None, &public_key);
+    assert!(!verification.is_genuine);
+
+    let (message, signature) = chip.sign(&e, ChipOperation::Claim, &client.address, Some(&alice), 2);
+    client.claim(&alice, &message, &signature, &None, &public_key, &2, &0);
+    let (message, signature) = chip.sign(&e, ChipOperation::Transfer, &client.address, Some(&bob), 3);
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &None, &public_key, &3, &0);
+    assert_eq!(client.owner_of(&token_id), bob);
+    assert_eq!(client.get_nonce(&public_key), 3);
+
+    // Signed for another recipient
+    let (message, signature) = chip.sign(&e, ChipOperation::Transfer, &client.address, Some(&alice), 4);
+    let result = client.try_chip_transfer(&bob, &alice, &token_id, &message, &signature, &None, &BytesN::from_array(&e, &[0u8; 65]), &4, &0);
+    assert!(result.is_err());
+    let result = client.try_chip_transfer(&bob, &admin, &token_id, &message, &signature, &None, &public_key, &4, &0);
+    assert!(result.is_err());
+    assert_eq!(client.get_nonce(&public_key), 3);
+
+    // A secp256k1 chip registered as P-256 cannot sign anymore
+    let k1_chip = SoftwareChip::new(2);
+    client.set_chip_curve(&k1_chip.public_key(&e), &CurveType::Secp256r1);
+    let (message, signature, recovery_id, k1_public_key) = k1_chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &k1_public_key, &1, &0, &None, &None);
+    assert!(result.is_err());
+    client.set_chip_curve(&k1_public_key, &CurveType::Secp256k1);
+    assert_eq!(client.mint(&0, &message, &signature, &Some(recovery_id), &k1_public_key, &1, &0, &None, &None), 1);
+}
+
+#[test]
+fn test_ed25519_chip() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = Ed25519Chip::new(1);
+    let chip_key = ChipKey::Ed25519(chip.public_key(&e));
+
+    let (message, signature) = chip.sign(&e, ChipOperation::Mint, &client.address, None, 1);
+    let token_id = client.mint_ed25519(&0, &message, &signature, &chip.public_key(&e), &1, &0, &None, &None);
+
+    // Recorded under the padded key, which the other chip functions take
+    let public_key = client.chip_public_key(&chip_key);
+    assert_eq!(public_key.to_array()[..33], [0u8; 33]);
+    assert_eq!(public_key.to_array()[33..], chip.public_key(&e).to_array());
+    assert_eq!(client.chip_key(&token_id), chip_key);
+    assert_eq!(client.public_key(&token_id), public_key);
+    assert_eq!(client.token_id(&public_key), token_id);
+    assert_eq!(client.get_nonce(&public_key), 1);
+
+    let (message, signature) = chip.sign(&e, ChipOperation::Claim, &client.address, Some(&alice), 2);
+    assert_eq!(client.claim_ed25519(&alice, &message, &signature, &chip.public_key(&e), &2, &0), token_id);
+    let result = client.try_claim_ed25519(&alice, &message, &signature, &chip.public_key(&e), &2, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+
+    let (message, signature) = chip.sign(&e, ChipOperation::Transfer, &client.address, Some(&bob), 3);
+    client.chip_transfer_ed25519(&alice, &bob, &token_id, &message, &signature, &chip.public_key(&e), &3, &0);
+    assert_eq!(client.owner_of(&token_id), bob);
+    assert_eq!(client.get_nonce(&public_key), 3);
+
+    // Signed for another recipient, the host rejects the signature
+    let (message, signature) = chip.sign(&e, ChipOperation::Transfer, &client.address, Some(&alice), 4);
+    assert!(client.try_chip_transfer_ed25519(&bob, &admin, &token_id, &message, &signature, &chip.public_key(&e), &4, &0).is_err());
+
+    // A valid signature of another chip does not move the token
+    let other_chip = Ed25519Chip::new(2);
+    let (message, signature) = other_chip.sign(&e, ChipOperation::Transfer, &client.address, Some(&alice), 1);
+    let result = client.try_chip_transfer_ed25519(&bob, &alice, &token_id, &message, &signature, &other_chip.public_key(&e), &1, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidSignature.into())));
+
+    // Revocation goes through the padded key too
+    client.revoke_chip(&public_key);
+    let (message, signature) = chip.sign(&e, ChipOperation::Transfer, &client.address, Some(&alice), 4);
+    let result = client.try_chip_transfer_ed25519(&bob, &alice, &token_id, &message, &signature, &chip.public_key(&e), &4, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ChipRevoked.into())));
+
+    // ECDSA chips keep their key as is
+    let ecdsa_chip = SoftwareChip::new(1);
+    let ecdsa_token_id = ecdsa_chip.mint(&e, &client, 1);
+    assert_eq!(client.chip_key(&ecdsa_token_id), ChipKey::Ecdsa(ecdsa_chip.public_key(&e)));
+    assert_eq!(client.chip_public_key(&ChipKey::Ecdsa(ecdsa_chip.public_key(&e))), ecdsa_chip.public_key(&e));
+}
+
+#[test]
+fn test_simulate_mint() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client_with_max_tokens(&e, &admin, 1);
+
+    // Chip 1, nonce 1: success is predicted without consuming the nonce
+    let chip = SoftwareChip::new(1);
+    let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+    let preview = client.simulate_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0);
+    assert_eq!(preview, MintPreview { would_succeed: true, error_code: 0, predicted_token_id: 0 });
+    assert_eq!(client.get_nonce(&public_key), 0u64);
+
+    let token_id = client.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None);
+    assert_eq!(token_id, preview.predicted_token_id);
+
+    // Nonce reuse
+    let preview = client.simulate_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0);
+    assert!(!preview.would_succeed);
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None);
+    assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(preview.error_code))));
+    assert_eq!(preview.error_code, errors::NonFungibleTokenError::NonceTooLow as u32);
+
+    // Signature over another message
+    let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 2);
+    let wrong_message = Bytes::from_slice(&e, b"another message");
+    let preview = client.simulate_mint(&0, &wrong_message, &signature, &Some(recovery_id), &public_key, &2, &0);
+    let result = client.try_mint(&0, &wrong_message, &signature, &Some(recovery_id), &public_key, &2, &0, &None, &None);
+    assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(preview.error_code))));
+    assert_eq!(preview.error_code, errors::NonFungibleTokenError::SignatureRecoveryMismatch as u32);
+
+    // Chip 1, nonce 2: chip already minted
+    let preview = client.simulate_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &2, &0, &None, &None);
+    assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(preview.error_code))));
+    assert_eq!(preview.error_code, errors::NonFungibleTokenError::TokenAlreadyMinted as u32);
+
+    // Chip 2, nonce 3: supply exhausted
+    let (message, signature, recovery_id, public_key) = SoftwareChip::new(2).sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 3);
+    let preview = client.simulate_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &3, &0, &None, &None);
+    assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(preview.error_code))));
+    assert_eq!(preview.error_code, errors::NonFungibleTokenError::TokenIDsAreDepleted as u32);
+    assert_eq!(client.get_nonce(&public_key), 0u64);
+}
+
+#[test]
+fn test_software_chip() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+    assert_eq!(chip.claim(&e, &client, &claimant, 2), token_id);
+    assert_eq!(client.public_key(&token_id), chip.public_key(&e));
+    assert_eq!(client.owner_of(&token_id), claimant);
+}
+
+#[test]
+#[cfg(feature = "provenance")]
+fn test_recent_claims() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    assert_eq!(client.recent_claims().len(), 0);
+
+    let mut claims = Vec::new();
+    for i in 0..25u32 {
+        e.ledger().set_sequence_number(100 + i);
+        let claimant = Address::generate(&e);
+        let chip = SoftwareChip::new(i);
+        let token_id = chip.mint(&e, &client, 1);
+        chip.claim(&e, &client, &claimant, 2);
+        claims.push(RecentClaim { token_id, ledger: 100 + i, claimant });
+    }
+
+    // Only the newest 20 claims are kept, newest first
+    let recent = client.recent_claims();
+    assert_eq!(recent.len(), crate::recent_claims::RECENT_CLAIMS_CAPACITY);
+    for (i, claim) in recent.iter().enumerate() {
+        assert_eq!(claim, claims[24 - i]);
+    }
+}
+
+#[test]
+fn test_redeem() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+
+    // Only claimed tokens can be redeemed
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Redeem, &client.address, TEST_MESSAGE, &alice, 2);
+    let result = client.try_redeem(&token_id, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenNotClaimed.into())));
+
+    chip.claim(&e, &client, &alice, 2);
+    assert!(!client.is_redeemed(&token_id));
+    assert_eq!(client.redeemed_at(&token_id), None);
+
+    e.ledger().set_timestamp(1_717_200_000);
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Redeem, &client.address, TEST_MESSAGE, &alice, 3);
+    client.redeem(&token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert!(e.auths().iter().any(|(address, _)| *address == alice));
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [
+            events::NonceUsed { public_key: public_key.clone(), nonce: 3 }.to_xdr(&e, &client.address),
+            events::Redeem { token_id, owner: alice.clone(), timestamp: 1_717_200_000 }.to_xdr(&e, &client.address),
+        ]
+    );
+    assert!(client.is_redeemed(&token_id));
+    assert_eq!(client.redeemed_at(&token_id), Some(1_717_200_000));
+
+    // A second tap with a fresh nonce is refused
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Redeem, &client.address, TEST_MESSAGE, &alice, 4);
+    let result = client.try_redeem(&token_id, &message, &signature, &Some(recovery_id), &public_key, &4, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::AlreadyRedeemed.into())));
+    assert_eq!(client.get_nonce(&public_key), 3);
+}
+
+#[test]
+#[cfg(feature = "warranty")]
+fn test_warranty_claim_approval() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let token_id = mint_and_claim(&e, &client, &SoftwareChip::new(1), &owner);
+    let issue_hash = BytesN::from_array(&e, &[7u8; 32]);
+
+    client.open_warranty_claim(&token_id, &issue_hash);
+    let claim = client.warranty_claim(&token_id).unwrap();
+    assert_eq!(claim.status, WarrantyStatus::Opened);
+    assert_eq!(claim.owner, owner);
+    assert_eq!(claim.issue_hash, issue_hash);
+
+    // Fulfilment requires an approval first
+    let result = client.try_fulfill_warranty_claim(&token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidStatusTransition.into())));
+
+    client.resolve_warranty_claim(&token_id, &true);
+    assert_eq!(client.warranty_claim(&token_id).unwrap().status, WarrantyStatus::Approved);
+
+    // An approved claim is still in progress
+    let result = client.try_open_warranty_claim(&token_id, &issue_hash);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidStatusTransition.into())));
+
+    client.fulfill_warranty_claim(&token_id);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::WarrantyClaimFulfilled { token_id }.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.warranty_claim(&token_id).unwrap().status, WarrantyStatus::Fulfilled);
+
+    // A new issue can be reported once the previous claim is closed
+    client.open_warranty_claim(&token_id, &issue_hash);
+    assert_eq!(client.warranty_claim(&token_id).unwrap().status, WarrantyStatus::Opened);
+}
+
+#[test]
+#[cfg(feature = "warranty")]
+fn test_warranty_claim_rejection() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let token_id = mint_and_claim(&e, &client, &SoftwareChip::new(1), &owner);
+    let issue_hash = BytesN::from_array(&e, &[7u8; 32]);
+
+    // Nothing to resolve yet
+    let result = client.try_resolve_warranty_claim(&token_id, &false);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidStatusTransition.into())));
+
+    client.open_warranty_claim(&token_id, &issue_hash);
+
+    // One open claim per token
+    let result = client.try_open_warranty_claim(&token_id, &issue_hash);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidStatusTransition.into())));
+
+    client.resolve_warranty_claim(&token_id, &false);
+    assert_eq!(client.warranty_claim(&token_id).unwrap().status, WarrantyStatus::Rejected);
+
+    let result = client.try_fulfill_warranty_claim(&token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidStatusTransition.into())));
+    let result = client.try_resolve_warranty_claim(&token_id, &true);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidStatusTransition.into())));
+}
+
+#[test]
+fn test_transfer_cooldown() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+
+    assert_eq!(client.transfer_cooldown(), 0u32);
+    client.set_transfer_cooldown(&10u32);
+    assert_eq!(client.transfer_cooldown(), 10u32);
+
+    e.ledger().set_sequence_number(100);
+    chip.mint(&e, &client, 1);
+    let token_id = chip.claim(&e, &client, &alice, 2);
+
+    // The claim starts the cooldown
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    let result = client.try_chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3u64, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TransferCooldownActive.into())));
+
+    e.ledger().set_sequence_number(110);
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3u64, &0);
+    assert_eq!(client.owner_of(&token_id), bob);
+
+    // Transferring straight back is blocked until the cooldown elapses again
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &alice, 4);
+    let result = client.try_chip_transfer(&bob, &alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &4u64, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TransferCooldownActive.into())));
+
+    // Admin transfers are exempt
+    client.admin_transfer(&token_id, &alice, &1u32);
+    assert_eq!(client.owner_of(&token_id), alice);
+
+    // A cooldown of 0 disables the check
+    client.set_transfer_cooldown(&0u32);
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 4);
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &4u64, &0);
+    assert_eq!(client.owner_of(&token_id), bob);
+}
+
+#[test]
+#[cfg(feature = "reservations")]
+fn test_reservation() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let vip = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chips = [SoftwareChip::new(1), SoftwareChip::new(2), SoftwareChip::new(3)];
+    for chip in chips.iter() {
+        chip.mint(&e, &client, 1);
+    }
+
+    e.ledger().set_sequence_number(100);
+    for token_id in 0..3u64 {
+        client.reserve_for(&token_id, &vip, &200u32);
+    }
+    assert_eq!(client.reservation_of(&0), Some((vip.clone(), 200u32)));
+
+    // Only the reserved claimant can claim
+    let (message, signature, recovery_id, public_key) = chips[0].sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &stranger, 2);
+    let result = client.try_claim(&stranger, &message, &signature, &Some(recovery_id), &public_key, &2u64, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ReservedForAnother.into())));
+    let token_id = chips[0].claim(&e, &client, &vip, 2);
+    assert_eq!(client.owner_of(&token_id), vip);
+    assert_eq!(client.reservation_of(&token_id), None);
+
+    // Claimed tokens cannot be reserved
+    let result = client.try_reserve_for(&token_id, &stranger, &200u32);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenAlreadyMinted.into())));
+
+    // Releasing the reservation opens the token to anyone
+    client.release_reservation(&1);
+    assert_eq!(client.reservation_of(&1), None);
+    let token_id = chips[1].claim(&e, &client, &stranger, 2);
+    assert_eq!(client.owner_of(&token_id), stranger);
+
+    // Expired reservations no longer apply
+    e.ledger().set_sequence_number(200);
+    assert_eq!(client.reservation_of(&2), None);
+    let token_id = chips[2].claim(&e, &client, &stranger, 2);
+    assert_eq!(client.owner_of(&token_id), stranger);
+}
+
+#[test]
+#[cfg(feature = "claim-codes")]
+fn test_claim_codes() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let customer = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let other_chip = SoftwareChip::new(2);
+
+    let token_id = chip.mint(&e, &client, 1);
+    let other_token_id = other_chip.mint(&e, &client, 1);
+
+    e.ledger().set_sequence_number(100);
+    let code = Bytes::from_slice(&e, b"order-1234-secret");
+    let code_hash: BytesN<32> = e.crypto().sha256(&code).into();
+    client.issue_claim_code(&token_id, &code_hash, &200u32);
+    client.issue_claim_code(&other_token_id, &code_hash, &150u32);
+
+    let wrong_code = Bytes::from_slice(&e, b"order-1234-guess");
+    let result = client.try_claim_with_code(&customer, &token_id, &wrong_code);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidClaimCode.into())));
+
+    assert_eq!(client.claim_with_code(&customer, &token_id, &code), token_id);
+    assert_eq!(client.owner_of(&token_id), customer);
+    assert_eq!(client.balance(&customer), 1u32);
+
+    let result = client.try_claim_with_code(&customer, &token_id, &code);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ClaimCodeRedeemed.into())));
+
+    // The chip still has to be present for later transfers
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &buyer, 2);
+    client.chip_transfer(&customer, &buyer, &token_id, &message, &signature, &Some(recovery_id), &public_key, &2u64, &0);
+    assert_eq!(client.owner_of(&token_id), buyer);
+
+    e.ledger().set_sequence_number(150);
+    let result = client.try_claim_with_code(&customer, &other_token_id, &code);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ClaimCodeExpired.into())));
+}
+
+#[test]
+#[cfg(feature = "reservations")]
+fn test_delegate_claim() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let company = Address::generate(&e);
+    let employee = Address::generate(&e);
+    let colleague = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+
+    let token_id = chip.mint(&e, &client, 1);
+    client.reserve_for(&token_id, &company, &1_000u32);
+
+    // Only the reservation holder can delegate
+    let result = client.try_delegate_claim(&colleague, &token_id, &colleague);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::IncorrectOwner.into())));
+
+    client.delegate_claim(&company, &token_id, &colleague);
+    client.delegate_claim(&company, &token_id, &employee);
+    assert_eq!(client.claim_delegate_of(&token_id), Some(employee.clone()));
+
+    // The delegate replaces the reservation holder as the only valid claimant
+    for claimant in [&colleague, &company] {
+        let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, claimant, 2);
+        let result = client.try_claim(claimant, &message, &signature, &Some(recovery_id), &public_key, &2u64, &0);
+        assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ReservedForAnother.into())));
+    }
+
+    chip.claim(&e, &client, &employee, 2);
+    assert_eq!(client.owner_of(&token_id), employee);
+    assert_eq!(client.claim_delegate_of(&token_id), None);
+}
+
+#[test]
+fn test_chip_info() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let mut batch_7 = Vec::new();
+    for seed in 1..=4u32 {
+        let chip = SoftwareChip::new(seed);
+        let info = ChipInfo {
+            model: Symbol::new(&e, "SECORA"),
+            firmware: 2,
+            batch: if seed % 2 == 0 { 7 } else { 8 },
+        };
+        let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+        let token_id = client.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1u64, &0, &Some(info.clone()), &None);
+        assert_eq!(client.chip_info(&public_key), Some(info.clone()));
+        if info.batch == 7 {
+            batch_7.push(token_id);
+        }
+    }
+
+    // Chips minted without information are not indexed
+    SoftwareChip::new(5).mint(&e, &client, 1);
+    assert_eq!(client.chip_info(&SoftwareChip::new(5).public_key(&e)), None);
+
+    assert_eq!(client.tokens_by_batch(&7, &0, &10), soroban_sdk::Vec::from_slice(&e, &batch_7));
+    assert_eq!(client.tokens_by_batch(&7, &1, &10), soroban_sdk::Vec::from_slice(&e, &batch_7[1..]));
+    assert_eq!(client.tokens_by_batch(&7, &0, &1), soroban_sdk::Vec::from_slice(&e, &batch_7[..1]));
+    assert_eq!(client.tokens_by_batch(&9, &0, &10), soroban_sdk::Vec::<u64>::new(&e));
+
+    let public_key = SoftwareChip::new(2).public_key(&e);
+    client.update_chip_firmware(&public_key, &3);
+
+    let event = events::ChipFirmwareUpdated { public_key: public_key.clone(), previous_firmware: 2, firmware: 3 };
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [event.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.chip_info(&public_key).unwrap().firmware, 3);
+}
+
+#[test]
+fn test_verify_product() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let unregistered_chip = SoftwareChip::new(2);
+
+    let token_id = chip.mint(&e, &client, 1);
+    let challenge = Bytes::from_slice(&e, b"store scan 2024-06-01 #42");
+    let message_hash: BytesN<32> = e.crypto().sha256(&challenge).into();
+    let sign = |chip: &SoftwareChip| {
+        let (signature, recovery_id) = chip
+            .signing_key
+            .sign_prehash_recoverable(&message_hash.to_array())
+            .unwrap();
+        (BytesN::<64>::from_array(&e, &signature.to_bytes().into()), recovery_id.to_byte() as u32)
+    };
+
+    // Genuine but not claimed yet
+    let (signature, recovery_id) = sign(&chip);
+    let verification = client.verify_product(&challenge, &signature, &Some(recovery_id), &chip.public_key(&e));
+    assert_eq!(
+        verification,
+        ProductVerification { is_genuine: true, token_id: Some(token_id), owner: None, revoked: false }
+    );
+
+    chip.claim(&e, &client, &owner, 2);
+    let verification = client.verify_product(&challenge, &signature, &Some(recovery_id), &chip.public_key(&e));
+    assert_eq!(verification.owner, Some(owner.clone()));
+
+    // A revoked chip is still reported, flagged as revoked
+    client.revoke_chip(&chip.public_key(&e));
+    let verification = client.verify_product(&challenge, &signature, &Some(recovery_id), &chip.public_key(&e));
+    assert_eq!(
+        verification,
+        ProductVerification { is_genuine: true, token_id: Some(token_id), owner: Some(owner), revoked: true }
+    );
+
+    // Verifying does not consume the chip nonce
+    assert_eq!(client.get_nonce(&chip.public_key(&e)), 2u64);
+
+    // A valid signature from a chip this contract does not know
+    let (signature, recovery_id) = sign(&unregistered_chip);
+    let verification = client.verify_product(&challenge, &signature, &Some(recovery_id), &unregistered_chip.public_key(&e));
+    assert_eq!(
+        verification,
+        ProductVerification { is_genuine: false, token_id: None, owner: None, revoked: false }
+    );
+
+    // A registered key does not vouch for a signature made by another chip
+    let verification = client.verify_product(&challenge, &signature, &Some(recovery_id), &chip.public_key(&e));
+    assert!(!verification.is_genuine);
+}
+
+#[test]
+#[cfg(feature = "provenance")]
+fn test_ownership_history() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let owners: Vec<Address> = (0..11).map(|_| Address::generate(&e)).collect();
+
+    chip.mint(&e, &client, 1);
+    e.ledger().set_sequence_number(1_000);
+    let token_id = chip.claim(&e, &client, &owners[0], 2);
+    assert_eq!(client.ownership_history(&token_id), soroban_sdk::vec![&e, (owners[0].clone(), 1_000u32)]);
+
+    for i in 1..owners.len() {
+        e.ledger().set_sequence_number(1_000 + i as u32);
+        let nonce = i as u64 + 2;
+        let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &owners[i], nonce);
+        client.chip_transfer(&owners[i - 1], &owners[i], &token_id, &message, &signature, &Some(recovery_id), &public_key, &nonce, &0);
+    }
+
+    // Only the 8 most recent owners remain, newest first
+    let history = client.ownership_history(&token_id);
+    assert_eq!(history.len(), 8);
+    for (i, (owner, ledger)) in history.iter().enumerate() {
+        let expected = owners.len() - 1 - i;
+        assert_eq!(owner, owners[expected]);
+        assert_eq!(ledger, 1_000 + expected as u32);
+    }
+}
+
+#[test]
+fn test_token_ttl() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+
+    e.ledger().set_sequence_number(100);
+    chip.mint(&e, &client, 1);
+    let token_id = chip.claim(&e, &client, &owner, 2);
+
+    let entry_ttl = || {
+        e.as_contract(&client.address, || {
+            e.storage().persistent().get_ttl(&crate::contract::NFTStorageKey::Token(token_id))
+        })
+    };
+
+    // Minting gives the token a known lifetime
+    let live_until = client.token_ttl(&token_id);
+    assert_eq!(live_until, 100 + crate::token_ttl::MINT_TTL_LEDGERS);
+    assert_eq!(100 + entry_ttl(), live_until);
+
+    e.ledger().set_sequence_number(10_000);
+    client.extend_token_ttl(&token_id, &1_000_000);
+    assert_eq!(client.token_ttl(&token_id), 1_010_000);
+    assert_eq!(10_000 + entry_ttl(), 1_010_000);
+
+    // A shorter extension never brings the live-until ledger closer
+    client.extend_token_ttl(&token_id, &1_000);
+    assert_eq!(client.token_ttl(&token_id), 1_010_000);
+}
+
+#[test]
+fn test_storage_ttl_extension() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+    let token_id = mint_and_claim(&e, &client, &chip, &owner);
+
+    let ttl = |key: &crate::contract::NFTStorageKey| e.as_contract(&client.address, || e.storage().persistent().get_ttl(key));
+    let token_key = crate::contract::NFTStorageKey::Token(token_id);
+    let balance_key = crate::contract::NFTStorageKey::Balance(owner.clone());
+    let nonce_key = crate::contract::NFTStorageKey::ChipNonceByPublicKey(public_key.clone());
+
+    // Entries written by a claim start with a full lifetime
+    assert_eq!(ttl(&token_key), crate::token_ttl::TTL_EXTEND_LEDGERS);
+    assert_eq!(ttl(&balance_key), crate::token_ttl::TTL_EXTEND_LEDGERS);
+    assert_eq!(ttl(&nonce_key), crate::token_ttl::TTL_EXTEND_LEDGERS);
+
+    // Reads only extend entries close to archival
+    e.ledger().set_sequence_number(100_000);
+    client.owner_of(&token_id);
+    assert_eq!(ttl(&token_key), crate::token_ttl::TTL_EXTEND_LEDGERS - 100_000);
+
+    e.ledger().set_sequence_number(450_000);
+    assert_eq!(client.owner_of(&token_id), owner);
+    assert_eq!(client.balance(&owner), 1);
+    assert_eq!(client.get_nonce(&public_key), 2);
+    assert_eq!(ttl(&token_key), crate::token_ttl::TTL_EXTEND_LEDGERS);
+    assert_eq!(ttl(&balance_key), crate::token_ttl::TTL_EXTEND_LEDGERS);
+    assert_eq!(ttl(&nonce_key), crate::token_ttl::TTL_EXTEND_LEDGERS);
+
+    // Anyone can refresh a token, a chip or the instance explicitly
+    e.ledger().set_sequence_number(500_000);
+    client.bump_token(&token_id);
+    assert_eq!(ttl(&token_key), crate::token_ttl::TTL_EXTEND_LEDGERS);
+    assert_eq!(ttl(&balance_key), crate::token_ttl::TTL_EXTEND_LEDGERS);
+
+    e.ledger().set_sequence_number(510_000);
+    client.bump_chip(&public_key);
+    assert_eq!(ttl(&crate::contract::NFTStorageKey::TokenIdByPublicKey(public_key.clone())), crate::token_ttl::TTL_EXTEND_LEDGERS);
+    assert_eq!(ttl(&nonce_key), crate::token_ttl::TTL_EXTEND_LEDGERS);
+
+    client.extend_instance_ttl();
+    let instance_ttl = e.as_contract(&client.address, || e.storage().instance().get_ttl());
+    assert_eq!(instance_ttl, crate::token_ttl::TTL_EXTEND_LEDGERS);
+}
+
+#[test]
+fn test_double_initialization() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let attacker = Address::generate(&e);
+    let client = create_client_with_max_tokens(&e, &admin, 5);
+    let chip = SoftwareChip::new(1);
+    chip.mint(&e, &client, 1);
+
+    // A redeploy script re-running setup against the live contract
+    let result = e.as_contract(&client.address, || {
+        crate::contract::initialize(
+            &e,
+            &attacker,
+            &String::from_str(&e, "Other"),
+            &String::from_str(&e, "OTH"),
+            &String::from_str(&e, "ipfs://other"),
+            1_000,
+            false,
+            false,
+        )
+    });
+    assert_eq!(result, Err(errors::NonFungibleTokenError::AlreadyInitialized));
+
+    assert_eq!(client.name(), String::from_str(&e, "TestNFT"));
+    e.as_contract(&client.address, || {
+        use crate::contract::DataKey;
+        let instance = e.storage().instance();
+        assert_eq!(instance.get::<_, Address>(&DataKey::Admin), Some(admin.clone()));
+        assert_eq!(instance.get::<_, u64>(&DataKey::NextTokenId), Some(1));
+    });
+    assert_eq!(client.max_tokens(), 5);
+}
+
+#[test]
+fn test_unclaimed_uri() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chips = [SoftwareChip::new(1), SoftwareChip::new(2)];
+
+    // Without a placeholder the real URI is public right away
+    let token_id = chips[0].mint(&e, &client, 1);
+    assert_eq!(client.unclaimed_uri(), String::from_str(&e, ""));
+    assert_eq!(client.token_uri(&token_id), String::from_str(&e, "ipfs://abcd/0"));
+    chips[0].claim(&e, &client, &claimant, 2);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [
+            events::NonceUsed { public_key: chips[0].public_key(&e), nonce: 2 }.to_xdr(&e, &client.address),
+            events::Claim { claimant: claimant.clone(), public_key: chips[0].public_key(&e), merchant_id: 0, sku: None, token_id }.to_xdr(&e, &client.address),
+        ]
+    );
+
+    let placeholder = String::from_str(&e, "ipfs://placeholder");
+    client.set_unclaimed_uri(&placeholder);
+    assert_eq!(client.unclaimed_uri(), placeholder);
+    assert_eq!(client.token_uri(&token_id), String::from_str(&e, "ipfs://abcd/0"));
+
+    let token_id = chips[1].mint(&e, &client, 1);
+    assert_eq!(client.token_uri(&token_id), placeholder);
+
+    // The real URI is revealed at claim
+    chips[1].claim(&e, &client, &claimant, 2);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [
+            events::NonceUsed { public_key: chips[1].public_key(&e), nonce: 2 }.to_xdr(&e, &client.address),
+            events::MetadataUpdate { token_id }.to_xdr(&e, &client.address),
+            events::Claim { claimant: claimant.clone(), public_key: chips[1].public_key(&e), merchant_id: 0, sku: None, token_id }.to_xdr(&e, &client.address),
+        ]
+    );
+    assert_eq!(client.token_uri(&token_id), String::from_str(&e, "ipfs://abcd/1"));
+
+    // Same length bound as the base URI
+    let uri = |length: usize| String::from_str(&e, &"a".repeat(length));
+    let result = client.try_set_unclaimed_uri(&uri(201));
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::BaseUriMaxLenExceeded.into())));
+    client.set_unclaimed_uri(&uri(200));
+    assert_eq!(client.unclaimed_uri(), uri(200));
+}
+
+#[test]
+fn test_nonce_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chips = [SoftwareChip::new(1), SoftwareChip::new(2)];
+    let mint = |chip: &SoftwareChip, nonce: u64| {
+        let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, nonce);
+        client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &nonce, &0, &None, &None)
+    };
+
+    assert_eq!(client.nonce_window(), 0u32);
+    client.set_nonce_window(&3u32);
+
+    // Beyond the window
+    let result = mint(&chips[0], 4);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooFarAhead.into())));
+
+    // At the edge of the window, the stored nonce jumps to the accepted one
+    assert!(mint(&chips[0], 3).is_ok());
+    assert_eq!(client.get_nonce(&chips[0].public_key(&e)), 3u64);
+
+    // Window 1 is strict
+    client.set_nonce_window(&1u32);
+    let result = mint(&chips[1], 2);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooFarAhead.into())));
+    assert!(mint(&chips[1], 1).is_ok());
+}
+
+#[test]
+fn test_u64_nonce() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+    let token_id = mint_and_claim(&e, &client, &chip, &alice);
+
+    // Chips used before the widening have their nonce stored as a u32
+    let nonce_key = crate::contract::NFTStorageKey::ChipNonceByPublicKey(public_key.clone());
+    e.as_contract(&client.address, || e.storage().persistent().set(&nonce_key, &u32::MAX));
+    assert_eq!(client.get_nonce(&public_key), u32::MAX as u64);
+
+    let nonce = u32::MAX as u64;
+    let (message, signature, recovery_id, _) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, nonce);
+    let result = client.try_chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &nonce, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+
+    // The next signature goes past u32::MAX and rewrites the nonce as a u64
+    let nonce = u32::MAX as u64 + 1;
+    let (message, signature, recovery_id, _) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, nonce);
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &nonce, &0);
+    assert!(e.events().all().filter_by_contract(&client.address).events().contains(&events::NonceUsed { public_key: public_key.clone(), nonce }.to_xdr(&e, &client.address)));
+    assert_eq!(client.get_nonce(&public_key), nonce);
+    let stored: Option<u64> = e.as_contract(&client.address, || e.storage().persistent().get(&nonce_key));
+    assert_eq!(stored, Some(nonce));
+
+    // A chip counter can be used as the nonce up to the end of the range
+    let nonce = u64::MAX;
+    let (message, signature, recovery_id, _) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &alice, nonce);
+    client.chip_transfer(&bob, &alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &nonce, &0);
+    assert_eq!(client.get_nonce(&public_key), u64::MAX);
+    let result = client.try_chip_transfer(&bob, &alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &nonce, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+}
+
+#[test]
+fn test_chip_stats() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+
+    assert_eq!(client.chip_stats(&public_key), ChipStats { mints: 0, claims: 0, transfers: 0 });
+
+    let token_id = chip.mint(&e, &client, 1);
+    chip.claim(&e, &client, &alice, 2);
+
+    // Failed attempts are not counted
+    let (message, signature, recovery_id, _) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    assert!(client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &3u64, &0, &None, &None).is_err());
+    assert!(client.try_chip_transfer(&bob, &alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3u64, &0).is_err());
+
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3u64, &0);
+    let (message, signature, recovery_id, _) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &alice, 4);
+    #[cfg(feature = "escrow")]
+    client.escrow_transfer(&bob, &alice, &token_id, &10u32, &message, &signature, &Some(recovery_id), &public_key, &4u64);
+    #[cfg(not(feature = "escrow"))]
+    client.chip_transfer(&bob, &alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &4u64, &0);
+
+    // Admin transfers count like any other transfer, the escrow counts once
+    #[cfg(feature = "escrow")]
+    client.confirm_receipt(&alice, &token_id);
+    client.admin_transfer(&token_id, &bob, &1u32);
+
+    assert_eq!(client.chip_stats(&public_key), ChipStats { mints: 1, claims: 1, transfers: 3 });
+}
+
+#[test]
+fn test_feature_entry_points() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let token_id = SoftwareChip::new(1).mint(&e, &client, 1);
+
+    // Each extension exposes its entry points only when its feature is enabled
+    let exposed = |fn_name: &str, args: soroban_sdk::Vec<soroban_sdk::Val>| {
+        let result = e.try_invoke_contract::<soroban_sdk::Val, soroban_sdk::Error>(&client.address, &Symbol::new(&e, fn_name), args);
+        let missing = soroban_sdk::Error::from_type_and_code(soroban_sdk::xdr::ScErrorType::Context, soroban_sdk::xdr::ScErrorCode::InvalidAction);
+        !matches!(result, Err(Ok(error)) if error == missing)
+    };
+    let features = [
+        (cfg!(feature = "claim-codes"), "claim_with_code", (admin.clone(), token_id, Bytes::new(&e)).into_val(&e)),
+        (cfg!(feature = "composable"), "children_of", (token_id,).into_val(&e)),
+        (cfg!(feature = "escrow"), "escrow_of", (token_id,).into_val(&e)),
+        (cfg!(feature = "locales"), "locales_of", (token_id,).into_val(&e)),
+        (cfg!(feature = "partner-mint"), "chip_sku", (client.public_key(&token_id),).into_val(&e)),
+        (cfg!(feature = "provenance"), "recent_claims", ().into_val(&e)),
+        (cfg!(feature = "provenance"), "ownership_history", (token_id,).into_val(&e)),
+        (cfg!(feature = "recovery"), "recovery_of", (admin.clone(),).into_val(&e)),
+        (cfg!(feature = "reservations"), "reservation_of", (token_id,).into_val(&e)),
+        (cfg!(feature = "warranty"), "warranty_claim", (token_id,).into_val(&e)),
+    ];
+    for (enabled, fn_name, args) in features {
+        assert_eq!(exposed(fn_name, args), enabled, "{fn_name}");
+    }
+
+    // The core contract is always there
+    assert!(exposed("owner_of_opt", (token_id,).into_val(&e)));
+    assert!(!exposed("no_such_entry_point", soroban_sdk::vec![&e]));
+}
+
+#[test]
+fn test_scan_count() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let other_chip = SoftwareChip::new(2);
+    let public_key = chip.public_key(&e);
+
+    // Only minted chips can be scanned
+    let (message, signature, recovery_id, _) = chip.sign(&e, ChipOperation::Scan, &client.address, TEST_MESSAGE, 1);
+    let result = client.try_record_scan(&public_key, &message, &signature, &Some(recovery_id), &1, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+    assert_eq!(client.scan_count(&public_key), 0);
+
+    // Scans work before the claim and share the nonce with the other operations
+    chip.mint(&e, &client, 1);
+    let (message, signature, recovery_id, _) = chip.sign(&e, ChipOperation::Scan, &client.address, TEST_MESSAGE, 2);
+    assert_eq!(client.record_scan(&public_key, &message, &signature, &Some(recovery_id), &2, &0), 1);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [
+            events::NonceUsed { public_key: public_key.clone(), nonce: 2 }.to_xdr(&e, &client.address),
+            events::Scan { public_key: public_key.clone(), count: 1 }.to_xdr(&e, &client.address),
+        ]
+    );
+    assert_eq!(client.get_nonce(&public_key), 2);
+
+    let result = client.try_record_scan(&public_key, &message, &signature, &Some(recovery_id), &2, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+
+    // Minting another chip does not touch this chip's nonce
+    other_chip.mint(&e, &client, 1);
+    let result = client.try_claim(&alice, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+    assert!(result.is_err());
+    chip.claim(&e, &client, &alice, 3);
+
+    let (message, signature, recovery_id, _) = chip.sign(&e, ChipOperation::Scan, &client.address, TEST_MESSAGE, 4);
+    assert_eq!(client.record_scan(&public_key, &message, &signature, &Some(recovery_id), &4, &0), 2);
+    assert_eq!(client.scan_count(&public_key), 2);
+    assert_eq!(client.scan_count(&other_chip.public_key(&e)), 0);
+    assert_eq!(client.get_nonce(&public_key), 4);
+
+    // A scan signature cannot be replayed as a transfer
+    let bob = Address::generate(&e);
+    let (message, signature, recovery_id, _) = chip.sign(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, 5);
+    let result = client.try_chip_transfer(&alice, &bob, &1, &message, &signature, &Some(recovery_id), &public_key, &5, &0);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_treasury_rotation() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let typo = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    assert_eq!(client.treasury(), admin);
+    let result = client.try_accept_treasury();
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NoPendingTreasury.into())));
+
+    // A fat-fingered proposal is replaced before anyone accepts it
+    client.set_treasury(&typo);
+    client.set_treasury(&treasury);
+    assert_eq!(client.pending_treasury(), Some(treasury.clone()));
+    assert_eq!(client.treasury(), admin);
+
+    client.accept_treasury();
+    assert_eq!(
+        e.auths(),
+        [(treasury.clone(), AuthorizedInvocation {
+            function: AuthorizedFunction::Contract((client.address.clone(), Symbol::new(&e, "accept_treasury"), ().into_val(&e))),
+            sub_invocations: std::vec![],
+        })]
+    );
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::TreasuryChanged { previous_treasury: admin.clone(), treasury: treasury.clone() }.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.treasury(), treasury);
+    assert_eq!(client.pending_treasury(), None);
+}
+
+#[test]
+fn test_withdraw() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let payment_token = e.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token = soroban_sdk::token::Client::new(&e, &payment_token);
+    // Funds sent to the contract directly
+    soroban_sdk::token::StellarAssetClient::new(&e, &payment_token).mint(&client.address, &100);
+    assert_eq!(client.treasury_balance(&payment_token), 100);
+
+    let result = client.try_withdraw(&0, &payment_token, &Some(alice.clone()), &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+
+    client.withdraw(&0, &payment_token, &Some(alice.clone()), &60);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::Withdraw { token: payment_token.clone(), to: alice.clone(), merchant_id: 0, amount: 60 }.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(token.balance(&alice), 60);
+    assert_eq!(client.treasury_balance(&payment_token), 40);
+
+    // Without a destination the funds go to the treasury
+    client.withdraw(&0, &payment_token, &None, &30);
+    assert_eq!(token.balance(&admin), 30);
+    assert_eq!(client.treasury_balance(&payment_token), 10);
+
+    // Over-withdrawing fails in the token contract and moves nothing
+    assert!(client.try_withdraw(&0, &payment_token, &None, &11).is_err());
+    assert_eq!(token.balance(&admin), 30);
+    assert_eq!(client.treasury_balance(&payment_token), 10);
+}
+
+#[test]
+fn test_fees_follow_treasury() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let payment_token = e.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token = soroban_sdk::token::Client::new(&e, &payment_token);
+    soroban_sdk::token::StellarAssetClient::new(&e, &payment_token).mint(&alice, &200);
+    client.set_price(&0, &payment_token, &100);
+    let chips: std::vec::Vec<SoftwareChip> = (1..=2).map(SoftwareChip::new).collect();
+    for chip in chips.iter() {
+        chip.mint(&e, &client, 1);
+    }
+
+    // A proposed treasury receives nothing until it accepts
+    client.set_treasury(&treasury);
+    chips[0].claim(&e, &client, &alice, 2);
+    assert_eq!((token.balance(&admin), token.balance(&treasury)), (100, 0));
+
+    client.accept_treasury();
+    chips[1].claim(&e, &client, &alice, 2);
+    assert_eq!((token.balance(&admin), token.balance(&treasury)), (100, 100));
+
+    // Withdrawals default to the new treasury as well
+    soroban_sdk::token::StellarAssetClient::new(&e, &payment_token).mint(&client.address, &50);
+    client.withdraw(&0, &payment_token, &None, &50);
+    assert_eq!((token.balance(&admin), token.balance(&treasury)), (100, 150));
+}
+
+#[test]
+fn test_inconsistent_balance() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = mint_and_claim(&e, &client, &chip, &alice);
+
+    // Seed storage where the owner's balance no longer counts its token
+    e.as_contract(&client.address, || {
+        e.storage().persistent().set(&crate::contract::NFTStorageKey::Balance(alice.clone()), &0u32);
+    });
+
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    let result = client.try_chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::MathOverflow.into())));
+
+    let result = client.try_burn_from_owner(&alice, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::MathOverflow.into())));
+    assert_eq!(client.owner_of(&token_id), alice);
+}
+
+#[test]
+fn test_admin_rotation() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let typo = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    assert_eq!(client.get_admin(), admin);
+    let result = client.try_accept_admin();
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NoPendingAdmin.into())));
+
+    // Proposing again cancels the mistaken proposal
+    client.transfer_admin(&typo);
+    client.transfer_admin(&new_admin);
+    assert_eq!(client.pending_admin(), Some(new_admin.clone()));
+
+    // The current admin keeps its rights until the proposal is accepted
+    client.set_treasury(&admin);
+    assert_eq!(e.auths()[0].0, admin);
+    assert_eq!(client.get_admin(), admin);
+
+    client.accept_admin();
+    assert_eq!(
+        e.auths(),
+        [(new_admin.clone(), AuthorizedInvocation {
+            function: AuthorizedFunction::Contract((client.address.clone(), Symbol::new(&e, "accept_admin"), ().into_val(&e))),
+            sub_invocations: std::vec![],
+        })]
+    );
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::AdminChanged { previous_admin: admin.clone(), admin: new_admin.clone() }.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.pending_admin(), None);
+
+    client.set_treasury(&new_admin);
+    assert_eq!(e.auths()[0].0, new_admin);
+
+    // The previous admin can no longer sign admin operations
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "transfer_admin",
+                args: (&admin,).into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .try_transfer_admin(&admin);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_token_info() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let claimed_chip = SoftwareChip::new(1);
+    let claimed_id = claimed_chip.mint(&e, &client, 1);
+    claimed_chip.claim(&e, &client, &owner, 2);
+    let unclaimed_chip = SoftwareChip::new(2);
+    let unclaimed_id = unclaimed_chip.mint(&e, &client, 1);
+
+    let claimed_info = TokenInfo {
+        token_id: claimed_id,
+        owner: Some(owner.clone()),
+        public_key: claimed_chip.public_key(&e),
+        uri: String::from_str(&e, "ipfs://abcd/0"),
+        claimed: true,
+    };
+    let unclaimed_info = TokenInfo {
+        token_id: unclaimed_id,
+        owner: None,
+        public_key: unclaimed_chip.public_key(&e),
+        uri: String::from_str(&e, "ipfs://abcd/1"),
+        claimed: false,
+    };
+    assert_eq!(client.token_info(&claimed_id), claimed_info);
+    assert_eq!(client.token_info(&unclaimed_id), unclaimed_info);
+    let result = client.try_token_info(&2);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+
+    // Unknown ids are skipped in batches
+    let token_ids = soroban_sdk::vec![&e, unclaimed_id, 2, claimed_id, 100];
+    assert_eq!(client.tokens_info(&token_ids), soroban_sdk::vec![&e, unclaimed_info, claimed_info]);
+
+    let token_ids = soroban_sdk::Vec::from_slice(&e, &[0u64; 51]);
+    let result = client.try_tokens_info(&token_ids);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+}
+
+#[test]
+fn test_supply_counters() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chips = [SoftwareChip::new(1), SoftwareChip::new(2), SoftwareChip::new(3)];
+    let counters = || (client.total_supply(), client.minted_count(), client.claimed_count(), client.burned_count());
+    assert_eq!(counters(), (0, 0, 0, 0));
+
+    let token_ids: std::vec::Vec<u64> = chips.iter().map(|chip| chip.mint(&e, &client, 1)).collect();
+    assert_eq!(counters(), (3, 3, 0, 0));
+
+    for (chip, token_id) in chips.iter().zip(&token_ids).take(2) {
+        chip.claim(&e, &client, &alice, 2);
+        assert_eq!(client.owner_of(token_id), alice);
+    }
+    assert_eq!(counters(), (3, 3, 2, 0));
+
+    client.burn_from_owner(&alice, &token_ids[0]);
+    assert_eq!(counters(), (2, 3, 2, 1));
+    assert_eq!(client.remaining_supply(), 9_997);
+}
+
+#[test]
+fn test_royalties() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let artist = Address::generate(&e);
+    let staff = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let token_id = SoftwareChip::new(1).mint(&e, &client, 1);
+    let other_token_id = SoftwareChip::new(2).mint(&e, &client, 1);
+
+    // Nothing owed until a royalty is set
+    assert_eq!(client.royalty_info(&token_id, &1_000), (admin.clone(), 0));
+
+    client.set_default_royalty(&artist, &250);
+    assert_eq!(client.royalty_info(&token_id, &1_000), (artist.clone(), 25));
+    assert_eq!(client.royalty_info(&other_token_id, &1_000), (artist.clone(), 25));
+
+    // A token royalty shadows the default
+    client.set_token_royalty(&token_id, &staff, &0);
+    assert_eq!(client.royalty_info(&token_id, &1_000), (staff.clone(), 0));
+    client.set_token_royalty(&token_id, &staff, &10_000);
+    assert_eq!(client.royalty_info(&token_id, &1_000), (staff.clone(), 1_000));
+    assert_eq!(client.royalty_info(&other_token_id, &1_000), (artist.clone(), 25));
+
+    let result = client.try_set_default_royalty(&artist, &10_001);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidRoyaltyAmount.into())));
+    let result = client.try_set_token_royalty(&token_id, &staff, &10_001);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidRoyaltyAmount.into())));
+
+    let result = client.try_royalty_info(&token_id, &i128::MAX);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::MathOverflow.into())));
+    let result = client.try_royalty_info(&2, &1_000);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+}
+
+#[test]
+fn test_purchase_orders() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let mut po_tokens = [Vec::new(), Vec::new()];
+    for seed in 1..=5u32 {
+        let po = seed % 2;
+        let (message, signature, recovery_id, public_key) = SoftwareChip::new(seed).sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+        let token_id = client.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1u64, &0, &None, &Some(4_000 + po));
+
+        assert_eq!(
+            e.events().all().filter_by_contract(&client.address),
+            [
+                events::NonceUsed { public_key: public_key.clone(), nonce: 1 }.to_xdr(&e, &client.address),
+                events::Mint { token_id, public_key: public_key.clone(), merchant_id: 0, sku: None, to: None, po_number: Some(4_000 + po) }.to_xdr(&e, &client.address),
+            ]
+        );
+        assert_eq!(client.po_of(&token_id), Some(4_000 + po));
+        po_tokens[po as usize].push(token_id);
+    }
+
+    let token_id = SoftwareChip::new(6).mint(&e, &client, 1);
+    assert_eq!(client.po_of(&token_id), None);
+
+    assert_eq!(client.tokens_by_po(&4_000, &0, &10), soroban_sdk::Vec::from_slice(&e, &po_tokens[0]));
+    assert_eq!(client.tokens_by_po(&4_001, &0, &10), soroban_sdk::Vec::from_slice(&e, &po_tokens[1]));
+    assert_eq!(client.tokens_by_po(&4_001, &1, &1), soroban_sdk::Vec::from_slice(&e, &po_tokens[1][1..2]));
+}
+
+#[test]
+fn test_checkpoint_events() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    client.set_checkpoint_interval(&3u32);
+
+    let mut checkpoints = Vec::new();
+    for seed in 1..=7u32 {
+        e.ledger().set_sequence_number(100 + seed);
+        let chip = SoftwareChip::new(seed);
+        let token_id = chip.mint(&e, &client, 1);
+
+        let mint_event = events::Mint { token_id, public_key: chip.public_key(&e), merchant_id: 0, sku: None, to: None, po_number: None }.to_xdr(&e, &client.address);
+        let emitted = e.events().all().filter_by_contract(&client.address);
+        assert_eq!(emitted.events()[1], mint_event);
+        checkpoints.extend_from_slice(&emitted.events()[2..]);
+
+        if seed % 2 == 0 {
+            chip.claim(&e, &client, &claimant, 2);
+        }
+    }
+
+    let expected = [
+        events::Checkpoint { next_token_id: 3, total_supply: 3, claimed_count: 1, ledger: 103 },
+        events::Checkpoint { next_token_id: 6, total_supply: 6, claimed_count: 2, ledger: 106 },
+    ];
+    assert_eq!(
+        checkpoints,
+        expected.map(|checkpoint| checkpoint.to_xdr(&e, &client.address))
+    );
+}
+
+#[test]
+fn test_optional_lookups() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let claimed_chip = SoftwareChip::new(1);
+    let unclaimed_chip = SoftwareChip::new(2);
+    let missing_chip = SoftwareChip::new(3);
+
+    let claimed_id = claimed_chip.mint(&e, &client, 1);
+    claimed_chip.claim(&e, &client, &owner, 2);
+    let unclaimed_id = unclaimed_chip.mint(&e, &client, 1);
+    let missing_id = 2u64;
+
+    // Existing token
+    assert!(client.exists(&claimed_id));
+    assert!(client.is_claimed(&claimed_id));
+    assert_eq!(client.owner_of_opt(&claimed_id), Some(client.owner_of(&claimed_id)));
+    assert_eq!(client.token_uri_opt(&claimed_id), Some(client.token_uri(&claimed_id)));
+    assert_eq!(client.public_key_opt(&claimed_id), Some(client.public_key(&claimed_id)));
+    assert_eq!(client.token_id_opt(&claimed_chip.public_key(&e)), Some(client.token_id(&claimed_chip.public_key(&e))));
+
+    // Unclaimed token
+    assert!(client.exists(&unclaimed_id));
+    assert!(!client.is_claimed(&unclaimed_id));
+    assert_eq!(client.owner_of_opt(&unclaimed_id), None);
+    assert!(client.try_owner_of(&unclaimed_id).is_err());
+    assert_eq!(client.token_uri_opt(&unclaimed_id), Some(client.token_uri(&unclaimed_id)));
+    assert_eq!(client.public_key_opt(&unclaimed_id), Some(client.public_key(&unclaimed_id)));
+    assert_eq!(client.token_id_opt(&unclaimed_chip.public_key(&e)), Some(unclaimed_id));
+
+    // Missing token
+    assert!(!client.exists(&missing_id));
+    assert!(!client.is_claimed(&missing_id));
+    assert_eq!(client.owner_of_opt(&missing_id), None);
+    assert!(client.try_owner_of(&missing_id).is_err());
+    assert_eq!(client.token_uri_opt(&missing_id), None);
+    assert!(client.try_token_uri(&missing_id).is_err());
+    assert_eq!(client.public_key_opt(&missing_id), None);
+    assert!(client.try_public_key(&missing_id).is_err());
+    assert_eq!(client.token_id_opt(&missing_chip.public_key(&e)), None);
+    assert!(client.try_token_id(&missing_chip.public_key(&e)).is_err());
+}
+
+#[test]
+fn test_chip_auth_delegation() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip_auth = chip_auth::ChipAuthClient::new(&e, &e.register(chip_auth::ChipAuth, ()));
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+
+    // Internal path first
+    assert_eq!(client.chip_auth(), None);
+    chip.mint(&e, &client, 1);
+
+    client.set_chip_auth(&Some(chip_auth.address.clone()));
+    assert_eq!(client.chip_auth(), Some(chip_auth.address.clone()));
+
+    // A signature consumed internally cannot be replayed through chip-auth
+    let (message, signature, recovery_id, _) = chip.sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &claimant, 1);
+    let result = client.try_claim(&claimant, &message, &signature, &Some(recovery_id), &public_key, &1, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+
+    // Simulating does not consume the nonce in chip-auth
+    let (message, signature, recovery_id, _) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 2);
+    let preview = client.simulate_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+    assert_eq!(preview.error_code, errors::NonFungibleTokenError::TokenAlreadyMinted as u32);
+    assert_eq!(chip_auth.get_nonce(&client.address, &public_key), 0);
+
+    chip.claim(&e, &client, &claimant, 2);
+    assert_eq!(client.owner_of(&0), claimant);
+    assert_eq!(chip_auth.get_nonce(&client.address, &public_key), 2);
+    assert_eq!(client.get_nonce(&public_key), 2);
+
+    // A bad signature is refused by chip-auth
+    let (message, signature, recovery_id, _) = SoftwareChip::new(2).sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &claimant, 3);
+    let result = client.try_claim(&claimant, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidSignature.into())));
+
+    client.set_chip_auth(&None);
+    assert_eq!(client.chip_auth(), None);
+}
+
+/// Partner loyalty program minting a chip when a user completes a quest
+#[cfg(feature = "partner-mint")]
+#[soroban_sdk::contract]
+struct LoyaltyProgram;
+
+#[cfg(feature = "partner-mint")]
+#[soroban_sdk::contractimpl]
+impl LoyaltyProgram {
+    pub fn complete_quest(
+        e: Env,
+        shop: Address,
+        user: Address,
+        sku: u32,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: Option<u32>,
+        public_key: BytesN<65>,
+        nonce: u64,
+    ) -> u64 {
+        StellarMerchShopClient::new(&e, &shop).partner_mint(
+            &e.current_contract_address(),
+            &user,
+            &sku,
+            &message,
+            &signature,
+            &recovery_id,
+            &public_key,
+            &nonce,
+        )
+    }
+}
+
+#[test]
+#[cfg(feature = "partner-mint")]
+fn test_partner_mint() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let program = LoyaltyProgramClient::new(&e, &e.register(LoyaltyProgram, ()));
+    let chips = [SoftwareChip::new(1), SoftwareChip::new(2), SoftwareChip::new(3)];
+    for chip in &chips[..2] {
+        client.set_chip_sku(&chip.public_key(&e), &7u32);
+    }
+    client.set_chip_sku(&chips[2].public_key(&e), &8u32);
+    client.grant_mint_allowance(&program.address, &7u32, &1u64);
+    assert_eq!(client.mint_allowance(&program.address, &7u32), 1u64);
+
+    // Only the partner contract itself can spend its allowance
+    e.set_auths(&[]);
+    let (message, signature, recovery_id, public_key) = chips[0].sign_for(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, &user, 1);
+    let result = client.try_partner_mint(&program.address, &user, &7u32, &message, &signature, &Some(recovery_id), &public_key, &1);
+    assert!(result.is_err());
+
+    let token_id = program.complete_quest(&client.address, &user, &7u32, &message, &signature, &Some(recovery_id), &public_key, &1);
+
+    // Mint, claim and transfer events carry the SKU as a topic
+    let sku = Some(7u32);
+    let mint = events::Mint { token_id, public_key: public_key.clone(), merchant_id: 0, sku, to: Some(user.clone()), po_number: None };
+    let claim = events::Claim { claimant: user.clone(), public_key: public_key.clone(), merchant_id: 0, sku, token_id };
+    let published = e.events().all().filter_by_contract(&client.address);
+    assert!(published.events().contains(&mint.to_xdr(&e, &client.address)));
+    assert!(published.events().contains(&claim.to_xdr(&e, &client.address)));
+    assert_eq!(client.owner_of(&token_id), user);
+    assert_eq!(client.balance(&user), 1u32);
+    assert_eq!(client.mint_allowance(&program.address, &7u32), 0u64);
+
+    // Chip of another SKU
+    let (message, signature, recovery_id, public_key) = chips[2].sign_for(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, &user, 1);
+    let result = program.try_complete_quest(&client.address, &user, &8u32, &message, &signature, &Some(recovery_id), &public_key, &1);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::MintAllowanceExhausted.into())));
+
+    e.mock_all_auths();
+    client.grant_mint_allowance(&program.address, &7u32, &5u64);
+    let result = program.try_complete_quest(&client.address, &user, &7u32, &message, &signature, &Some(recovery_id), &public_key, &1);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ChipSkuMismatch.into())));
+
+    // Allowance exhaustion
+    client.grant_mint_allowance(&program.address, &7u32, &0u64);
+    let (message, signature, recovery_id, public_key) = chips[1].sign_for(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, &user, 1);
+    let result = program.try_complete_quest(&client.address, &user, &7u32, &message, &signature, &Some(recovery_id), &public_key, &1);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::MintAllowanceExhausted.into())));
+
+    let friend = Address::generate(&e);
+    client.transfer(&user, &friend, &token_id);
+    let transfer = events::Transfer { from: user.clone(), to: friend.clone(), merchant_id: 0, sku, token_id };
+    assert!(e.events().all().filter_by_contract(&client.address).events().contains(&transfer.to_xdr(&e, &client.address)));
+}
+
+#[test]
+fn test_activity_events() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client_with_options(&e, &admin, 10_000, true, false);
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+    #[cfg(feature = "partner-mint")]
+    client.set_chip_sku(&public_key, &7u32);
+    let sku = if cfg!(feature = "partner-mint") { Some(7u32) } else { None };
+
+    let activity = |operation, from: Option<Address>, to: Option<Address>, nonce: u64| {
+        events::ActivityV1 {
+            operation,
+            token_id: 0,
+            public_key: public_key.clone(),
+            from,
+            to,
+            sku,
+            nonce: Some(nonce),
+            ledger: e.ledger().sequence(),
+            version: 1,
+        }
+        .to_xdr(&e, &client.address)
+    };
+    let last_event = || e.events().all().filter_by_contract(&client.address).events().last().unwrap().clone();
+
+    // The schema is only bumped on purpose
+    assert_eq!(events::ACTIVITY_SCHEMA_VERSION, 1);
+
+    chip.mint(&e, &client, 1);
+    assert_eq!(last_event(), activity(events::Operation::Mint, None, None, 1));
+
+    chip.claim(&e, &client, &alice, 2);
+    assert_eq!(last_event(), activity(events::Operation::Claim, None, Some(alice.clone()), 2));
+
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    client.chip_transfer(&alice, &bob, &0, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(last_event(), activity(events::Operation::Transfer, Some(alice), Some(bob), 3));
+
+    // Disabled by default
+    let client = create_client(&e, &admin);
+    chip.mint(&e, &client, 1);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [
+            events::NonceUsed { public_key: chip.public_key(&e), nonce: 1 }.to_xdr(&e, &client.address),
+            events::Mint { token_id: 0, public_key: chip.public_key(&e), merchant_id: 0, sku: None, to: None, po_number: None }.to_xdr(&e, &client.address),
+        ]
+    );
+}
+
+#[test]
+fn test_max_message_length() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let mint = |length: usize, nonce: u64| {
+        let message = std::vec![b'a'; length];
+        let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, &message, nonce);
+        client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &nonce, &0, &None, &None)
+    };
+    let malformed = Err(Ok(errors::NonFungibleTokenError::MalformedMessage.into()));
+
+    assert_eq!(client.max_message_length(), 256u32);
+    assert_eq!(mint(0, 1), malformed);
+    assert_eq!(mint(257, 1), malformed);
+
+    let (_, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, b"a", 1);
+    let result = client.try_verify_product(&Bytes::new(&e), &signature, &Some(recovery_id), &public_key);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::MalformedMessage.into())));
+
+    // Lowering the cap
+    client.set_max_message_length(&16u32);
+    assert_eq!(mint(17, 1), malformed);
+    assert!(mint(16, 1).is_ok());
+
+    // The cap stays within the hard ceiling
+    let result = client.try_set_max_message_length(&0u32);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+    let result = client.try_set_max_message_length(&1025u32);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+    client.set_max_message_length(&1024u32);
+    assert_eq!(client.max_message_length(), 1024u32);
+}
+
+#[test]
+fn test_max_tokens() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client_with_max_tokens(&e, &admin, 2);
+    SoftwareChip::new(1).mint(&e, &client, 1);
+    SoftwareChip::new(2).mint(&e, &client, 1);
+    assert_eq!(client.max_tokens(), 2);
+    assert_eq!(client.remaining_supply(), 0);
+
+    // Extending the run
+    client.set_max_tokens(&5);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::MaxTokensUpdated { previous_max_tokens: 2, max_tokens: 5 }.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.max_tokens(), 5);
+    assert_eq!(client.remaining_supply(), 3);
+    SoftwareChip::new(3).mint(&e, &client, 1);
+    assert_eq!(client.remaining_supply(), 2);
+
+    // The cap cannot go below the minted tokens, but can close the run
+    let result = client.try_set_max_tokens(&2);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+    assert_eq!(client.max_tokens(), 5);
+    client.set_max_tokens(&3);
+    assert_eq!(client.remaining_supply(), 0);
+    let (message, signature, recovery_id, public_key) = SoftwareChip::new(4).sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenIDsAreDepleted.into())));
+}
+
+#[test]
+#[cfg(feature = "locales")]
+fn test_locale_uris() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+    chip.claim(&e, &client, &claimant, 2);
+
+    let fr = Symbol::new(&e, "fr");
+    let de = Symbol::new(&e, "de");
+    let fr_uri = String::from_str(&e, "ipfs://abcd/fr/0");
+    let de_uri = String::from_str(&e, "ipfs://abcd/de/0");
+    client.set_locale_uri(&token_id, &fr, &fr_uri);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::MetadataUpdate { token_id }.to_xdr(&e, &client.address)]
+    );
+    client.set_locale_uri(&token_id, &de, &de_uri);
+
+    assert_eq!(client.token_uri_localized(&token_id, &fr), fr_uri);
+    assert_eq!(client.token_uri_localized(&token_id, &de), de_uri);
+    assert_eq!(client.locales_of(&token_id), soroban_sdk::vec![&e, fr.clone(), de]);
+
+    // Missing locales fall back to the default URI
+    let it = Symbol::new(&e, "it");
+    assert_eq!(client.token_uri_localized(&token_id, &it), client.token_uri(&token_id));
+
+    // Replacing a locale does not count against the cap
+    client.set_locale_uri(&token_id, &fr, &de_uri);
+    assert_eq!(client.locales_of(&token_id).len(), 2);
+
+    let result = client.try_set_locale_uri(&token_id, &it, &String::from_str(&e, ""));
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidUri.into())));
+    let long_uri = String::from_str(&e, &"a".repeat(201));
+    let result = client.try_set_locale_uri(&token_id, &it, &long_uri);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidUri.into())));
+
+    for locale in ["it", "es", "nl", "pt", "pl", "sv"] {
+        client.set_locale_uri(&token_id, &Symbol::new(&e, locale), &fr_uri);
+    }
+    let result = client.try_set_locale_uri(&token_id, &Symbol::new(&e, "da"), &fr_uri);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+}
+
+#[test]
+fn test_owner_index() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chips = [SoftwareChip::new(1), SoftwareChip::new(2), SoftwareChip::new(3)];
+    for chip in &chips {
+        chip.mint(&e, &client, 1);
+        chip.claim(&e, &client, &alice, 2);
+    }
+    assert_eq!(client.tokens_of(&alice, &0, &10), soroban_sdk::vec![&e, 0u64, 1, 2]);
+    assert_eq!(client.tokens_of(&alice, &1, &1), soroban_sdk::vec![&e, 1u64]);
+
+    // The last token takes the position of the one leaving
+    let (message, signature, recovery_id, public_key) = chips[0].sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    client.chip_transfer(&alice, &bob, &0, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(client.tokens_of(&alice, &0, &10), soroban_sdk::vec![&e, 2u64, 1]);
+    assert_eq!(client.tokens_of(&bob, &0, &10), soroban_sdk::vec![&e, 0u64]);
+
+    // Along a chain of owners only the last one holds the token
+    let (message, signature, recovery_id, public_key) = chips[0].sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &carol, 4);
+    client.chip_transfer(&bob, &carol, &0, &message, &signature, &Some(recovery_id), &public_key, &4, &0);
+    let (message, signature, recovery_id, public_key) = chips[1].sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &carol, 3);
+    client.chip_transfer(&alice, &carol, &1, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(client.tokens_of(&alice, &0, &10), soroban_sdk::vec![&e, 2u64]);
+    assert_eq!(client.tokens_of(&bob, &0, &10), soroban_sdk::vec![&e]);
+    assert_eq!(client.tokens_of(&carol, &0, &10), soroban_sdk::vec![&e, 0u64, 1]);
+
+    client.burn_from_owner(&carol, &0);
+    assert_eq!(client.tokens_of(&carol, &0, &10), soroban_sdk::vec![&e, 1u64]);
+
+    // Pages past the end are empty
+    assert_eq!(client.tokens_of(&carol, &1, &10), soroban_sdk::vec![&e]);
+    assert_eq!(client.tokens_of(&carol, &u32::MAX, &u32::MAX), soroban_sdk::vec![&e]);
+}
+
+#[test]
+fn test_owner_index_legacy_tokens() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chips = [SoftwareChip::new(1), SoftwareChip::new(2)];
+    for chip in &chips {
+        chip.mint(&e, &client, 1);
+        chip.claim(&e, &client, &alice, 2);
+    }
+
+    // Tokens claimed before the owner index have no entry in it
+    e.as_contract(&client.address, || {
+        use crate::owner_index::OwnerIndexStorageKey;
+        let storage = e.storage().persistent();
+        for token_id in 0..2u64 {
+            storage.remove(&OwnerIndexStorageKey::TokenPosition(token_id));
+            storage.remove(&OwnerIndexStorageKey::HeldToken(alice.clone(), token_id as u32));
+        }
+        storage.remove(&OwnerIndexStorageKey::HeldCount(alice.clone()));
+    });
+
+    // They still leave their owner, and the new owner indexes them
+    let (message, signature, recovery_id, public_key) = chips[0].sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    client.chip_transfer(&alice, &bob, &0, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(client.owner_of(&0), bob);
+    assert_eq!(client.tokens_of(&alice, &0, &10), soroban_sdk::vec![&e]);
+    assert_eq!(client.tokens_of(&bob, &0, &10), soroban_sdk::vec![&e, 0u64]);
+
+    client.burn_from_owner(&alice, &1);
+    assert!(!client.exists(&1));
+    assert_eq!(client.tokens_of(&alice, &0, &10), soroban_sdk::vec![&e]);
+}
+
+#[test]
+fn test_token_enumeration() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    for seed in 1..=4 {
+        let chip = SoftwareChip::new(seed);
+        chip.mint(&e, &client, 1);
+        chip.claim(&e, &client, &alice, 2);
+    }
+    assert_eq!(client.all_tokens(&0, &10), soroban_sdk::vec![&e, 0u64, 1, 2, 3]);
+    assert_eq!(client.all_tokens(&1, &2), soroban_sdk::vec![&e, 1u64, 2]);
+    assert_eq!(client.token_by_index(&3), 3);
+
+    // Burned ids leave no gap, the last token fills it
+    client.burn_from_owner(&alice, &1);
+    assert_eq!(client.all_tokens(&0, &10), soroban_sdk::vec![&e, 0u64, 3, 2]);
+    client.burn_from_owner(&alice, &2);
+    assert_eq!(client.all_tokens(&0, &10), soroban_sdk::vec![&e, 0u64, 3]);
+    assert_eq!(client.token_by_index(&1), 3);
+    let result = client.try_token_by_index(&2);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+
+    let token_id = SoftwareChip::new(5).mint(&e, &client, 1);
+    assert_eq!(client.all_tokens(&0, &10), soroban_sdk::vec![&e, 0u64, 3, token_id]);
+    assert_eq!(client.all_tokens(&3, &10), soroban_sdk::vec![&e]);
+    assert_eq!(client.all_tokens(&u64::MAX, &u32::MAX), soroban_sdk::vec![&e]);
+}
+
+#[test]
+#[cfg(feature = "recovery")]
+fn test_recovery() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let recovery = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    for seed in 1..=3 {
+        let chip = SoftwareChip::new(seed);
+        chip.mint(&e, &client, 1);
+        chip.claim(&e, &client, &owner, 2);
+    }
+
+    // Only the designated recovery address can announce a recovery
+    let result = client.try_initiate_recovery(&recovery, &owner);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::RecoveryNotConfigured.into())));
+
+    client.set_recovery_address(&owner, &recovery, &100u32);
+    assert_eq!(
+        client.recovery_of(&owner),
+        Some(crate::recovery::RecoveryConfig { recovery: recovery.clone(), delay_ledgers: 100 })
+    );
+
+    // The owner cancels an announced recovery
+    client.initiate_recovery(&recovery, &owner);
+    assert_eq!(client.pending_recovery(&owner), Some(e.ledger().sequence() + 100));
+    client.cancel_recovery(&owner);
+    assert_eq!(client.pending_recovery(&owner), None);
+    let result = client.try_execute_recovery(&recovery, &owner);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NoPendingRecovery.into())));
+
+    client.initiate_recovery(&recovery, &owner);
+    e.ledger().with_mut(|ledger| ledger.sequence_number += 99);
+    let result = client.try_execute_recovery(&recovery, &owner);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::RecoveryDelayActive.into())));
+
+    e.ledger().with_mut(|ledger| ledger.sequence_number += 1);
+    client.execute_recovery(&recovery, &owner);
+    let recoveries: Vec<_> = [0u64, 1, 2]
+        .iter()
+        .map(|token_id| {
+            events::Recovery { owner: owner.clone(), recovery: recovery.clone(), token_id: *token_id }
+                .to_xdr(&e, &client.address)
+        })
+        .collect();
+    assert_eq!(e.events().all().filter_by_contract(&client.address), recoveries.as_slice());
+    for token_id in 0..3 {
+        assert_eq!(client.owner_of(&token_id), recovery);
+    }
+    assert_eq!(client.balance(&owner), 0u32);
+    assert_eq!(client.balance(&recovery), 3u32);
+    assert_eq!(client.tokens_of(&owner, &0, &10).len(), 0);
+    assert_eq!(client.pending_recovery(&owner), None);
+}
+
+#[test]
+fn test_mint_leaves_token_unclaimed() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+
+    let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+    let token_id = client.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &Some(42u32));
+    assert_eq!(token_id, 0);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [
+            events::NonceUsed { public_key: public_key.clone(), nonce: 1 }.to_xdr(&e, &client.address),
+            events::Mint { token_id, public_key: public_key.clone(), merchant_id: 0, sku: None, to: None, po_number: Some(42) }.to_xdr(&e, &client.address),
+        ]
+    );
+
+    // Ownership only comes from claim
+    assert_eq!(client.try_owner_of(&token_id), Err(Ok(errors::NonFungibleTokenError::TokenNotClaimed.into())));
+    assert_eq!(client.owner_of_opt(&token_id), None);
+    assert_eq!(client.balance(&admin), 0u32);
+
+    chip.claim(&e, &client, &claimant, 2);
+    assert_eq!(client.owner_of(&token_id), claimant);
+}
+
+#[test]
+fn test_approve() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+    chip.claim(&e, &client, &alice, 2);
+
+    let result = client.try_approve(&bob, &operator, &token_id, &100u32);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidApprover.into())));
+
+    let live_until_ledger = e.ledger().sequence() + 10;
+    client.approve(&alice, &operator, &token_id, &live_until_ledger);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::Approve { approver: alice.clone(), token_id, approved: operator.clone(), live_until_ledger }
+            .to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.get_approved(&token_id), Some(operator.clone()));
+
+    // Expired after live_until_ledger
+    e.ledger().with_mut(|ledger| ledger.sequence_number = live_until_ledger);
+    assert_eq!(client.get_approved(&token_id), Some(operator.clone()));
+    e.ledger().with_mut(|ledger| ledger.sequence_number += 1);
+    assert_eq!(client.get_approved(&token_id), None);
+    let result = client.try_approve(&alice, &operator, &token_id, &live_until_ledger);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidLiveUntilLedger.into())));
+
+    // Re-approving replaces the approval, a transfer clears it
+    client.approve(&alice, &operator, &token_id, &(live_until_ledger + 100));
+    client.approve(&alice, &bob, &token_id, &(live_until_ledger + 100));
+    assert_eq!(client.get_approved(&token_id), Some(bob.clone()));
+
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(client.get_approved(&token_id), None);
+
+    // Revoking
+    client.approve(&bob, &operator, &token_id, &(live_until_ledger + 100));
+    client.approve(&bob, &operator, &token_id, &0u32);
+    assert_eq!(client.get_approved(&token_id), None);
+}
+
+#[test]
+fn test_transfer_from() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+    chip.claim(&e, &client, &alice, 2);
+
+    let result = client.try_transfer_from(&operator, &alice, &bob, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::Unauthorized.into())));
+
+    client.approve(&alice, &operator, &token_id, &(e.ledger().sequence() + 10));
+    let result = client.try_transfer_from(&operator, &bob, &alice, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::IncorrectOwner.into())));
+
+    client.transfer_from(&operator, &alice, &bob, &token_id);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::Transfer { from: alice.clone(), to: bob.clone(), merchant_id: 0, sku: None, token_id }.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.owner_of(&token_id), bob);
+    assert_eq!(client.balance(&alice), 0);
+    assert_eq!(client.balance(&bob), 1);
+
+    // The approval is consumed by the transfer
+    assert_eq!(client.get_approved(&token_id), None);
+    let result = client.try_transfer_from(&operator, &bob, &alice, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::Unauthorized.into())));
+}
+
+#[test]
+fn test_approve_for_recipient() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+    let shop = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+    chip.claim(&e, &client, &alice, 2);
+    let live_until_ledger = e.ledger().sequence() + 10;
+
+    // Unrestricted approvals send the token anywhere
+    client.approve(&alice, &shop, &token_id, &live_until_ledger);
+    assert_eq!(client.approved_recipient(&token_id), None);
+    client.transfer_from(&shop, &alice, &carol, &token_id);
+    assert_eq!(client.owner_of(&token_id), carol);
+
+    let result = client.try_approve_for_recipient(&alice, &shop, &token_id, &bob, &live_until_ledger);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidApprover.into())));
+    client.approve_for_recipient(&carol, &shop, &token_id, &bob, &live_until_ledger);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::ApproveForRecipient { approver: carol.clone(), token_id, approved: shop.clone(), recipient: bob.clone(), live_until_ledger }
+            .to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.get_approved(&token_id), Some(shop.clone()));
+    assert_eq!(client.approved_recipient(&token_id), Some(bob.clone()));
+
+    // Only to the agreed buyer
+    let result = client.try_transfer_from(&shop, &carol, &alice, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::RecipientNotAllowed.into())));
+    client.transfer_from(&shop, &carol, &bob, &token_id);
+    assert_eq!(client.owner_of(&token_id), bob);
+    assert_eq!(client.approved_recipient(&token_id), None);
+
+    // An unrestricted approval replaces the restricted one
+    client.approve_for_recipient(&bob, &shop, &token_id, &carol, &live_until_ledger);
+    client.approve(&bob, &shop, &token_id, &live_until_ledger);
+    assert_eq!(client.approved_recipient(&token_id), None);
+    client.transfer_from(&shop, &bob, &alice, &token_id);
+    assert_eq!(client.owner_of(&token_id), alice);
+}
+
+#[test]
+fn test_revoke_chip() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+    let token_id = chip.mint(&e, &client, 1);
+    chip.claim(&e, &client, &alice, 2);
+
+    client.revoke_chip(&public_key);
+    assert!(client.is_revoked(&public_key));
+    assert_eq!(client.owner_of(&token_id), alice);
+    assert_eq!(client.token_id(&public_key), token_id);
+
+    // The chip no longer authorizes a transfer
+    let (message, signature, recovery_id, _) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    let result = client.try_chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ChipRevoked.into())));
+
+    // Nor a mint, for a chip revoked before it was registered
+    let other_chip = SoftwareChip::new(2);
+    client.revoke_chip(&other_chip.public_key(&e));
+    let (message, signature, recovery_id, other_public_key) = other_chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &other_public_key, &1, &0, &None, &None);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ChipRevoked.into())));
+
+    // The owner can still hand the token over without the chip
+    client.approve(&alice, &bob, &token_id, &(e.ledger().sequence() + 10));
+    client.transfer_from(&bob, &alice, &bob, &token_id);
+    assert_eq!(client.owner_of(&token_id), bob);
+
+    client.unrevoke_chip(&public_key);
+    assert!(!client.is_revoked(&public_key));
+    let (message, signature, recovery_id, _) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &alice, 3);
+    client.chip_transfer(&bob, &alice, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(client.owner_of(&token_id), alice);
+}
+
+#[test]
+fn test_soulbound() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+
+    client.set_soulbound(&token_id, &true);
+    assert!(client.is_soulbound(&token_id));
+    let result = client.try_set_soulbound(&1, &true);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+
+    // Claiming is still allowed
+    chip.claim(&e, &client, &alice, 2);
+    assert_eq!(client.owner_of(&token_id), alice);
+
+    // A valid chip signature and nonce do not unlock the token
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    let result = client.try_chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenSoulbound.into())));
+    client.approve(&alice, &bob, &token_id, &(e.ledger().sequence() + 10));
+    let result = client.try_transfer_from(&bob, &alice, &bob, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenSoulbound.into())));
+
+    client.set_soulbound(&token_id, &false);
+    assert!(!client.is_soulbound(&token_id));
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(client.owner_of(&token_id), bob);
+
+    // Burning is still allowed
+    client.set_soulbound(&token_id, &true);
+    client.burn_from_owner(&bob, &token_id);
+    assert!(!client.exists(&token_id));
+}
+
+#[test]
+fn test_security_level() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+    chip.claim(&e, &client, &alice, 2);
+
+    assert_eq!(client.security_level(&token_id), SecurityLevel::OwnerOnly);
+    let result = client.try_set_security_level(&1, &SecurityLevel::Both);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+    client.set_security_level(&token_id, &SecurityLevel::Both);
+    assert_eq!(client.security_level(&token_id), SecurityLevel::Both);
+
+    // The owner's authorization alone is not enough
+    let result = client.try_transfer(&alice, &bob, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::DualAuthRequired.into())));
+    client.approve(&alice, &bob, &token_id, &(e.ledger().sequence() + 10));
+    let result = client.try_transfer_from(&bob, &alice, &bob, &token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::DualAuthRequired.into())));
+
+    // The chip signature on top of it is
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(client.owner_of(&token_id), bob);
+
+    // Back to the owner alone
+    client.set_security_level(&token_id, &SecurityLevel::OwnerOnly);
+    client.transfer(&bob, &alice, &token_id);
+    assert_eq!(client.owner_of(&token_id), alice);
+}
+
+#[test]
+fn test_rebind_chip() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let broken_chip = SoftwareChip::new(1);
+    let new_chip = SoftwareChip::new(2);
+    let token_id = broken_chip.mint(&e, &client, 1);
+    broken_chip.claim(&e, &client, &alice, 2);
+    let other_token_id = SoftwareChip::new(3).mint(&e, &client, 1);
+
+    // A key bound to another token is refused
+    let result = client.try_rebind_chip(&token_id, &client.public_key(&other_token_id));
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenAlreadyMinted.into())));
+
+    client.rebind_chip(&token_id, &new_chip.public_key(&e));
+    assert!(e.auths().iter().any(|(address, _)| *address == admin));
+    assert!(e.auths().iter().any(|(address, _)| *address == alice));
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::ChipRebound {
+            token_id,
+            previous_public_key: broken_chip.public_key(&e),
+            public_key: new_chip.public_key(&e),
+        }
+        .to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.public_key(&token_id), new_chip.public_key(&e));
+    assert_eq!(client.token_id(&new_chip.public_key(&e)), token_id);
+    assert_eq!(client.token_id_opt(&broken_chip.public_key(&e)), None);
+    assert_eq!(client.owner_of(&token_id), alice);
+
+    // The old chip no longer authorizes a transfer of the token
+    let (message, signature, recovery_id, public_key) = broken_chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 3);
+    let result = client.try_chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidSignature.into())));
+
+    // The new chip starts fresh
+    assert_eq!(client.get_nonce(&new_chip.public_key(&e)), 0);
+    let (message, signature, recovery_id, public_key) = new_chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &bob, 1);
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &Some(recovery_id), &public_key, &1, &0);
+    assert_eq!(client.owner_of(&token_id), bob);
+}
+
+#[test]
+fn test_approve_for_all() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chips = [SoftwareChip::new(1), SoftwareChip::new(2), SoftwareChip::new(3)];
+    let token_ids = chips.each_ref().map(|chip| {
+        let token_id = chip.mint(&e, &client, 1);
+        chip.claim(&e, &client, &alice, 2);
+        token_id
+    });
+    assert!(!client.is_approved_for_all(&alice, &operator));
+
+    let live_until_ledger = e.ledger().sequence() + 10;
+    client.approve_for_all(&alice, &operator, &live_until_ledger);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::ApproveForAll { owner: alice.clone(), operator: operator.clone(), live_until_ledger }
+            .to_xdr(&e, &client.address)]
+    );
+    assert!(client.is_approved_for_all(&alice, &operator));
+    assert!(!client.is_approved_for_all(&bob, &operator));
+
+    // One blanket approval covers several tokens
+    client.transfer_from(&operator, &alice, &bob, &token_ids[0]);
+    client.transfer_from(&operator, &alice, &bob, &token_ids[1]);
+    assert_eq!(client.owner_of(&token_ids[0]), bob);
+    assert_eq!(client.owner_of(&token_ids[1]), bob);
+    assert_eq!(client.balance(&bob), 2);
+
+    // Expired after live_until_ledger
+    e.ledger().with_mut(|ledger| ledger.sequence_number = live_until_ledger + 1);
+    assert!(!client.is_approved_for_all(&alice, &operator));
+    let result = client.try_transfer_from(&operator, &alice, &bob, &token_ids[2]);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::Unauthorized.into())));
+
+    // A past ledger revokes
+    client.approve_for_all(&alice, &operator, &(live_until_ledger + 100));
+    assert!(client.is_approved_for_all(&alice, &operator));
+    client.approve_for_all(&alice, &operator, &0u32);
+    assert!(!client.is_approved_for_all(&alice, &operator));
+}
+
+#[test]
+fn test_approve_operator_limited() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chips = [SoftwareChip::new(1), SoftwareChip::new(2), SoftwareChip::new(3)];
+    let token_ids = chips.each_ref().map(|chip| {
+        let token_id = chip.mint(&e, &client, 1);
+        chip.claim(&e, &client, &alice, 2);
+        token_id
+    });
+
+    let live_until_ledger = e.ledger().sequence() + 10;
+    client.approve_operator_limited(&alice, &operator, &2, &live_until_ledger);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::ApproveOperatorLimited { owner: alice.clone(), operator: operator.clone(), remaining: 2, live_until_ledger }
+            .to_xdr(&e, &client.address)]
+    );
+    assert!(client.is_approved_for_all(&alice, &operator));
+    assert_eq!(client.operator_allowance(&alice, &operator), Some(2));
+
+    // Each transfer uses up one, the approval is gone after the last one
+    client.transfer_from(&operator, &alice, &bob, &token_ids[0]);
+    assert_eq!(client.operator_allowance(&alice, &operator), Some(1));
+    client.transfer_from(&operator, &alice, &bob, &token_ids[1]);
+    assert_eq!(client.balance(&bob), 2);
+    assert_eq!(client.operator_allowance(&alice, &operator), None);
+    assert!(!client.is_approved_for_all(&alice, &operator));
+    let result = client.try_transfer_from(&operator, &alice, &bob, &token_ids[2]);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::Unauthorized.into())));
+
+    // Expired after live_until_ledger
+    client.approve_operator_limited(&alice, &operator, &5, &live_until_ledger);
+    e.ledger().with_mut(|ledger| ledger.sequence_number = live_until_ledger + 1);
+    assert_eq!(client.operator_allowance(&alice, &operator), None);
+    let result = client.try_transfer_from(&operator, &alice, &bob, &token_ids[2]);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::Unauthorized.into())));
+
+    // An unlimited approval replaces the limited one, a count of 0 revokes
+    client.approve_operator_limited(&alice, &operator, &1, &(live_until_ledger + 100));
+    client.approve_for_all(&alice, &operator, &(live_until_ledger + 100));
+    assert_eq!(client.operator_allowance(&alice, &operator), None);
+    assert!(client.is_approved_for_all(&alice, &operator));
+    client.approve_operator_limited(&alice, &operator, &0, &(live_until_ledger + 100));
+    assert!(!client.is_approved_for_all(&alice, &operator));
+}
+
+#[test]
+fn test_token_uri_base_with_trailing_slash() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let address = e.register(
+        StellarMerchShop,
+        (
+            &admin,
+            &String::from_str(&e, "TestNFT"),
+            &String::from_str(&e, "TNFT"),
+            &String::from_str(&e, "ipfs://abcd/"),
+            &10_000u64,
+            &false,
+            &false,
+        ),
+    );
+    let client = StellarMerchShopClient::new(&e, &address);
+    let token_id = SoftwareChip::new(1).mint(&e, &client, 1);
+
+    assert_eq!(client.token_uri(&token_id), String::from_str(&e, "ipfs://abcd/0"));
+}
+
+#[test]
+fn test_set_base_uri() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let token_id = SoftwareChip::new(1).mint(&e, &client, 1);
+    assert_eq!(client.base_uri(), String::from_str(&e, "ipfs://abcd"));
+
+    client.set_base_uri(&String::from_str(&e, "ipfs://efgh"));
+    assert_eq!(client.base_uri(), String::from_str(&e, "ipfs://efgh"));
+    assert_eq!(client.token_uri(&token_id), String::from_str(&e, "ipfs://efgh/0"));
+
+    // Length boundary
+    let uri = |length: usize| String::from_str(&e, &"a".repeat(length));
+    let result = client.try_set_base_uri(&uri(201));
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::BaseUriMaxLenExceeded.into())));
+    client.set_base_uri(&uri(200));
+    assert_eq!(client.base_uri(), uri(200));
+}
+
+#[test]
+fn test_token_uri_override() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let special = SoftwareChip::new(1).mint(&e, &client, 1);
+    let regular = SoftwareChip::new(2).mint(&e, &client, 1);
+
+    let uri = String::from_str(&e, "ipfs://signed-edition.json");
+    client.set_token_uri(&special, &uri);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::MetadataUpdate { token_id: special }.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.token_uri(&special), uri);
+    assert_eq!(client.token_uri(&regular), String::from_str(&e, "ipfs://abcd/1"));
+
+    // The override takes precedence over a new base URI
+    client.set_base_uri(&String::from_str(&e, "ipfs://efgh"));
+    assert_eq!(client.token_uri(&special), uri);
+    assert_eq!(client.token_uri(&regular), String::from_str(&e, "ipfs://efgh/1"));
+
+    let result = client.try_set_token_uri(&special, &String::from_str(&e, ""));
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidUri.into())));
+    let result = client.try_set_token_uri(&2, &uri);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+
+    client.clear_token_uri(&special);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::MetadataUpdate { token_id: special }.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.token_uri(&special), String::from_str(&e, "ipfs://efgh/0"));
+}
+
+// Decode a `data:application/json;base64,` URI back to its JSON document
+fn decode_data_uri(uri: &String) -> std::string::String {
+    let mut bytes = std::vec![0u8; uri.len() as usize];
+    uri.copy_into_slice(&mut bytes);
+    let encoded = bytes.strip_prefix(b"data:application/json;base64,").expect("not a JSON data URI");
+
+    let alphabet = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut decoded = std::vec::Vec::new();
+    for group in encoded.chunks(4) {
+        let sextets: std::vec::Vec<u32> = group
+            .iter()
+            .take_while(|&&c| c != b'=')
+            .map(|c| alphabet.iter().position(|a| a == c).unwrap() as u32)
+            .collect();
+        let triple = sextets.iter().enumerate().fold(0, |triple, (i, sextet)| triple | sextet << (18 - 6 * i));
+        decoded.extend_from_slice(&triple.to_be_bytes()[1..sextets.len()]);
+    }
+    std::string::String::from_utf8(decoded).unwrap()
+}
+
+#[test]
+fn test_onchain_metadata() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client_with_options(&e, &admin, 10_000, false, true);
+    let token_id = SoftwareChip::new(1).mint(&e, &client, 1);
+    client.set_attribute(&token_id, &String::from_str(&e, "size"), &String::from_str(&e, "M"));
+    client.set_attribute(&token_id, &String::from_str(&e, "print"), &String::from_str(&e, "say \"gm\"\\\n"));
+
+    assert_eq!(
+        decode_data_uri(&client.token_uri(&token_id)),
+        r#"{"name":"TestNFT #0","description":"TestNFT","image":"ipfs://abcd/0","attributes":[{"trait_type":"print","value":"say \"gm\"\\\u000a"},{"trait_type":"size","value":"M"}]}"#
+    );
+
+    // Tokens of other series are described by their series, overrides become the image
+    client.create_series(&1, &String::from_str(&e, "Summer 2026"), &String::from_str(&e, "ipfs://summer"), &10);
+    let (message, signature, recovery_id, public_key) = SoftwareChip::new(2).sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+    let token_id = client.mint(&1, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None);
+    client.set_token_uri(&token_id, &String::from_str(&e, "ipfs://signed-edition.png"));
+    assert_eq!(
+        decode_data_uri(&client.token_uri(&token_id)),
+        r#"{"name":"TestNFT #1","description":"Summer 2026","image":"ipfs://signed-edition.png","attributes":[]}"#
+    );
+
+    // Names are capped so the document stays bounded
+    let name = |length: usize| String::from_str(&e, &"a".repeat(length));
+    client.create_series(&2, &name(64), &String::from_str(&e, "ipfs://long"), &10);
+    let result = client.try_create_series(&3, &name(65), &String::from_str(&e, "ipfs://long"), &10);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NameTooLong.into())));
+}
+
+#[test]
+fn test_claim_deadline() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let other_chip = SoftwareChip::new(2);
+    let claimed = chip.mint(&e, &client, 1);
+    let unclaimed = other_chip.mint(&e, &client, 1);
+
+    assert_eq!(client.claim_deadline(), 0);
+    let result = client.try_reclaim_unclaimed(&unclaimed);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ClaimWindowOpen.into())));
+
+    client.set_claim_deadline(&1_717_200_000);
+    assert_eq!(client.claim_deadline(), 1_717_200_000);
+
+    // Claims are accepted up to the deadline included
+    e.ledger().set_timestamp(1_717_200_000);
+    chip.claim(&e, &client, &alice, 2);
+    let result = client.try_reclaim_unclaimed(&unclaimed);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ClaimWindowOpen.into())));
+
+    e.ledger().set_timestamp(1_717_200_001);
+    let (message, signature, recovery_id, public_key) = other_chip.sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &alice, 2);
+    let result = client.try_claim(&alice, &message, &signature, &Some(recovery_id), &public_key, &2, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::ClaimWindowClosed.into())));
+
+    // Only unclaimed tokens can be reclaimed
+    let result = client.try_reclaim_unclaimed(&claimed);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenAlreadyClaimed.into())));
+    assert_eq!(client.owner_of(&claimed), alice);
+
+    client.reclaim_unclaimed(&unclaimed);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::Reclaim { token_id: unclaimed, public_key: public_key.clone() }.to_xdr(&e, &client.address)]
+    );
+    assert!(!client.exists(&unclaimed));
+    assert_eq!(client.token_id_opt(&public_key), None);
+    assert_eq!(client.total_supply(), 1);
+
+    // The chip is free for the next drop, with a fresh nonce
+    client.set_claim_deadline(&0);
+    let (message, signature, recovery_id, _) = other_chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+    let token_id = other_chip.mint(&e, &client, 2);
+    assert_eq!(other_chip.claim(&e, &client, &alice, 3), token_id);
+}
+
+#[test]
+fn test_paid_claim() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let payment_token = e.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token = soroban_sdk::token::Client::new(&e, &payment_token);
+    soroban_sdk::token::StellarAssetClient::new(&e, &payment_token).mint(&alice, &250);
+
+    let chips: std::vec::Vec<SoftwareChip> = (1..=3).map(SoftwareChip::new).collect();
+    for chip in chips.iter() {
+        chip.mint(&e, &client, 1);
+    }
+
+    // Free by default
+    assert_eq!(client.price(&0), (client.address.clone(), 0));
+    chips[0].claim(&e, &client, &bob, 2);
+
+    let result = client.try_set_price(&0, &payment_token, &-1);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+    client.set_price(&0, &payment_token, &100);
+    assert_eq!(client.price(&0), (payment_token.clone(), 100));
+
+    // The payment goes to the treasury, the admin by default
+    let token_id = chips[1].claim(&e, &client, &alice, 2);
+    assert_eq!(client.owner_of(&token_id), alice);
+    assert_eq!(token.balance(&alice), 150);
+    assert_eq!(token.balance(&admin), 100);
+
+    // Each paid claim issues a receipt, free ones do not
+    let receipt = Receipt {
+        token_id,
+        merchant_id: 0,
+        payer: alice.clone(),
+        payment_token: payment_token.clone(),
+        amount: 100,
+        ledger: e.ledger().sequence(),
+        timestamp: e.ledger().timestamp(),
+    };
+    assert_eq!(client.receipt(&0), Some(receipt.clone()));
+    assert_eq!(client.latest_receipt_for(&token_id), Some((0, receipt.clone())));
+    assert_eq!(client.latest_receipt_for(&0), None);
+    assert_eq!(client.receipt(&1), None);
+
+    // A claim that cannot be paid fails as a whole
+    let (message, signature, recovery_id, public_key) = chips[2].sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &carol, 2);
+    assert!(client.try_claim(&carol, &message, &signature, &Some(recovery_id), &public_key, &2, &0).is_err());
+    assert_eq!(client.owner_of_opt(&2), None);
+
+    // A failed claim does not take payment
+    let (message, signature, recovery_id, public_key) = chips[1].sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &alice, 3);
+    let result = client.try_claim(&alice, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenAlreadyMinted.into())));
+    assert_eq!(token.balance(&alice), 150);
+    assert_eq!(token.balance(&admin), 100);
+    assert_eq!(client.receipt(&1), None);
+
+    // Receipt ids keep increasing, earlier receipts stay available
+    client.set_price(&0, &payment_token, &50);
+    e.ledger().with_mut(|ledger| ledger.sequence_number += 1);
+    let token_id = chips[2].claim(&e, &client, &alice, 2);
+    assert_eq!(client.latest_receipt_for(&token_id).map(|(receipt_id, receipt)| (receipt_id, receipt.amount)), Some((1, 50)));
+    assert_eq!(client.receipt(&0), Some(receipt));
+}
+
+#[test]
+fn test_transfer_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    client.set_treasury(&treasury);
+    client.accept_treasury();
+    let payment_token = e.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token = soroban_sdk::token::Client::new(&e, &payment_token);
+    for account in [&alice, &bob, &operator] {
+        soroban_sdk::token::StellarAssetClient::new(&e, &payment_token).mint(account, &100);
+    }
+    let token_id = mint_and_claim(&e, &client, &SoftwareChip::new(1), &alice);
+
+    // Free by default
+    assert_eq!(client.transfer_fee(), (client.address.clone(), 0));
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(token.balance(&bob), 100);
+
+    let result = client.try_set_transfer_fee(&payment_token, &-1);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+    client.set_transfer_fee(&payment_token, &5);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::TransferFeeUpdated { payment_token: payment_token.clone(), amount: 5 }.to_xdr(&e, &client.address)]
+    );
+    assert_eq!(client.transfer_fee(), (payment_token.clone(), 5));
+
+    // The owner pays the treasury before the token moves
+    client.transfer(&bob, &alice, &token_id);
+    assert_eq!(client.owner_of(&token_id), alice);
+    assert_eq!(token.balance(&bob), 95);
+    assert_eq!(token.balance(&treasury), 5);
+
+    // An approved spender pays in place of the owner
+    client.approve(&alice, &operator, &token_id, &(e.ledger().sequence() + 100));
+    client.transfer_from(&operator, &alice, &bob, &token_id);
+    assert_eq!(token.balance(&alice), 100);
+    assert_eq!(token.balance(&operator), 95);
+    assert_eq!(token.balance(&treasury), 10);
+
+    // Exempt accounts and admin transfers do not pay
+    client.set_fee_exempt(&bob, &true);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::FeeExemptionUpdated { account: bob.clone(), exempt: true }.to_xdr(&e, &client.address)]
+    );
+    assert!(client.is_fee_exempt(&bob));
+    client.transfer(&bob, &alice, &token_id);
+    client.admin_transfer(&token_id, &bob, &1);
+    assert_eq!(token.balance(&alice), 100);
+    assert_eq!(token.balance(&bob), 95);
+    assert_eq!(token.balance(&treasury), 10);
+
+    client.set_fee_exempt(&bob, &false);
+    assert!(!client.is_fee_exempt(&bob));
+    client.transfer(&bob, &alice, &token_id);
+    assert_eq!(token.balance(&bob), 90);
+    assert_eq!(token.balance(&treasury), 15);
+
+    // A fee of 0 disables it
+    client.set_transfer_fee(&payment_token, &0);
+    assert_eq!(client.transfer_fee(), (client.address.clone(), 0));
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(token.balance(&alice), 100);
+    assert_eq!(token.balance(&treasury), 15);
+}
+
+#[test]
+fn test_claim_with_donation() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let charity = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let payment_token = e.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token = soroban_sdk::token::Client::new(&e, &payment_token);
+    soroban_sdk::token::StellarAssetClient::new(&e, &payment_token).mint(&alice, &1_000);
+    client.set_price(&0, &payment_token, &100);
+
+    let chips: std::vec::Vec<SoftwareChip> = (1..=3).map(SoftwareChip::new).collect();
+    for chip in chips.iter() {
+        chip.mint(&e, &client, 1);
+    }
+    let claim = |chip: &SoftwareChip, donation: i128| {
+        let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &alice, 2);
+        client.try_claim_with_donation(&alice, &message, &signature, &Some(recovery_id), &public_key, &2, &0, &donation)
+    };
+
+    // Without a donation, only the price is paid
+    let token_id = claim(&chips[0], 0).unwrap().unwrap();
+    assert_eq!(client.owner_of(&token_id), alice);
+    assert_eq!(token.balance(&alice), 900);
+    assert_eq!(client.total_donations(&payment_token), 0);
+
+    // Donations are rejected, with the claim, until a donation address is set
+    assert_eq!(claim(&chips[1], 25), Err(Ok(errors::NonFungibleTokenError::DonationAddressNotSet.into())));
+    assert_eq!(client.try_owner_of(&1), Err(Ok(errors::NonFungibleTokenError::TokenNotClaimed.into())));
+    assert_eq!(token.balance(&alice), 900);
+
+    client.set_donation_address(&Some(charity.clone()));
+    assert_eq!(client.donation_address(), Some(charity.clone()));
+    assert_eq!(claim(&chips[1], -1), Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+
+    let token_id = claim(&chips[1], 25).unwrap().unwrap();
+    assert!(e.events().all().filter_by_contract(&client.address).events().contains(
+        &events::Donation { donor: alice.clone(), token_id, payment_token: payment_token.clone(), amount: 25 }.to_xdr(&e, &client.address)
+    ));
+    assert_eq!(client.owner_of(&token_id), alice);
+    assert_eq!(token.balance(&alice), 775);
+    assert_eq!(token.balance(&charity), 25);
+    assert_eq!(token.balance(&admin), 200);
+
+    // Totals add up per token and for every claim
+    let token_id = claim(&chips[2], 50).unwrap().unwrap();
+    assert_eq!(token.balance(&charity), 75);
+    assert_eq!(client.token_donations(&1, &payment_token), 25);
+    assert_eq!(client.token_donations(&token_id, &payment_token), 50);
+    assert_eq!(client.total_donations(&payment_token), 75);
+
+    client.set_donation_address(&None);
+    assert_eq!(client.donation_address(), None);
+}
+
+#[test]
+fn test_series() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client_with_max_tokens(&e, &admin, 2);
+    let chips: std::vec::Vec<SoftwareChip> = (1..=9).map(SoftwareChip::new).collect();
+    let mint = |chip: &SoftwareChip, series_id: u32| {
+        let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+        client.try_mint(&series_id, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None)
+    };
+
+    // The constructor set up the collection as the default series
+    let default_series = client.series_info(&0);
+    assert_eq!(default_series.name, String::from_str(&e, "TestNFT"));
+    assert_eq!(default_series.base_uri, client.base_uri());
+    assert_eq!(default_series.max_tokens, 2);
+
+    let result = mint(&chips[0], 1);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SeriesNotFound.into())));
+
+    let name = String::from_str(&e, "Meridian 2025");
+    let base_uri = String::from_str(&e, "ipfs://meridian-2025");
+    client.create_series(&1, &name, &base_uri, &3);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::SeriesCreated { series_id: 1, name: name.clone(), base_uri: base_uri.clone(), max_tokens: 3 }.to_xdr(&e, &client.address)]
+    );
+    let result = client.try_create_series(&1, &name, &base_uri, &3);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SeriesAlreadyExists.into())));
+
+    // Both series mint independently, token ids are shared
+    assert_eq!(mint(&chips[0], 0), Ok(Ok(0)));
+    assert_eq!(mint(&chips[1], 1), Ok(Ok(1)));
+    assert_eq!(mint(&chips[2], 0), Ok(Ok(2)));
+    assert_eq!(client.series_of(&1), 1);
+    assert_eq!(client.series_of(&2), 0);
+    assert_eq!(client.token_uri(&1), String::from_str(&e, "ipfs://meridian-2025/1"));
+    assert_eq!(client.token_uri(&2), String::from_str(&e, "ipfs://abcd/2"));
+
+    // Each series hits its own cap
+    let result = mint(&chips[3], 0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenIDsAreDepleted.into())));
+    assert_eq!(client.remaining_supply(), 0);
+
+    let public_keys = soroban_sdk::vec![&e, chips[4].public_key(&e), chips[5].public_key(&e)];
+    assert_eq!(client.register_chips(&1, &public_keys), soroban_sdk::vec![&e, 3u64, 4]);
+    let result = mint(&chips[6], 1);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::TokenIDsAreDepleted.into())));
+    assert_eq!(client.series_info(&1).minted, 3);
+    assert_eq!(client.minted_count(), 5);
+
+    // Full register_chips batches fit the ledger limits of a series
+    client.create_series(&2, &String::from_str(&e, "Meridian 2026"), &String::from_str(&e, "ipfs://meridian-2026"), &10);
+    let public_keys: std::vec::Vec<BytesN<65>> = (10..14).map(|seed| SoftwareChip::new(seed).public_key(&e)).collect();
+    client.register_chips(&2, &soroban_sdk::Vec::from_slice(&e, &public_keys));
+    assert_eq!(client.series_of(&8), 2);
+
+    // Contracts deployed before series get their collection moved to the default series
+    e.as_contract(&client.address, || {
+        use crate::contract::{DataKey, NFTStorageKey};
+        let instance = e.storage().instance();
+        instance.remove(&crate::series::SeriesStorageKey::Series(0));
+        instance.set(&NFTStorageKey::URI, &String::from_str(&e, "ipfs://legacy"));
+        instance.set(&DataKey::MaxTokens, &2u64);
+    });
+    client.migrate();
+    let default_series = client.series_info(&0);
+    assert_eq!(default_series.base_uri, String::from_str(&e, "ipfs://legacy"));
+    assert_eq!((default_series.max_tokens, default_series.minted), (2, 2));
+    assert_eq!(client.remaining_supply(), 0);
+}
+
+#[test]
+fn test_merchants() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let admin_a = Address::generate(&e);
+    let admin_b = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let mint = |chip: &SoftwareChip, series_id: u32| {
+        let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+        client.mint(&series_id, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None)
+    };
+
+    // Deployments are their own merchant, administered by the contract admin
+    let legacy_token_id = mint(&SoftwareChip::new(1), 0);
+    assert_eq!(client.merchant_info(&0), crate::merchants::MerchantInfo { admin: admin.clone(), name: String::from_str(&e, "TestNFT") });
+    assert_eq!(client.merchant_of(&legacy_token_id), 0);
+    let result = client.try_merchant_info(&1);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::MerchantNotFound.into())));
+
+    // Merchant ids skip the series already created
+    client.create_series(&1, &String::from_str(&e, "Meridian 2025"), &String::from_str(&e, "ipfs://meridian-2025"), &10);
+    let name_a = String::from_str(&e, "Brand A");
+    let merchant_a = client.create_merchant(&admin_a, &name_a, &String::from_str(&e, "ipfs://brand-a"), &10);
+    assert_eq!(merchant_a, 2);
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::MerchantCreated { merchant_id: merchant_a, admin: admin_a.clone(), name: name_a }.to_xdr(&e, &client.address)]
+    );
+    let merchant_b = client.create_merchant(&admin_b, &String::from_str(&e, "Brand B"), &String::from_str(&e, "ipfs://brand-b"), &10);
+    assert_eq!(merchant_b, 3);
+    assert_eq!(client.series_info(&merchant_b).max_tokens, 10);
+
+    // Each merchant admin mints in its own series, token ids stay shared
+    let token_a = mint(&SoftwareChip::new(2), merchant_a);
+    assert_eq!(e.auths()[0].0, admin_a);
+    let token_b = mint(&SoftwareChip::new(3), merchant_b);
+    assert_eq!(e.auths()[0].0, admin_b);
+    assert_eq!((token_a, token_b), (1, 2));
+    assert_eq!(client.merchant_of(&token_a), merchant_a);
+    assert_eq!(client.token_uri(&token_b), String::from_str(&e, "ipfs://brand-b/2"));
+    mint(&SoftwareChip::new(4), 1);
+    assert_eq!(e.auths()[0].0, admin);
+
+    // Merchant A's admin cannot touch merchant B's namespace
+    let uri = String::from_str(&e, "ipfs://brand-a/special.json");
+    let authorized = |address: &Address, fn_name: &'static str, args: soroban_sdk::Vec<soroban_sdk::Val>| {
+        e.mock_auths(&[MockAuth {
+            address,
+            invoke: &MockAuthInvoke { contract: &client.address, fn_name, args, sub_invokes: &[] },
+        }]);
+    };
+    authorized(&admin_a, "set_token_uri", (token_b, uri.clone()).into_val(&e));
+    assert!(client.try_set_token_uri(&token_b, &uri).is_err());
+    authorized(&admin_a, "clear_token_uri", (token_b,).into_val(&e));
+    assert!(client.try_clear_token_uri(&token_b).is_err());
+    authorized(&admin_a, "set_token_royalty", (token_b, admin_a.clone(), 500u32).into_val(&e));
+    assert!(client.try_set_token_royalty(&token_b, &admin_a, &500).is_err());
+    authorized(&admin_a, "set_merchant_base_uri", (merchant_b, uri.clone()).into_val(&e));
+    assert!(client.try_set_merchant_base_uri(&merchant_b, &uri).is_err());
+    let public_keys = soroban_sdk::vec![&e, SoftwareChip::new(5).public_key(&e)];
+    authorized(&admin_a, "register_chips", (merchant_b, public_keys.clone()).into_val(&e));
+    assert!(client.try_register_chips(&merchant_b, &public_keys).is_err());
+    authorized(&admin_a, "register_chips", (0u32, public_keys.clone()).into_val(&e));
+    assert!(client.try_register_chips(&0, &public_keys).is_err());
+
+    // Nor can the contract admin
+    authorized(&admin, "set_token_uri", (token_b, uri.clone()).into_val(&e));
+    assert!(client.try_set_token_uri(&token_b, &uri).is_err());
+
+    // Its own namespace is open to it
+    authorized(&admin_a, "set_token_uri", (token_a, uri.clone()).into_val(&e));
+    client.set_token_uri(&token_a, &uri);
+    assert_eq!(client.token_uri(&token_a), uri);
+    authorized(&admin_a, "register_chips", (merchant_a, public_keys.clone()).into_val(&e));
+    assert_eq!(client.register_chips(&merchant_a, &public_keys), soroban_sdk::vec![&e, 4u64]);
+    let base_uri = String::from_str(&e, "ipfs://brand-a-v2");
+    authorized(&admin_a, "set_merchant_base_uri", (merchant_a, base_uri.clone()).into_val(&e));
+    client.set_merchant_base_uri(&merchant_a, &base_uri);
+    assert_eq!(client.token_uri(&4), String::from_str(&e, "ipfs://brand-a-v2/4"));
+
+    // Each merchant prices its tokens and the contract holds their payments
+    e.mock_all_auths();
+    let payment_token = e.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token = soroban_sdk::token::Client::new(&e, &payment_token);
+    let buyer = Address::generate(&e);
+    soroban_sdk::token::StellarAssetClient::new(&e, &payment_token).mint(&buyer, &1_000);
+    client.set_price(&merchant_b, &payment_token, &300);
+    assert_eq!(e.auths()[0].0, admin_b);
+    assert_eq!(client.price(&merchant_a), (client.address.clone(), 0));
+    assert_eq!(client.price(&0), (client.address.clone(), 0));
+    SoftwareChip::new(3).claim(&e, &client, &buyer, 2);
+    let claim = events::Claim { claimant: buyer.clone(), public_key: SoftwareChip::new(3).public_key(&e), merchant_id: merchant_b, sku: None, token_id: token_b };
+    assert!(e.events().all().filter_by_contract(&client.address).events().contains(&claim.to_xdr(&e, &client.address)));
+    assert_eq!(token.balance(&client.address), 300);
+    assert_eq!(client.merchant_balance(&merchant_b, &payment_token), 300);
+    assert_eq!(client.merchant_balance(&merchant_a, &payment_token), 0);
+    assert_eq!(client.merchant_balance(&0, &payment_token), 0);
+
+    // Merchant A's admin cannot touch merchant B's funds, nor can the contract admin
+    let withdraw_args = |merchant_id: u32, to: Option<Address>, amount: i128| (merchant_id, payment_token.clone(), to, amount).into_val(&e);
+    authorized(&admin_a, "withdraw", withdraw_args(merchant_b, Some(admin_a.clone()), 300));
+    assert!(client.try_withdraw(&merchant_b, &payment_token, &Some(admin_a.clone()), &300).is_err());
+    authorized(&admin_a, "set_price", (merchant_b, payment_token.clone(), 0i128).into_val(&e));
+    assert!(client.try_set_price(&merchant_b, &payment_token, &0).is_err());
+    authorized(&admin, "withdraw", withdraw_args(merchant_b, Some(admin.clone()), 300));
+    assert!(client.try_withdraw(&merchant_b, &payment_token, &Some(admin.clone()), &300).is_err());
+    authorized(&admin_a, "withdraw", withdraw_args(merchant_a, None, 300));
+    let result = client.try_withdraw(&merchant_a, &payment_token, &None, &300);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+    authorized(&admin, "withdraw", withdraw_args(0, None, 300));
+    let result = client.try_withdraw(&0, &payment_token, &None, &300);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidAmount.into())));
+    assert_eq!(token.balance(&client.address), 300);
+
+    // Merchant B's admin withdraws to itself by default
+    authorized(&admin_b, "withdraw", withdraw_args(merchant_b, None, 300));
+    client.withdraw(&merchant_b, &payment_token, &None, &300);
+    assert_eq!(token.balance(&admin_b), 300);
+    assert_eq!(client.merchant_balance(&merchant_b, &payment_token), 0);
+
+    // Handing a merchant over
+    let new_admin_a = Address::generate(&e);
+    authorized(&admin_a, "set_merchant_admin", (merchant_a, new_admin_a.clone()).into_val(&e));
+    client.set_merchant_admin(&merchant_a, &new_admin_a);
+    assert_eq!(client.merchant_info(&merchant_a).admin, new_admin_a);
+    authorized(&admin_a, "clear_token_uri", (token_a,).into_val(&e));
+    assert!(client.try_clear_token_uri(&token_a).is_err());
+    let result = client.mock_all_auths().try_set_merchant_admin(&0, &admin_a);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::MerchantNotFound.into())));
+}
+
+#[test]
+fn test_migrate_token_data() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    mint_and_claim(&e, &client, &chip, &owner);
+    for batch in 0..5 {
+        let public_keys: std::vec::Vec<BytesN<65>> = (0..4).map(|i| SoftwareChip::new(10 + batch * 4 + i).public_key(&e)).collect();
+        client.register_chips(&0, &soroban_sdk::Vec::from_slice(&e, &public_keys));
+    }
+    let tokens_info = client.tokens_info(&soroban_sdk::Vec::from_slice(&e, &(0..21).collect::<std::vec::Vec<u64>>()));
+
+    // Contracts deployed before TokenData keep the owner and public key apart
+    e.as_contract(&client.address, || {
+        use crate::contract::{DataKey, NFTStorageKey};
+        let storage = e.storage().persistent();
+        for token_id in 0..21u64 {
+            let token: crate::contract::TokenData = storage.get(&NFTStorageKey::Token(token_id)).unwrap();
+            storage.remove(&NFTStorageKey::Token(token_id));
+            storage.set(&NFTStorageKey::PublicKey(token_id), &token.public_key);
+            if let Some(owner) = token.owner {
+                storage.set(&NFTStorageKey::Owner(token_id), &owner);
+            }
+        }
+        e.storage().instance().remove(&DataKey::TokenMigrationCursor);
+
+        // Nor did they have the owner index
+        use crate::owner_index::OwnerIndexStorageKey;
+        storage.remove(&OwnerIndexStorageKey::TokenPosition(0));
+        storage.remove(&OwnerIndexStorageKey::HeldToken(owner.clone(), 0));
+        storage.remove(&OwnerIndexStorageKey::HeldCount(owner.clone()));
+    });
+    assert_eq!(client.tokens_of(&owner, &0, &10), soroban_sdk::vec![&e]);
+    assert_eq!(client.migration_progress(), (0, 21));
+    assert!(client.exists(&20));
+
+    // Reading a token rewrites it
+    assert_eq!(client.owner_of(&0), owner);
+    assert_eq!(client.migration_progress(), (1, 21));
+    assert_eq!(client.tokens_of(&owner, &0, &10), soroban_sdk::vec![&e, 0u64]);
+
+    // The others are rewritten in batches, skipping the ones already read
+    assert!(!client.migrate());
+    assert_eq!(client.migration_progress(), (7, 14));
+    client.tokens_info(&soroban_sdk::vec![&e, 10u64]);
+    assert_eq!(client.migration_progress(), (8, 14));
+    assert!(!client.migrate());
+    assert!(client.migrate());
+    assert_eq!(client.migration_progress(), (21, 0));
+    assert!(client.migrate());
+
+    let migrated_info = client.tokens_info(&soroban_sdk::Vec::from_slice(&e, &(0..21).collect::<std::vec::Vec<u64>>()));
+    assert_eq!(migrated_info, tokens_info);
+    e.as_contract(&client.address, || {
+        use crate::contract::NFTStorageKey;
+        assert!(!e.storage().persistent().has(&NFTStorageKey::PublicKey(0)));
+        assert!(!e.storage().persistent().has(&NFTStorageKey::Owner(0)));
+    });
+    assert_eq!(client.tokens_of(&owner, &0, &10), soroban_sdk::vec![&e, 0u64]);
+
+    // Migrated tokens keep working
+    let (message, signature, recovery_id, public_key) = chip.sign_for(&e, ChipOperation::Transfer, &client.address, TEST_MESSAGE, &recipient, 3);
+    client.chip_transfer(&owner, &recipient, &0, &message, &signature, &Some(recovery_id), &public_key, &3, &0);
+    assert_eq!(client.owner_of(&0), recipient);
+    assert_eq!(client.tokens_of(&owner, &0, &10), soroban_sdk::vec![&e]);
+    assert_eq!(client.tokens_of(&recipient, &0, &10), soroban_sdk::vec![&e, 0u64]);
+}
+
+#[test]
+fn test_attributes() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let token_id = chip.mint(&e, &client, 1);
+    let size = String::from_str(&e, "size");
+    let color = String::from_str(&e, "color");
+
+    assert_eq!(client.get_attribute(&token_id, &size), None);
+    assert_eq!(client.attributes(&token_id), Map::new(&e));
+
+    client.set_attribute(&token_id, &size, &String::from_str(&e, "M"));
+    assert_eq!(
+        e.events().all().filter_by_contract(&client.address),
+        [events::MetadataUpdate { token_id }.to_xdr(&e, &client.address)]
+    );
+    client.set_attribute(&token_id, &color, &String::from_str(&e, "black"));
+
+    // Setting a key again overwrites its value
+    client.set_attribute(&token_id, &size, &String::from_str(&e, "L"));
+    assert_eq!(client.get_attribute(&token_id, &size), Some(String::from_str(&e, "L")));
+    let attributes = client.attributes(&token_id);
+    assert_eq!(attributes.len(), 2);
+    assert_eq!(attributes.get(color.clone()), Some(String::from_str(&e, "black")));
+
+    let too_long = String::from_bytes(&e, &[b'a'; MAX_ATTRIBUTE_VALUE_LENGTH as usize + 1]);
+    let result = client.try_set_attribute(&token_id, &size, &too_long);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::AttributeTooLong.into())));
+    let result = client.try_set_attribute(&token_id, &too_long, &String::from_str(&e, "L"));
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::AttributeTooLong.into())));
+
+    let result = client.try_set_attribute(&7, &size, &String::from_str(&e, "L"));
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+
+    // Burning the token drops its attributes
+    chip.claim(&e, &client, &alice, 2);
+    client.burn_from_owner(&alice, &token_id);
+    let result = client.try_attributes(&token_id);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonExistentToken.into())));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #211)")]
+fn test_constructor_base_uri_too_long() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    e.register(
+        StellarMerchShop,
+        (
+            &admin,
+            &String::from_str(&e, "TestNFT"),
+            &String::from_str(&e, "TNFT"),
+            &String::from_str(&e, &"a".repeat(201)),
+            &10_000u64,
+            &false,
+            &false,
+        ),
+    );
+}
+
+#[test]
+fn test_nonce_semantics() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chips = [SoftwareChip::new(1), SoftwareChip::new(2)];
+    let mint = |chip: &SoftwareChip, nonce: u64| {
+        let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, nonce);
+        client.try_mint(&0, &message, &signature, &Some(recovery_id), &public_key, &nonce, &0, &None, &None)
+    };
+
+    // First use: nothing consumed yet, nonce 0 is already taken
+    assert_eq!(client.get_nonce(&chips[0].public_key(&e)), 0u64);
+    let result = mint(&chips[0], 0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+    assert!(mint(&chips[0], client.get_nonce(&chips[0].public_key(&e)) + 1).is_ok());
+    assert_eq!(client.get_nonce(&chips[0].public_key(&e)), 1u64);
+
+    // Exact replay of the consumed nonce
+    let result = mint(&chips[0], 1);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+
+    // Skipping ahead by more than one is fine without a window
+    assert!(mint(&chips[1], 5).is_ok());
+    assert_eq!(client.get_nonce(&chips[1].public_key(&e)), 5u64);
+
+    // Nonces skipped over can't be used afterwards
+    let claimant = Address::generate(&e);
+    let (message, signature, recovery_id, public_key) = chips[1].sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &claimant, 4);
+    let result = client.try_claim(&claimant, &message, &signature, &Some(recovery_id), &public_key, &4, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+}
+
+#[test]
+fn test_signature_error_codes() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let chip = SoftwareChip::new(1);
+    let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+
+    // Recovery ID out of range is rejected before recovering
+    let result = client.try_mint(&0, &message, &signature, &Some(4), &public_key, &1, &0, &None, &None);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::InvalidRecoveryId.into())));
+
+    // Wrong recovery ID recovers another key
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id ^ 1), &public_key, &1, &0, &None, &None);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+
+    // Signature from another chip
+    let other_public_key = SoftwareChip::new(2).public_key(&e);
+    let result = client.try_mint(&0, &message, &signature, &Some(recovery_id), &other_public_key, &1, &0, &None, &None);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+
+    // Stale nonce
+    client.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None);
+    let claimant = Address::generate(&e);
+    let result = client.try_claim(&claimant, &message, &signature, &Some(recovery_id), &public_key, &1, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::NonceTooLow.into())));
+}
+
+#[test]
+fn test_mint_with_raw_chip_signature() {
+    assert!(TEST_SIGNATURES.iter().any(|sig| !merch_crypto::is_low_s(&sig.sig_s)));
+
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Raw (r, s) straight from the chip, high S ones need the other recovery ID parity
+    let mut items = soroban_sdk::Vec::new(&e);
+    for sig in TEST_SIGNATURES {
+        let message_hash = calculate_message_hash(&e, sig.message, sig.nonce);
+        let (_, low_s_recovery_id) = create_test_signature_and_recovery_id(&e, &message_hash, sig);
+        let recovery_id = if merch_crypto::is_low_s(&sig.sig_s) { low_s_recovery_id } else { low_s_recovery_id ^ 1 };
+        let mut item = verify_item(&e, sig);
+        item.signature = raw_signature(&e, sig);
+        item.recovery_id = Some(recovery_id);
+        items.push_back(item);
+    }
+    assert!(client.verify_batch(&items).iter().all(|is_valid| is_valid));
+
+    // Mint normalizes them the same way
+    let (message, signature, recovery_id, public_key) = SoftwareChip::new(1).sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+    let (signature, recovery_id) = high_s_signature(&e, &signature, recovery_id);
+    let token_id = client.mint(&0, &message, &signature, &Some(recovery_id), &public_key, &1, &0, &None, &None);
+    assert_eq!(client.public_key(&token_id), public_key);
+}
+
+// Helper returning the (r, s) of a test signature as the chip emits it
+fn raw_signature(e: &Env, sig: &TestSignature) -> BytesN<64> {
+    let mut sig_bytes = [0u8; 64];
+    sig_bytes[..32].copy_from_slice(&sig.sig_r);
+    sig_bytes[32..].copy_from_slice(&sig.sig_s);
+    BytesN::from_array(e, &sig_bytes)
+}
+
+// Helper substituting n - s for s, as the chip may emit, which flips the recovery ID parity
+fn high_s_signature(e: &Env, signature: &BytesN<64>, recovery_id: u32) -> (BytesN<64>, u32) {
+    let signature = k256::ecdsa::Signature::from_slice(&signature.to_array()).unwrap();
+    let (r, s) = signature.split_scalars();
+    let signature = k256::ecdsa::Signature::from_scalars(r, -s).unwrap();
+    (BytesN::from_array(e, &signature.to_bytes().into()), recovery_id ^ 1)
+}
+
+// Helper encoding R and S the way the chip does: 0x30 [len] 0x02 [len] [R] 0x02 [len] [S]
+fn der_encode(e: &Env, sig_r: &[u8; 32], sig_s: &[u8; 32]) -> Bytes {
+    let mut integers = Vec::new();
+    for value in [sig_r, sig_s] {
+        let first = value.iter().position(|&byte| byte != 0).unwrap_or(31);
+        let mut integer = value[first..].to_vec();
+        if integer[0] & 0x80 != 0 {
+            integer.insert(0, 0x00);
+        }
+        integers.push(0x02);
+        integers.push(integer.len() as u8);
+        integers.extend_from_slice(&integer);
+    }
+    let mut der = Vec::from([0x30, integers.len() as u8]);
+    der.extend_from_slice(&integers);
+    Bytes::from_slice(e, &der)
+}
+
+#[test]
+fn test_der_signatures() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // The chip DER encodes high and low S signatures alike
+    for seed in 1..=2 {
+        let chip = SoftwareChip::new(seed);
+        let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+        let (signature, _) = if seed == 1 { high_s_signature(&e, &signature, recovery_id) } else { (signature, recovery_id) };
+        let signature = signature.to_array();
+        let der_signature = der_encode(&e, signature[..32].try_into().unwrap(), signature[32..].try_into().unwrap());
+        let token_id = client.mint_der(&0, &message, &der_signature, &public_key, &1, &0, &None, &None);
+        assert_eq!(client.public_key(&token_id), public_key);
+    }
+
+    let chip = SoftwareChip::new(1);
+    let token_id = client.token_id(&chip.public_key(&e));
+    let der_arguments = |chip: &SoftwareChip, operation: ChipOperation, recipient: &Address, nonce: u64| {
+        let (message, signature, _, public_key) = chip.sign_for(&e, operation, &client.address, TEST_MESSAGE, recipient, nonce);
+        let signature = signature.to_array();
+        let der_signature = der_encode(&e, signature[..32].try_into().unwrap(), signature[32..].try_into().unwrap());
+        (message, der_signature, public_key)
+    };
+
+    let (message, der_signature, public_key) = der_arguments(&chip, ChipOperation::Claim, &alice, 2);
+    assert_eq!(client.claim_der(&alice, &message, &der_signature, &public_key, &2, &0), token_id);
+    assert_eq!(client.owner_of(&token_id), alice);
+
+    let (message, der_signature, public_key) = der_arguments(&chip, ChipOperation::Transfer, &bob, 3);
+    client.chip_transfer_der(&alice, &bob, &token_id, &message, &der_signature, &public_key, &3, &0);
+    assert_eq!(client.owner_of(&token_id), bob);
+
+    // Malformed DER
+    let (message, der_signature, public_key) = der_arguments(&chip, ChipOperation::Transfer, &alice, 4);
+    for malformed in [
+        Bytes::from_slice(&e, &[0x31, 0x00]),
+        der_signature.slice(..der_signature.len() - 1),
+        Bytes::from_array(&e, &[0x30; 73]),
+    ] {
+        let result = client.try_chip_transfer_der(&bob, &alice, &token_id, &message, &malformed, &public_key, &4, &0);
+        assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::MalformedSignature.into())));
+    }
+
+    // Valid DER from another chip
+    let (_, other_der_signature, _) = der_arguments(&SoftwareChip::new(2), ChipOperation::Transfer, &alice, 4);
+    let result = client.try_chip_transfer_der(&bob, &alice, &token_id, &message, &other_der_signature, &public_key, &4, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+}
+
+#[test]
+fn test_recovery_id_detection() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let claimant = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Raw chip signatures without recovery ID
+    let mut items = soroban_sdk::Vec::new(&e);
+    for sig in TEST_SIGNATURES {
+        let mut item = verify_item(&e, sig);
+        item.signature = raw_signature(&e, sig);
+        item.recovery_id = None;
+        items.push_back(item);
+    }
+    assert!(client.verify_batch(&items).iter().all(|is_valid| is_valid));
+
+    let chip = SoftwareChip::new(1);
+    let (message, signature, recovery_id, public_key) = chip.sign(&e, ChipOperation::Mint, &client.address, TEST_MESSAGE, 1);
+    let (signature, _) = high_s_signature(&e, &signature, recovery_id);
+    let preview = client.simulate_mint(&0, &message, &signature, &None, &public_key, &1, &0);
+    assert!(preview.would_succeed);
+    let token_id = client.mint(&0, &message, &signature, &None, &public_key, &1, &0, &None, &None);
+    assert_eq!(client.public_key(&token_id), public_key);
+
+    // Signature from another chip recovers to neither candidate
+    let (message, signature, _, _) = SoftwareChip::new(2).sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &claimant, 2);
+    let result = client.try_claim(&claimant, &message, &signature, &None, &chip.public_key(&e), &2, &0);
+    assert_eq!(result, Err(Ok(errors::NonFungibleTokenError::SignatureRecoveryMismatch.into())));
+
+    let (message, signature, _, public_key) = chip.sign_for(&e, ChipOperation::Claim, &client.address, TEST_MESSAGE, &claimant, 2);
+    assert_eq!(client.claim(&claimant, &message, &signature, &None, &public_key, &2, &0), token_id);
+    let verification = client.verify_product(&Bytes::from_slice(&e, b"challenge"), &signature, &None, &public_key);
+    assert!(!verification.is_genuine);
+}
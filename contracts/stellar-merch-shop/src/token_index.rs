@@ -0,0 +1,121 @@
+//! Index of every token in circulation, for enumeration
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Env, Vec};
+use crate::{errors, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Maximum number of token ids returned by one `all_tokens` call
+pub const MAX_ALL_TOKENS_LIMIT: u32 = 100;
+
+#[contracttype]
+pub enum TokenIndexStorageKey {
+    TokenCount,
+    IndexedToken(u64),
+    TokenIndex(u64),
+}
+
+pub trait TokenIndex {
+    /// Returns the token id at `index`, between 0 and `total_supply` excluded.
+    ///
+    /// The index is kept compact: burning a token moves the last token to
+    /// the index of the burned one, so indices are not stable across burns.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `index` - Index of the token.
+    fn token_by_index(e: &Env, index: u64) -> u64;
+
+    /// Returns up to `limit` token ids in circulation, starting at index
+    /// `start`. Burned tokens are not listed.
+    ///
+    /// `limit` is capped to `MAX_ALL_TOKENS_LIMIT`, and a `start` past the
+    /// last token returns an empty list.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `start` - Index of the first token id to return.
+    /// * `limit` - Maximum number of token ids to return.
+    fn all_tokens(e: &Env, start: u64, limit: u32) -> Vec<u64>;
+}
+
+#[contractimpl]
+impl TokenIndex for StellarMerchShop {
+    fn token_by_index(e: &Env, index: u64) -> u64 {
+        e.storage()
+            .persistent()
+            .get(&TokenIndexStorageKey::IndexedToken(index))
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NonExistentToken))
+    }
+
+    fn all_tokens(e: &Env, start: u64, limit: u32) -> Vec<u64> {
+        let count = token_count(e);
+        let end = start.saturating_add(limit.min(MAX_ALL_TOKENS_LIMIT) as u64).min(count);
+
+        let mut token_ids = Vec::new(e);
+        for index in start..end {
+            token_ids.push_back(Self::token_by_index(e, index));
+        }
+        token_ids
+    }
+}
+
+fn token_count(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&TokenIndexStorageKey::TokenCount)
+        .unwrap_or(0)
+}
+
+/// Append the minted token_id to the index
+pub(crate) fn add_token(e: &Env, token_id: u64) {
+    let count = token_count(e);
+    let next_count = count
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+    e.storage()
+        .persistent()
+        .set(&TokenIndexStorageKey::IndexedToken(count), &token_id);
+    e.storage()
+        .persistent()
+        .set(&TokenIndexStorageKey::TokenIndex(token_id), &count);
+    e.storage()
+        .instance()
+        .set(&TokenIndexStorageKey::TokenCount, &next_count);
+}
+
+/// Remove the burned token_id from the index, the last token takes its index
+pub(crate) fn remove_token(e: &Env, token_id: u64) {
+    let index: u64 = e
+        .storage()
+        .persistent()
+        .get(&TokenIndexStorageKey::TokenIndex(token_id))
+        .unwrap();
+    let last = token_count(e)
+        .checked_sub(1)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow));
+
+    if index != last {
+        let last_token_id: u64 = e
+            .storage()
+            .persistent()
+            .get(&TokenIndexStorageKey::IndexedToken(last))
+            .unwrap();
+        e.storage()
+            .persistent()
+            .set(&TokenIndexStorageKey::IndexedToken(index), &last_token_id);
+        e.storage()
+            .persistent()
+            .set(&TokenIndexStorageKey::TokenIndex(last_token_id), &index);
+    }
+
+    e.storage()
+        .persistent()
+        .remove(&TokenIndexStorageKey::IndexedToken(last));
+    e.storage()
+        .persistent()
+        .remove(&TokenIndexStorageKey::TokenIndex(token_id));
+    e.storage()
+        .instance()
+        .set(&TokenIndexStorageKey::TokenCount, &last);
+}
@@ -0,0 +1,161 @@
+//! Rent of the contract's storage entries, extended before they get archived
+
+use soroban_sdk::{contractimpl, contracttype, BytesN, Env, IntoVal, Val};
+use crate::attributes::AttributeStorageKey;
+use crate::contract::NFTStorageKey;
+use crate::series::SeriesStorageKey;
+use crate::{NFCtoNFTContract, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+/// Number of ledgers the entries of a freshly minted token live for (about 30 days)
+pub const MINT_TTL_LEDGERS: u32 = 518_400;
+
+/// Remaining lifetime under which an entry read or written is extended (about 7 days)
+pub const TTL_THRESHOLD_LEDGERS: u32 = 120_960;
+
+/// Number of ledgers an entry lives for once extended (about 30 days)
+pub const TTL_EXTEND_LEDGERS: u32 = 518_400;
+
+#[contracttype]
+pub enum TokenTtlStorageKey {
+    /// Live-until ledger of the token's entries, tracked because the TTL
+    /// cannot be read on-chain
+    TokenLiveUntil(u64),
+}
+
+pub trait TokenTtl {
+    /// Returns the ledger until which the entries of `token_id` token are live,
+    /// as of their last extension.
+    ///
+    /// After that ledger the entries are archived and must be restored before
+    /// the token can be used again.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn token_ttl(e: &Env, token_id: u64) -> u32;
+
+    /// Extends the entries of `token_id` token so they live for at least
+    /// `extend_to` more ledgers. Anyone can pay for the extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `extend_to` - Number of ledgers the entries must live from now on.
+    fn extend_token_ttl(e: &Env, token_id: u64, extend_to: u32);
+
+    /// Extends the entries of `token_id` token, and the balance of its
+    /// owner, to live for `TTL_EXTEND_LEDGERS` more ledgers. Anyone can pay
+    /// for the extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn bump_token(e: &Env, token_id: u64);
+
+    /// Extends the entries of a chip, its registration and nonce, to live
+    /// for `TTL_EXTEND_LEDGERS` more ledgers. Anyone can pay for the
+    /// extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The chip's public key (uncompressed SEC1 format, 65 bytes).
+    fn bump_chip(e: &Env, public_key: BytesN<65>);
+
+    /// Extends the contract instance and its configuration to live for
+    /// `TTL_EXTEND_LEDGERS` more ledgers. Anyone can pay for the extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn extend_instance_ttl(e: &Env);
+}
+
+#[contractimpl]
+impl TokenTtl for StellarMerchShop {
+    fn token_ttl(e: &Env, token_id: u64) -> u32 {
+        // Verify the token exists (this will panic if it doesn't)
+        StellarMerchShop::public_key(e, token_id);
+
+        e.storage()
+            .persistent()
+            .get(&TokenTtlStorageKey::TokenLiveUntil(token_id))
+            .unwrap_or(0u32)
+    }
+
+    fn extend_token_ttl(e: &Env, token_id: u64, extend_to: u32) {
+        let public_key = StellarMerchShop::public_key(e, token_id);
+        extend_token_entries(e, token_id, &public_key, extend_to);
+
+        // Entries only some tokens carry, kept out of the mint footprint
+        let storage = e.storage().persistent();
+        if storage.has(&AttributeStorageKey::Attributes(token_id)) {
+            storage.extend_ttl(&AttributeStorageKey::Attributes(token_id), extend_to, extend_to);
+        }
+        if storage.has(&SeriesStorageKey::TokenSeries(token_id)) {
+            storage.extend_ttl(&SeriesStorageKey::TokenSeries(token_id), extend_to, extend_to);
+        }
+        if storage.has(&NFTStorageKey::TokenURI(token_id)) {
+            storage.extend_ttl(&NFTStorageKey::TokenURI(token_id), extend_to, extend_to);
+        }
+    }
+
+    fn bump_token(e: &Env, token_id: u64) {
+        Self::extend_token_ttl(e, token_id, TTL_EXTEND_LEDGERS);
+
+        if let Some(owner) = StellarMerchShop::owner_of_opt(e, token_id) {
+            e.storage()
+                .persistent()
+                .extend_ttl(&NFTStorageKey::Balance(owner), TTL_EXTEND_LEDGERS, TTL_EXTEND_LEDGERS);
+        }
+    }
+
+    fn bump_chip(e: &Env, public_key: BytesN<65>) {
+        let storage = e.storage().persistent();
+        for key in [
+            NFTStorageKey::TokenIdByPublicKey(public_key.clone()),
+            NFTStorageKey::ChipNonceByPublicKey(public_key),
+        ] {
+            if storage.has(&key) {
+                storage.extend_ttl(&key, TTL_EXTEND_LEDGERS, TTL_EXTEND_LEDGERS);
+            }
+        }
+    }
+
+    fn extend_instance_ttl(e: &Env) {
+        e.storage()
+            .instance()
+            .extend_ttl(TTL_EXTEND_LEDGERS, TTL_EXTEND_LEDGERS);
+    }
+}
+
+/// Extend an existing persistent entry that is read or written once it gets close to archival
+pub(crate) fn extend_entry<K: IntoVal<Env, Val>>(e: &Env, key: &K) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+/// Extend every persistent entry of `token_id` to live `extend_to` more ledgers
+pub(crate) fn extend_token_entries(e: &Env, token_id: u64, public_key: &BytesN<65>, extend_to: u32) {
+    let storage = e.storage().persistent();
+
+    storage.extend_ttl(&NFTStorageKey::Token(token_id), extend_to, extend_to);
+    storage.extend_ttl(&NFTStorageKey::TokenIdByPublicKey(public_key.clone()), extend_to, extend_to);
+    // Chips registered without a signature have not consumed a nonce yet
+    if storage.has(&NFTStorageKey::ChipNonceByPublicKey(public_key.clone())) {
+        storage.extend_ttl(&NFTStorageKey::ChipNonceByPublicKey(public_key.clone()), extend_to, extend_to);
+    }
+
+    // Entries are never shortened, keep the furthest live-until ledger
+    let live_until_key = TokenTtlStorageKey::TokenLiveUntil(token_id);
+    let live_until = storage
+        .get::<_, u32>(&live_until_key)
+        .unwrap_or(0)
+        .max(e.ledger().sequence().saturating_add(extend_to));
+    storage.set(&live_until_key, &live_until);
+    storage.extend_ttl(&live_until_key, extend_to, extend_to);
+}
@@ -0,0 +1,193 @@
+//! Treasury destination of collected funds, rotated in two steps
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, token, Address, Env};
+use crate::merchants::{self, DEFAULT_MERCHANT_ID};
+use crate::{contract, errors, events, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+pub enum TreasuryStorageKey {
+    Treasury,
+    PendingTreasury,
+    /// Funds of a merchant created with create_merchant, held by the contract
+    MerchantBalance(u32, Address),
+    /// Sum of the MerchantBalance of every merchant for a token
+    HeldBalance(Address),
+}
+
+pub trait Treasury {
+    /// Proposes `new_treasury` as the destination of collected funds.
+    ///
+    /// The change only takes effect once `new_treasury` calls
+    /// `accept_treasury`. Proposing again replaces the pending address.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `new_treasury` - Account proposed as the treasury.
+    fn set_treasury(e: &Env, new_treasury: Address);
+
+    /// Accepts the pending treasury proposal.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["treasury_changed", previous_treasury: Address, treasury: Address]`
+    /// * data - `[]`
+    fn accept_treasury(e: &Env);
+
+    /// Returns the treasury, which defaults to the admin until one is accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn treasury(e: &Env) -> Address;
+
+    /// Returns the proposed treasury awaiting acceptance, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn pending_treasury(e: &Env) -> Option<Address>;
+
+    /// Sends `amount` of `token` held by the contract for a merchant to
+    /// `to`. Requires the merchant admin.
+    ///
+    /// A merchant created with `create_merchant` withdraws the claim payments
+    /// of its tokens. `DEFAULT_MERCHANT_ID` withdraws the funds held for no
+    /// merchant, such as funds sent to the contract directly. Fails with
+    /// `InvalidAmount` past `merchant_balance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `merchant_id` - Id of the merchant.
+    /// * `token` - Stellar Asset Contract of the token to send.
+    /// * `to` - Account receiving the funds. When `None`, the treasury for
+    ///   `DEFAULT_MERCHANT_ID` and the merchant admin otherwise.
+    /// * `amount` - Amount to send, in the smallest unit of `token`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["withdraw", token: Address, to: Address, merchant_id: u32]`
+    /// * data - `[amount: i128]`
+    fn withdraw(e: &Env, merchant_id: u32, token: Address, to: Option<Address>, amount: i128);
+
+    /// Returns the balance of `token` held by the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token` - Stellar Asset Contract of the token.
+    fn treasury_balance(e: &Env, token: Address) -> i128;
+
+    /// Returns the balance of `token` a merchant can withdraw.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `merchant_id` - Id of the merchant.
+    /// * `token` - Stellar Asset Contract of the token.
+    fn merchant_balance(e: &Env, merchant_id: u32, token: Address) -> i128;
+}
+
+#[contractimpl]
+impl Treasury for StellarMerchShop {
+    fn set_treasury(e: &Env, new_treasury: Address) {
+        contract::require_admin(e);
+
+        e.storage().instance().set(&TreasuryStorageKey::PendingTreasury, &new_treasury);
+    }
+
+    fn accept_treasury(e: &Env) {
+        let treasury = Self::pending_treasury(e)
+            .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::NoPendingTreasury));
+        // Proves the new treasury is an account we control
+        treasury.require_auth();
+
+        let previous_treasury = Self::treasury(e);
+        e.storage().instance().set(&TreasuryStorageKey::Treasury, &treasury);
+        e.storage().instance().remove(&TreasuryStorageKey::PendingTreasury);
+
+        events::TreasuryChanged { previous_treasury, treasury }.publish(e);
+    }
+
+    fn treasury(e: &Env) -> Address {
+        e.storage()
+            .instance()
+            .get(&TreasuryStorageKey::Treasury)
+            .unwrap_or_else(|| e.storage().instance().get(&contract::DataKey::Admin).unwrap())
+    }
+
+    fn pending_treasury(e: &Env) -> Option<Address> {
+        e.storage().instance().get(&TreasuryStorageKey::PendingTreasury)
+    }
+
+    fn withdraw(e: &Env, merchant_id: u32, token: Address, to: Option<Address>, amount: i128) {
+        let merchant_admin = merchants::require_merchant_admin(e, merchant_id);
+
+        let balance = Self::merchant_balance(e, merchant_id, token.clone());
+        if amount <= 0 || amount > balance {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidAmount);
+        }
+        let to = match to {
+            Some(to) => to,
+            None if merchant_id == DEFAULT_MERCHANT_ID => fee_destination(e),
+            None => merchant_admin,
+        };
+        if merchant_id != DEFAULT_MERCHANT_ID {
+            set_merchant_balance(e, merchant_id, &token, balance - amount);
+            let held = held_balance(e, &token);
+            e.storage().persistent().set(&TreasuryStorageKey::HeldBalance(token.clone()), &(held - amount));
+        }
+        token::Client::new(e, &token).transfer(&e.current_contract_address(), &to, &amount);
+
+        events::Withdraw { token, to, merchant_id, amount }.publish(e);
+    }
+
+    fn treasury_balance(e: &Env, token: Address) -> i128 {
+        token::Client::new(e, &token).balance(&e.current_contract_address())
+    }
+
+    fn merchant_balance(e: &Env, merchant_id: u32, token: Address) -> i128 {
+        if merchant_id == DEFAULT_MERCHANT_ID {
+            return Self::treasury_balance(e, token.clone()) - held_balance(e, &token);
+        }
+        e.storage()
+            .persistent()
+            .get(&TreasuryStorageKey::MerchantBalance(merchant_id, token))
+            .unwrap_or(0)
+    }
+}
+
+/// Account the fees are paid to, read by every fee-paying path so a rotation
+/// applies to all of them once accepted
+pub(crate) fn fee_destination(e: &Env) -> Address {
+    StellarMerchShop::treasury(e)
+}
+
+/// Record amount of token received by the contract on behalf of merchant_id
+pub(crate) fn credit_merchant(e: &Env, merchant_id: u32, token: &Address, amount: i128) {
+    let balance = StellarMerchShop::merchant_balance(e, merchant_id, token.clone());
+    set_merchant_balance(e, merchant_id, token, checked_add(e, balance, amount));
+    let held = held_balance(e, token);
+    e.storage().persistent().set(&TreasuryStorageKey::HeldBalance(token.clone()), &checked_add(e, held, amount));
+}
+
+fn set_merchant_balance(e: &Env, merchant_id: u32, token: &Address, balance: i128) {
+    e.storage()
+        .persistent()
+        .set(&TreasuryStorageKey::MerchantBalance(merchant_id, token.clone()), &balance);
+}
+
+fn held_balance(e: &Env, token: &Address) -> i128 {
+    e.storage().persistent().get(&TreasuryStorageKey::HeldBalance(token.clone())).unwrap_or(0)
+}
+
+pub(crate) fn checked_add(e: &Env, balance: i128, amount: i128) -> i128 {
+    balance
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::MathOverflow))
+}
@@ -0,0 +1,141 @@
+//! Warranty claim workflow
+
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, BytesN, Env};
+use crate::{contract, errors, events, NonFungibleToken, StellarMerchShop, StellarMerchShopArgs, StellarMerchShopClient};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum WarrantyStatus {
+    Opened = 0,
+    Approved = 1,
+    Rejected = 2,
+    Fulfilled = 3,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WarrantyClaim {
+    pub owner: Address,
+    pub issue_hash: BytesN<32>,
+    pub status: WarrantyStatus,
+    pub opened_ledger: u32,
+}
+
+#[contracttype]
+pub enum WarrantyStorageKey {
+    WarrantyClaim(u64),
+}
+
+pub trait Warranty {
+    /// Opens a warranty claim for `token_id` token.
+    ///
+    /// Only one claim can be in progress per token: a new claim can be opened
+    /// once the previous one was rejected or fulfilled.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `issue_hash` - Hash of the off-chain issue description.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["warranty_claim_opened", token_id: u64]`
+    /// * data - `[owner: Address, issue_hash: BytesN<32>]`
+    fn open_warranty_claim(e: &Env, token_id: u64, issue_hash: BytesN<32>);
+
+    /// Approves or rejects the open warranty claim of `token_id` token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `approved` - Whether support accepted the claim.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["warranty_claim_resolved", token_id: u64]`
+    /// * data - `[approved: bool]`
+    fn resolve_warranty_claim(e: &Env, token_id: u64, approved: bool);
+
+    /// Marks the approved warranty claim of `token_id` token as fulfilled.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["warranty_claim_fulfilled", token_id: u64]`
+    /// * data - `[]`
+    fn fulfill_warranty_claim(e: &Env, token_id: u64);
+
+    /// Returns the latest warranty claim of `token_id` token, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn warranty_claim(e: &Env, token_id: u64) -> Option<WarrantyClaim>;
+}
+
+#[contractimpl]
+impl Warranty for StellarMerchShop {
+    fn open_warranty_claim(e: &Env, token_id: u64, issue_hash: BytesN<32>) {
+        let owner = StellarMerchShop::owner_of(e, token_id);
+        owner.require_auth();
+
+        let in_progress = Self::warranty_claim(e, token_id)
+            .is_some_and(|claim| matches!(claim.status, WarrantyStatus::Opened | WarrantyStatus::Approved));
+        if in_progress {
+            panic_with_error!(e, &errors::NonFungibleTokenError::InvalidStatusTransition);
+        }
+
+        let claim = WarrantyClaim {
+            owner: owner.clone(),
+            issue_hash: issue_hash.clone(),
+            status: WarrantyStatus::Opened,
+            opened_ledger: e.ledger().sequence(),
+        };
+        e.storage().persistent().set(&WarrantyStorageKey::WarrantyClaim(token_id), &claim);
+
+        events::WarrantyClaimOpened { token_id, owner, issue_hash }.publish(e);
+    }
+
+    fn resolve_warranty_claim(e: &Env, token_id: u64, approved: bool) {
+        contract::require_admin(e);
+
+        let status = if approved { WarrantyStatus::Approved } else { WarrantyStatus::Rejected };
+        transition(e, token_id, WarrantyStatus::Opened, status);
+
+        events::WarrantyClaimResolved { token_id, approved }.publish(e);
+    }
+
+    fn fulfill_warranty_claim(e: &Env, token_id: u64) {
+        contract::require_admin(e);
+
+        transition(e, token_id, WarrantyStatus::Approved, WarrantyStatus::Fulfilled);
+
+        events::WarrantyClaimFulfilled { token_id }.publish(e);
+    }
+
+    fn warranty_claim(e: &Env, token_id: u64) -> Option<WarrantyClaim> {
+        e.storage()
+            .persistent()
+            .get(&WarrantyStorageKey::WarrantyClaim(token_id))
+    }
+}
+
+/// Move the warranty claim of token_id from `from` to `to` status
+fn transition(e: &Env, token_id: u64, from: WarrantyStatus, to: WarrantyStatus) {
+    let mut claim = StellarMerchShop::warranty_claim(e, token_id)
+        .unwrap_or_else(|| panic_with_error!(e, errors::NonFungibleTokenError::InvalidStatusTransition));
+    if claim.status != from {
+        panic_with_error!(e, &errors::NonFungibleTokenError::InvalidStatusTransition);
+    }
+
+    claim.status = to;
+    e.storage().persistent().set(&WarrantyStorageKey::WarrantyClaim(token_id), &claim);
+}
@@ -0,0 +1,232 @@
+//! Lifecycle tests against the compiled contract
+//!
+//! The unit tests run the contract natively, which hides differences with
+//! the wasm execution such as budget limits or how panics surface. These
+//! tests register the built artifacts instead, build them and run with:
+//!
+//!    make contract_test_wasm
+//!
+//! `STELLAR_MERCH_SHOP_WASM` overrides the artifact path and
+//! `STELLAR_MERCH_SHOP_UPGRADE_WASM` the path of the next version built with
+//! the `upgrade-fixture` feature. A test whose artifact is missing is
+//! skipped with a message, so a plain `cargo test` passes without them.
+
+use soroban_sdk::{contractclient, contracttype, testutils::Address as _, xdr::ToXdr, Address, Bytes, BytesN, Env, Error, String, Symbol};
+
+const DEFAULT_WASM: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../target/wasm32v1-none/release/stellar_merch_shop.wasm"
+);
+
+const DEFAULT_UPGRADE_WASM: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../target/wasm32v1-none/release/stellar_merch_shop_upgrade_fixture.wasm"
+);
+
+const TEST_MESSAGE: &[u8] = b"test message for minting";
+
+// Error codes of NonFungibleTokenError
+const NON_EXISTENT_TOKEN: u32 = 200;
+const INCORRECT_OWNER: u32 = 201;
+const TOKEN_ALREADY_MINTED: u32 = 210;
+const NONCE_TOO_LOW: u32 = 238;
+
+// Operation tags of ChipOperation
+const MINT: u8 = 0;
+const CLAIM: u8 = 1;
+const TRANSFER: u8 = 2;
+
+/// Mirror of the contract's ChipInfo, the crate only builds as a cdylib
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChipInfo {
+    pub model: Symbol,
+    pub firmware: u32,
+    pub batch: u32,
+}
+
+/// Subset of the contract interface exercised here
+#[allow(dead_code)]
+#[contractclient(name = "StellarMerchShopClient")]
+trait StellarMerchShop {
+    fn name(e: Env) -> String;
+    fn mint(e: Env, series_id: u32, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32, chip_info: Option<ChipInfo>, po_number: Option<u32>) -> u64;
+    fn claim(e: Env, claimant: Address, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32) -> u64;
+    fn chip_transfer(e: Env, from: Address, to: Address, token_id: u64, message: Bytes, signature: BytesN<64>, recovery_id: Option<u32>, public_key: BytesN<65>, nonce: u64, expiration_ledger: u32);
+    fn owner_of(e: Env, token_id: u64) -> Address;
+    fn balance(e: Env, owner: Address) -> u32;
+    fn get_nonce(e: Env, public_key: BytesN<65>) -> u64;
+    fn upgrade(e: Env, wasm_hash: BytesN<32>);
+    fn migrate(e: Env) -> bool;
+    fn version(e: Env) -> u32;
+}
+
+// Software chip signing like the NFC chip, see SoftwareChip in the unit tests
+struct SoftwareChip {
+    signing_key: k256::ecdsa::SigningKey,
+}
+
+impl SoftwareChip {
+    fn new(seed: u32) -> Self {
+        let mut secret = [0u8; 32];
+        secret[28..].copy_from_slice(&(seed + 1).to_be_bytes());
+        SoftwareChip { signing_key: k256::ecdsa::SigningKey::from_slice(&secret).unwrap() }
+    }
+
+    fn public_key(&self, e: &Env) -> BytesN<65> {
+        let point = self.signing_key.verifying_key().to_encoded_point(false);
+        BytesN::from_array(e, point.as_bytes().try_into().unwrap())
+    }
+
+    fn sign(&self, e: &Env, operation: u8, contract: &Address, recipient: Option<&Address>, nonce: u64) -> (Bytes, BytesN<64>, Option<u32>) {
+        let mut preimage = contract.clone().to_xdr(e);
+        preimage.push_back(operation);
+        preimage.append(&Bytes::from_slice(e, TEST_MESSAGE));
+        if let Some(recipient) = recipient {
+            preimage.append(&recipient.clone().to_xdr(e));
+        }
+        preimage.extend_from_slice(&merch_crypto::nonce_xdr(nonce));
+        let message_hash: BytesN<32> = e.crypto().sha256(&preimage).into();
+
+        let (signature, recovery_id) = self
+            .signing_key
+            .sign_prehash_recoverable(&message_hash.to_array())
+            .unwrap();
+        let signature = BytesN::from_array(e, &signature.to_bytes().into());
+        (Bytes::from_slice(e, TEST_MESSAGE), signature, Some(recovery_id.to_byte() as u32))
+    }
+}
+
+/// Read the artifact, None and a skip message when it is not built
+fn load_artifact(variable: &str, default: &str) -> Option<std::vec::Vec<u8>> {
+    let path = std::env::var(variable).unwrap_or_else(|_| default.into());
+    match std::fs::read(&path) {
+        Ok(wasm) => Some(wasm),
+        Err(error) => {
+            eprintln!("skipping: no artifact at {path} ({error}), run `make contract_test_wasm`");
+            None
+        }
+    }
+}
+
+fn load_wasm() -> Option<std::vec::Vec<u8>> {
+    load_artifact("STELLAR_MERCH_SHOP_WASM", DEFAULT_WASM)
+}
+
+fn load_upgrade_wasm() -> Option<std::vec::Vec<u8>> {
+    load_artifact("STELLAR_MERCH_SHOP_UPGRADE_WASM", DEFAULT_UPGRADE_WASM)
+}
+
+fn create_client<'a>(e: &Env, wasm: &[u8], admin: &Address) -> StellarMerchShopClient<'a> {
+    let address = e.register(
+        wasm,
+        (
+            admin,
+            String::from_str(e, "TestNFT"),
+            String::from_str(e, "TNFT"),
+            String::from_str(e, "ipfs://abcd"),
+            10_000u64,
+            false,
+            false,
+        ),
+    );
+    StellarMerchShopClient::new(e, &address)
+}
+
+fn contract_error(code: u32) -> Error {
+    Error::from_contract_error(code)
+}
+
+#[test]
+fn test_wasm_lifecycle() {
+    let Some(wasm) = load_wasm() else { return };
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &wasm, &admin);
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+    assert_eq!(client.name(), String::from_str(&e, "TestNFT"));
+
+    let (message, signature, recovery_id) = chip.sign(&e, MINT, &client.address, None, 1);
+    let token_id = client.mint(&0, &message, &signature, &recovery_id, &public_key, &1, &0, &None, &None);
+    assert_eq!(token_id, 0);
+
+    let (message, signature, recovery_id) = chip.sign(&e, CLAIM, &client.address, Some(&alice), 2);
+    client.claim(&alice, &message, &signature, &recovery_id, &public_key, &2, &0);
+    assert_eq!(client.owner_of(&token_id), alice);
+    assert_eq!(client.get_nonce(&public_key), 2);
+
+    let (message, signature, recovery_id) = chip.sign(&e, TRANSFER, &client.address, Some(&bob), 3);
+    client.chip_transfer(&alice, &bob, &token_id, &message, &signature, &recovery_id, &public_key, &3, &0);
+    assert_eq!(client.owner_of(&token_id), bob);
+    assert_eq!(client.balance(&alice), 0);
+    assert_eq!(client.balance(&bob), 1);
+}
+
+#[test]
+fn test_wasm_error_codes() {
+    let Some(wasm) = load_wasm() else { return };
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let client = create_client(&e, &wasm, &admin);
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+
+    assert_eq!(client.try_owner_of(&0), Err(Ok(contract_error(NON_EXISTENT_TOKEN))));
+
+    let (message, signature, recovery_id) = chip.sign(&e, MINT, &client.address, None, 1);
+    client.mint(&0, &message, &signature, &recovery_id, &public_key, &1, &0, &None, &None);
+
+    // Replayed nonce
+    let result = client.try_claim(&alice, &message, &signature, &recovery_id, &public_key, &1, &0);
+    assert_eq!(result, Err(Ok(contract_error(NONCE_TOO_LOW))));
+
+    let (message, signature, recovery_id) = chip.sign(&e, CLAIM, &client.address, Some(&alice), 2);
+    let result = client.try_mint(&0, &message, &signature, &recovery_id, &public_key, &2, &0, &None, &None);
+    assert_eq!(result, Err(Ok(contract_error(TOKEN_ALREADY_MINTED))));
+
+    client.claim(&alice, &message, &signature, &recovery_id, &public_key, &2, &0);
+    let (message, signature, recovery_id) = chip.sign(&e, TRANSFER, &client.address, Some(&alice), 3);
+    let result = client.try_chip_transfer(&admin, &alice, &0, &message, &signature, &recovery_id, &public_key, &3, &0);
+    assert_eq!(result, Err(Ok(contract_error(INCORRECT_OWNER))));
+}
+
+#[test]
+fn test_wasm_upgrade() {
+    let Some(wasm) = load_wasm() else { return };
+    let Some(upgrade_wasm) = load_upgrade_wasm() else { return };
+    assert_ne!(wasm, upgrade_wasm, "the upgrade fixture must be a distinct build");
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let client = create_client(&e, &wasm, &admin);
+    let chip = SoftwareChip::new(1);
+    let public_key = chip.public_key(&e);
+
+    let (message, signature, recovery_id) = chip.sign(&e, MINT, &client.address, None, 1);
+    let token_id = client.mint(&0, &message, &signature, &recovery_id, &public_key, &1, &0, &None, &None);
+    let (message, signature, recovery_id) = chip.sign(&e, CLAIM, &client.address, Some(&alice), 2);
+    client.claim(&alice, &message, &signature, &recovery_id, &public_key, &2, &0);
+    let version = client.version();
+
+    let wasm_hash = e.deployer().upload_contract_wasm(upgrade_wasm.as_slice());
+    client.upgrade(&wasm_hash);
+    client.migrate();
+
+    // The new code runs, and tokens and consumed nonces survive the swap
+    assert_eq!(client.version(), version + 1);
+    assert_eq!(client.owner_of(&token_id), alice);
+    assert_eq!(client.balance(&alice), 1);
+    assert_eq!(client.get_nonce(&public_key), 2);
+    let result = client.try_claim(&alice, &message, &signature, &recovery_id, &public_key, &2, &0);
+    assert_eq!(result, Err(Ok(contract_error(NONCE_TOO_LOW))));
+}
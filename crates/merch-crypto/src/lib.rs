@@ -0,0 +1,363 @@
+//! Chip signature primitives shared by the contracts and off-chain tooling
+//!
+//! Everything here is pure byte manipulation without any soroban_sdk
+//! dependency, so the CLI or backend can use it directly. The `soroban`
+//! feature adds the adapters over `Env` types the contracts use.
+//!
+//! A chip signs `sha256(message || nonce_xdr(nonce))` where `nonce_xdr` is
+//! the XDR encoding of the nonce as a Soroban `u64` value. chip-auth takes
+//! the message as is. stellar-merch-shop binds it to the deployment and the
+//! operation first, see `write_signed_message`, so the full preimage is
+//! `domain || operation || message || recipient_xdr? || expiration_xdr? || nonce_xdr`.
+//! Ed25519 chips sign the preimage itself instead of its hash.
+
+#![no_std]
+
+#[cfg(feature = "soroban")]
+pub mod soroban;
+#[cfg(test)]
+mod test;
+
+/// Order of the secp256k1 curve
+pub const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Half of the secp256k1 curve order, upper bound of a low S value
+pub const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// Order of the NIST P-256 (secp256r1) curve
+pub const P256_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17, 0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x51,
+];
+
+/// Half of the P-256 curve order, upper bound of a low S value
+pub const P256_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xde, 0x73, 0x7d, 0x56, 0xd3, 0x8b, 0xcf, 0x42, 0x79, 0xdc, 0xe5, 0x61, 0x7e, 0x31, 0x92, 0xa8,
+];
+
+/// XDR type tag of a Soroban `u32` value (SCV_U32)
+const SCV_U32: [u8; 4] = [0x00, 0x00, 0x00, 0x03];
+
+/// XDR type tag of a Soroban `u64` value (SCV_U64)
+const SCV_U64: [u8; 4] = [0x00, 0x00, 0x00, 0x05];
+
+/// Length of the nonce suffix appended to the message
+pub const NONCE_XDR_LEN: usize = 12;
+
+/// Length of the expiration ledger bound in the signed message
+pub const EXPIRATION_XDR_LEN: usize = 8;
+
+/// Errors when parsing a DER encoded signature
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DerError {
+    /// The input does not start with a sequence tag
+    MissingSequence,
+    /// R or S is not tagged as an integer
+    MissingInteger,
+    /// A length runs past the end of the input
+    Truncated,
+    /// Bytes follow the sequence or its last integer
+    TrailingData,
+    /// R or S has no content byte
+    EmptyInteger,
+    /// R or S has its high bit set without a leading zero
+    NegativeInteger,
+    /// R or S carries a leading zero it does not need
+    NonMinimalInteger,
+    /// R or S does not fit in 32 bytes
+    IntegerTooLarge,
+}
+
+/// Returns the nonce suffix of the signed preimage, `nonce.to_xdr()` in the contracts
+pub fn nonce_xdr(nonce: u64) -> [u8; NONCE_XDR_LEN] {
+    let mut suffix = [0u8; NONCE_XDR_LEN];
+    suffix[..4].copy_from_slice(&SCV_U64);
+    suffix[4..].copy_from_slice(&nonce.to_be_bytes());
+    suffix
+}
+
+/// Returns the expiration ledger as bound in the signed message, `expiration_ledger.to_xdr()` in the contracts
+pub fn expiration_xdr(expiration_ledger: u32) -> [u8; EXPIRATION_XDR_LEN] {
+    let mut encoded = [0u8; EXPIRATION_XDR_LEN];
+    encoded[..4].copy_from_slice(&SCV_U32);
+    encoded[4..].copy_from_slice(&expiration_ledger.to_be_bytes());
+    encoded
+}
+
+/// Destination of a preimage written part by part, `Bytes` in the contracts
+///
+/// `T` is the type of the parts the caller provides, encoded beforehand.
+pub trait PreimageSink<T: ?Sized> {
+    /// Appends a part provided by the caller: domain, message or recipient
+    fn append_part(&mut self, part: &T);
+
+    /// Appends bytes encoded here: operation tag, expiration and nonce
+    fn append_bytes(&mut self, bytes: &[u8]);
+}
+
+/// Writes the message a stellar-merch-shop chip signs before the nonce
+///
+/// `domain || operation || message || recipient_xdr? || expiration_xdr?`,
+/// where `domain` is the contract address and `recipient_xdr` the recipient
+/// address, both XDR encoded. The operation tag follows the fixed length
+/// domain so a signature cannot be redirected into another operation. An
+/// expiration ledger of 0 is left out, keeping the hash of signatures
+/// without expiry.
+pub fn write_signed_message<T: ?Sized>(
+    sink: &mut impl PreimageSink<T>,
+    domain: &T,
+    operation: u8,
+    message: &T,
+    recipient_xdr: Option<&T>,
+    expiration_ledger: u32,
+) {
+    sink.append_part(domain);
+    sink.append_bytes(&[operation]);
+    sink.append_part(message);
+    if let Some(recipient_xdr) = recipient_xdr {
+        sink.append_part(recipient_xdr);
+    }
+    if expiration_ledger != 0 {
+        sink.append_bytes(&expiration_xdr(expiration_ledger));
+    }
+}
+
+/// Writes the full preimage of a stellar-merch-shop chip signature, the
+/// signed message of `write_signed_message` followed by `nonce_xdr(nonce)`
+pub fn write_preimage<T: ?Sized>(
+    sink: &mut impl PreimageSink<T>,
+    domain: &T,
+    operation: u8,
+    message: &T,
+    recipient_xdr: Option<&T>,
+    expiration_ledger: u32,
+    nonce: u64,
+) {
+    write_signed_message(sink, domain, operation, message, recipient_xdr, expiration_ledger);
+    sink.append_bytes(&nonce_xdr(nonce));
+}
+
+/// Whether `s` is in the low half of the curve order and not zero
+///
+/// The host only recovers signatures with a low S value.
+pub fn is_low_s(s: &[u8; 32]) -> bool {
+    *s != [0u8; 32] && *s <= SECP256K1_HALF_ORDER
+}
+
+/// Returns the low S form of `s`, `n - s` if `s` is above half the curve order
+pub fn normalize_s(s: &[u8; 32]) -> [u8; 32] {
+    normalize_s_with(s, &SECP256K1_ORDER, &SECP256K1_HALF_ORDER)
+}
+
+/// Whether the P-256 signature `r || s` has both scalars in range and a low S
+///
+/// The host aborts instead of rejecting a P-256 signature, so malformed ones
+/// are caught beforehand.
+pub fn is_valid_p256_signature(r: &[u8; 32], s: &[u8; 32]) -> bool {
+    *r != [0u8; 32] && *r < P256_ORDER && *s != [0u8; 32] && *s <= P256_HALF_ORDER
+}
+
+/// Returns the low S form of a P-256 `s`, see `normalize_s`
+///
+/// P-256 signatures are verified against the public key, no recovery ID
+/// is affected.
+pub fn normalize_s_p256(s: &[u8; 32]) -> [u8; 32] {
+    normalize_s_with(s, &P256_ORDER, &P256_HALF_ORDER)
+}
+
+fn normalize_s_with(s: &[u8; 32], order: &[u8; 32], half_order: &[u8; 32]) -> [u8; 32] {
+    if *s <= *half_order || *s >= *order {
+        return *s;
+    }
+
+    let mut result = [0u8; 32];
+    let mut borrow = 0u16;
+    for i in (0..32).rev() {
+        let subtrahend = s[i] as u16 + borrow;
+        let minuend = order[i] as u16;
+        if minuend >= subtrahend {
+            result[i] = (minuend - subtrahend) as u8;
+            borrow = 0;
+        } else {
+            result[i] = (256 + minuend - subtrahend) as u8;
+            borrow = 1;
+        }
+    }
+    result
+}
+
+/// Field prime of secp256k1, `2^256 - 2^32 - 977`, as little endian 64-bit limbs
+const FIELD_PRIME: [u64; 4] = [0xffff_fffe_ffff_fc2f, u64::MAX, u64::MAX, u64::MAX];
+
+/// `2^256 mod FIELD_PRIME`, folds the upper half of a product back into the field
+const FIELD_FOLD: u64 = 0x1_0000_03d1;
+
+/// `(FIELD_PRIME - 1) / 2`, Euler's criterion exponent
+const FIELD_HALF_ORDER: [u64; 4] = [0xffff_ffff_7fff_fe17, u64::MAX, u64::MAX, 0x7fff_ffff_ffff_ffff];
+
+/// Whether `r` can be recovered from with `recovery_id`
+///
+/// Recovery lifts `r`, or `r + n` for a recovery ID of 2 or 3, to a point on
+/// the curve, which the host rejects by trapping when there is none.
+pub fn is_valid_r(r: &[u8; 32], recovery_id: u32) -> bool {
+    if *r == [0u8; 32] || *r >= SECP256K1_ORDER {
+        return false;
+    }
+
+    let mut x = field_from_bytes(r);
+    if recovery_id >= 2 {
+        let order = field_from_bytes(&SECP256K1_ORDER);
+        let mut carry = 0u128;
+        for i in 0..4 {
+            let limb = x[i] as u128 + order[i] as u128 + carry;
+            x[i] = limb as u64;
+            carry = limb >> 64;
+        }
+        if carry != 0 || limbs_geq(&x, &FIELD_PRIME) {
+            return false;
+        }
+    }
+
+    // y^2 = x^3 + 7 must have a solution, zero or a quadratic residue
+    let y2 = field_add_small(field_mul(&field_mul(&x, &x), &x), 7);
+    y2 == [0u64; 4] || field_pow(&y2, &FIELD_HALF_ORDER) == [1, 0, 0, 0]
+}
+
+fn field_from_bytes(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let start = 24 - 8 * i;
+        *limb = u64::from_be_bytes(bytes[start..start + 8].try_into().unwrap());
+    }
+    limbs
+}
+
+/// `a + value` reduced into the field
+fn field_add_small(a: [u64; 4], value: u128) -> [u64; 4] {
+    let mut sum = [0u64; 4];
+    let mut carry = value;
+    for i in 0..4 {
+        let limb = a[i] as u128 + carry;
+        sum[i] = limb as u64;
+        carry = limb >> 64;
+    }
+    if carry != 0 {
+        // 2^256 wrapped around, worth FIELD_FOLD
+        return field_add_small(sum, carry * FIELD_FOLD as u128);
+    }
+
+    if limbs_geq(&sum, &FIELD_PRIME) {
+        let mut borrow = 0i128;
+        for i in 0..4 {
+            let limb = sum[i] as i128 - FIELD_PRIME[i] as i128 - borrow;
+            sum[i] = limb as u64;
+            borrow = (limb < 0) as i128;
+        }
+    }
+    sum
+}
+
+fn field_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut product = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let limb = product[i + j] as u128 + a[i] as u128 * b[j] as u128 + carry;
+            product[i + j] = limb as u64;
+            carry = limb >> 64;
+        }
+        product[i + 4] = carry as u64;
+    }
+
+    // low + high * 2^256 = low + high * FIELD_FOLD
+    let mut folded = [0u64; 4];
+    let mut carry = 0u128;
+    for i in 0..4 {
+        let limb = product[i] as u128 + product[i + 4] as u128 * FIELD_FOLD as u128 + carry;
+        folded[i] = limb as u64;
+        carry = limb >> 64;
+    }
+    field_add_small(folded, carry * FIELD_FOLD as u128)
+}
+
+fn field_pow(base: &[u64; 4], exponent: &[u64; 4]) -> [u64; 4] {
+    let mut result = [1, 0, 0, 0];
+    for limb in exponent.iter().rev() {
+        for bit in (0..64).rev() {
+            result = field_mul(&result, &result);
+            if (limb >> bit) & 1 == 1 {
+                result = field_mul(&result, base);
+            }
+        }
+    }
+    result
+}
+
+fn limbs_geq(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+/// Returns the 64 bytes `R || S` signature of a DER encoded signature
+///
+/// DER format: `0x30 [length] 0x02 [R length] [R] 0x02 [S length] [S]`.
+/// S is returned as is, see `normalize_s`.
+///
+/// Only strict DER is accepted, so a signature has a single encoding: the
+/// sequence length covers exactly both integers and each integer is
+/// positive and minimally encoded.
+pub fn parse_der_signature(der: &[u8]) -> Result<[u8; 64], DerError> {
+    if der.len() < 2 || der[0] != 0x30 {
+        return Err(DerError::MissingSequence);
+    }
+    let len = der[1] as usize + 2;
+    if der.len() < len {
+        return Err(DerError::Truncated);
+    }
+    if der.len() > len {
+        return Err(DerError::TrailingData);
+    }
+
+    let mut signature = [0u8; 64];
+    let (r, rest) = parse_der_integer(&der[2..])?;
+    let (s, rest) = parse_der_integer(rest)?;
+    if !rest.is_empty() {
+        return Err(DerError::TrailingData);
+    }
+    signature[32 - r.len()..32].copy_from_slice(r);
+    signature[64 - s.len()..].copy_from_slice(s);
+    Ok(signature)
+}
+
+/// Parse one DER integer, returns its big-endian value without sign padding and the remaining input
+fn parse_der_integer(input: &[u8]) -> Result<(&[u8], &[u8]), DerError> {
+    if input.first() != Some(&0x02) {
+        return Err(DerError::MissingInteger);
+    }
+    let len = *input.get(1).ok_or(DerError::Truncated)? as usize;
+    let value = input.get(2..2 + len).ok_or(DerError::Truncated)?;
+
+    // Positive integers with the high bit set carry a leading zero, and only them
+    let value = match value {
+        [] => return Err(DerError::EmptyInteger),
+        [first, ..] if first & 0x80 != 0 => return Err(DerError::NegativeInteger),
+        [0x00, next, ..] if next & 0x80 == 0 => return Err(DerError::NonMinimalInteger),
+        [0x00, rest @ ..] => rest,
+        _ => value,
+    };
+    if value.len() > 32 {
+        return Err(DerError::IntegerTooLarge);
+    }
+    Ok((value, &input[2 + len..]))
+}
@@ -0,0 +1,49 @@
+//! Adapters of the primitives over soroban_sdk types, shared by the contracts
+
+use soroban_sdk::{crypto::Hash, xdr::ToXdr, Address, Bytes, Env, TryFromVal, Val};
+use crate::{nonce_xdr, write_signed_message, PreimageSink};
+
+impl PreimageSink<Bytes> for Bytes {
+    fn append_part(&mut self, part: &Bytes) {
+        self.append(part);
+    }
+
+    fn append_bytes(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+/// Message a stellar-merch-shop chip signs before the nonce, see `write_signed_message`
+pub fn signed_message(
+    e: &Env,
+    domain: &Bytes,
+    operation: u8,
+    message: &Bytes,
+    recipient: Option<&Address>,
+    expiration_ledger: u32,
+) -> Bytes {
+    let recipient_xdr = recipient.map(|recipient| recipient.clone().to_xdr(e));
+    let mut signed_message = Bytes::new(e);
+    write_signed_message(&mut signed_message, domain, operation, message, recipient_xdr.as_ref(), expiration_ledger);
+    signed_message
+}
+
+/// Preimage of chip_message_hash: message || nonce.to_xdr(), signed as is by Ed25519 chips
+pub fn chip_message_preimage(e: &Env, message: &Bytes, nonce: u64) -> Bytes {
+    let mut preimage = message.clone();
+    preimage.append(&Bytes::from_array(e, &nonce_xdr(nonce)));
+    preimage
+}
+
+/// Hash signed by the chip: sha256(message || nonce.to_xdr())
+pub fn chip_message_hash(e: &Env, message: &Bytes, nonce: u64) -> Hash<32> {
+    e.crypto().sha256(&chip_message_preimage(e, message, nonce))
+}
+
+/// Stored nonce as a u64, nonces stored before they were widened are a u32
+pub fn widen_nonce(e: &Env, nonce: Val) -> u64 {
+    match u32::try_from_val(e, &nonce) {
+        Ok(nonce) => nonce.into(),
+        Err(_) => u64::try_from_val(e, &nonce).unwrap(),
+    }
+}
@@ -0,0 +1,177 @@
+//! Vectors shared with the stellar-merch-shop tests (chip 1, nonces 1 and 3)
+
+use crate::{
+    expiration_xdr, is_low_s, is_valid_p256_signature, is_valid_r, nonce_xdr, normalize_s, normalize_s_p256,
+    parse_der_signature, write_preimage, DerError, PreimageSink, P256_HALF_ORDER, P256_ORDER, SECP256K1_HALF_ORDER,
+    SECP256K1_ORDER,
+};
+
+const CHIP1_NONCE1_R: [u8; 32] = [
+    0xf4, 0x09, 0xa1, 0xdf, 0x1a, 0xe1, 0xee, 0x37, 0x20, 0x04, 0x55, 0xf5, 0x42, 0x2f, 0xa4, 0xb3,
+    0x63, 0xec, 0xc3, 0x65, 0xe6, 0xd0, 0x52, 0xd0, 0x81, 0x9a, 0xcf, 0x4a, 0x8a, 0x27, 0x75, 0xe1,
+];
+
+const CHIP1_NONCE1_S: [u8; 32] = [
+    0x12, 0xce, 0x57, 0xeb, 0x17, 0xa2, 0x41, 0x34, 0x9c, 0x75, 0x21, 0xdb, 0x60, 0xc5, 0x1b, 0x81,
+    0x2a, 0x3f, 0x0a, 0xe5, 0xc0, 0x07, 0xd5, 0x1f, 0xb5, 0x26, 0x2f, 0xba, 0x0b, 0x15, 0xf3, 0xae,
+];
+
+// High S as returned by the chip
+const CHIP1_NONCE3_S: [u8; 32] = [
+    0xbd, 0x86, 0x53, 0x9a, 0xa5, 0x75, 0xd2, 0xcb, 0xad, 0x55, 0x87, 0xa0, 0xfc, 0xdb, 0x25, 0x2d,
+    0x1e, 0x9d, 0xc0, 0xff, 0x14, 0x17, 0x4f, 0x08, 0xb4, 0x1a, 0xe3, 0x8e, 0xc6, 0xb2, 0x1b, 0x49,
+];
+
+const CHIP1_NONCE3_S_NORMALIZED: [u8; 32] = [
+    0x42, 0x79, 0xac, 0x65, 0x5a, 0x8a, 0x2d, 0x34, 0x52, 0xaa, 0x78, 0x5f, 0x03, 0x24, 0xda, 0xd1,
+    0x9c, 0x11, 0x1b, 0xe7, 0x9b, 0x31, 0x51, 0x33, 0x0b, 0xb7, 0x7a, 0xfe, 0x09, 0x84, 0x25, 0xf8,
+];
+
+#[test]
+fn test_nonce_xdr() {
+    assert_eq!(nonce_xdr(1), [0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]);
+    assert_eq!(nonce_xdr(0x0102_0304_0506_0708), [0x00, 0x00, 0x00, 0x05, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+}
+
+/// Fixed capacity sink standing in for `Bytes`
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl PreimageSink<[u8]> for Buffer {
+    fn append_part(&mut self, part: &[u8]) {
+        self.append_bytes(part);
+    }
+
+    fn append_bytes(&mut self, bytes: &[u8]) {
+        self.bytes[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+}
+
+#[test]
+fn test_write_preimage() {
+    assert_eq!(expiration_xdr(0x0102_0304), [0x00, 0x00, 0x00, 0x03, 0x01, 0x02, 0x03, 0x04]);
+
+    let mut buffer = Buffer { bytes: [0; 64], len: 0 };
+    write_preimage(&mut buffer, &[0xd0, 0xd1][..], 2, &[0xaa][..], Some(&[0xbb, 0xbc][..]), 7, 1);
+    let expected = [
+        0xd0, 0xd1, 0x02, 0xaa, 0xbb, 0xbc, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+    assert_eq!(&buffer.bytes[..buffer.len], &expected);
+
+    // No recipient and no expiry
+    let mut buffer = Buffer { bytes: [0; 64], len: 0 };
+    write_preimage(&mut buffer, &[0xd0, 0xd1][..], 0, &[0xaa][..], None, 0, 1);
+    assert_eq!(&buffer.bytes[..buffer.len], &[0xd0, 0xd1, 0x00, 0xaa, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]);
+}
+
+#[test]
+fn test_normalize_s() {
+    assert!(!is_low_s(&CHIP1_NONCE3_S));
+    assert_eq!(normalize_s(&CHIP1_NONCE3_S), CHIP1_NONCE3_S_NORMALIZED);
+    assert!(is_low_s(&CHIP1_NONCE3_S_NORMALIZED));
+
+    // Low S values are untouched
+    assert_eq!(normalize_s(&CHIP1_NONCE1_S), CHIP1_NONCE1_S);
+    assert_eq!(normalize_s(&SECP256K1_HALF_ORDER), SECP256K1_HALF_ORDER);
+    assert!(is_low_s(&SECP256K1_HALF_ORDER));
+    assert!(!is_low_s(&[0u8; 32]));
+}
+
+#[test]
+fn test_is_valid_r() {
+    assert!(is_valid_r(&CHIP1_NONCE1_R, 0));
+    assert!(is_valid_r(&CHIP1_NONCE1_R, 1));
+
+    // x^3 + 7 is a square for x = 1, not for x = 5
+    let mut r = [0u8; 32];
+    r[31] = 1;
+    assert!(is_valid_r(&r, 0));
+    r[31] = 5;
+    assert!(!is_valid_r(&r, 0));
+
+    assert!(!is_valid_r(&[0u8; 32], 0));
+    assert!(!is_valid_r(&SECP256K1_ORDER, 0));
+    assert!(!is_valid_r(&[0xff; 32], 0));
+
+    // r + n has to stay below the field prime and be on the curve
+    assert!(!is_valid_r(&CHIP1_NONCE1_R, 2));
+    r[31] = 2;
+    assert!(is_valid_r(&r, 3));
+    r[31] = 1;
+    assert!(!is_valid_r(&r, 2));
+}
+
+#[test]
+fn test_p256_signature() {
+    // RFC 6979 A.2.5, P-256 with SHA-256 signing "sample"
+    let r = [
+        0xef, 0xd4, 0x8b, 0x2a, 0xac, 0xb6, 0xa8, 0xfd, 0x11, 0x40, 0xdd, 0x9c, 0xd4, 0x5e, 0x81, 0xd6,
+        0x9d, 0x2c, 0x87, 0x7b, 0x56, 0xaa, 0xf9, 0x91, 0xc3, 0x4d, 0x0e, 0xa8, 0x4e, 0xaf, 0x37, 0x16,
+    ];
+    let s = [
+        0xf7, 0xcb, 0x1c, 0x94, 0x2d, 0x65, 0x7c, 0x41, 0xd4, 0x36, 0xc7, 0xa1, 0xb6, 0xe2, 0x9f, 0x65,
+        0xf3, 0xe9, 0x00, 0xdb, 0xb9, 0xaf, 0xf4, 0x06, 0x4d, 0xc4, 0xab, 0x2f, 0x84, 0x3a, 0xcd, 0xa8,
+    ];
+    let s_normalized = [
+        0x08, 0x34, 0xe3, 0x6a, 0xd2, 0x9a, 0x83, 0xbf, 0x2b, 0xc9, 0x38, 0x5e, 0x49, 0x1d, 0x60, 0x99,
+        0xc8, 0xfd, 0xf9, 0xd1, 0xed, 0x67, 0xaa, 0x7e, 0xa5, 0xf5, 0x1f, 0x93, 0x78, 0x28, 0x57, 0xa9,
+    ];
+    assert!(!is_valid_p256_signature(&r, &s));
+    assert_eq!(normalize_s_p256(&s), s_normalized);
+    assert!(is_valid_p256_signature(&r, &s_normalized));
+    assert_eq!(normalize_s_p256(&s_normalized), s_normalized);
+
+    // The secp256k1 order is larger, the same S is low there
+    assert_eq!(normalize_s(&s_normalized), s_normalized);
+    assert_eq!(normalize_s_p256(&P256_HALF_ORDER), P256_HALF_ORDER);
+    assert!(!is_valid_p256_signature(&[0u8; 32], &s_normalized));
+    assert!(!is_valid_p256_signature(&P256_ORDER, &s_normalized));
+    assert!(!is_valid_p256_signature(&r, &[0u8; 32]));
+}
+
+#[test]
+fn test_parse_der_signature() {
+    // R has its high bit set so it carries a leading zero, S does not
+    let mut der = [0u8; 71];
+    der[..4].copy_from_slice(&[0x30, 69, 0x02, 33]);
+    der[5..37].copy_from_slice(&CHIP1_NONCE1_R);
+    der[37..39].copy_from_slice(&[0x02, 32]);
+    der[39..].copy_from_slice(&CHIP1_NONCE1_S);
+
+    let signature = parse_der_signature(&der).unwrap();
+    assert_eq!(signature[..32], CHIP1_NONCE1_R);
+    assert_eq!(signature[32..], CHIP1_NONCE1_S);
+
+    // Short integers are left padded
+    let der = [0x30, 6, 0x02, 1, 0x05, 0x02, 1, 0x07];
+    let signature = parse_der_signature(&der).unwrap();
+    assert_eq!(signature[31], 0x05);
+    assert_eq!(signature[63], 0x07);
+    assert_eq!(signature.iter().filter(|byte| **byte != 0).count(), 2);
+
+    assert_eq!(parse_der_signature(&[0x31, 0]), Err(DerError::MissingSequence));
+    assert_eq!(parse_der_signature(&[0x30, 2, 0x03, 0]), Err(DerError::MissingInteger));
+    assert_eq!(parse_der_signature(&[0x30, 4, 0x02, 3, 0x01]), Err(DerError::Truncated));
+    assert_eq!(parse_der_signature(&der[..7]), Err(DerError::Truncated));
+
+    let mut der = [0x01u8; 38];
+    der[..4].copy_from_slice(&[0x30, 36, 0x02, 34]);
+    assert_eq!(parse_der_signature(&der), Err(DerError::IntegerTooLarge));
+
+    // The sequence length covers exactly both integers
+    assert_eq!(parse_der_signature(&[0x30, 6, 0x02, 1, 0x05, 0x02, 1, 0x07, 0x00]), Err(DerError::TrailingData));
+    assert_eq!(parse_der_signature(&[0x30, 7, 0x02, 1, 0x05, 0x02, 1, 0x07, 0x00]), Err(DerError::TrailingData));
+
+    // Integers are positive and minimally encoded
+    assert_eq!(parse_der_signature(&[0x30, 5, 0x02, 0, 0x02, 1, 0x07]), Err(DerError::EmptyInteger));
+    assert_eq!(parse_der_signature(&[0x30, 6, 0x02, 1, 0x85, 0x02, 1, 0x07]), Err(DerError::NegativeInteger));
+    assert_eq!(parse_der_signature(&[0x30, 7, 0x02, 1, 0x05, 0x02, 2, 0x00, 0x07]), Err(DerError::NonMinimalInteger));
+    assert_eq!(parse_der_signature(&[0x30, 7, 0x02, 2, 0x00, 0x00, 0x02, 1, 0x07]), Err(DerError::NonMinimalInteger));
+    let signature = parse_der_signature(&[0x30, 7, 0x02, 2, 0x00, 0x85, 0x02, 1, 0x00]).unwrap();
+    assert_eq!(signature[31], 0x85);
+    assert_eq!(signature[63], 0x00);
+}
@@ -33,17 +33,326 @@ if (typeof window !== "undefined") {
 export type DataKey =
   | { tag: "Admin"; values: void }
   | { tag: "NextTokenId"; values: void }
-  | { tag: "MaxTokens"; values: void };
+  | { tag: "MaxTokens"; values: void }
+  | { tag: "BuildInfo"; values: void }
+  | { tag: "TransferCooldown"; values: void }
+  | { tag: "NonceWindow"; values: void }
+  | { tag: "ClaimedCount"; values: void }
+  | { tag: "CheckpointInterval"; values: void }
+  | { tag: "ChipAuth"; values: void }
+  | { tag: "ActivityEvents"; values: void }
+  | { tag: "MaxMessageLength"; values: void }
+  | { tag: "PendingAdmin"; values: void }
+  | { tag: "BurnedCount"; values: void }
+  | { tag: "ClaimDeadline"; values: void }
+  | { tag: "TokenMigrationCursor"; values: void }
+  | { tag: "MigratedTokenCount"; values: void }
+  | { tag: "OnchainMetadata"; values: void };
+
+export interface BuildInfo {
+  git_describe: string;
+  profile: string;
+  version: string;
+}
 
 export type NFTStorageKey =
   | { tag: "ChipNonceByPublicKey"; values: readonly [Buffer] }
   | { tag: "Owner"; values: readonly [u64] }
   | { tag: "PublicKey"; values: readonly [u64] }
+  | { tag: "Token"; values: readonly [u64] }
   | { tag: "TokenIdByPublicKey"; values: readonly [Buffer] }
   | { tag: "Balance"; values: readonly [string] }
+  | { tag: "LastTransferLedger"; values: readonly [u64] }
   | { tag: "Name"; values: void }
   | { tag: "Symbol"; values: void }
-  | { tag: "URI"; values: void };
+  | { tag: "URI"; values: void }
+  | { tag: "UnclaimedURI"; values: void }
+  | { tag: "Approval"; values: readonly [u64] }
+  | { tag: "ApprovalForAll"; values: readonly [string, string] }
+  | { tag: "OperatorAllowance"; values: readonly [string, string] }
+  | { tag: "ApprovalRecipient"; values: readonly [u64] }
+  | { tag: "TokenURI"; values: readonly [u64] }
+  | { tag: "Revoked"; values: readonly [Buffer] }
+  | { tag: "Soulbound"; values: readonly [u64] }
+  | { tag: "ChipCurve"; values: readonly [Buffer] }
+  | { tag: "SecurityLevel"; values: readonly [u64] };
+
+/**
+ * Elliptic curve a chip signs with
+ */
+export type CurveType =
+  | { tag: "Secp256k1"; values: void }
+  | { tag: "Secp256r1"; values: void };
+
+/**
+ * Factors an owner-initiated transfer of a token must present
+ *
+ * Every chip-signed transfer also requires the owner's authorization, so
+ * there is no chip-only level: requiring the chip means requiring both.
+ */
+export type SecurityLevel =
+  | { tag: "OwnerOnly"; values: void }
+  | { tag: "Both"; values: void };
+
+/**
+ * Public key of a chip, whatever scheme it signs with
+ */
+export type ChipKey =
+  | { tag: "Ecdsa"; values: readonly [Buffer] }
+  | { tag: "Ed25519"; values: readonly [Buffer] };
+
+/**
+ * Operation a chip signature authorizes, its tag is part of the signed
+ * message so a signature produced for one operation cannot authorize another
+ */
+export enum ChipOperation {
+  Mint = 0,
+  Claim = 1,
+  Transfer = 2,
+  Burn = 3,
+  Redeem = 4,
+  Scan = 5,
+}
+
+/**
+ * Chip and owner of a token, read together on every hot path
+ */
+export interface TokenData {
+  owner: Option<string>;
+  public_key: Buffer;
+}
+
+export interface TokenApproval {
+  approved: string;
+  live_until_ledger: u32;
+}
+
+export interface MintPreview {
+  error_code: u32;
+  predicted_token_id: u64;
+  would_succeed: boolean;
+}
+
+export interface TokenInfo {
+  claimed: boolean;
+  owner: Option<string>;
+  public_key: Buffer;
+  token_id: u64;
+  uri: string;
+}
+
+export interface VerifyItem {
+  message: Buffer;
+  public_key: Buffer;
+  recovery_id: Option<u32>;
+  signature: Buffer;
+}
+
+export interface ProductVerification {
+  is_genuine: boolean;
+  owner: Option<string>;
+  revoked: boolean;
+  token_id: Option<u64>;
+}
+
+export type AttributeStorageKey = { tag: "Attributes"; values: readonly [u64] };
+
+export type AllowlistStorageKey =
+  | { tag: "AllowlistEnabled"; values: void }
+  | { tag: "AllowedChip"; values: readonly [Buffer] };
+
+export interface ChipInfo {
+  batch: u32;
+  firmware: u32;
+  model: string;
+}
+
+export type ChipInfoStorageKey =
+  | { tag: "ChipInfo"; values: readonly [Buffer] }
+  | { tag: "BatchTokenCount"; values: readonly [u32] }
+  | { tag: "BatchToken"; values: readonly [u32, u32] };
+
+export type ChipRegistryStorageKey =
+  | { tag: "ChipCount"; values: void }
+  | { tag: "RegisteredChip"; values: readonly [u64] }
+  | { tag: "ChipPosition"; values: readonly [Buffer] };
+
+export interface ChipStats {
+  claims: u32;
+  mints: u32;
+  transfers: u32;
+}
+
+export type ChipStatsStorageKey =
+  | { tag: "ChipStats"; values: readonly [Buffer] }
+  | { tag: "ScanCount"; values: readonly [Buffer] };
+
+export interface ClaimCode {
+  code_hash: Buffer;
+  expiry_ledger: u32;
+  redeemed: boolean;
+}
+
+export type ClaimCodeStorageKey = { tag: "ClaimCode"; values: readonly [u64] };
+
+export type ComposableStorageKey =
+  | { tag: "Children"; values: readonly [u64] }
+  | { tag: "Parent"; values: readonly [u64] };
+
+export interface Escrow {
+  expiration_ledger: u32;
+  from: string;
+  to: string;
+}
+
+export type EscrowStorageKey = { tag: "Escrow"; values: readonly [u64] };
+
+export type LocaleStorageKey =
+  | { tag: "LocaleUri"; values: readonly [u64, string] }
+  | { tag: "Locales"; values: readonly [u64] };
+
+export interface MerchantInfo {
+  admin: string;
+  name: string;
+}
+
+export type MerchantStorageKey =
+  | { tag: "Merchant"; values: readonly [u32] }
+  | { tag: "NextMerchantId"; values: void };
+
+export type OwnerIndexStorageKey =
+  | { tag: "HeldCount"; values: readonly [string] }
+  | { tag: "HeldToken"; values: readonly [string, u32] }
+  | { tag: "TokenPosition"; values: readonly [u64] };
+
+export type OwnershipHistoryStorageKey = {
+  tag: "OwnershipHistory";
+  values: readonly [u64];
+};
+
+export type PartnerMintStorageKey =
+  | { tag: "ChipSku"; values: readonly [Buffer] }
+  | { tag: "MintAllowance"; values: readonly [string, u32] };
+
+export type PurchaseOrderStorageKey =
+  | { tag: "TokenPo"; values: readonly [u64] }
+  | { tag: "PoTokenCount"; values: readonly [u32] }
+  | { tag: "PoToken"; values: readonly [u32, u32] };
+
+export type ReceiverStorageKey = { tag: "OptOut"; values: readonly [string] };
+
+export interface RecoveryConfig {
+  delay_ledgers: u32;
+  recovery: string;
+}
+
+export type RecoveryStorageKey =
+  | { tag: "RecoveryAddress"; values: readonly [string] }
+  | { tag: "PendingRecovery"; values: readonly [string] };
+
+export interface RecentClaim {
+  claimant: string;
+  ledger: u32;
+  token_id: u64;
+}
+
+export type RecentClaimsStorageKey =
+  | { tag: "RecentClaimsCursor"; values: void }
+  | { tag: "RecentClaimSlot"; values: readonly [u32] };
+
+export type RedemptionStorageKey = { tag: "Redeemed"; values: readonly [u64] };
+
+export type ReservationStorageKey =
+  | { tag: "Reservation"; values: readonly [u64] }
+  | { tag: "ClaimDelegate"; values: readonly [u64] };
+
+export interface Royalty {
+  basis_points: u32;
+  receiver: string;
+}
+
+export type RoyaltyStorageKey =
+  | { tag: "DefaultRoyalty"; values: void }
+  | { tag: "TokenRoyalty"; values: readonly [u64] };
+
+export interface Price {
+  amount: i128;
+  payment_token: string;
+}
+
+/**
+ * Proof of a payment collected by `claim`
+ */
+export interface Receipt {
+  amount: i128;
+  ledger: u32;
+  merchant_id: u32;
+  payer: string;
+  payment_token: string;
+  timestamp: u64;
+  token_id: u64;
+}
+
+export type SalesStorageKey =
+  | { tag: "Price"; values: void }
+  | { tag: "MerchantPrice"; values: readonly [u32] }
+  | { tag: "ReceiptCount"; values: void }
+  | { tag: "Receipt"; values: readonly [u64] }
+  | { tag: "LatestReceipt"; values: readonly [u64] }
+  | { tag: "TransferFee"; values: void }
+  | { tag: "FeeExempt"; values: readonly [string] }
+  | { tag: "DonationAddress"; values: void }
+  | { tag: "TokenDonations"; values: readonly [u64, string] }
+  | { tag: "Donations"; values: readonly [string] };
+
+export interface SeriesInfo {
+  base_uri: string;
+  max_tokens: u64;
+  /**
+   * Number of tokens minted in the series, burned ones included
+   */
+  minted: u64;
+  name: string;
+}
+
+export type SeriesStorageKey =
+  | { tag: "Series"; values: readonly [u32] }
+  | { tag: "TokenSeries"; values: readonly [u64] };
+
+export type TokenIndexStorageKey =
+  | { tag: "TokenCount"; values: void }
+  | { tag: "IndexedToken"; values: readonly [u64] }
+  | { tag: "TokenIndex"; values: readonly [u64] };
+
+export type TokenTtlStorageKey = {
+  tag: "TokenLiveUntil";
+  values: readonly [u64];
+};
+
+export type TreasuryStorageKey =
+  | { tag: "Treasury"; values: void }
+  | { tag: "PendingTreasury"; values: void }
+  | { tag: "MerchantBalance"; values: readonly [u32, string] }
+  | { tag: "HeldBalance"; values: readonly [string] };
+
+export enum WarrantyStatus {
+  Opened = 0,
+  Approved = 1,
+  Rejected = 2,
+  Fulfilled = 3,
+}
+
+export interface WarrantyClaim {
+  issue_hash: Buffer;
+  opened_ledger: u32;
+  owner: string;
+  status: WarrantyStatus;
+}
+
+export type WarrantyStorageKey = {
+  tag: "WarrantyClaim";
+  values: readonly [u64];
+};
 
 export const NonFungibleTokenError = {
   /**
@@ -56,169 +365,2182 @@ export const NonFungibleTokenError = {
    */
   201: { message: "IncorrectOwner" },
   /**
-   * Indicates overflow when adding two values
+   * Indicates a failure with the `approver` of a token to be approved.
+   */
+  203: { message: "InvalidApprover" },
+  /**
+   * Indicates an expiration ledger in the past.
+   */
+  204: { message: "InvalidLiveUntilLedger" },
+  /**
+   * Indicates overflow when adding two values
+   */
+  205: { message: "MathOverflow" },
+  /**
+   * Indicates all possible `token_id`s are already in use.
+   */
+  206: { message: "TokenIDsAreDepleted" },
+  /**
+   * Indicates an invalid amount to batch mint in `consecutive` extension.
+   */
+  207: { message: "InvalidAmount" },
+  /**
+   * Indicates the token was already minted.
+   */
+  210: { message: "TokenAlreadyMinted" },
+  /**
+   * Indicates the base URI is longer than `MAX_BASE_URI_LENGTH`.
+   */
+  211: { message: "BaseUriMaxLenExceeded" },
+  /**
+   * Indicates the royalty amount is higher than 10_000 (100%) basis points.
+   */
+  212: { message: "InvalidRoyaltyAmount" },
+  /**
+   * Indicates an invalid signature
+   */
+  214: { message: "InvalidSignature" },
+  /**
+   * Indicates the token exists but has not been claimed yet
+   */
+  215: { message: "TokenNotClaimed" },
+  /**
+   * Indicates a missing or invalid reason code for an admin action
+   */
+  216: { message: "InvalidReasonCode" },
+  /**
+   * Indicates the token is attached to a parent token or has children
+   * attached, or the attachment would nest tokens more than one level deep
+   */
+  217: { message: "TokenAttached" },
+  /**
+   * Indicates there is no open escrow for the token
+   */
+  218: { message: "EscrowNotFound" },
+  /**
+   * Indicates the escrow timeout has not been reached yet
+   */
+  219: { message: "EscrowNotExpired" },
+  /**
+   * Indicates the escrow timeout has been reached
+   */
+  220: { message: "EscrowExpired" },
+  /**
+   * Indicates the token is held in escrow
+   */
+  221: { message: "TokenInEscrow" },
+  /**
+   * Indicates a status change that the workflow does not allow
+   */
+  222: { message: "InvalidStatusTransition" },
+  /**
+   * Indicates the token changed hands too recently to be transferred again
+   */
+  223: { message: "TransferCooldownActive" },
+  /**
+   * Indicates the token is reserved for another claimant
+   */
+  224: { message: "ReservedForAnother" },
+  /**
+   * Indicates a missing or incorrect claim code
+   */
+  225: { message: "InvalidClaimCode" },
+  /**
+   * Indicates the claim code was already redeemed
+   */
+  226: { message: "ClaimCodeRedeemed" },
+  /**
+   * Indicates the claim code expired
+   */
+  227: { message: "ClaimCodeExpired" },
+  /**
+   * Indicates the contract was already initialized
+   */
+  228: { message: "AlreadyInitialized" },
+  /**
+   * Indicates there is no treasury proposal to accept
+   */
+  229: { message: "NoPendingTreasury" },
+  /**
+   * Indicates the partner has no mint allowance left for the SKU
+   */
+  230: { message: "MintAllowanceExhausted" },
+  /**
+   * Indicates the chip is not allocated to the requested SKU
+   */
+  231: { message: "ChipSkuMismatch" },
+  /**
+   * Indicates an empty signed message or one over the maximum length
+   */
+  232: { message: "MalformedMessage" },
+  /**
+   * Indicates an empty URI or one over the maximum length
+   */
+  233: { message: "InvalidUri" },
+  /**
+   * Indicates the account is not the recovery address of the owner
+   */
+  234: { message: "RecoveryNotConfigured" },
+  /**
+   * Indicates there is no announced recovery for the owner
+   */
+  235: { message: "NoPendingRecovery" },
+  /**
+   * Indicates the recovery delay has not passed yet
+   */
+  236: { message: "RecoveryDelayActive" },
+  /**
+   * Indicates the spender has no valid approval for the token
+   */
+  237: { message: "Unauthorized" },
+  /**
+   * Indicates the nonce was not greater than the last consumed one
+   */
+  238: { message: "NonceTooLow" },
+  /**
+   * Indicates the signature recovers to another public key
+   */
+  239: { message: "SignatureRecoveryMismatch" },
+  /**
+   * Indicates a recovery ID outside of 0-3
+   */
+  240: { message: "InvalidRecoveryId" },
+  /**
+   * Indicates a signature that is not valid DER
+   */
+  241: { message: "MalformedSignature" },
+  /**
+   * Indicates a signature used after its expiration ledger
+   */
+  242: { message: "SignatureExpired" },
+  /**
+   * Indicates there is no admin transfer to accept
+   */
+  243: { message: "NoPendingAdmin" },
+  /**
+   * Indicates the chip is not on the allowlist
+   */
+  244: { message: "ChipNotAllowed" },
+  /**
+   * Indicates the chip was revoked
+   */
+  245: { message: "ChipRevoked" },
+  /**
+   * Indicates the token is soulbound and cannot be transferred
+   */
+  246: { message: "TokenSoulbound" },
+  /**
+   * Indicates the perk of the token was already redeemed
+   */
+  247: { message: "AlreadyRedeemed" },
+  /**
+   * Indicates an attribute key or value over the maximum length
+   */
+  248: { message: "AttributeTooLong" },
+  /**
+   * Indicates a series that does not exist
+   */
+  249: { message: "SeriesNotFound" },
+  /**
+   * Indicates a series id that is already taken
+   */
+  250: { message: "SeriesAlreadyExists" },
+  /**
+   * Indicates a claim after the claim deadline
+   */
+  251: { message: "ClaimWindowClosed" },
+  /**
+   * Indicates the claim deadline has not passed yet
+   */
+  252: { message: "ClaimWindowOpen" },
+  /**
+   * Indicates the recipient contract refused the token
+   */
+  253: { message: "ReceiverRejected" },
+  /**
+   * Indicates a public key that is neither an uncompressed SEC1 point
+   * nor a non-zero Ed25519 key
+   */
+  254: { message: "InvalidPublicKey" },
+  /**
+   * Indicates the token requires a chip signature and the owner's authorization to move
+   */
+  255: { message: "DualAuthRequired" },
+  /**
+   * Indicates the nonce is further ahead of the last consumed one than the nonce window
+   */
+  256: { message: "NonceTooFarAhead" },
+  /**
+   * Indicates a merchant that does not exist
+   */
+  257: { message: "MerchantNotFound" },
+  /**
+   * Indicates a read-only check of a `Secp256r1` chip, the host aborts
+   * instead of failing on a signature that does not match
+   */
+  258: { message: "UnverifiableCurve" },
+  /**
+   * Indicates the token already has an owner
+   */
+  259: { message: "TokenAlreadyClaimed" },
+  /**
+   * Indicates a transfer to another account than the one the approval is restricted to
+   */
+  260: { message: "RecipientNotAllowed" },
+  /**
+   * Indicates a collection or series name longer than `MAX_NAME_LENGTH`
+   */
+  261: { message: "NameTooLong" },
+  /**
+   * Indicates a donation while no donation address is set
+   */
+  262: { message: "DonationAddressNotSet" },
+};
+
+/**
+ * Token state change reported by ActivityV1
+ */
+export type Operation =
+  | { tag: "Mint"; values: void }
+  | { tag: "Claim"; values: void }
+  | { tag: "Transfer"; values: void }
+  | { tag: "AdminTransfer"; values: void }
+  | { tag: "EscrowOpened"; values: void }
+  | { tag: "EscrowCompleted"; values: void }
+  | { tag: "EscrowReclaimed"; values: void }
+  | { tag: "Burn"; values: void };
+
+export interface Client {
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  upgrade: (
+    { wasm_hash }: { wasm_hash: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  migrate: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a migration_progress transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  migration_progress: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<readonly [u64, u64]>>;
+
+  /**
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a build_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  build_info: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<BuildInfo>>;
+
+  /**
+   * Construct and simulate a transfer_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  transfer_admin: (
+    { new_admin }: { new_admin: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  accept_admin: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  pending_admin: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  mint: (
+    {
+      series_id,
+      message,
+      signature,
+      recovery_id,
+      public_key,
+      nonce,
+      expiration_ledger,
+      chip_info,
+      po_number,
+    }: {
+      series_id: u32;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+      chip_info: Option<ChipInfo>;
+      po_number: Option<u32>;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a mint_der transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  mint_der: (
+    {
+      series_id,
+      message,
+      der_signature,
+      public_key,
+      nonce,
+      expiration_ledger,
+      chip_info,
+      po_number,
+    }: {
+      series_id: u32;
+      message: Buffer;
+      der_signature: Buffer;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+      chip_info: Option<ChipInfo>;
+      po_number: Option<u32>;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a mint_ed25519 transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  mint_ed25519: (
+    {
+      series_id,
+      message,
+      signature,
+      public_key,
+      nonce,
+      expiration_ledger,
+      chip_info,
+      po_number,
+    }: {
+      series_id: u32;
+      message: Buffer;
+      signature: Buffer;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+      chip_info: Option<ChipInfo>;
+      po_number: Option<u32>;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a simulate_mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  simulate_mint: (
+    {
+      series_id,
+      message,
+      signature,
+      recovery_id,
+      public_key,
+      nonce,
+      expiration_ledger,
+    }: {
+      series_id: u32;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<MintPreview>>;
+
+  /**
+   * Construct and simulate a verify transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  verify: (
+    {
+      operation,
+      message,
+      signature,
+      recovery_id,
+      public_key,
+      recipient,
+      nonce,
+      expiration_ledger,
+    }: {
+      operation: ChipOperation;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+      recipient: Option<string>;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim: (
+    {
+      claimant,
+      message,
+      signature,
+      recovery_id,
+      public_key,
+      nonce,
+      expiration_ledger,
+    }: {
+      claimant: string;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a claim_der transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_der: (
+    {
+      claimant,
+      message,
+      der_signature,
+      public_key,
+      nonce,
+      expiration_ledger,
+    }: {
+      claimant: string;
+      message: Buffer;
+      der_signature: Buffer;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a claim_ed25519 transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_ed25519: (
+    {
+      claimant,
+      message,
+      signature,
+      public_key,
+      nonce,
+      expiration_ledger,
+    }: {
+      claimant: string;
+      message: Buffer;
+      signature: Buffer;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a claim_with_donation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_with_donation: (
+    {
+      claimant,
+      message,
+      signature,
+      recovery_id,
+      public_key,
+      nonce,
+      expiration_ledger,
+      donation,
+    }: {
+      claimant: string;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+      donation: i128;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a mint_and_claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  mint_and_claim: (
+    {
+      to,
+      message,
+      signature,
+      recovery_id,
+      public_key,
+      nonce,
+      expiration_ledger,
+    }: {
+      to: string;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a register_chips transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  register_chips: (
+    { series_id, public_keys }: { series_id: u32; public_keys: Array<Buffer> },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+
+  /**
+   * Construct and simulate a set_claim_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_claim_deadline: (
+    { ledger_timestamp }: { ledger_timestamp: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a claim_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_deadline: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a reclaim_unclaimed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  reclaim_unclaimed: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a chip_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  chip_transfer: (
+    {
+      from,
+      to,
+      token_id,
+      message,
+      signature,
+      recovery_id,
+      public_key,
+      nonce,
+      expiration_ledger,
+    }: {
+      from: string;
+      to: string;
+      token_id: u64;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a chip_transfer_der transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  chip_transfer_der: (
+    {
+      from,
+      to,
+      token_id,
+      message,
+      der_signature,
+      public_key,
+      nonce,
+      expiration_ledger,
+    }: {
+      from: string;
+      to: string;
+      token_id: u64;
+      message: Buffer;
+      der_signature: Buffer;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a chip_transfer_ed25519 transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  chip_transfer_ed25519: (
+    {
+      from,
+      to,
+      token_id,
+      message,
+      signature,
+      public_key,
+      nonce,
+      expiration_ledger,
+    }: {
+      from: string;
+      to: string;
+      token_id: u64;
+      message: Buffer;
+      signature: Buffer;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  burn: (
+    {
+      from,
+      token_id,
+      message,
+      signature,
+      recovery_id,
+      public_key,
+      nonce,
+      expiration_ledger,
+    }: {
+      from: string;
+      token_id: u64;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a burn_from_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  burn_from_owner: (
+    { owner, token_id }: { owner: string; token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a admin_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  admin_transfer: (
+    { token_id, to, reason }: { token_id: u64; to: string; reason: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a approve_operator_limited transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  approve_operator_limited: (
+    {
+      owner,
+      operator,
+      remaining,
+      live_until_ledger,
+    }: {
+      owner: string;
+      operator: string;
+      remaining: u32;
+      live_until_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a operator_allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  operator_allowance: (
+    { owner, operator }: { owner: string; operator: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<u32>>>;
+
+  /**
+   * Construct and simulate a approve_for_recipient transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  approve_for_recipient: (
+    {
+      approver,
+      approved,
+      token_id,
+      recipient,
+      live_until_ledger,
+    }: {
+      approver: string;
+      approved: string;
+      token_id: u64;
+      recipient: string;
+      live_until_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a approved_recipient transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  approved_recipient: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a set_soulbound transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_soulbound: (
+    { token_id, soulbound }: { token_id: u64; soulbound: boolean },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a is_soulbound transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_soulbound: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a set_security_level transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_security_level: (
+    { token_id, level }: { token_id: u64; level: SecurityLevel },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a security_level transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  security_level: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<SecurityLevel>>;
+
+  /**
+   * Construct and simulate a rebind_chip transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  rebind_chip: (
+    { token_id, public_key }: { token_id: u64; public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_transfer_cooldown transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_transfer_cooldown: (
+    { ledgers }: { ledgers: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a transfer_cooldown transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  transfer_cooldown: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a set_nonce_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_nonce_window: (
+    { window }: { window: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a nonce_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  nonce_window: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a set_chip_auth transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_chip_auth: (
+    { chip_auth }: { chip_auth: Option<string> },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a chip_auth transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  chip_auth: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a set_max_message_length transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_max_message_length: (
+    { length }: { length: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a max_message_length transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  max_message_length: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a set_max_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_max_tokens: (
+    { max_tokens }: { max_tokens: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a max_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  max_tokens: (options?: MethodOptions) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a remaining_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  remaining_supply: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  total_supply: (options?: MethodOptions) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a minted_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  minted_count: (options?: MethodOptions) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a claimed_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claimed_count: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a burned_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  burned_count: (options?: MethodOptions) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a set_checkpoint_interval transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_checkpoint_interval: (
+    { mints }: { mints: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a checkpoint_interval transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  checkpoint_interval: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a verify_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  verify_batch: (
+    { items }: { items: Array<VerifyItem> },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<boolean>>>;
+
+  /**
+   * Construct and simulate a verify_product transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  verify_product: (
+    {
+      message,
+      signature,
+      recovery_id,
+      public_key,
+    }: {
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<ProductVerification>>;
+
+  /**
+   * Construct and simulate a revoke_chip transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  revoke_chip: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a unrevoke_chip transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  unrevoke_chip: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a is_revoked transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_revoked: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a set_chip_curve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_chip_curve: (
+    { public_key, curve }: { public_key: Buffer; curve: CurveType },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a chip_curve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  chip_curve: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<CurveType>>;
+
+  /**
+   * Construct and simulate a get_nonce transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_nonce: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a message_domain transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  message_domain: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a build_message_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  build_message_hash: (
+    {
+      operation,
+      message,
+      recipient,
+      nonce,
+      expiration_ledger,
+    }: {
+      operation: ChipOperation;
+      message: Buffer;
+      recipient: Option<string>;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a owner_of_opt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  owner_of_opt: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a exists transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  exists: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a is_claimed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_claimed: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a token_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  token_info: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<TokenInfo>>;
+
+  /**
+   * Construct and simulate a tokens_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  tokens_info: (
+    { token_ids }: { token_ids: Array<u64> },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<TokenInfo>>>;
+
+  /**
+   * Construct and simulate a token_uri_opt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  token_uri_opt: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a set_base_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_base_uri: (
+    { uri }: { uri: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a base_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  base_uri: (options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a set_token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_token_uri: (
+    { token_id, uri }: { token_id: u64; uri: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a clear_token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  clear_token_uri: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_unclaimed_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_unclaimed_uri: (
+    { uri }: { uri: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a unclaimed_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  unclaimed_uri: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a token_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  token_id: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a token_id_opt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  token_id_opt: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<u64>>>;
+
+  /**
+   * Construct and simulate a public_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  public_key: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a public_key_opt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  public_key_opt: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<Buffer>>>;
+
+  /**
+   * Construct and simulate a chip_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  chip_key: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<ChipKey>>;
+
+  /**
+   * Construct and simulate a chip_public_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  chip_public_key: (
+    { chip_key }: { chip_key: ChipKey },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  balance: (
+    { owner }: { owner: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  owner_of: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  transfer: (
+    { from, to, token_id }: { from: string; to: string; token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  transfer_from: (
+    {
+      spender,
+      from,
+      to,
+      token_id,
+    }: {
+      spender: string;
+      from: string;
+      to: string;
+      token_id: u64;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  approve: (
+    {
+      approver,
+      approved,
+      token_id,
+      live_until_ledger,
+    }: {
+      approver: string;
+      approved: string;
+      token_id: u64;
+      live_until_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a approve_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  approve_for_all: (
+    {
+      owner,
+      operator,
+      live_until_ledger,
+    }: {
+      owner: string;
+      operator: string;
+      live_until_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a get_approved transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_approved: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a is_approved_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_approved_for_all: (
+    { owner, operator }: { owner: string; operator: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  name: (options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  symbol: (options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  token_uri: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a set_attribute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_attribute: (
+    { token_id, key, value }: { token_id: u64; key: string; value: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a get_attribute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_attribute: (
+    { token_id, key }: { token_id: u64; key: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a attributes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  attributes: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Map<string, string>>>;
+
+  /**
+   * Construct and simulate a add_allowed_chips transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  add_allowed_chips: (
+    { public_keys }: { public_keys: Array<Buffer> },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a remove_allowed_chip transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  remove_allowed_chip: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_allowlist_enabled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_allowlist_enabled: (
+    { enabled }: { enabled: boolean },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a allowlist_enabled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  allowlist_enabled: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a is_chip_allowed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_chip_allowed: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a chip_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  chip_info: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<ChipInfo>>>;
+
+  /**
+   * Construct and simulate a tokens_by_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  tokens_by_batch: (
+    { batch, start, limit }: { batch: u32; start: u32; limit: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+
+  /**
+   * Construct and simulate a update_chip_firmware transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  update_chip_firmware: (
+    { public_key, firmware }: { public_key: Buffer; firmware: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a registered_chips transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  registered_chips: (
+    { start, limit }: { start: u32; limit: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<Buffer>>>;
+
+  /**
+   * Construct and simulate a registered_chip_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  registered_chip_count: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a chip_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  chip_stats: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<ChipStats>>;
+
+  /**
+   * Construct and simulate a record_scan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  record_scan: (
+    {
+      public_key,
+      message,
+      signature,
+      recovery_id,
+      nonce,
+      expiration_ledger,
+    }: {
+      public_key: Buffer;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a scan_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  scan_count: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a issue_claim_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  issue_claim_code: (
+    {
+      token_id,
+      code_hash,
+      expiry_ledger,
+    }: {
+      token_id: u64;
+      code_hash: Buffer;
+      expiry_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a claim_with_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_with_code: (
+    {
+      claimant,
+      token_id,
+      code_preimage,
+    }: {
+      claimant: string;
+      token_id: u64;
+      code_preimage: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a attach transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  attach: (
+    { parent_id, child_id }: { parent_id: u64; child_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a detach transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  detach: (
+    { parent_id, child_id }: { parent_id: u64; child_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a children_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  children_of: (
+    { parent_id }: { parent_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+
+  /**
+   * Construct and simulate a parent_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  parent_of: (
+    { child_id }: { child_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<u64>>>;
+
+  /**
+   * Construct and simulate a escrow_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  escrow_transfer: (
+    {
+      from,
+      to,
+      token_id,
+      timeout_ledgers,
+      message,
+      signature,
+      recovery_id,
+      public_key,
+      nonce,
+    }: {
+      from: string;
+      to: string;
+      token_id: u64;
+      timeout_ledgers: u32;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+      nonce: u64;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a confirm_receipt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  confirm_receipt: (
+    { to, token_id }: { to: string; token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a reclaim_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  reclaim_escrow: (
+    { from, token_id }: { from: string; token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a escrow_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  escrow_of: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<Escrow>>>;
+
+  /**
+   * Construct and simulate a set_locale_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_locale_uri: (
+    { token_id, locale, uri }: { token_id: u64; locale: string; uri: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a token_uri_localized transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  token_uri_localized: (
+    { token_id, locale }: { token_id: u64; locale: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a locales_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  locales_of: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<string>>>;
+
+  /**
+   * Construct and simulate a create_merchant transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  create_merchant: (
+    {
+      merchant_admin,
+      name,
+      base_uri,
+      max_tokens,
+    }: {
+      merchant_admin: string;
+      name: string;
+      base_uri: string;
+      max_tokens: u64;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a set_merchant_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_merchant_admin: (
+    { merchant_id, new_admin }: { merchant_id: u32; new_admin: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_merchant_base_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_merchant_base_uri: (
+    { merchant_id, uri }: { merchant_id: u32; uri: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a merchant_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  merchant_info: (
+    { merchant_id }: { merchant_id: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<MerchantInfo>>;
+
+  /**
+   * Construct and simulate a merchant_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  merchant_of: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a tokens_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  tokens_of: (
+    { owner, start, limit }: { owner: string; start: u32; limit: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+
+  /**
+   * Construct and simulate a ownership_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  ownership_history: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<readonly [string, u32]>>>;
+
+  /**
+   * Construct and simulate a set_chip_sku transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_chip_sku: (
+    { public_key, sku }: { public_key: Buffer; sku: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a chip_sku transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  chip_sku: (
+    { public_key }: { public_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<u32>>>;
+
+  /**
+   * Construct and simulate a grant_mint_allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  grant_mint_allowance: (
+    { contract, sku, quantity }: { contract: string; sku: u32; quantity: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a mint_allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  mint_allowance: (
+    { contract, sku }: { contract: string; sku: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a partner_mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  partner_mint: (
+    {
+      partner,
+      to,
+      sku,
+      message,
+      signature,
+      recovery_id,
+      public_key,
+      nonce,
+    }: {
+      partner: string;
+      to: string;
+      sku: u32;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+      nonce: u64;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a po_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  po_of: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<u32>>>;
+
+  /**
+   * Construct and simulate a tokens_by_po transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  tokens_by_po: (
+    { po, start, limit }: { po: u32; start: u32; limit: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+
+  /**
+   * Construct and simulate a set_receiver_hook transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_receiver_hook: (
+    { receiver, enabled }: { receiver: string; enabled: boolean },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a receiver_hook transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  receiver_hook: (
+    { receiver }: { receiver: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a set_recovery_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_recovery_address: (
+    {
+      owner,
+      recovery,
+      delay_ledgers,
+    }: {
+      owner: string;
+      recovery: string;
+      delay_ledgers: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a recovery_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  recovery_of: (
+    { owner }: { owner: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<RecoveryConfig>>>;
+
+  /**
+   * Construct and simulate a initiate_recovery transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  initiate_recovery: (
+    { recovery, owner }: { recovery: string; owner: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a cancel_recovery transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  cancel_recovery: (
+    { owner }: { owner: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a pending_recovery transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  pending_recovery: (
+    { owner }: { owner: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<u32>>>;
+
+  /**
+   * Construct and simulate a execute_recovery transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  execute_recovery: (
+    { recovery, owner }: { recovery: string; owner: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a recent_claims transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  recent_claims: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<RecentClaim>>>;
+
+  /**
+   * Construct and simulate a redeem transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  redeem: (
+    {
+      token_id,
+      message,
+      signature,
+      recovery_id,
+      public_key,
+      nonce,
+      expiration_ledger,
+    }: {
+      token_id: u64;
+      message: Buffer;
+      signature: Buffer;
+      recovery_id: Option<u32>;
+      public_key: Buffer;
+      nonce: u64;
+      expiration_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a is_redeemed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  205: { message: "MathOverflow" },
+  is_redeemed: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
   /**
-   * Indicates all possible `token_id`s are already in use.
+   * Construct and simulate a redeemed_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  206: { message: "TokenIDsAreDepleted" },
+  redeemed_at: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<u64>>>;
+
   /**
-   * Indicates an invalid amount to batch mint in `consecutive` extension.
+   * Construct and simulate a reserve_for transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  207: { message: "InvalidAmount" },
+  reserve_for: (
+    {
+      token_id,
+      claimant,
+      expiry_ledger,
+    }: {
+      token_id: u64;
+      claimant: string;
+      expiry_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
   /**
-   * Indicates the token was already minted.
+   * Construct and simulate a release_reservation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  210: { message: "TokenAlreadyMinted" },
+  release_reservation: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
   /**
-   * Indicates the royalty amount is higher than 10_000 (100%) basis points.
+   * Construct and simulate a reservation_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  212: { message: "InvalidRoyaltyAmount" },
+  reservation_of: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<readonly [string, u32]>>>;
+
   /**
-   * Indicates an invalid signature
+   * Construct and simulate a delegate_claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  214: { message: "InvalidSignature" },
+  delegate_claim: (
+    {
+      delegator,
+      token_id,
+      delegate,
+    }: {
+      delegator: string;
+      token_id: u64;
+      delegate: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
   /**
-   * Indicates the token exists but has not been claimed yet
+   * Construct and simulate a claim_delegate_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  215: { message: "TokenNotClaimed" },
-};
+  claim_delegate_of: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
 
-export interface Client {
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_default_royalty transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  upgrade: (
-    { wasm_hash }: { wasm_hash: Buffer },
+  set_default_royalty: (
+    { receiver, basis_points }: { receiver: string; basis_points: u32 },
     options?: MethodOptions,
   ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_token_royalty transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  mint: (
+  set_token_royalty: (
     {
-      message,
-      signature,
-      recovery_id,
-      public_key,
-      nonce,
+      token_id,
+      receiver,
+      basis_points,
     }: {
-      message: Buffer;
-      signature: Buffer;
-      recovery_id: u32;
-      public_key: Buffer;
-      nonce: u32;
+      token_id: u64;
+      receiver: string;
+      basis_points: u32;
     },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<u64>>;
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a royalty_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  claim: (
+  royalty_info: (
+    { token_id, sale_price }: { token_id: u64; sale_price: i128 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<readonly [string, i128]>>;
+
+  /**
+   * Construct and simulate a set_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_price: (
     {
-      claimant,
-      message,
-      signature,
-      recovery_id,
-      public_key,
-      nonce,
+      merchant_id,
+      payment_token,
+      amount,
     }: {
-      claimant: string;
-      message: Buffer;
-      signature: Buffer;
-      recovery_id: u32;
-      public_key: Buffer;
-      nonce: u32;
+      merchant_id: u32;
+      payment_token: string;
+      amount: i128;
     },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<u64>>;
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  transfer: (
+  price: (
+    { merchant_id }: { merchant_id: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<readonly [string, i128]>>;
+
+  /**
+   * Construct and simulate a receipt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  receipt: (
+    { receipt_id }: { receipt_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<Receipt>>>;
+
+  /**
+   * Construct and simulate a latest_receipt_for transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  latest_receipt_for: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<readonly [u64, Receipt]>>>;
+
+  /**
+   * Construct and simulate a set_transfer_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_transfer_fee: (
+    { payment_token, amount }: { payment_token: string; amount: i128 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a transfer_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  transfer_fee: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<readonly [string, i128]>>;
+
+  /**
+   * Construct and simulate a set_fee_exempt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_fee_exempt: (
+    { account, exempt }: { account: string; exempt: boolean },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a is_fee_exempt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_fee_exempt: (
+    { account }: { account: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a set_donation_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_donation_address: (
+    { donation_address }: { donation_address: Option<string> },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a donation_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  donation_address: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a token_donations transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  token_donations: (
+    { token_id, payment_token }: { token_id: u64; payment_token: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a total_donations transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  total_donations: (
+    { payment_token }: { payment_token: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a create_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  create_series: (
     {
-      from,
-      to,
-      token_id,
-      message,
-      signature,
-      recovery_id,
-      public_key,
-      nonce,
+      series_id,
+      name,
+      base_uri,
+      max_tokens,
     }: {
-      from: string;
-      to: string;
-      token_id: u64;
-      message: Buffer;
-      signature: Buffer;
-      recovery_id: u32;
-      public_key: Buffer;
-      nonce: u32;
+      series_id: u32;
+      name: string;
+      base_uri: string;
+      max_tokens: u64;
     },
     options?: MethodOptions,
   ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a get_nonce transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a series_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_nonce: (
-    { public_key }: { public_key: Buffer },
+  series_of: (
+    { token_id }: { token_id: u64 },
     options?: MethodOptions,
   ) => Promise<AssembledTransaction<u32>>;
 
   /**
-   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a series_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  balance: (
-    { owner }: { owner: string },
+  series_info: (
+    { series_id }: { series_id: u32 },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<u32>>;
+  ) => Promise<AssembledTransaction<SeriesInfo>>;
 
   /**
-   * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a token_by_index transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  owner_of: (
-    { token_id }: { token_id: u64 },
+  token_by_index: (
+    { index }: { index: u64 },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<string>>;
+  ) => Promise<AssembledTransaction<u64>>;
 
   /**
-   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a all_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  name: (options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+  all_tokens: (
+    { start, limit }: { start: u64; limit: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<u64>>>;
 
   /**
-   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a token_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  symbol: (options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+  token_ttl: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
 
   /**
-   * Construct and simulate a token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a extend_token_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  token_uri: (
+  extend_token_ttl: (
+    { token_id, extend_to }: { token_id: u64; extend_to: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a bump_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  bump_token: (
     { token_id }: { token_id: u64 },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<string>>;
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a token_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a bump_chip transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  token_id: (
+  bump_chip: (
     { public_key }: { public_key: Buffer },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<u64>>;
+  ) => Promise<AssembledTransaction<null>>;
 
   /**
-   * Construct and simulate a public_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a extend_instance_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  public_key: (
+  extend_instance_ttl: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_treasury: (
+    { new_treasury }: { new_treasury: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a accept_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  accept_treasury: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  treasury: (options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a pending_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  pending_treasury: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a withdraw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  withdraw: (
+    {
+      merchant_id,
+      token,
+      to,
+      amount,
+    }: {
+      merchant_id: u32;
+      token: string;
+      to: Option<string>;
+      amount: i128;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a treasury_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  treasury_balance: (
+    { token }: { token: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a merchant_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  merchant_balance: (
+    { merchant_id, token }: { merchant_id: u32; token: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<i128>>;
+
+  /**
+   * Construct and simulate a open_warranty_claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  open_warranty_claim: (
+    { token_id, issue_hash }: { token_id: u64; issue_hash: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a resolve_warranty_claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  resolve_warranty_claim: (
+    { token_id, approved }: { token_id: u64; approved: boolean },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a fulfill_warranty_claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  fulfill_warranty_claim: (
     { token_id }: { token_id: u64 },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<Buffer>>;
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a warranty_claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  warranty_claim: (
+    { token_id }: { token_id: u64 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<WarrantyClaim>>>;
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
@@ -229,12 +2551,16 @@ export class Client extends ContractClient {
       symbol,
       uri,
       max_tokens,
+      activity_events,
+      onchain_metadata,
     }: {
       admin: string;
       name: string;
       symbol: string;
       uri: string;
       max_tokens: u64;
+      activity_events: boolean;
+      onchain_metadata: boolean;
     },
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
@@ -248,51 +2574,525 @@ export class Client extends ContractClient {
       },
   ): Promise<AssembledTransaction<T>> {
     return ContractClient.deploy(
-      { admin, name, symbol, uri, max_tokens },
+      {
+        admin,
+        name,
+        symbol,
+        uri,
+        max_tokens,
+        activity_events,
+        onchain_metadata,
+      },
       options,
     );
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
       new ContractSpec([
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAAAAAAAAAAABUFkbWluAAAAAAAAAAAAAAAAAAALTmV4dFRva2VuSWQAAAAAAAAAAAAAAAAJTWF4VG9rZW5zAAAA",
-        "AAAAAgAAAAAAAAAAAAAADU5GVFN0b3JhZ2VLZXkAAAAAAAAIAAAAAQAAAAAAAAAUQ2hpcE5vbmNlQnlQdWJsaWNLZXkAAAABAAAD7gAAAEEAAAABAAAAAAAAAAVPd25lcgAAAAAAAAEAAAAGAAAAAQAAAAAAAAAJUHVibGljS2V5AAAAAAAAAQAAAAYAAAABAAAAAAAAABJUb2tlbklkQnlQdWJsaWNLZXkAAAAAAAEAAAPuAAAAQQAAAAEAAAAAAAAAB0JhbGFuY2UAAAAAAQAAABMAAAAAAAAAAAAAAAROYW1lAAAAAAAAAAAAAAAGU3ltYm9sAAAAAAAAAAAAAAAAAANVUkkA",
-        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAUAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAADdXJpAAAAABAAAAAAAAAACm1heF90b2tlbnMAAAAAAAYAAAAA",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAEQAAAAAAAAAAAAAABUFkbWluAAAAAAAAAAAAAAAAAAALTmV4dFRva2VuSWQAAAAAAAAAAChNb3ZlZCB0byB0aGUgZGVmYXVsdCBzZXJpZXMgYnkgYG1pZ3JhdGVgAAAACU1heFRva2VucwAAAAAAAAAAAAAAAAAACUJ1aWxkSW5mbwAAAAAAAAAAAAAAAAAAEFRyYW5zZmVyQ29vbGRvd24AAAAAAAAAAAAAAAtOb25jZVdpbmRvdwAAAAAAAAAAAAAAAAxDbGFpbWVkQ291bnQAAAAAAAAAAAAAABJDaGVja3BvaW50SW50ZXJ2YWwAAAAAAAAAAAAAAAAACENoaXBBdXRoAAAAAAAAAAAAAAAOQWN0aXZpdHlFdmVudHMAAAAAAAAAAAAAAAAAEE1heE1lc3NhZ2VMZW5ndGgAAAAAAAAAAAAAAAxQZW5kaW5nQWRtaW4AAAAAAAAAAAAAAAtCdXJuZWRDb3VudAAAAAAAAAAAAAAAAA1DbGFpbURlYWRsaW5lAAAAAAAAAAAAADNOZXh0IHRva2VuIGlkIGBtaWdyYXRlYCBtb3ZlcyB0byB0aGUgYFRva2VuYCBsYXlvdXQAAAAAFFRva2VuTWlncmF0aW9uQ3Vyc29yAAAAAAAAAEVOdW1iZXIgb2YgdG9rZW5zIG1vdmVkIHRvIHRoZSBgVG9rZW5gIGxheW91dCwgYnkgYG1pZ3JhdGVgIG9yIG9uIHJlYWQAAAAAAAASTWlncmF0ZWRUb2tlbkNvdW50AAAAAAAAAAAAAAAAAA9PbmNoYWluTWV0YWRhdGEA",
+        "AAAAAQAAAAAAAAAAAAAACUJ1aWxkSW5mbwAAAAAAAAMAAAAAAAAADGdpdF9kZXNjcmliZQAAABAAAAAAAAAAB3Byb2ZpbGUAAAAAEAAAAAAAAAAHdmVyc2lvbgAAAAAQ",
+        "AAAAAgAAAAAAAAAAAAAADU5GVFN0b3JhZ2VLZXkAAAAAAAAUAAAAAQAAAAAAAAAUQ2hpcE5vbmNlQnlQdWJsaWNLZXkAAAABAAAD7gAAAEEAAAABAAAAHU1vdmVkIHRvIGBUb2tlbmAgYnkgYG1pZ3JhdGVgAAAAAAAABU93bmVyAAAAAAAAAQAAAAYAAAABAAAAHU1vdmVkIHRvIGBUb2tlbmAgYnkgYG1pZ3JhdGVgAAAAAAAACVB1YmxpY0tleQAAAAAAAAEAAAAGAAAAAQAAAAAAAAAFVG9rZW4AAAAAAAABAAAABgAAAAEAAAAAAAAAElRva2VuSWRCeVB1YmxpY0tleQAAAAAAAQAAA+4AAABBAAAAAQAAAAAAAAAHQmFsYW5jZQAAAAABAAAAEwAAAAEAAAAAAAAAEkxhc3RUcmFuc2ZlckxlZGdlcgAAAAAAAQAAAAYAAAAAAAAAAAAAAAROYW1lAAAAAAAAAAAAAAAGU3ltYm9sAAAAAAAAAAAAKE1vdmVkIHRvIHRoZSBkZWZhdWx0IHNlcmllcyBieSBgbWlncmF0ZWAAAAADVVJJAAAAAAAAAAAAAAAADFVuY2xhaW1lZFVSSQAAAAEAAAAAAAAACEFwcHJvdmFsAAAAAQAAAAYAAAABAAAAAAAAAA5BcHByb3ZhbEZvckFsbAAAAAAAAgAAABMAAAATAAAAAQAAAEZUcmFuc2ZlcnMgbGVmdCB0byBhbiBvcGVyYXRvciBhcHByb3ZlZCB3aXRoIGBhcHByb3ZlX29wZXJhdG9yX2xpbWl0ZWRgAAAAAAART3BlcmF0b3JBbGxvd2FuY2UAAAAAAAACAAAAEwAAABMAAAABAAAARk9ubHkgcmVjaXBpZW50IG9mIHRoZSB0b2tlbidzIGFwcHJvdmFsLCBzZXQgYnkgYGFwcHJvdmVfZm9yX3JlY2lwaWVudGAAAAAAABFBcHByb3ZhbFJlY2lwaWVudAAAAAAAAAEAAAAGAAAAAQAAAAAAAAAIVG9rZW5VUkkAAAABAAAABgAAAAEAAAAAAAAAB1Jldm9rZWQAAAAAAQAAA+4AAABBAAAAAQAAAAAAAAAJU291bGJvdW5kAAAAAAAAAQAAAAYAAAABAAAAAAAAAAlDaGlwQ3VydmUAAAAAAAABAAAD7gAAAEEAAAABAAAAAAAAAA1TZWN1cml0eUxldmVsAAAAAAAAAQAAAAY=",
+        "AAAAAgAAACBFbGxpcHRpYyBjdXJ2ZSBhIGNoaXAgc2lnbnMgd2l0aAAAAAAAAAAJQ3VydmVUeXBlAAAAAAAAAgAAAAAAAAAAAAAACVNlY3AyNTZrMQAAAAAAAAAAAAAAAAAACVNlY3AyNTZyMQAAAA==",
+        "AAAAAgAAAMlGYWN0b3JzIGFuIG93bmVyLWluaXRpYXRlZCB0cmFuc2ZlciBvZiBhIHRva2VuIG11c3QgcHJlc2VudAoKRXZlcnkgY2hpcC1zaWduZWQgdHJhbnNmZXIgYWxzbyByZXF1aXJlcyB0aGUgb3duZXIncyBhdXRob3JpemF0aW9uLCBzbwp0aGVyZSBpcyBubyBjaGlwLW9ubHkgbGV2ZWw6IHJlcXVpcmluZyB0aGUgY2hpcCBtZWFucyByZXF1aXJpbmcgYm90aC4AAAAAAAAAAAAADVNlY3VyaXR5TGV2ZWwAAAAAAAACAAAAAAAAAE9UaGUgb3duZXIncyBhdXRob3JpemF0aW9uIGlzIGVub3VnaCwgYHRyYW5zZmVyYCBhbmQgYHRyYW5zZmVyX2Zyb21gIGFyZSBhbGxvd2VkAAAAAAlPd25lck9ubHkAAAAAAAAAAAAAWlRoZSBvd25lcidzIGF1dGhvcml6YXRpb24gYW5kIGEgY2hpcCBzaWduYXR1cmUsIG9ubHkgdGhlIGNoaXAtc2lnbmVkIHRyYW5zZmVycyBhcmUgYWxsb3dlZAAAAAAABEJvdGg=",
+        "AAAAAgAAADNQdWJsaWMga2V5IG9mIGEgY2hpcCwgd2hhdGV2ZXIgc2NoZW1lIGl0IHNpZ25zIHdpdGgAAAAAAAAAAAdDaGlwS2V5AAAAAAIAAAABAAAASFVuY29tcHJlc3NlZCBTRUMxIGtleSwgc2VjcDI1NmsxIHVubGVzcyByZWdpc3RlcmVkIHdpdGggYHNldF9jaGlwX2N1cnZlYAAAAAVFY2RzYQAAAAAAAAEAAAPuAAAAQQAAAAEAAAAAAAAAB0VkMjU1MTkAAAAAAQAAA+4AAAAg",
+        "AAAAAwAAAI9PcGVyYXRpb24gYSBjaGlwIHNpZ25hdHVyZSBhdXRob3JpemVzLCBpdHMgdGFnIGlzIHBhcnQgb2YgdGhlIHNpZ25lZAptZXNzYWdlIHNvIGEgc2lnbmF0dXJlIHByb2R1Y2VkIGZvciBvbmUgb3BlcmF0aW9uIGNhbm5vdCBhdXRob3JpemUgYW5vdGhlcgAAAAAAAAAADUNoaXBPcGVyYXRpb24AAAAAAAAGAAAAAAAAAARNaW50AAAAAAAAAAAAAAAFQ2xhaW0AAAAAAAABAAAAAAAAAAhUcmFuc2ZlcgAAAAIAAAAAAAAABEJ1cm4AAAADAAAAAAAAAAZSZWRlZW0AAAAAAAQAAAAAAAAABFNjYW4AAAAF",
+        "AAAAAQAAADpDaGlwIGFuZCBvd25lciBvZiBhIHRva2VuLCByZWFkIHRvZ2V0aGVyIG9uIGV2ZXJ5IGhvdCBwYXRoAAAAAAAAAAAACVRva2VuRGF0YQAAAAAAAAIAAAAAAAAABW93bmVyAAAAAAAD6AAAABMAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABB",
+        "AAAAAQAAAAAAAAAAAAAADVRva2VuQXBwcm92YWwAAAAAAAACAAAAAAAAAAhhcHByb3ZlZAAAABMAAAAAAAAAEWxpdmVfdW50aWxfbGVkZ2VyAAAAAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAAC01pbnRQcmV2aWV3AAAAAAMAAAAAAAAACmVycm9yX2NvZGUAAAAAAAQAAAAAAAAAEnByZWRpY3RlZF90b2tlbl9pZAAAAAAABgAAAAAAAAANd291bGRfc3VjY2VlZAAAAAAAAAE=",
+        "AAAAAQAAAAAAAAAAAAAACVRva2VuSW5mbwAAAAAAAAUAAAAAAAAAB2NsYWltZWQAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAPoAAAAEwAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAADdXJpAAAAABA=",
+        "AAAAAQAAAAAAAAAAAAAAClZlcmlmeUl0ZW0AAAAAAAQAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAAAAAAAC3JlY292ZXJ5X2lkAAAAA+gAAAAEAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQA==",
+        "AAAAAQAAAAAAAAAAAAAAE1Byb2R1Y3RWZXJpZmljYXRpb24AAAAABAAAAAAAAAAKaXNfZ2VudWluZQAAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAPoAAAAEwAAAAAAAAAHcmV2b2tlZAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAA+gAAAAG",
+        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAcAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAADdXJpAAAAABAAAAAAAAAACm1heF90b2tlbnMAAAAAAAYAAAAAAAAAD2FjdGl2aXR5X2V2ZW50cwAAAAABAAAAAAAAABBvbmNoYWluX21ldGFkYXRhAAAAAQAAAAA=",
         "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
-        "AAAAAAAAAAAAAAAEbWludAAAAAUAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAAAQAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABBAAAAAAAAAAVub25jZQAAAAAAAAQAAAABAAAABg==",
-        "AAAAAAAAAAAAAAAFY2xhaW0AAAAAAAAGAAAAAAAAAAhjbGFpbWFudAAAABMAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAAAQAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABBAAAAAAAAAAVub25jZQAAAAAAAAQAAAABAAAABg==",
-        "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAAIAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAHbWVzc2FnZQAAAAAOAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQAAAAAAAAAALcmVjb3ZlcnlfaWQAAAAABAAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAAAAAAABW5vbmNlAAAAAAAABAAAAAA=",
-        "AAAAAAAAAAAAAAAJZ2V0X25vbmNlAAAAAAAAAQAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAHbWlncmF0ZQAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAASbWlncmF0aW9uX3Byb2dyZXNzAAAAAAAAAAAAAQAAA+0AAAACAAAABgAAAAY=",
+        "AAAAAAAAAAAAAAAHdmVyc2lvbgAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAKYnVpbGRfaW5mbwAAAAAAAAAAAAEAAAfQAAAACUJ1aWxkSW5mbwAAAA==",
+        "AAAAAAAAAAAAAAAOdHJhbnNmZXJfYWRtaW4AAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAAMYWNjZXB0X2FkbWluAAAAAAAAAAA=",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAANcGVuZGluZ19hZG1pbgAAAAAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAAEbWludAAAAAkAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAA+gAAAAEAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAABFleHBpcmF0aW9uX2xlZGdlcgAAAAAAAAQAAAAAAAAACWNoaXBfaW5mbwAAAAAAA+gAAAfQAAAACENoaXBJbmZvAAAAAAAAAAlwb19udW1iZXIAAAAAAAPoAAAABAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAIbWludF9kZXIAAAAIAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAdtZXNzYWdlAAAAAA4AAAAAAAAADWRlcl9zaWduYXR1cmUAAAAAAAAOAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAABFleHBpcmF0aW9uX2xlZGdlcgAAAAAAAAQAAAAAAAAACWNoaXBfaW5mbwAAAAAAA+gAAAfQAAAACENoaXBJbmZvAAAAAAAAAAlwb19udW1iZXIAAAAAAAPoAAAABAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAMbWludF9lZDI1NTE5AAAACAAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAHbWVzc2FnZQAAAAAOAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQAAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAACAAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAAAAAAljaGlwX2luZm8AAAAAAAPoAAAH0AAAAAhDaGlwSW5mbwAAAAAAAAAJcG9fbnVtYmVyAAAAAAAD6AAAAAQAAAABAAAABg==",
+        "AAAAAAAAAAAAAAANc2ltdWxhdGVfbWludAAAAAAAAAcAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAA+gAAAAEAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAABFleHBpcmF0aW9uX2xlZGdlcgAAAAAAAAQAAAABAAAH0AAAAAtNaW50UHJldmlldwA=",
+        "AAAAAAAAAAAAAAAGdmVyaWZ5AAAAAAAIAAAAAAAAAAlvcGVyYXRpb24AAAAAAAfQAAAADUNoaXBPcGVyYXRpb24AAAAAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAA+gAAAAEAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAAAAAAJcmVjaXBpZW50AAAAAAAD6AAAABMAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAFY2xhaW0AAAAAAAAHAAAAAAAAAAhjbGFpbWFudAAAABMAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAA+gAAAAEAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAABFleHBpcmF0aW9uX2xlZGdlcgAAAAAAAAQAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAJY2xhaW1fZGVyAAAAAAAABgAAAAAAAAAIY2xhaW1hbnQAAAATAAAAAAAAAAdtZXNzYWdlAAAAAA4AAAAAAAAADWRlcl9zaWduYXR1cmUAAAAAAAAOAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAABFleHBpcmF0aW9uX2xlZGdlcgAAAAAAAAQAAAABAAAABg==",
+        "AAAAAAAAAAAAAAANY2xhaW1fZWQyNTUxOQAAAAAAAAYAAAAAAAAACGNsYWltYW50AAAAEwAAAAAAAAAHbWVzc2FnZQAAAAAOAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQAAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAACAAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAQAAAAY=",
+        "AAAAAAAAAAAAAAATY2xhaW1fd2l0aF9kb25hdGlvbgAAAAAIAAAAAAAAAAhjbGFpbWFudAAAABMAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAA+gAAAAEAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAABFleHBpcmF0aW9uX2xlZGdlcgAAAAAAAAQAAAAAAAAACGRvbmF0aW9uAAAACwAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAObWludF9hbmRfY2xhaW0AAAAAAAcAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAdtZXNzYWdlAAAAAA4AAAAAAAAACXNpZ25hdHVyZQAAAAAAA+4AAABAAAAAAAAAAAtyZWNvdmVyeV9pZAAAAAPoAAAABAAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAQAAAAY=",
+        "AAAAAAAAAAAAAAAOcmVnaXN0ZXJfY2hpcHMAAAAAAAIAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAAC3B1YmxpY19rZXlzAAAAA+oAAAPuAAAAQQAAAAEAAAPqAAAABg==",
+        "AAAAAAAAAAAAAAASc2V0X2NsYWltX2RlYWRsaW5lAAAAAAABAAAAAAAAABBsZWRnZXJfdGltZXN0YW1wAAAABgAAAAA=",
+        "AAAAAAAAAAAAAAAOY2xhaW1fZGVhZGxpbmUAAAAAAAAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAARcmVjbGFpbV91bmNsYWltZWQAAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAA",
+        "AAAAAAAAAAAAAAANY2hpcF90cmFuc2ZlcgAAAAAAAAkAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAdtZXNzYWdlAAAAAA4AAAAAAAAACXNpZ25hdHVyZQAAAAAAA+4AAABAAAAAAAAAAAtyZWNvdmVyeV9pZAAAAAPoAAAABAAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAARY2hpcF90cmFuc2Zlcl9kZXIAAAAAAAAIAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAHbWVzc2FnZQAAAAAOAAAAAAAAAA1kZXJfc2lnbmF0dXJlAAAAAAAADgAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAAVY2hpcF90cmFuc2Zlcl9lZDI1NTE5AAAAAAAACAAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAAAgAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAAEYnVybgAAAAgAAAAAAAAABGZyb20AAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAA+gAAAAEAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAABFleHBpcmF0aW9uX2xlZGdlcgAAAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAAPYnVybl9mcm9tX293bmVyAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAA==",
+        "AAAAAAAAAAAAAAAOYWRtaW5fdHJhbnNmZXIAAAAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAACdG8AAAAAABMAAAAAAAAABnJlYXNvbgAAAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAAYYXBwcm92ZV9vcGVyYXRvcl9saW1pdGVkAAAABAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAhvcGVyYXRvcgAAABMAAAAAAAAACXJlbWFpbmluZwAAAAAAAAQAAAAAAAAAEWxpdmVfdW50aWxfbGVkZ2VyAAAAAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAASb3BlcmF0b3JfYWxsb3dhbmNlAAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAEAAAPoAAAABA==",
+        "AAAAAAAAAAAAAAAVYXBwcm92ZV9mb3JfcmVjaXBpZW50AAAAAAAABQAAAAAAAAAIYXBwcm92ZXIAAAATAAAAAAAAAAhhcHByb3ZlZAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAJcmVjaXBpZW50AAAAAAAAEwAAAAAAAAARbGl2ZV91bnRpbF9sZWRnZXIAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAASYXBwcm92ZWRfcmVjaXBpZW50AAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAANc2V0X3NvdWxib3VuZAAAAAAAAAIAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAJc291bGJvdW5kAAAAAAAAAQAAAAA=",
+        "AAAAAAAAAAAAAAAMaXNfc291bGJvdW5kAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAASc2V0X3NlY3VyaXR5X2xldmVsAAAAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAABWxldmVsAAAAAAAH0AAAAA1TZWN1cml0eUxldmVsAAAAAAAAAA==",
+        "AAAAAAAAAAAAAAAOc2VjdXJpdHlfbGV2ZWwAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAfQAAAADVNlY3VyaXR5TGV2ZWwAAAA=",
+        "AAAAAAAAAAAAAAALcmViaW5kX2NoaXAAAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAA=",
+        "AAAAAAAAAAAAAAAVc2V0X3RyYW5zZmVyX2Nvb2xkb3duAAAAAAAAAQAAAAAAAAAHbGVkZ2VycwAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAARdHJhbnNmZXJfY29vbGRvd24AAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAQc2V0X25vbmNlX3dpbmRvdwAAAAEAAAAAAAAABndpbmRvdwAAAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAAMbm9uY2Vfd2luZG93AAAAAAAAAAEAAAAE",
+        "AAAAAAAAAAAAAAANc2V0X2NoaXBfYXV0aAAAAAAAAAEAAAAAAAAACWNoaXBfYXV0aAAAAAAAA+gAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAAJY2hpcF9hdXRoAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAAAAAAAWc2V0X21heF9tZXNzYWdlX2xlbmd0aAAAAAAAAQAAAAAAAAAGbGVuZ3RoAAAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAASbWF4X21lc3NhZ2VfbGVuZ3RoAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAOc2V0X21heF90b2tlbnMAAAAAAAEAAAAAAAAACm1heF90b2tlbnMAAAAAAAYAAAAA",
+        "AAAAAAAAAAAAAAAKbWF4X3Rva2VucwAAAAAAAAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAQcmVtYWluaW5nX3N1cHBseQAAAAAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAMbWludGVkX2NvdW50AAAAAAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAANY2xhaW1lZF9jb3VudAAAAAAAAAAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAMYnVybmVkX2NvdW50AAAAAAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAXc2V0X2NoZWNrcG9pbnRfaW50ZXJ2YWwAAAAAAQAAAAAAAAAFbWludHMAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAATY2hlY2twb2ludF9pbnRlcnZhbAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAMdmVyaWZ5X2JhdGNoAAAAAQAAAAAAAAAFaXRlbXMAAAAAAAPqAAAH0AAAAApWZXJpZnlJdGVtAAAAAAABAAAD6gAAAAE=",
+        "AAAAAAAAAAAAAAAOdmVyaWZ5X3Byb2R1Y3QAAAAAAAQAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAA+gAAAAEAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAEAAAfQAAAAE1Byb2R1Y3RWZXJpZmljYXRpb24A",
+        "AAAAAAAAAAAAAAALcmV2b2tlX2NoaXAAAAAAAQAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAAA",
+        "AAAAAAAAAAAAAAANdW5yZXZva2VfY2hpcAAAAAAAAAEAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABBAAAAAA==",
+        "AAAAAAAAAAAAAAAKaXNfcmV2b2tlZAAAAAAAAQAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAAAQ==",
+        "AAAAAAAAAAAAAAAOc2V0X2NoaXBfY3VydmUAAAAAAAIAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABBAAAAAAAAAAVjdXJ2ZQAAAAAAB9AAAAAJQ3VydmVUeXBlAAAAAAAAAA==",
+        "AAAAAAAAAAAAAAAKY2hpcF9jdXJ2ZQAAAAAAAQAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAH0AAAAAlDdXJ2ZVR5cGUAAAA=",
+        "AAAAAAAAAAAAAAAJZ2V0X25vbmNlAAAAAAAAAQAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAObWVzc2FnZV9kb21haW4AAAAAAAAAAAABAAAADg==",
+        "AAAAAAAAAAAAAAASYnVpbGRfbWVzc2FnZV9oYXNoAAAAAAAFAAAAAAAAAAlvcGVyYXRpb24AAAAAAAfQAAAADUNoaXBPcGVyYXRpb24AAAAAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJcmVjaXBpZW50AAAAAAAD6AAAABMAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAQAAA+4AAAAg",
+        "AAAAAAAAAAAAAAAMb3duZXJfb2Zfb3B0AAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAAGZXhpc3RzAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAQ==",
+        "AAAAAAAAAAAAAAAKaXNfY2xhaW1lZAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAKdG9rZW5faW5mbwAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAB9AAAAAJVG9rZW5JbmZvAAAA",
+        "AAAAAAAAAAAAAAALdG9rZW5zX2luZm8AAAAAAQAAAAAAAAAJdG9rZW5faWRzAAAAAAAD6gAAAAYAAAABAAAD6gAAB9AAAAAJVG9rZW5JbmZvAAAA",
+        "AAAAAAAAAAAAAAANdG9rZW5fdXJpX29wdAAAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPoAAAAEA==",
+        "AAAAAAAAAAAAAAAMc2V0X2Jhc2VfdXJpAAAAAQAAAAAAAAADdXJpAAAAABAAAAAA",
+        "AAAAAAAAAAAAAAAIYmFzZV91cmkAAAAAAAAAAQAAABA=",
+        "AAAAAAAAAAAAAAANc2V0X3Rva2VuX3VyaQAAAAAAAAIAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAADdXJpAAAAABAAAAAA",
+        "AAAAAAAAAAAAAAAPY2xlYXJfdG9rZW5fdXJpAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAA=",
+        "AAAAAAAAAAAAAAARc2V0X3VuY2xhaW1lZF91cmkAAAAAAAABAAAAAAAAAAN1cmkAAAAAEAAAAAA=",
+        "AAAAAAAAAAAAAAANdW5jbGFpbWVkX3VyaQAAAAAAAAAAAAABAAAAEA==",
+        "AAAAAAAAAAAAAAAIdG9rZW5faWQAAAABAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAMdG9rZW5faWRfb3B0AAAAAQAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAD6AAAAAY=",
+        "AAAAAAAAAAAAAAAKcHVibGljX2tleQAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+4AAABB",
+        "AAAAAAAAAAAAAAAOcHVibGljX2tleV9vcHQAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPoAAAD7gAAAEE=",
+        "AAAAAAAAAAAAAAAIY2hpcF9rZXkAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAH0AAAAAdDaGlwS2V5AA==",
+        "AAAAAAAAAAAAAAAPY2hpcF9wdWJsaWNfa2V5AAAAAAEAAAAAAAAACGNoaXBfa2V5AAAH0AAAAAdDaGlwS2V5AAAAAAEAAAPuAAAAQQ==",
         "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAABA==",
         "AAAAAAAAAAAAAAAIb3duZXJfb2YAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAEw==",
+        "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAA=",
+        "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAA",
+        "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAAEAAAAAAAAAAhhcHByb3ZlcgAAABMAAAAAAAAACGFwcHJvdmVkAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAAPYXBwcm92ZV9mb3JfYWxsAAAAAAMAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAAMZ2V0X2FwcHJvdmVkAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAATaXNfYXBwcm92ZWRfZm9yX2FsbAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAEAAAAB",
         "AAAAAAAAAAAAAAAEbmFtZQAAAAAAAAABAAAAEA==",
         "AAAAAAAAAAAAAAAGc3ltYm9sAAAAAAAAAAAAAQAAABA=",
         "AAAAAAAAAAAAAAAJdG9rZW5fdXJpAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAABA=",
-        "AAAAAAAAAAAAAAAIdG9rZW5faWQAAAABAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAEAAAAG",
-        "AAAAAAAAAAAAAAAKcHVibGljX2tleQAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+4AAABB",
-        "AAAABAAAAAAAAAAAAAAAFU5vbkZ1bmdpYmxlVG9rZW5FcnJvcgAAAAAAAAkAAAAkSW5kaWNhdGVzIGEgbm9uLWV4aXN0ZW50IGB0b2tlbl9pZGAuAAAAEE5vbkV4aXN0ZW50VG9rZW4AAADIAAAAV0luZGljYXRlcyBhbiBlcnJvciByZWxhdGVkIHRvIHRoZSBvd25lcnNoaXAgb3ZlciBhIHBhcnRpY3VsYXIgdG9rZW4uClVzZWQgaW4gdHJhbnNmZXJzLgAAAAAOSW5jb3JyZWN0T3duZXIAAAAAAMkAAAApSW5kaWNhdGVzIG92ZXJmbG93IHdoZW4gYWRkaW5nIHR3byB2YWx1ZXMAAAAAAAAMTWF0aE92ZXJmbG93AAAAzQAAADZJbmRpY2F0ZXMgYWxsIHBvc3NpYmxlIGB0b2tlbl9pZGBzIGFyZSBhbHJlYWR5IGluIHVzZS4AAAAAABNUb2tlbklEc0FyZURlcGxldGVkAAAAAM4AAABFSW5kaWNhdGVzIGFuIGludmFsaWQgYW1vdW50IHRvIGJhdGNoIG1pbnQgaW4gYGNvbnNlY3V0aXZlYCBleHRlbnNpb24uAAAAAAAADUludmFsaWRBbW91bnQAAAAAAADPAAAAJ0luZGljYXRlcyB0aGUgdG9rZW4gd2FzIGFscmVhZHkgbWludGVkLgAAAAASVG9rZW5BbHJlYWR5TWludGVkAAAAAADSAAAAR0luZGljYXRlcyB0aGUgcm95YWx0eSBhbW91bnQgaXMgaGlnaGVyIHRoYW4gMTBfMDAwICgxMDAlKSBiYXNpcyBwb2ludHMuAAAAABRJbnZhbGlkUm95YWx0eUFtb3VudAAAANQAAAAeSW5kaWNhdGVzIGFuIGludmFsaWQgc2lnbmF0dXJlAAAAAAAQSW52YWxpZFNpZ25hdHVyZQAAANYAAAA3SW5kaWNhdGVzIHRoZSB0b2tlbiBleGlzdHMgYnV0IGhhcyBub3QgYmVlbiBjbGFpbWVkIHlldAAAAAAPVG9rZW5Ob3RDbGFpbWVkAAAAANc=",
+        "AAAAAgAAAAAAAAAAAAAAE0F0dHJpYnV0ZVN0b3JhZ2VLZXkAAAAAAQAAAAEAAAAAAAAACkF0dHJpYnV0ZXMAAAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAANc2V0X2F0dHJpYnV0ZQAAAAAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAADa2V5AAAAABAAAAAAAAAABXZhbHVlAAAAAAAAEAAAAAA=",
+        "AAAAAAAAAAAAAAANZ2V0X2F0dHJpYnV0ZQAAAAAAAAIAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAADa2V5AAAAABAAAAABAAAD6AAAABA=",
+        "AAAAAAAAAAAAAAAKYXR0cmlidXRlcwAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+wAAAAQAAAAEA==",
+        "AAAAAgAAAAAAAAAAAAAAE0FsbG93bGlzdFN0b3JhZ2VLZXkAAAAAAgAAAAAAAAAAAAAAEEFsbG93bGlzdEVuYWJsZWQAAAABAAAAAAAAAAtBbGxvd2VkQ2hpcAAAAAABAAAD7gAAAEE=",
+        "AAAAAAAAAAAAAAARYWRkX2FsbG93ZWRfY2hpcHMAAAAAAAABAAAAAAAAAAtwdWJsaWNfa2V5cwAAAAPqAAAD7gAAAEEAAAAA",
+        "AAAAAAAAAAAAAAATcmVtb3ZlX2FsbG93ZWRfY2hpcAAAAAABAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAA=",
+        "AAAAAAAAAAAAAAAVc2V0X2FsbG93bGlzdF9lbmFibGVkAAAAAAAAAQAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAA==",
+        "AAAAAAAAAAAAAAARYWxsb3dsaXN0X2VuYWJsZWQAAAAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAPaXNfY2hpcF9hbGxvd2VkAAAAAAEAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABBAAAAAQAAAAE=",
+        "AAAAAQAAAAAAAAAAAAAACENoaXBJbmZvAAAAAwAAAAAAAAAFYmF0Y2gAAAAAAAAEAAAAAAAAAAhmaXJtd2FyZQAAAAQAAAAAAAAABW1vZGVsAAAAAAAAEQ==",
+        "AAAAAgAAAAAAAAAAAAAAEkNoaXBJbmZvU3RvcmFnZUtleQAAAAAAAwAAAAEAAAAAAAAACENoaXBJbmZvAAAAAQAAA+4AAABBAAAAAQAAAAAAAAAPQmF0Y2hUb2tlbkNvdW50AAAAAAEAAAAEAAAAAQAAAAAAAAAKQmF0Y2hUb2tlbgAAAAAAAgAAAAQAAAAE",
+        "AAAAAAAAAAAAAAAJY2hpcF9pbmZvAAAAAAAAAQAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAD6AAAB9AAAAAIQ2hpcEluZm8=",
+        "AAAAAAAAAAAAAAAPdG9rZW5zX2J5X2JhdGNoAAAAAAMAAAAAAAAABWJhdGNoAAAAAAAABAAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAAAY=",
+        "AAAAAAAAAAAAAAAUdXBkYXRlX2NoaXBfZmlybXdhcmUAAAACAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAAAAAAIZmlybXdhcmUAAAAEAAAAAA==",
+        "AAAAAgAAAAAAAAAAAAAAFkNoaXBSZWdpc3RyeVN0b3JhZ2VLZXkAAAAAAAMAAAAAAAAAAAAAAAlDaGlwQ291bnQAAAAAAAABAAAAAAAAAA5SZWdpc3RlcmVkQ2hpcAAAAAAAAQAAAAYAAAABAAAAAAAAAAxDaGlwUG9zaXRpb24AAAABAAAD7gAAAEE=",
+        "AAAAAAAAAAAAAAAQcmVnaXN0ZXJlZF9jaGlwcwAAAAIAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAPuAAAAQQ==",
+        "AAAAAAAAAAAAAAAVcmVnaXN0ZXJlZF9jaGlwX2NvdW50AAAAAAAAAAAAAAEAAAAG",
+        "AAAAAQAAAAAAAAAAAAAACUNoaXBTdGF0cwAAAAAAAAMAAAAAAAAABmNsYWltcwAAAAAABAAAAAAAAAAFbWludHMAAAAAAAAEAAAAAAAAAAl0cmFuc2ZlcnMAAAAAAAAE",
+        "AAAAAgAAAAAAAAAAAAAAE0NoaXBTdGF0c1N0b3JhZ2VLZXkAAAAAAgAAAAEAAAAAAAAACUNoaXBTdGF0cwAAAAAAAAEAAAPuAAAAQQAAAAEAAAAAAAAACVNjYW5Db3VudAAAAAAAAAEAAAPuAAAAQQ==",
+        "AAAAAAAAAAAAAAAKY2hpcF9zdGF0cwAAAAAAAQAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAH0AAAAAlDaGlwU3RhdHMAAAA=",
+        "AAAAAAAAAAAAAAALcmVjb3JkX3NjYW4AAAAABgAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAA+gAAAAEAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAAAEAAAAE",
+        "AAAAAAAAAAAAAAAKc2Nhbl9jb3VudAAAAAAAAQAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAACUNsYWltQ29kZQAAAAAAAAMAAAAAAAAACWNvZGVfaGFzaAAAAAAAA+4AAAAgAAAAAAAAAA1leHBpcnlfbGVkZ2VyAAAAAAAABAAAAAAAAAAIcmVkZWVtZWQAAAAB",
+        "AAAAAgAAAAAAAAAAAAAAE0NsYWltQ29kZVN0b3JhZ2VLZXkAAAAAAQAAAAEAAAAAAAAACUNsYWltQ29kZQAAAAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAQaXNzdWVfY2xhaW1fY29kZQAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAJY29kZV9oYXNoAAAAAAAD7gAAACAAAAAAAAAADWV4cGlyeV9sZWRnZXIAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAAPY2xhaW1fd2l0aF9jb2RlAAAAAAMAAAAAAAAACGNsYWltYW50AAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAA1jb2RlX3ByZWltYWdlAAAAAAAADgAAAAEAAAAG",
+        "AAAAAgAAAAAAAAAAAAAAFENvbXBvc2FibGVTdG9yYWdlS2V5AAAAAgAAAAEAAAAAAAAACENoaWxkcmVuAAAAAQAAAAYAAAABAAAAAAAAAAZQYXJlbnQAAAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAGYXR0YWNoAAAAAAACAAAAAAAAAAlwYXJlbnRfaWQAAAAAAAAGAAAAAAAAAAhjaGlsZF9pZAAAAAYAAAAA",
+        "AAAAAAAAAAAAAAAGZGV0YWNoAAAAAAACAAAAAAAAAAlwYXJlbnRfaWQAAAAAAAAGAAAAAAAAAAhjaGlsZF9pZAAAAAYAAAAA",
+        "AAAAAAAAAAAAAAALY2hpbGRyZW5fb2YAAAAAAQAAAAAAAAAJcGFyZW50X2lkAAAAAAAABgAAAAEAAAPqAAAABg==",
+        "AAAAAAAAAAAAAAAJcGFyZW50X29mAAAAAAAAAQAAAAAAAAAIY2hpbGRfaWQAAAAGAAAAAQAAA+gAAAAG",
+        "AAAAAQAAAAAAAAAAAAAABkVzY3JvdwAAAAAAAwAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABM=",
+        "AAAAAgAAAAAAAAAAAAAAEEVzY3Jvd1N0b3JhZ2VLZXkAAAABAAAAAQAAAAAAAAAGRXNjcm93AAAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAPZXNjcm93X3RyYW5zZmVyAAAAAAkAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAA90aW1lb3V0X2xlZGdlcnMAAAAABAAAAAAAAAAHbWVzc2FnZQAAAAAOAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQAAAAAAAAAALcmVjb3ZlcnlfaWQAAAAD6AAAAAQAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABBAAAAAAAAAAVub25jZQAAAAAAAAYAAAAA",
+        "AAAAAAAAAAAAAAAPY29uZmlybV9yZWNlaXB0AAAAAAIAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAA",
+        "AAAAAAAAAAAAAAAOcmVjbGFpbV9lc2Nyb3cAAAAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAA",
+        "AAAAAAAAAAAAAAAJZXNjcm93X29mAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAfQAAAABkVzY3JvdwAA",
+        "AAAAAgAAAAAAAAAAAAAAEExvY2FsZVN0b3JhZ2VLZXkAAAACAAAAAQAAAAAAAAAJTG9jYWxlVXJpAAAAAAAAAgAAAAYAAAARAAAAAQAAAAAAAAAHTG9jYWxlcwAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAOc2V0X2xvY2FsZV91cmkAAAAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAGbG9jYWxlAAAAAAARAAAAAAAAAAN1cmkAAAAAEAAAAAA=",
+        "AAAAAAAAAAAAAAATdG9rZW5fdXJpX2xvY2FsaXplZAAAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAABmxvY2FsZQAAAAAAEQAAAAEAAAAQ",
+        "AAAAAAAAAAAAAAAKbG9jYWxlc19vZgAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+oAAAAR",
+        "AAAAAQAAAAAAAAAAAAAADE1lcmNoYW50SW5mbwAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAEbmFtZQAAABA=",
+        "AAAAAgAAAAAAAAAAAAAAEk1lcmNoYW50U3RvcmFnZUtleQAAAAAAAgAAAAEAAAAzT25seSBzZXQgZm9yIG1lcmNoYW50cyBjcmVhdGVkIHdpdGggY3JlYXRlX21lcmNoYW50AAAAAAhNZXJjaGFudAAAAAEAAAAEAAAAAAAAAAAAAAAOTmV4dE1lcmNoYW50SWQAAA==",
+        "AAAAAAAAAAAAAAAPY3JlYXRlX21lcmNoYW50AAAAAAQAAAAAAAAADm1lcmNoYW50X2FkbWluAAAAAAATAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAIYmFzZV91cmkAAAAQAAAAAAAAAAptYXhfdG9rZW5zAAAAAAAGAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAASc2V0X21lcmNoYW50X2FkbWluAAAAAAACAAAAAAAAAAttZXJjaGFudF9pZAAAAAAEAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAAVc2V0X21lcmNoYW50X2Jhc2VfdXJpAAAAAAAAAgAAAAAAAAALbWVyY2hhbnRfaWQAAAAABAAAAAAAAAADdXJpAAAAABAAAAAA",
+        "AAAAAAAAAAAAAAANbWVyY2hhbnRfaW5mbwAAAAAAAAEAAAAAAAAAC21lcmNoYW50X2lkAAAAAAQAAAABAAAH0AAAAAxNZXJjaGFudEluZm8=",
+        "AAAAAAAAAAAAAAALbWVyY2hhbnRfb2YAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAAFE93bmVySW5kZXhTdG9yYWdlS2V5AAAAAwAAAAEAAAAAAAAACUhlbGRDb3VudAAAAAAAAAEAAAATAAAAAQAAAAAAAAAJSGVsZFRva2VuAAAAAAAAAgAAABMAAAAEAAAAAQAAAAAAAAANVG9rZW5Qb3NpdGlvbgAAAAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAJdG9rZW5zX29mAAAAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAABg==",
+        "AAAAAgAAAAAAAAAAAAAAGk93bmVyc2hpcEhpc3RvcnlTdG9yYWdlS2V5AAAAAAABAAAAAQAAAAAAAAAQT3duZXJzaGlwSGlzdG9yeQAAAAEAAAAG",
+        "AAAAAAAAAAAAAAARb3duZXJzaGlwX2hpc3RvcnkAAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6gAAA+0AAAACAAAAEwAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAAFVBhcnRuZXJNaW50U3RvcmFnZUtleQAAAAAAAAIAAAABAAAAAAAAAAdDaGlwU2t1AAAAAAEAAAPuAAAAQQAAAAEAAAAAAAAADU1pbnRBbGxvd2FuY2UAAAAAAAACAAAAEwAAAAQ=",
+        "AAAAAAAAAAAAAAAMc2V0X2NoaXBfc2t1AAAAAgAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAAAAAAAA3NrdQAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAAIY2hpcF9za3UAAAABAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAEAAAPoAAAABA==",
+        "AAAAAAAAAAAAAAAUZ3JhbnRfbWludF9hbGxvd2FuY2UAAAADAAAAAAAAAAhjb250cmFjdAAAABMAAAAAAAAAA3NrdQAAAAAEAAAAAAAAAAhxdWFudGl0eQAAAAYAAAAA",
+        "AAAAAAAAAAAAAAAObWludF9hbGxvd2FuY2UAAAAAAAIAAAAAAAAACGNvbnRyYWN0AAAAEwAAAAAAAAADc2t1AAAAAAQAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAMcGFydG5lcl9taW50AAAACAAAAAAAAAAHcGFydG5lcgAAAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAADc2t1AAAAAAQAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAA+gAAAAEAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAQAAAAY=",
+        "AAAAAgAAAAAAAAAAAAAAF1B1cmNoYXNlT3JkZXJTdG9yYWdlS2V5AAAAAAMAAAABAAAAAAAAAAdUb2tlblBvAAAAAAEAAAAGAAAAAQAAAAAAAAAMUG9Ub2tlbkNvdW50AAAAAQAAAAQAAAABAAAAAAAAAAdQb1Rva2VuAAAAAAIAAAAEAAAABA==",
+        "AAAAAAAAAAAAAAAFcG9fb2YAAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAAAQ=",
+        "AAAAAAAAAAAAAAAMdG9rZW5zX2J5X3BvAAAAAwAAAAAAAAACcG8AAAAAAAQAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAAG",
+        "AAAAAgAAAAAAAAAAAAAAElJlY2VpdmVyU3RvcmFnZUtleQAAAAAAAQAAAAEAAAAAAAAABk9wdE91dAAAAAAAAQAAABM=",
+        "AAAAAAAAAAAAAAARc2V0X3JlY2VpdmVyX2hvb2sAAAAAAAACAAAAAAAAAAhyZWNlaXZlcgAAABMAAAAAAAAAB2VuYWJsZWQAAAAAAQAAAAA=",
+        "AAAAAAAAAAAAAAANcmVjZWl2ZXJfaG9vawAAAAAAAAEAAAAAAAAACHJlY2VpdmVyAAAAEwAAAAEAAAAB",
+        "AAAAAQAAAAAAAAAAAAAADlJlY292ZXJ5Q29uZmlnAAAAAAACAAAAAAAAAA1kZWxheV9sZWRnZXJzAAAAAAAABAAAAAAAAAAIcmVjb3ZlcnkAAAAT",
+        "AAAAAgAAAAAAAAAAAAAAElJlY292ZXJ5U3RvcmFnZUtleQAAAAAAAgAAAAEAAAAAAAAAD1JlY292ZXJ5QWRkcmVzcwAAAAABAAAAEwAAAAEAAAAAAAAAD1BlbmRpbmdSZWNvdmVyeQAAAAABAAAAEw==",
+        "AAAAAAAAAAAAAAAUc2V0X3JlY292ZXJ5X2FkZHJlc3MAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACHJlY292ZXJ5AAAAEwAAAAAAAAANZGVsYXlfbGVkZ2VycwAAAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAALcmVjb3Zlcnlfb2YAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAA+gAAAfQAAAADlJlY292ZXJ5Q29uZmlnAAA=",
+        "AAAAAAAAAAAAAAARaW5pdGlhdGVfcmVjb3ZlcnkAAAAAAAACAAAAAAAAAAhyZWNvdmVyeQAAABMAAAAAAAAABW93bmVyAAAAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAPY2FuY2VsX3JlY292ZXJ5AAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAQcGVuZGluZ19yZWNvdmVyeQAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAPoAAAABA==",
+        "AAAAAAAAAAAAAAAQZXhlY3V0ZV9yZWNvdmVyeQAAAAIAAAAAAAAACHJlY292ZXJ5AAAAEwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAA==",
+        "AAAAAQAAAAAAAAAAAAAAC1JlY2VudENsYWltAAAAAAMAAAAAAAAACGNsYWltYW50AAAAEwAAAAAAAAAGbGVkZ2VyAAAAAAAEAAAAAAAAAAh0b2tlbl9pZAAAAAY=",
+        "AAAAAgAAAAAAAAAAAAAAFlJlY2VudENsYWltc1N0b3JhZ2VLZXkAAAAAAAIAAAAAAAAAUFRvdGFsIG51bWJlciBvZiBjbGFpbXMgcmVjb3JkZWQsIHRoZSBuZXh0IHNsb3QgaXMgdGhpcyB2YWx1ZSBtb2R1bG8gdGhlIGNhcGFjaXR5AAAAElJlY2VudENsYWltc0N1cnNvcgAAAAAAAQAAAAAAAAAPUmVjZW50Q2xhaW1TbG90AAAAAAEAAAAE",
+        "AAAAAAAAAAAAAAANcmVjZW50X2NsYWltcwAAAAAAAAAAAAABAAAD6gAAB9AAAAALUmVjZW50Q2xhaW0A",
+        "AAAAAgAAAAAAAAAAAAAAFFJlZGVtcHRpb25TdG9yYWdlS2V5AAAAAQAAAAEAAAAiTGVkZ2VyIHRpbWVzdGFtcCBvZiB0aGUgcmVkZW1wdGlvbgAAAAAACFJlZGVlbWVkAAAAAQAAAAY=",
+        "AAAAAAAAAAAAAAAGcmVkZWVtAAAAAAAHAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAAB21lc3NhZ2UAAAAADgAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEAAAAAAAAAAC3JlY292ZXJ5X2lkAAAAA+gAAAAEAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAABFleHBpcmF0aW9uX2xlZGdlcgAAAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAALaXNfcmVkZWVtZWQAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAALcmVkZWVtZWRfYXQAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAA+gAAAAG",
+        "AAAAAgAAAAAAAAAAAAAAFVJlc2VydmF0aW9uU3RvcmFnZUtleQAAAAAAAAIAAAABAAAAAAAAAAtSZXNlcnZhdGlvbgAAAAABAAAABgAAAAEAAAAAAAAADUNsYWltRGVsZWdhdGUAAAAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAALcmVzZXJ2ZV9mb3IAAAAAAwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAhjbGFpbWFudAAAABMAAAAAAAAADWV4cGlyeV9sZWRnZXIAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAATcmVsZWFzZV9yZXNlcnZhdGlvbgAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAA",
+        "AAAAAAAAAAAAAAAOcmVzZXJ2YXRpb25fb2YAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPoAAAD7QAAAAIAAAATAAAABA==",
+        "AAAAAAAAAAAAAAAOZGVsZWdhdGVfY2xhaW0AAAAAAAMAAAAAAAAACWRlbGVnYXRvcgAAAAAAABMAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAIZGVsZWdhdGUAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAARY2xhaW1fZGVsZWdhdGVfb2YAAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAABM=",
+        "AAAAAQAAAAAAAAAAAAAAB1JveWFsdHkAAAAAAgAAAAAAAAAMYmFzaXNfcG9pbnRzAAAABAAAAAAAAAAIcmVjZWl2ZXIAAAAT",
+        "AAAAAgAAAAAAAAAAAAAAEVJveWFsdHlTdG9yYWdlS2V5AAAAAAAAAgAAAAAAAAAAAAAADkRlZmF1bHRSb3lhbHR5AAAAAAABAAAAAAAAAAxUb2tlblJveWFsdHkAAAABAAAABg==",
+        "AAAAAAAAAAAAAAATc2V0X2RlZmF1bHRfcm95YWx0eQAAAAACAAAAAAAAAAhyZWNlaXZlcgAAABMAAAAAAAAADGJhc2lzX3BvaW50cwAAAAQAAAAA",
+        "AAAAAAAAAAAAAAARc2V0X3Rva2VuX3JveWFsdHkAAAAAAAADAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAACHJlY2VpdmVyAAAAEwAAAAAAAAAMYmFzaXNfcG9pbnRzAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAAMcm95YWx0eV9pbmZvAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAApzYWxlX3ByaWNlAAAAAAALAAAAAQAAA+0AAAACAAAAEwAAAAs=",
+        "AAAAAQAAAAAAAAAAAAAABVByaWNlAAAAAAAAAgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEw==",
+        "AAAAAQAAACdQcm9vZiBvZiBhIHBheW1lbnQgY29sbGVjdGVkIGJ5IGBjbGFpbWAAAAAAAAAAAAdSZWNlaXB0AAAAAAcAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAGbGVkZ2VyAAAAAAAEAAAAAAAAAAttZXJjaGFudF9pZAAAAAAEAAAAAAAAAAVwYXllcgAAAAAAABMAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAAAAAAh0b2tlbl9pZAAAAAY=",
+        "AAAAAgAAAAAAAAAAAAAAD1NhbGVzU3RvcmFnZUtleQAAAAAKAAAAAAAAAB1QcmljZSBvZiB0aGUgZGVmYXVsdCBtZXJjaGFudAAAAAAAAAVQcmljZQAAAAAAAAEAAAAwUHJpY2Ugb2YgYSBtZXJjaGFudCBjcmVhdGVkIHdpdGggY3JlYXRlX21lcmNoYW50AAAADU1lcmNoYW50UHJpY2UAAAAAAAABAAAABAAAAAAAAAAxTnVtYmVyIG9mIHJlY2VpcHRzIGlzc3VlZCwgdGhlIGlkIG9mIHRoZSBuZXh0IG9uZQAAAAAAAAxSZWNlaXB0Q291bnQAAAABAAAAAAAAAAdSZWNlaXB0AAAAAAEAAAAGAAAAAQAAACNJZCBvZiB0aGUgbGF0ZXN0IHJlY2VpcHQgb2YgYSB0b2tlbgAAAAANTGF0ZXN0UmVjZWlwdAAAAAAAAAEAAAAGAAAAAAAAAAAAAAALVHJhbnNmZXJGZWUAAAAAAQAAAAAAAAAJRmVlRXhlbXB0AAAAAAAAAQAAABMAAAAAAAAAAAAAAA9Eb25hdGlvbkFkZHJlc3MAAAAAAQAAADZBbW91bnQgb2YgYSBwYXltZW50IHRva2VuIGRvbmF0ZWQgb24gY2xhaW1zIG9mIGEgdG9rZW4AAAAAAA5Ub2tlbkRvbmF0aW9ucwAAAAAAAgAAAAYAAAATAAAAAQAAADBBbW91bnQgb2YgYSBwYXltZW50IHRva2VuIGRvbmF0ZWQgb24gZXZlcnkgY2xhaW0AAAAJRG9uYXRpb25zAAAAAAAAAQAAABM=",
+        "AAAAAAAAAAAAAAAJc2V0X3ByaWNlAAAAAAAAAwAAAAAAAAALbWVyY2hhbnRfaWQAAAAABAAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAAAAAAAFcHJpY2UAAAAAAAABAAAAAAAAAAttZXJjaGFudF9pZAAAAAAEAAAAAQAAA+0AAAACAAAAEwAAAAs=",
+        "AAAAAAAAAAAAAAAHcmVjZWlwdAAAAAABAAAAAAAAAApyZWNlaXB0X2lkAAAAAAAGAAAAAQAAA+gAAAfQAAAAB1JlY2VpcHQA",
+        "AAAAAAAAAAAAAAASbGF0ZXN0X3JlY2VpcHRfZm9yAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAD6AAAA+0AAAACAAAABgAAB9AAAAAHUmVjZWlwdAA=",
+        "AAAAAAAAAAAAAAAQc2V0X3RyYW5zZmVyX2ZlZQAAAAIAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAAAAAAAAMdHJhbnNmZXJfZmVlAAAAAAAAAAEAAAPtAAAAAgAAABMAAAAL",
+        "AAAAAAAAAAAAAAAOc2V0X2ZlZV9leGVtcHQAAAAAAAIAAAAAAAAAB2FjY291bnQAAAAAEwAAAAAAAAAGZXhlbXB0AAAAAAABAAAAAA==",
+        "AAAAAAAAAAAAAAANaXNfZmVlX2V4ZW1wdAAAAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAAB",
+        "AAAAAAAAAAAAAAAUc2V0X2RvbmF0aW9uX2FkZHJlc3MAAAABAAAAAAAAABBkb25hdGlvbl9hZGRyZXNzAAAD6AAAABMAAAAA",
+        "AAAAAAAAAAAAAAAQZG9uYXRpb25fYWRkcmVzcwAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAAPdG9rZW5fZG9uYXRpb25zAAAAAAIAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAPdG90YWxfZG9uYXRpb25zAAAAAAEAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAQAAAAAAAAAAAAAAClNlcmllc0luZm8AAAAAAAQAAAAAAAAACGJhc2VfdXJpAAAAEAAAAAAAAAAKbWF4X3Rva2VucwAAAAAABgAAADtOdW1iZXIgb2YgdG9rZW5zIG1pbnRlZCBpbiB0aGUgc2VyaWVzLCBidXJuZWQgb25lcyBpbmNsdWRlZAAAAAAGbWludGVkAAAAAAAGAAAAAAAAAARuYW1lAAAAEA==",
+        "AAAAAgAAAAAAAAAAAAAAEFNlcmllc1N0b3JhZ2VLZXkAAAACAAAAAQAAAAAAAAAGU2VyaWVzAAAAAAABAAAABAAAAAEAAAAxT25seSBzZXQgZm9yIHRva2VucyBvdXRzaWRlIG9mIHRoZSBkZWZhdWx0IHNlcmllcwAAAAAAAAtUb2tlblNlcmllcwAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAANY3JlYXRlX3NlcmllcwAAAAAAAAQAAAAAAAAACXNlcmllc19pZAAAAAAAAAQAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAhiYXNlX3VyaQAAABAAAAAAAAAACm1heF90b2tlbnMAAAAAAAYAAAAA",
+        "AAAAAAAAAAAAAAAJc2VyaWVzX29mAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAALc2VyaWVzX2luZm8AAAAAAQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAEAAAfQAAAAClNlcmllc0luZm8AAA==",
+        "AAAAAgAAAAAAAAAAAAAAFFRva2VuSW5kZXhTdG9yYWdlS2V5AAAAAwAAAAAAAAAAAAAAClRva2VuQ291bnQAAAAAAAEAAAAAAAAADEluZGV4ZWRUb2tlbgAAAAEAAAAGAAAAAQAAAAAAAAAKVG9rZW5JbmRleAAAAAAAAQAAAAY=",
+        "AAAAAAAAAAAAAAAOdG9rZW5fYnlfaW5kZXgAAAAAAAEAAAAAAAAABWluZGV4AAAAAAAABgAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAKYWxsX3Rva2VucwAAAAAAAgAAAAAAAAAFc3RhcnQAAAAAAAAGAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAAAY=",
+        "AAAAAgAAAAAAAAAAAAAAElRva2VuVHRsU3RvcmFnZUtleQAAAAAAAQAAAAEAAABZTGl2ZS11bnRpbCBsZWRnZXIgb2YgdGhlIHRva2VuJ3MgZW50cmllcywgdHJhY2tlZCBiZWNhdXNlIHRoZSBUVEwKY2Fubm90IGJlIHJlYWQgb24tY2hhaW4AAAAAAAAOVG9rZW5MaXZlVW50aWwAAAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAJdG9rZW5fdHRsAAAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAQZXh0ZW5kX3Rva2VuX3R0bAAAAAIAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAJZXh0ZW5kX3RvAAAAAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAAKYnVtcF90b2tlbgAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAA==",
+        "AAAAAAAAAAAAAAAJYnVtcF9jaGlwAAAAAAAAAQAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAAA",
+        "AAAAAAAAAAAAAAATZXh0ZW5kX2luc3RhbmNlX3R0bAAAAAAAAAAAAA==",
+        "AAAAAgAAAAAAAAAAAAAAElRyZWFzdXJ5U3RvcmFnZUtleQAAAAAABAAAAAAAAAAAAAAACFRyZWFzdXJ5AAAAAAAAAAAAAAAPUGVuZGluZ1RyZWFzdXJ5AAAAAAEAAABGRnVuZHMgb2YgYSBtZXJjaGFudCBjcmVhdGVkIHdpdGggY3JlYXRlX21lcmNoYW50LCBoZWxkIGJ5IHRoZSBjb250cmFjdAAAAAAAD01lcmNoYW50QmFsYW5jZQAAAAACAAAABAAAABMAAAABAAAAOFN1bSBvZiB0aGUgTWVyY2hhbnRCYWxhbmNlIG9mIGV2ZXJ5IG1lcmNoYW50IGZvciBhIHRva2VuAAAAC0hlbGRCYWxhbmNlAAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAAMc2V0X3RyZWFzdXJ5AAAAAQAAAAAAAAAMbmV3X3RyZWFzdXJ5AAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAPYWNjZXB0X3RyZWFzdXJ5AAAAAAAAAAAA",
+        "AAAAAAAAAAAAAAAIdHJlYXN1cnkAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAAAAAAAQcGVuZGluZ190cmVhc3VyeQAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAAId2l0aGRyYXcAAAAEAAAAAAAAAAttZXJjaGFudF9pZAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAAAnRvAAAAAAPoAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAAAAAAAQdHJlYXN1cnlfYmFsYW5jZQAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAQbWVyY2hhbnRfYmFsYW5jZQAAAAIAAAAAAAAAC21lcmNoYW50X2lkAAAAAAQAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAL",
+        "AAAAAwAAAAAAAAAAAAAADldhcnJhbnR5U3RhdHVzAAAAAAAEAAAAAAAAAAZPcGVuZWQAAAAAAAAAAAAAAAAACEFwcHJvdmVkAAAAAQAAAAAAAAAIUmVqZWN0ZWQAAAACAAAAAAAAAAlGdWxmaWxsZWQAAAAAAAAD",
+        "AAAAAQAAAAAAAAAAAAAADVdhcnJhbnR5Q2xhaW0AAAAAAAAEAAAAAAAAAAppc3N1ZV9oYXNoAAAAAAPuAAAAIAAAAAAAAAANb3BlbmVkX2xlZGdlcgAAAAAAAAQAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAGc3RhdHVzAAAAAAfQAAAADldhcnJhbnR5U3RhdHVzAAA=",
+        "AAAAAgAAAAAAAAAAAAAAEldhcnJhbnR5U3RvcmFnZUtleQAAAAAAAQAAAAEAAAAAAAAADVdhcnJhbnR5Q2xhaW0AAAAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAATb3Blbl93YXJyYW50eV9jbGFpbQAAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAACmlzc3VlX2hhc2gAAAAAA+4AAAAgAAAAAA==",
+        "AAAAAAAAAAAAAAAWcmVzb2x2ZV93YXJyYW50eV9jbGFpbQAAAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAhhcHByb3ZlZAAAAAEAAAAA",
+        "AAAAAAAAAAAAAAAWZnVsZmlsbF93YXJyYW50eV9jbGFpbQAAAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAA==",
+        "AAAAAAAAAAAAAAAOd2FycmFudHlfY2xhaW0AAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAPoAAAH0AAAAA1XYXJyYW50eUNsYWltAAAA",
+        "AAAABAAAAAAAAAAAAAAAFU5vbkZ1bmdpYmxlVG9rZW5FcnJvcgAAAAAAADsAAAAkSW5kaWNhdGVzIGEgbm9uLWV4aXN0ZW50IGB0b2tlbl9pZGAuAAAAEE5vbkV4aXN0ZW50VG9rZW4AAADIAAAAV0luZGljYXRlcyBhbiBlcnJvciByZWxhdGVkIHRvIHRoZSBvd25lcnNoaXAgb3ZlciBhIHBhcnRpY3VsYXIgdG9rZW4uClVzZWQgaW4gdHJhbnNmZXJzLgAAAAAOSW5jb3JyZWN0T3duZXIAAAAAAMkAAABCSW5kaWNhdGVzIGEgZmFpbHVyZSB3aXRoIHRoZSBgYXBwcm92ZXJgIG9mIGEgdG9rZW4gdG8gYmUgYXBwcm92ZWQuAAAAAAAPSW52YWxpZEFwcHJvdmVyAAAAAMsAAAArSW5kaWNhdGVzIGFuIGV4cGlyYXRpb24gbGVkZ2VyIGluIHRoZSBwYXN0LgAAAAAWSW52YWxpZExpdmVVbnRpbExlZGdlcgAAAAAAzAAAAClJbmRpY2F0ZXMgb3ZlcmZsb3cgd2hlbiBhZGRpbmcgdHdvIHZhbHVlcwAAAAAAAAxNYXRoT3ZlcmZsb3cAAADNAAAANkluZGljYXRlcyBhbGwgcG9zc2libGUgYHRva2VuX2lkYHMgYXJlIGFscmVhZHkgaW4gdXNlLgAAAAAAE1Rva2VuSURzQXJlRGVwbGV0ZWQAAAAAzgAAAEVJbmRpY2F0ZXMgYW4gaW52YWxpZCBhbW91bnQgdG8gYmF0Y2ggbWludCBpbiBgY29uc2VjdXRpdmVgIGV4dGVuc2lvbi4AAAAAAAANSW52YWxpZEFtb3VudAAAAAAAAM8AAAAnSW5kaWNhdGVzIHRoZSB0b2tlbiB3YXMgYWxyZWFkeSBtaW50ZWQuAAAAABJUb2tlbkFscmVhZHlNaW50ZWQAAAAAANIAAAA8SW5kaWNhdGVzIHRoZSBiYXNlIFVSSSBpcyBsb25nZXIgdGhhbiBgTUFYX0JBU0VfVVJJX0xFTkdUSGAuAAAAFUJhc2VVcmlNYXhMZW5FeGNlZWRlZAAAAAAAANMAAABHSW5kaWNhdGVzIHRoZSByb3lhbHR5IGFtb3VudCBpcyBoaWdoZXIgdGhhbiAxMF8wMDAgKDEwMCUpIGJhc2lzIHBvaW50cy4AAAAAFEludmFsaWRSb3lhbHR5QW1vdW50AAAA1AAAAB5JbmRpY2F0ZXMgYW4gaW52YWxpZCBzaWduYXR1cmUAAAAAABBJbnZhbGlkU2lnbmF0dXJlAAAA1gAAADdJbmRpY2F0ZXMgdGhlIHRva2VuIGV4aXN0cyBidXQgaGFzIG5vdCBiZWVuIGNsYWltZWQgeWV0AAAAAA9Ub2tlbk5vdENsYWltZWQAAAAA1wAAAD5JbmRpY2F0ZXMgYSBtaXNzaW5nIG9yIGludmFsaWQgcmVhc29uIGNvZGUgZm9yIGFuIGFkbWluIGFjdGlvbgAAAAAAEUludmFsaWRSZWFzb25Db2RlAAAAAAAA2AAAAIhJbmRpY2F0ZXMgdGhlIHRva2VuIGlzIGF0dGFjaGVkIHRvIGEgcGFyZW50IHRva2VuIG9yIGhhcyBjaGlsZHJlbgphdHRhY2hlZCwgb3IgdGhlIGF0dGFjaG1lbnQgd291bGQgbmVzdCB0b2tlbnMgbW9yZSB0aGFuIG9uZSBsZXZlbCBkZWVwAAAADVRva2VuQXR0YWNoZWQAAAAAAADZAAAAL0luZGljYXRlcyB0aGVyZSBpcyBubyBvcGVuIGVzY3JvdyBmb3IgdGhlIHRva2VuAAAAAA5Fc2Nyb3dOb3RGb3VuZAAAAAAA2gAAADVJbmRpY2F0ZXMgdGhlIGVzY3JvdyB0aW1lb3V0IGhhcyBub3QgYmVlbiByZWFjaGVkIHlldAAAAAAAABBFc2Nyb3dOb3RFeHBpcmVkAAAA2wAAAC1JbmRpY2F0ZXMgdGhlIGVzY3JvdyB0aW1lb3V0IGhhcyBiZWVuIHJlYWNoZWQAAAAAAAANRXNjcm93RXhwaXJlZAAAAAAAANwAAAAlSW5kaWNhdGVzIHRoZSB0b2tlbiBpcyBoZWxkIGluIGVzY3JvdwAAAAAAAA1Ub2tlbkluRXNjcm93AAAAAAAA3QAAADpJbmRpY2F0ZXMgYSBzdGF0dXMgY2hhbmdlIHRoYXQgdGhlIHdvcmtmbG93IGRvZXMgbm90IGFsbG93AAAAAAAXSW52YWxpZFN0YXR1c1RyYW5zaXRpb24AAAAA3gAAAEZJbmRpY2F0ZXMgdGhlIHRva2VuIGNoYW5nZWQgaGFuZHMgdG9vIHJlY2VudGx5IHRvIGJlIHRyYW5zZmVycmVkIGFnYWluAAAAAAAWVHJhbnNmZXJDb29sZG93bkFjdGl2ZQAAAAAA3wAAADRJbmRpY2F0ZXMgdGhlIHRva2VuIGlzIHJlc2VydmVkIGZvciBhbm90aGVyIGNsYWltYW50AAAAElJlc2VydmVkRm9yQW5vdGhlcgAAAAAA4AAAACtJbmRpY2F0ZXMgYSBtaXNzaW5nIG9yIGluY29ycmVjdCBjbGFpbSBjb2RlAAAAABBJbnZhbGlkQ2xhaW1Db2RlAAAA4QAAAC1JbmRpY2F0ZXMgdGhlIGNsYWltIGNvZGUgd2FzIGFscmVhZHkgcmVkZWVtZWQAAAAAAAARQ2xhaW1Db2RlUmVkZWVtZWQAAAAAAADiAAAAIEluZGljYXRlcyB0aGUgY2xhaW0gY29kZSBleHBpcmVkAAAAEENsYWltQ29kZUV4cGlyZWQAAADjAAAALkluZGljYXRlcyB0aGUgY29udHJhY3Qgd2FzIGFscmVhZHkgaW5pdGlhbGl6ZWQAAAAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAOQAAAAxSW5kaWNhdGVzIHRoZXJlIGlzIG5vIHRyZWFzdXJ5IHByb3Bvc2FsIHRvIGFjY2VwdAAAAAAAABFOb1BlbmRpbmdUcmVhc3VyeQAAAAAAAOUAAAA8SW5kaWNhdGVzIHRoZSBwYXJ0bmVyIGhhcyBubyBtaW50IGFsbG93YW5jZSBsZWZ0IGZvciB0aGUgU0tVAAAAFk1pbnRBbGxvd2FuY2VFeGhhdXN0ZWQAAAAAAOYAAAA4SW5kaWNhdGVzIHRoZSBjaGlwIGlzIG5vdCBhbGxvY2F0ZWQgdG8gdGhlIHJlcXVlc3RlZCBTS1UAAAAPQ2hpcFNrdU1pc21hdGNoAAAAAOcAAABASW5kaWNhdGVzIGFuIGVtcHR5IHNpZ25lZCBtZXNzYWdlIG9yIG9uZSBvdmVyIHRoZSBtYXhpbXVtIGxlbmd0aAAAABBNYWxmb3JtZWRNZXNzYWdlAAAA6AAAADVJbmRpY2F0ZXMgYW4gZW1wdHkgVVJJIG9yIG9uZSBvdmVyIHRoZSBtYXhpbXVtIGxlbmd0aAAAAAAAAApJbnZhbGlkVXJpAAAAAADpAAAAPkluZGljYXRlcyB0aGUgYWNjb3VudCBpcyBub3QgdGhlIHJlY292ZXJ5IGFkZHJlc3Mgb2YgdGhlIG93bmVyAAAAAAAVUmVjb3ZlcnlOb3RDb25maWd1cmVkAAAAAAAA6gAAADZJbmRpY2F0ZXMgdGhlcmUgaXMgbm8gYW5ub3VuY2VkIHJlY292ZXJ5IGZvciB0aGUgb3duZXIAAAAAABFOb1BlbmRpbmdSZWNvdmVyeQAAAAAAAOsAAAAvSW5kaWNhdGVzIHRoZSByZWNvdmVyeSBkZWxheSBoYXMgbm90IHBhc3NlZCB5ZXQAAAAAE1JlY292ZXJ5RGVsYXlBY3RpdmUAAAAA7AAAADlJbmRpY2F0ZXMgdGhlIHNwZW5kZXIgaGFzIG5vIHZhbGlkIGFwcHJvdmFsIGZvciB0aGUgdG9rZW4AAAAAAAAMVW5hdXRob3JpemVkAAAA7QAAAD5JbmRpY2F0ZXMgdGhlIG5vbmNlIHdhcyBub3QgZ3JlYXRlciB0aGFuIHRoZSBsYXN0IGNvbnN1bWVkIG9uZQAAAAAAC05vbmNlVG9vTG93AAAAAO4AAAA2SW5kaWNhdGVzIHRoZSBzaWduYXR1cmUgcmVjb3ZlcnMgdG8gYW5vdGhlciBwdWJsaWMga2V5AAAAAAAZU2lnbmF0dXJlUmVjb3ZlcnlNaXNtYXRjaAAAAAAAAO8AAAAmSW5kaWNhdGVzIGEgcmVjb3ZlcnkgSUQgb3V0c2lkZSBvZiAwLTMAAAAAABFJbnZhbGlkUmVjb3ZlcnlJZAAAAAAAAPAAAAArSW5kaWNhdGVzIGEgc2lnbmF0dXJlIHRoYXQgaXMgbm90IHZhbGlkIERFUgAAAAASTWFsZm9ybWVkU2lnbmF0dXJlAAAAAADxAAAANkluZGljYXRlcyBhIHNpZ25hdHVyZSB1c2VkIGFmdGVyIGl0cyBleHBpcmF0aW9uIGxlZGdlcgAAAAAAEFNpZ25hdHVyZUV4cGlyZWQAAADyAAAALkluZGljYXRlcyB0aGVyZSBpcyBubyBhZG1pbiB0cmFuc2ZlciB0byBhY2NlcHQAAAAAAA5Ob1BlbmRpbmdBZG1pbgAAAAAA8wAAACpJbmRpY2F0ZXMgdGhlIGNoaXAgaXMgbm90IG9uIHRoZSBhbGxvd2xpc3QAAAAAAA5DaGlwTm90QWxsb3dlZAAAAAAA9AAAAB5JbmRpY2F0ZXMgdGhlIGNoaXAgd2FzIHJldm9rZWQAAAAAAAtDaGlwUmV2b2tlZAAAAAD1AAAAOkluZGljYXRlcyB0aGUgdG9rZW4gaXMgc291bGJvdW5kIGFuZCBjYW5ub3QgYmUgdHJhbnNmZXJyZWQAAAAAAA5Ub2tlblNvdWxib3VuZAAAAAAA9gAAADRJbmRpY2F0ZXMgdGhlIHBlcmsgb2YgdGhlIHRva2VuIHdhcyBhbHJlYWR5IHJlZGVlbWVkAAAAD0FscmVhZHlSZWRlZW1lZAAAAAD3AAAAO0luZGljYXRlcyBhbiBhdHRyaWJ1dGUga2V5IG9yIHZhbHVlIG92ZXIgdGhlIG1heGltdW0gbGVuZ3RoAAAAABBBdHRyaWJ1dGVUb29Mb25nAAAA+AAAACZJbmRpY2F0ZXMgYSBzZXJpZXMgdGhhdCBkb2VzIG5vdCBleGlzdAAAAAAADlNlcmllc05vdEZvdW5kAAAAAAD5AAAAK0luZGljYXRlcyBhIHNlcmllcyBpZCB0aGF0IGlzIGFscmVhZHkgdGFrZW4AAAAAE1Nlcmllc0FscmVhZHlFeGlzdHMAAAAA+gAAACpJbmRpY2F0ZXMgYSBjbGFpbSBhZnRlciB0aGUgY2xhaW0gZGVhZGxpbmUAAAAAABFDbGFpbVdpbmRvd0Nsb3NlZAAAAAAAAPsAAAAvSW5kaWNhdGVzIHRoZSBjbGFpbSBkZWFkbGluZSBoYXMgbm90IHBhc3NlZCB5ZXQAAAAAD0NsYWltV2luZG93T3BlbgAAAAD8AAAAMkluZGljYXRlcyB0aGUgcmVjaXBpZW50IGNvbnRyYWN0IHJlZnVzZWQgdGhlIHRva2VuAAAAAAAQUmVjZWl2ZXJSZWplY3RlZAAAAP0AAABcSW5kaWNhdGVzIGEgcHVibGljIGtleSB0aGF0IGlzIG5laXRoZXIgYW4gdW5jb21wcmVzc2VkIFNFQzEgcG9pbnQKbm9yIGEgbm9uLXplcm8gRWQyNTUxOSBrZXkAAAAQSW52YWxpZFB1YmxpY0tleQAAAP4AAABTSW5kaWNhdGVzIHRoZSB0b2tlbiByZXF1aXJlcyBhIGNoaXAgc2lnbmF0dXJlIGFuZCB0aGUgb3duZXIncyBhdXRob3JpemF0aW9uIHRvIG1vdmUAAAAAEER1YWxBdXRoUmVxdWlyZWQAAAD/AAAAU0luZGljYXRlcyB0aGUgbm9uY2UgaXMgZnVydGhlciBhaGVhZCBvZiB0aGUgbGFzdCBjb25zdW1lZCBvbmUgdGhhbiB0aGUgbm9uY2Ugd2luZG93AAAAABBOb25jZVRvb0ZhckFoZWFkAAABAAAAAChJbmRpY2F0ZXMgYSBtZXJjaGFudCB0aGF0IGRvZXMgbm90IGV4aXN0AAAAEE1lcmNoYW50Tm90Rm91bmQAAAEBAAAAeEluZGljYXRlcyBhIHJlYWQtb25seSBjaGVjayBvZiBhIGBTZWNwMjU2cjFgIGNoaXAsIHRoZSBob3N0IGFib3J0cwppbnN0ZWFkIG9mIGZhaWxpbmcgb24gYSBzaWduYXR1cmUgdGhhdCBkb2VzIG5vdCBtYXRjaAAAABFVbnZlcmlmaWFibGVDdXJ2ZQAAAAAAAQIAAAAoSW5kaWNhdGVzIHRoZSB0b2tlbiBhbHJlYWR5IGhhcyBhbiBvd25lcgAAABNUb2tlbkFscmVhZHlDbGFpbWVkAAAAAQMAAABSSW5kaWNhdGVzIGEgdHJhbnNmZXIgdG8gYW5vdGhlciBhY2NvdW50IHRoYW4gdGhlIG9uZSB0aGUgYXBwcm92YWwgaXMgcmVzdHJpY3RlZCB0bwAAAAAAE1JlY2lwaWVudE5vdEFsbG93ZWQAAAABBAAAAENJbmRpY2F0ZXMgYSBjb2xsZWN0aW9uIG9yIHNlcmllcyBuYW1lIGxvbmdlciB0aGFuIGBNQVhfTkFNRV9MRU5HVEhgAAAAAAtOYW1lVG9vTG9uZwAAAAEFAAAANUluZGljYXRlcyBhIGRvbmF0aW9uIHdoaWxlIG5vIGRvbmF0aW9uIGFkZHJlc3MgaXMgc2V0AAAAAAAAFURvbmF0aW9uQWRkcmVzc05vdFNldAAAAAAAAQY=",
         "AAAABQAAAAAAAAAAAAAAB1VwZ3JhZGUAAAAAAQAAAAd1cGdyYWRlAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAAAAAACXdhc21faGFzaAAAAAAAAA4AAAAAAAAAAg==",
-        "AAAABQAAAAAAAAAAAAAACFRyYW5zZmVyAAAAAQAAAAh0cmFuc2ZlcgAAAAMAAAAAAAAABGZyb20AAAATAAAAAQAAAAAAAAACdG8AAAAAABMAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAACFRyYW5zZmVyAAAAAQAAAAh0cmFuc2ZlcgAAAAUAAAAAAAAABGZyb20AAAATAAAAAQAAAAAAAAACdG8AAAAAABMAAAABAAAAAAAAAAttZXJjaGFudF9pZAAAAAAEAAAAAQAAAAAAAAADc2t1AAAAA+gAAAAEAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAABEJ1cm4AAAABAAAABGJ1cm4AAAACAAAAAAAAAARmcm9tAAAAEwAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADUFkbWluVHJhbnNmZXIAAAAAAAABAAAADmFkbWluX3RyYW5zZmVyAAAAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAEAAAAAAAAAAnRvAAAAAAATAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAAAAAAGcmVhc29uAAAAAAAEAAAAAAAAAAI=",
         "AAAABQAAAAAAAAAAAAAAB0FwcHJvdmUAAAAAAQAAAAdhcHByb3ZlAAAAAAQAAAAAAAAACGFwcHJvdmVyAAAAEwAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAACGFwcHJvdmVkAAAAEwAAAAAAAAAAAAAAEWxpdmVfdW50aWxfbGVkZ2VyAAAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAE0FwcHJvdmVGb3JSZWNpcGllbnQAAAAAAQAAABVhcHByb3ZlX2Zvcl9yZWNpcGllbnQAAAAAAAAFAAAAAAAAAAhhcHByb3ZlcgAAABMAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAhhcHByb3ZlZAAAABMAAAAAAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAAAAAAARbGl2ZV91bnRpbF9sZWRnZXIAAAAAAAAEAAAAAAAAAAI=",
         "AAAABQAAAAAAAAAAAAAADUFwcHJvdmVGb3JBbGwAAAAAAAABAAAAD2FwcHJvdmVfZm9yX2FsbAAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAAAAAAAAhvcGVyYXRvcgAAABMAAAAAAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAAAAAAAAg==",
-        "AAAABQAAAAAAAAAAAAAABE1pbnQAAAABAAAABG1pbnQAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAg==",
-        "AAAABQAAAAAAAAAAAAAABUNsYWltAAAAAAAAAQAAAAVjbGFpbQAAAAAAAAIAAAAAAAAACGNsYWltYW50AAAAEwAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAFkFwcHJvdmVPcGVyYXRvckxpbWl0ZWQAAAAAAAEAAAAYYXBwcm92ZV9vcGVyYXRvcl9saW1pdGVkAAAABAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAAAAAAAAAAAJcmVtYWluaW5nAAAAAAAABAAAAAAAAAAAAAAAEWxpdmVfdW50aWxfbGVkZ2VyAAAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAABE1pbnQAAAABAAAABG1pbnQAAAAGAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAEAAAAAAAAAC21lcmNoYW50X2lkAAAAAAQAAAABAAAAAAAAAANza3UAAAAD6AAAAAQAAAABAAAAAAAAAAJ0bwAAAAAD6AAAABMAAAAAAAAAAAAAAAlwb19udW1iZXIAAAAAAAPoAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAACkNoZWNrcG9pbnQAAAAAAAEAAAAKY2hlY2twb2ludAAAAAAABAAAAAAAAAANbmV4dF90b2tlbl9pZAAAAAAAAAYAAAAAAAAAAAAAAAx0b3RhbF9zdXBwbHkAAAAGAAAAAAAAAAAAAAANY2xhaW1lZF9jb3VudAAAAAAAAAYAAAAAAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAABUNsYWltAAAAAAAAAQAAAAVjbGFpbQAAAAAAAAUAAAAAAAAACGNsYWltYW50AAAAEwAAAAEAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABBAAAAAQAAAAAAAAALbWVyY2hhbnRfaWQAAAAABAAAAAEAAAAAAAAAA3NrdQAAAAPoAAAABAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAACU5vbmNlVXNlZAAAAAAAAAEAAAAKbm9uY2VfdXNlZAAAAAAAAgAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADk1ldGFkYXRhVXBkYXRlAAAAAAABAAAAD21ldGFkYXRhX3VwZGF0ZQAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAABkF0dGFjaAAAAAAAAQAAAAZhdHRhY2gAAAAAAAMAAAAAAAAACXBhcmVudF9pZAAAAAAAAAYAAAABAAAAAAAAAAhjaGlsZF9pZAAAAAYAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAABkRldGFjaAAAAAAAAQAAAAZkZXRhY2gAAAAAAAMAAAAAAAAACXBhcmVudF9pZAAAAAAAAAYAAAABAAAAAAAAAAhjaGlsZF9pZAAAAAYAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADEVzY3Jvd09wZW5lZAAAAAEAAAANZXNjcm93X29wZW5lZAAAAAAAAAQAAAAAAAAABGZyb20AAAATAAAAAQAAAAAAAAACdG8AAAAAABMAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAAAAAAAAAAAABFleHBpcmF0aW9uX2xlZGdlcgAAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAD0VzY3Jvd0NvbXBsZXRlZAAAAAABAAAAEGVzY3Jvd19jb21wbGV0ZWQAAAADAAAAAAAAAARmcm9tAAAAEwAAAAEAAAAAAAAAAnRvAAAAAAATAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAD0VzY3Jvd1JlY2xhaW1lZAAAAAABAAAAEGVzY3Jvd19yZWNsYWltZWQAAAACAAAAAAAAAARmcm9tAAAAEwAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAE1dhcnJhbnR5Q2xhaW1PcGVuZWQAAAAAAQAAABV3YXJyYW50eV9jbGFpbV9vcGVuZWQAAAAAAAADAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAAAAAAAppc3N1ZV9oYXNoAAAAAAPuAAAAIAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAFVdhcnJhbnR5Q2xhaW1SZXNvbHZlZAAAAAAAAAEAAAAXd2FycmFudHlfY2xhaW1fcmVzb2x2ZWQAAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAIYXBwcm92ZWQAAAABAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAFldhcnJhbnR5Q2xhaW1GdWxmaWxsZWQAAAAAAAEAAAAYd2FycmFudHlfY2xhaW1fZnVsZmlsbGVkAAAAAQAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAB1JlY2xhaW0AAAAAAQAAAAdyZWNsYWltAAAAAAIAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABBAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAABlJlZGVlbQAAAAAAAQAAAAZyZWRlZW0AAAAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAYAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAABFNjYW4AAAABAAAABHNjYW4AAAACAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAEAAAAAAAAABWNvdW50AAAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAE0NoaXBGaXJtd2FyZVVwZGF0ZWQAAAAAAQAAABVjaGlwX2Zpcm13YXJlX3VwZGF0ZWQAAAAAAAADAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAEAAAAAAAAAEXByZXZpb3VzX2Zpcm13YXJlAAAAAAAABAAAAAAAAAAAAAAACGZpcm13YXJlAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADVNlcmllc0NyZWF0ZWQAAAAAAAABAAAADnNlcmllc19jcmVhdGVkAAAAAAAEAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAQAAAAAAAAAEbmFtZQAAABAAAAAAAAAAAAAAAAhiYXNlX3VyaQAAABAAAAAAAAAAAAAAAAptYXhfdG9rZW5zAAAAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEE1heFRva2Vuc1VwZGF0ZWQAAAABAAAAEm1heF90b2tlbnNfdXBkYXRlZAAAAAAAAgAAAAAAAAATcHJldmlvdXNfbWF4X3Rva2VucwAAAAAGAAAAAAAAAAAAAAAKbWF4X3Rva2VucwAAAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAC0NoaXBSZWJvdW5kAAAAAAEAAAAMY2hpcF9yZWJvdW5kAAAAAwAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAATcHJldmlvdXNfcHVibGljX2tleQAAAAPuAAAAQQAAAAAAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABBAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADUFkbWluUHJvcG9zZWQAAAAAAAABAAAADmFkbWluX3Byb3Bvc2VkAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAAAAAAAA1wZW5kaW5nX2FkbWluAAAAAAAAEwAAAAEAAAAC",
+        "AAAABQAAAAAAAAAAAAAADEFkbWluQ2hhbmdlZAAAAAEAAAANYWRtaW5fY2hhbmdlZAAAAAAAAAIAAAAAAAAADnByZXZpb3VzX2FkbWluAAAAAAATAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAD1RyZWFzdXJ5Q2hhbmdlZAAAAAABAAAAEHRyZWFzdXJ5X2NoYW5nZWQAAAACAAAAAAAAABFwcmV2aW91c190cmVhc3VyeQAAAAAAABMAAAABAAAAAAAAAAh0cmVhc3VyeQAAABMAAAABAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAACFdpdGhkcmF3AAAAAQAAAAh3aXRoZHJhdwAAAAQAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAAAAAAAnRvAAAAAAATAAAAAQAAAAAAAAALbWVyY2hhbnRfaWQAAAAABAAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEVJlY292ZXJ5SW5pdGlhdGVkAAAAAAAAAQAAABJyZWNvdmVyeV9pbml0aWF0ZWQAAAAAAAMAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAACHJlY292ZXJ5AAAAEwAAAAEAAAAAAAAAEWV4ZWN1dGFibGVfbGVkZ2VyAAAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEVJlY292ZXJ5Q2FuY2VsbGVkAAAAAAAAAQAAABJyZWNvdmVyeV9jYW5jZWxsZWQAAAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAC",
+        "AAAABQAAAAAAAAAAAAAACFJlY292ZXJ5AAAAAQAAAAhyZWNvdmVyeQAAAAMAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAACHJlY292ZXJ5AAAAEwAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAElJlY292ZXJ5QWRkcmVzc1NldAAAAAAAAQAAABRyZWNvdmVyeV9hZGRyZXNzX3NldAAAAAMAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAACHJlY292ZXJ5AAAAEwAAAAEAAAAAAAAADWRlbGF5X2xlZGdlcnMAAAAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADkJhc2VVcmlVcGRhdGVkAAAAAAABAAAAEGJhc2VfdXJpX3VwZGF0ZWQAAAACAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAQAAAAAAAAAIYmFzZV91cmkAAAAQAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAE1VuY2xhaW1lZFVyaVVwZGF0ZWQAAAAAAQAAABV1bmNsYWltZWRfdXJpX3VwZGF0ZWQAAAAAAAABAAAAAAAAAAN1cmkAAAAAEAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAC0NoaXBSZXZva2VkAAAAAAEAAAAMY2hpcF9yZXZva2VkAAAAAgAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAAAAAAAAdyZXZva2VkAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEENoaXBDdXJ2ZVVwZGF0ZWQAAAABAAAAEmNoaXBfY3VydmVfdXBkYXRlZAAAAAAAAgAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAAAAAAAAVjdXJ2ZQAAAAAAB9AAAAAJQ3VydmVUeXBlAAAAAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEFNvdWxib3VuZFVwZGF0ZWQAAAABAAAAEXNvdWxib3VuZF91cGRhdGVkAAAAAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAAJc291bGJvdW5kAAAAAAAAAQAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAD01lcmNoYW50Q3JlYXRlZAAAAAABAAAAEG1lcmNoYW50X2NyZWF0ZWQAAAADAAAAAAAAAAttZXJjaGFudF9pZAAAAAAEAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAAAAAAEbmFtZQAAABAAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAFE1lcmNoYW50QWRtaW5VcGRhdGVkAAAAAQAAABZtZXJjaGFudF9hZG1pbl91cGRhdGVkAAAAAAACAAAAAAAAAAttZXJjaGFudF9pZAAAAAAEAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAE1JlY2VpdmVySG9va1VwZGF0ZWQAAAAAAQAAABVyZWNlaXZlcl9ob29rX3VwZGF0ZWQAAAAAAAACAAAAAAAAAAhyZWNlaXZlcgAAABMAAAABAAAAAAAAAAdlbmFibGVkAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAFFNlY3VyaXR5TGV2ZWxVcGRhdGVkAAAAAQAAABZzZWN1cml0eV9sZXZlbF91cGRhdGVkAAAAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAVsZXZlbAAAAAAAB9AAAAANU2VjdXJpdHlMZXZlbAAAAAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAFENsYWltRGVhZGxpbmVVcGRhdGVkAAAAAQAAABZjbGFpbV9kZWFkbGluZV91cGRhdGVkAAAAAAABAAAAAAAAABBsZWRnZXJfdGltZXN0YW1wAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAF1RyYW5zZmVyQ29vbGRvd25VcGRhdGVkAAAAAAEAAAAZdHJhbnNmZXJfY29vbGRvd25fdXBkYXRlZAAAAAAAAAEAAAAAAAAAB2xlZGdlcnMAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEk5vbmNlV2luZG93VXBkYXRlZAAAAAAAAQAAABRub25jZV93aW5kb3dfdXBkYXRlZAAAAAEAAAAAAAAABndpbmRvdwAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAD0NoaXBBdXRoVXBkYXRlZAAAAAABAAAAEWNoaXBfYXV0aF91cGRhdGVkAAAAAAAAAQAAAAAAAAAJY2hpcF9hdXRoAAAAAAAD6AAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAGUNoZWNrcG9pbnRJbnRlcnZhbFVwZGF0ZWQAAAAAAAABAAAAG2NoZWNrcG9pbnRfaW50ZXJ2YWxfdXBkYXRlZAAAAAABAAAAAAAAAAVtaW50cwAAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAF01heE1lc3NhZ2VMZW5ndGhVcGRhdGVkAAAAAAEAAAAabWF4X21lc3NhZ2VfbGVuZ3RoX3VwZGF0ZWQAAAAAAAEAAAAAAAAABmxlbmd0aAAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAC0NoaXBBbGxvd2VkAAAAAAEAAAAMY2hpcF9hbGxvd2VkAAAAAgAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAAEEAAAABAAAAAAAAAAdhbGxvd2VkAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEEFsbG93bGlzdEVuYWJsZWQAAAABAAAAEWFsbG93bGlzdF9lbmFibGVkAAAAAAAAAQAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADlJveWFsdHlVcGRhdGVkAAAAAAABAAAAD3JveWFsdHlfdXBkYXRlZAAAAAADAAAAAAAAAAh0b2tlbl9pZAAAA+gAAAAGAAAAAQAAAAAAAAAIcmVjZWl2ZXIAAAATAAAAAAAAAAAAAAAMYmFzaXNfcG9pbnRzAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADFByaWNlVXBkYXRlZAAAAAEAAAANcHJpY2VfdXBkYXRlZAAAAAAAAAMAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAAAAAAAALbWVyY2hhbnRfaWQAAAAABAAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAElRyYW5zZmVyRmVlVXBkYXRlZAAAAAAAAQAAABR0cmFuc2Zlcl9mZWVfdXBkYXRlZAAAAAIAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAACERvbmF0aW9uAAAAAQAAAAhkb25hdGlvbgAAAAQAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAE0ZlZUV4ZW1wdGlvblVwZGF0ZWQAAAAAAQAAABVmZWVfZXhlbXB0aW9uX3VwZGF0ZWQAAAAAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAAAAAAZleGVtcHQAAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADkNoaXBTa3VVcGRhdGVkAAAAAAABAAAAEGNoaXBfc2t1X3VwZGF0ZWQAAAACAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAQQAAAAEAAAAAAAAAA3NrdQAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAFE1pbnRBbGxvd2FuY2VVcGRhdGVkAAAAAQAAABZtaW50X2FsbG93YW5jZV91cGRhdGVkAAAAAAADAAAAAAAAAAdwYXJ0bmVyAAAAABMAAAABAAAAAAAAAANza3UAAAAABAAAAAEAAAAAAAAACHF1YW50aXR5AAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAD0NsYWltQ29kZUlzc3VlZAAAAAABAAAAEWNsYWltX2NvZGVfaXNzdWVkAAAAAAAAAgAAAAAAAAAIdG9rZW5faWQAAAAGAAAAAQAAAAAAAAANZXhwaXJ5X2xlZGdlcgAAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAACFJlc2VydmVkAAAAAQAAAAhyZXNlcnZlZAAAAAMAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAACGNsYWltYW50AAAAEwAAAAEAAAAAAAAADWV4cGlyeV9sZWRnZXIAAAAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAE1Jlc2VydmF0aW9uUmVsZWFzZWQAAAAAAQAAABRyZXNlcnZhdGlvbl9yZWxlYXNlZAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAC",
+        "AAAABQAAAAAAAAAAAAAADkNsYWltRGVsZWdhdGVkAAAAAAABAAAAD2NsYWltX2RlbGVnYXRlZAAAAAACAAAAAAAAAAh0b2tlbl9pZAAAAAYAAAABAAAAAAAAAAhkZWxlZ2F0ZQAAABMAAAABAAAAAg==",
+        "AAAAAgAAAClUb2tlbiBzdGF0ZSBjaGFuZ2UgcmVwb3J0ZWQgYnkgQWN0aXZpdHlWMQAAAAAAAAAAAAAJT3BlcmF0aW9uAAAAAAAACAAAAAAAAAAAAAAABE1pbnQAAAAAAAAAAAAAAAVDbGFpbQAAAAAAAAAAAAAAAAAACFRyYW5zZmVyAAAAAAAAAAAAAAANQWRtaW5UcmFuc2ZlcgAAAAAAAAAAAAAAAAAADEVzY3Jvd09wZW5lZAAAAAAAAAAAAAAAD0VzY3Jvd0NvbXBsZXRlZAAAAAAAAAAAAAAAAA9Fc2Nyb3dSZWNsYWltZWQAAAAAAAAAAAAAAAAEQnVybg==",
+        "AAAABQAAADZDb25zb2xpZGF0ZWQgdmlldyBvZiBhIHRva2VuIHN0YXRlIGNoYW5nZSBmb3IgaW5kZXhlcnMAAAAAAAAAAAAKQWN0aXZpdHlWMQAAAAAAAQAAAAthY3Rpdml0eV92MQAAAAAJAAAAAAAAAAlvcGVyYXRpb24AAAAAAAfQAAAACU9wZXJhdGlvbgAAAAAAAAEAAAAAAAAACHRva2VuX2lkAAAABgAAAAEAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABBAAAAAAAAAAAAAAAEZnJvbQAAA+gAAAATAAAAAAAAAAAAAAACdG8AAAAAA+gAAAATAAAAAAAAAAAAAAADc2t1AAAAA+gAAAAEAAAAAAAAAAAAAAAFbm9uY2UAAAAAAAPoAAAABgAAAAAAAAAAAAAABmxlZGdlcgAAAAAABAAAAAAAAAAAAAAAB3ZlcnNpb24AAAAABAAAAAAAAAAC",
       ]),
       options,
     );
   }
   public readonly fromJSON = {
     upgrade: this.txFromJSON<null>,
+    migrate: this.txFromJSON<boolean>,
+    migration_progress: this.txFromJSON<readonly [u64, u64]>,
+    version: this.txFromJSON<u32>,
+    build_info: this.txFromJSON<BuildInfo>,
+    transfer_admin: this.txFromJSON<null>,
+    accept_admin: this.txFromJSON<null>,
+    get_admin: this.txFromJSON<string>,
+    pending_admin: this.txFromJSON<Option<string>>,
     mint: this.txFromJSON<u64>,
+    mint_der: this.txFromJSON<u64>,
+    mint_ed25519: this.txFromJSON<u64>,
+    simulate_mint: this.txFromJSON<MintPreview>,
+    verify: this.txFromJSON<boolean>,
     claim: this.txFromJSON<u64>,
-    transfer: this.txFromJSON<null>,
-    get_nonce: this.txFromJSON<u32>,
+    claim_der: this.txFromJSON<u64>,
+    claim_ed25519: this.txFromJSON<u64>,
+    claim_with_donation: this.txFromJSON<u64>,
+    mint_and_claim: this.txFromJSON<u64>,
+    register_chips: this.txFromJSON<Array<u64>>,
+    set_claim_deadline: this.txFromJSON<null>,
+    claim_deadline: this.txFromJSON<u64>,
+    reclaim_unclaimed: this.txFromJSON<null>,
+    chip_transfer: this.txFromJSON<null>,
+    chip_transfer_der: this.txFromJSON<null>,
+    chip_transfer_ed25519: this.txFromJSON<null>,
+    burn: this.txFromJSON<null>,
+    burn_from_owner: this.txFromJSON<null>,
+    admin_transfer: this.txFromJSON<null>,
+    approve_operator_limited: this.txFromJSON<null>,
+    operator_allowance: this.txFromJSON<Option<u32>>,
+    approve_for_recipient: this.txFromJSON<null>,
+    approved_recipient: this.txFromJSON<Option<string>>,
+    set_soulbound: this.txFromJSON<null>,
+    is_soulbound: this.txFromJSON<boolean>,
+    set_security_level: this.txFromJSON<null>,
+    security_level: this.txFromJSON<SecurityLevel>,
+    rebind_chip: this.txFromJSON<null>,
+    set_transfer_cooldown: this.txFromJSON<null>,
+    transfer_cooldown: this.txFromJSON<u32>,
+    set_nonce_window: this.txFromJSON<null>,
+    nonce_window: this.txFromJSON<u32>,
+    set_chip_auth: this.txFromJSON<null>,
+    chip_auth: this.txFromJSON<Option<string>>,
+    set_max_message_length: this.txFromJSON<null>,
+    max_message_length: this.txFromJSON<u32>,
+    set_max_tokens: this.txFromJSON<null>,
+    max_tokens: this.txFromJSON<u64>,
+    remaining_supply: this.txFromJSON<u64>,
+    total_supply: this.txFromJSON<u64>,
+    minted_count: this.txFromJSON<u64>,
+    claimed_count: this.txFromJSON<u64>,
+    burned_count: this.txFromJSON<u64>,
+    set_checkpoint_interval: this.txFromJSON<null>,
+    checkpoint_interval: this.txFromJSON<u32>,
+    verify_batch: this.txFromJSON<Array<boolean>>,
+    verify_product: this.txFromJSON<ProductVerification>,
+    revoke_chip: this.txFromJSON<null>,
+    unrevoke_chip: this.txFromJSON<null>,
+    is_revoked: this.txFromJSON<boolean>,
+    set_chip_curve: this.txFromJSON<null>,
+    chip_curve: this.txFromJSON<CurveType>,
+    get_nonce: this.txFromJSON<u64>,
+    message_domain: this.txFromJSON<Buffer>,
+    build_message_hash: this.txFromJSON<Buffer>,
+    owner_of_opt: this.txFromJSON<Option<string>>,
+    exists: this.txFromJSON<boolean>,
+    is_claimed: this.txFromJSON<boolean>,
+    token_info: this.txFromJSON<TokenInfo>,
+    tokens_info: this.txFromJSON<Array<TokenInfo>>,
+    token_uri_opt: this.txFromJSON<Option<string>>,
+    set_base_uri: this.txFromJSON<null>,
+    base_uri: this.txFromJSON<string>,
+    set_token_uri: this.txFromJSON<null>,
+    clear_token_uri: this.txFromJSON<null>,
+    set_unclaimed_uri: this.txFromJSON<null>,
+    unclaimed_uri: this.txFromJSON<string>,
+    token_id: this.txFromJSON<u64>,
+    token_id_opt: this.txFromJSON<Option<u64>>,
+    public_key: this.txFromJSON<Buffer>,
+    public_key_opt: this.txFromJSON<Option<Buffer>>,
+    chip_key: this.txFromJSON<ChipKey>,
+    chip_public_key: this.txFromJSON<Buffer>,
     balance: this.txFromJSON<u32>,
     owner_of: this.txFromJSON<string>,
+    transfer: this.txFromJSON<null>,
+    transfer_from: this.txFromJSON<null>,
+    approve: this.txFromJSON<null>,
+    approve_for_all: this.txFromJSON<null>,
+    get_approved: this.txFromJSON<Option<string>>,
+    is_approved_for_all: this.txFromJSON<boolean>,
     name: this.txFromJSON<string>,
     symbol: this.txFromJSON<string>,
     token_uri: this.txFromJSON<string>,
-    token_id: this.txFromJSON<u64>,
-    public_key: this.txFromJSON<Buffer>,
+    set_attribute: this.txFromJSON<null>,
+    get_attribute: this.txFromJSON<Option<string>>,
+    attributes: this.txFromJSON<Map<string, string>>,
+    add_allowed_chips: this.txFromJSON<null>,
+    remove_allowed_chip: this.txFromJSON<null>,
+    set_allowlist_enabled: this.txFromJSON<null>,
+    allowlist_enabled: this.txFromJSON<boolean>,
+    is_chip_allowed: this.txFromJSON<boolean>,
+    chip_info: this.txFromJSON<Option<ChipInfo>>,
+    tokens_by_batch: this.txFromJSON<Array<u64>>,
+    update_chip_firmware: this.txFromJSON<null>,
+    registered_chips: this.txFromJSON<Array<Buffer>>,
+    registered_chip_count: this.txFromJSON<u64>,
+    chip_stats: this.txFromJSON<ChipStats>,
+    record_scan: this.txFromJSON<u32>,
+    scan_count: this.txFromJSON<u32>,
+    issue_claim_code: this.txFromJSON<null>,
+    claim_with_code: this.txFromJSON<u64>,
+    attach: this.txFromJSON<null>,
+    detach: this.txFromJSON<null>,
+    children_of: this.txFromJSON<Array<u64>>,
+    parent_of: this.txFromJSON<Option<u64>>,
+    escrow_transfer: this.txFromJSON<null>,
+    confirm_receipt: this.txFromJSON<null>,
+    reclaim_escrow: this.txFromJSON<null>,
+    escrow_of: this.txFromJSON<Option<Escrow>>,
+    set_locale_uri: this.txFromJSON<null>,
+    token_uri_localized: this.txFromJSON<string>,
+    locales_of: this.txFromJSON<Array<string>>,
+    create_merchant: this.txFromJSON<u32>,
+    set_merchant_admin: this.txFromJSON<null>,
+    set_merchant_base_uri: this.txFromJSON<null>,
+    merchant_info: this.txFromJSON<MerchantInfo>,
+    merchant_of: this.txFromJSON<u32>,
+    tokens_of: this.txFromJSON<Array<u64>>,
+    ownership_history: this.txFromJSON<Array<readonly [string, u32]>>,
+    set_chip_sku: this.txFromJSON<null>,
+    chip_sku: this.txFromJSON<Option<u32>>,
+    grant_mint_allowance: this.txFromJSON<null>,
+    mint_allowance: this.txFromJSON<u64>,
+    partner_mint: this.txFromJSON<u64>,
+    po_of: this.txFromJSON<Option<u32>>,
+    tokens_by_po: this.txFromJSON<Array<u64>>,
+    set_receiver_hook: this.txFromJSON<null>,
+    receiver_hook: this.txFromJSON<boolean>,
+    set_recovery_address: this.txFromJSON<null>,
+    recovery_of: this.txFromJSON<Option<RecoveryConfig>>,
+    initiate_recovery: this.txFromJSON<null>,
+    cancel_recovery: this.txFromJSON<null>,
+    pending_recovery: this.txFromJSON<Option<u32>>,
+    execute_recovery: this.txFromJSON<null>,
+    recent_claims: this.txFromJSON<Array<RecentClaim>>,
+    redeem: this.txFromJSON<null>,
+    is_redeemed: this.txFromJSON<boolean>,
+    redeemed_at: this.txFromJSON<Option<u64>>,
+    reserve_for: this.txFromJSON<null>,
+    release_reservation: this.txFromJSON<null>,
+    reservation_of: this.txFromJSON<Option<readonly [string, u32]>>,
+    delegate_claim: this.txFromJSON<null>,
+    claim_delegate_of: this.txFromJSON<Option<string>>,
+    set_default_royalty: this.txFromJSON<null>,
+    set_token_royalty: this.txFromJSON<null>,
+    royalty_info: this.txFromJSON<readonly [string, i128]>,
+    set_price: this.txFromJSON<null>,
+    price: this.txFromJSON<readonly [string, i128]>,
+    receipt: this.txFromJSON<Option<Receipt>>,
+    latest_receipt_for: this.txFromJSON<Option<readonly [u64, Receipt]>>,
+    set_transfer_fee: this.txFromJSON<null>,
+    transfer_fee: this.txFromJSON<readonly [string, i128]>,
+    set_fee_exempt: this.txFromJSON<null>,
+    is_fee_exempt: this.txFromJSON<boolean>,
+    set_donation_address: this.txFromJSON<null>,
+    donation_address: this.txFromJSON<Option<string>>,
+    token_donations: this.txFromJSON<i128>,
+    total_donations: this.txFromJSON<i128>,
+    create_series: this.txFromJSON<null>,
+    series_of: this.txFromJSON<u32>,
+    series_info: this.txFromJSON<SeriesInfo>,
+    token_by_index: this.txFromJSON<u64>,
+    all_tokens: this.txFromJSON<Array<u64>>,
+    token_ttl: this.txFromJSON<u32>,
+    extend_token_ttl: this.txFromJSON<null>,
+    bump_token: this.txFromJSON<null>,
+    bump_chip: this.txFromJSON<null>,
+    extend_instance_ttl: this.txFromJSON<null>,
+    set_treasury: this.txFromJSON<null>,
+    accept_treasury: this.txFromJSON<null>,
+    treasury: this.txFromJSON<string>,
+    pending_treasury: this.txFromJSON<Option<string>>,
+    withdraw: this.txFromJSON<null>,
+    treasury_balance: this.txFromJSON<i128>,
+    merchant_balance: this.txFromJSON<i128>,
+    open_warranty_claim: this.txFromJSON<null>,
+    resolve_warranty_claim: this.txFromJSON<null>,
+    fulfill_warranty_claim: this.txFromJSON<null>,
+    warranty_claim: this.txFromJSON<Option<WarrantyClaim>>,
   };
 }
@@ -11,6 +11,7 @@ import { useWallet } from "../../hooks/useWallet";
 import { useNFC } from "../../hooks/useNFC";
 import { useChipAuth } from "../../hooks/useChipAuth";
 import { useContractClient } from "../../hooks/useContractClient";
+import { ChipOperation } from "stellar_merch_shop";
 import { createSEP53Message } from "../../util/crypto";
 import { getNetworkPassphrase } from "../../contracts/util";
 import { handleChipError, formatChipError } from "../../util/chipErrorHandler";
@@ -117,7 +118,7 @@ export const ClaimSection = ({ keyId, contractId }: ClaimSectionProps) => {
       const chipPublicKeyBytes = hexToBytes(chipPublicKeyHex);
 
       // Get current nonce from contract
-      let currentNonce = 0;
+      let currentNonce = 0n;
       try {
         const nonceResult = await contractClient.get_nonce(
           {
@@ -127,15 +128,15 @@ export const ClaimSection = ({ keyId, contractId }: ClaimSectionProps) => {
             publicKey: address,
           } as ContractCallOptions,
         );
-        currentNonce = (nonceResult.result as number) || 0;
+        currentNonce = (nonceResult.result as bigint) || 0n;
       } catch (err) {
         // If get_nonce fails, default to 0
         // Nonce fetch failed, defaulting to 0 (first use)
-        currentNonce = 0;
+        currentNonce = 0n;
       }
 
       // Use next nonce (must be greater than stored)
-      const nonce = currentNonce + 1;
+      const nonce = currentNonce + 1n;
 
       // Create SEP-53 message for claim
       const { message, messageHash } = await createSEP53Message(
@@ -144,6 +145,8 @@ export const ClaimSection = ({ keyId, contractId }: ClaimSectionProps) => {
         [address],
         nonce,
         networkPassphraseToUse,
+        ChipOperation.Claim,
+        address,
       );
 
       // Authenticate with chip
@@ -160,6 +163,7 @@ export const ClaimSection = ({ keyId, contractId }: ClaimSectionProps) => {
           recovery_id: authResult.recoveryId,
           public_key: authResult.publicKeyBytes,
           nonce: nonce,
+          expiration_ledger: 0,
         },
         {
           publicKey: address,
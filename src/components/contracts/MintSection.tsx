@@ -13,6 +13,7 @@ import { useChipAuth } from "../../hooks/useChipAuth";
 import { useContractClient } from "../../hooks/useContractClient";
 import { useOperationSteps } from "../../hooks/useOperationSteps";
 import { useErrorHandler } from "../../hooks/useErrorHandler";
+import { ChipOperation } from "stellar_merch_shop";
 import { createSEP53Message } from "../../util/crypto";
 import { getNetworkPassphrase } from "../../contracts/util";
 import type { ContractCallOptions } from "../../types/contract";
@@ -128,7 +129,7 @@ export const MintSection = ({ keyId, contractId }: MintSectionProps) => {
         walletNetwork,
         walletPassphrase,
       );
-      const nonce = 1; // Mint uses nonce = 1
+      const nonce = 1n; // Mint uses nonce = 1
 
       // Create SEP-53 message
       const { message, messageHash } = await createSEP53Message(
@@ -137,6 +138,7 @@ export const MintSection = ({ keyId, contractId }: MintSectionProps) => {
         [address],
         nonce,
         networkPassphraseToUse,
+        ChipOperation.Mint,
       );
 
       // Proceed with chip operations
@@ -147,11 +149,15 @@ export const MintSection = ({ keyId, contractId }: MintSectionProps) => {
       setMintStep("calling");
       const tx = await contractClient.mint(
         {
+          series_id: 0,
           message: Buffer.from(message),
           signature: authResult.signature,
           recovery_id: authResult.recoveryId,
           public_key: authResult.publicKeyBytes,
           nonce: nonce,
+          expiration_ledger: 0,
+          chip_info: undefined,
+          po_number: undefined,
         },
         {
           publicKey: address,
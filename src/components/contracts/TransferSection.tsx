@@ -11,6 +11,7 @@ import { useWallet } from "../../hooks/useWallet";
 import { useNFC } from "../../hooks/useNFC";
 import { useChipAuth } from "../../hooks/useChipAuth";
 import { useContractClient } from "../../hooks/useContractClient";
+import { ChipOperation } from "stellar_merch_shop";
 import { createSEP53Message } from "../../util/crypto";
 import { getNetworkPassphrase } from "../../contracts/util";
 import { handleChipError, formatChipError } from "../../util/chipErrorHandler";
@@ -141,7 +142,7 @@ export const TransferSection = ({
       const chipPublicKeyBytes = hexToBytes(chipPublicKeyHex);
 
       // Get current nonce from contract
-      let currentNonce = 0;
+      let currentNonce = 0n;
       try {
         const nonceResult = await contractClient.get_nonce(
           {
@@ -151,23 +152,25 @@ export const TransferSection = ({
             publicKey: address,
           } as ContractCallOptions,
         );
-        currentNonce = (nonceResult.result as number) || 0;
+        currentNonce = (nonceResult.result as bigint) || 0n;
       } catch (err) {
         // If get_nonce fails, default to 0
         // Nonce fetch failed, defaulting to 0 (first use)
-        currentNonce = 0;
+        currentNonce = 0n;
       }
 
       // Use next nonce (must be greater than stored)
-      const nonce = currentNonce + 1;
+      const nonce = currentNonce + 1n;
 
       // Create SEP-53 message for transfer
       const { message, messageHash } = await createSEP53Message(
         contractId,
-        "transfer",
+        "chip_transfer",
         [address, recipientAddress.trim(), tokenIdNum.toString()],
         nonce,
         networkPassphraseToUse,
+        ChipOperation.Transfer,
+        recipientAddress.trim(),
       );
 
       // Authenticate with chip
@@ -177,7 +180,7 @@ export const TransferSection = ({
       // Chip operations complete - close scanning UI
       // Now move to blockchain operations
       setTransferStep("calling");
-      const tx = await contractClient.transfer(
+      const tx = await contractClient.chip_transfer(
         {
           from: address,
           to: recipientAddress.trim(),
@@ -187,6 +190,7 @@ export const TransferSection = ({
           recovery_id: authResult.recoveryId,
           public_key: authResult.publicKeyBytes,
           nonce: nonce,
+          expiration_ledger: 0,
         },
         {
           publicKey: address,
@@ -3,6 +3,9 @@
  * Provides SHA-256 hashing and signature format conversion for Soroban
  */
 
+import { Address } from "@stellar/stellar-sdk";
+import { ChipOperation } from "stellar_merch_shop";
+
 /**
  * Convert hex string to Uint8Array
  */
@@ -52,24 +55,30 @@ export interface SEP53AuthEntry {
   contractAddress: string;
   functionName: string;
   args: unknown[];
-  nonce: number;
+  nonce: bigint;
 }
 
 /**
  * Create SEP-53 compliant auth message (without nonce)
- * The nonce is appended to the message before hashing for signature
- * Returns both the message (without nonce) and the hash of (message + nonce)
+ * The contract binds the message to the deployment, the operation, the
+ * recipient and the expiry before appending the nonce, so the chip signs
+ * sha256(domain || operation || message || recipient? || expiration? || nonce)
+ * Returns both the message passed to the contract and the hash to sign,
+ * mirroring `build_message_hash` in the contract
  */
 export async function createSEP53Message(
   contractId: string,
   functionName: string,
   args: unknown[],
-  nonce: number,
+  nonce: bigint,
   networkPassphrase: string,
+  operation: ChipOperation,
+  recipient?: string,
+  expirationLedger = 0,
 ): Promise<{ message: Uint8Array; messageHash: Uint8Array }> {
   // SEP-53 format (without nonce):
-  // network_id || contract_id || function_name || args
-  // Nonce is appended separately before hashing
+  // network_id || function_name || args
+  // The contract id is part of the domain the contract prepends
 
   const encoder = new TextEncoder();
   const parts: Uint8Array[] = [];
@@ -78,10 +87,6 @@ export async function createSEP53Message(
   const networkHash = await sha256(encoder.encode(networkPassphrase));
   parts.push(networkHash);
 
-  // Contract ID (32 bytes for Stellar addresses)
-  const contractIdBytes = hexToBytes(contractId);
-  parts.push(contractIdBytes);
-
   // Function name
   const functionNameBytes = encoder.encode(functionName);
   parts.push(functionNameBytes);
@@ -90,27 +95,37 @@ export async function createSEP53Message(
   const argsBytes = encoder.encode(JSON.stringify(args));
   parts.push(argsBytes);
 
-  // Concatenate all parts (without nonce)
-  const totalLength = parts.reduce((sum, part) => sum + part.length, 0);
-  const message = new Uint8Array(totalLength);
-  let offset = 0;
-  for (const part of parts) {
-    message.set(part, offset);
-    offset += part.length;
+  const message = concatBytes(parts);
+
+  // Domain: the contract address as XDR, current_contract_address().to_xdr()
+  const preimage: Uint8Array[] = [
+    new Address(contractId).toScVal().toXDR(),
+    Uint8Array.of(operation),
+    message,
+  ];
+
+  // Recipient the signature is bound to, recipient.to_xdr()
+  if (recipient !== undefined) {
+    preimage.push(new Address(recipient).toScVal().toXDR());
   }
 
-  const nonceXdrBytes = new Uint8Array(8);
-  const view = new DataView(nonceXdrBytes.buffer);
-  view.setUint32(0, 3, false);
-  view.setUint32(4, nonce, false);
+  // Expiration ledger as a Soroban u32 value, left out when 0
+  if (expirationLedger !== 0) {
+    const expirationXdrBytes = new Uint8Array(8);
+    const expirationView = new DataView(expirationXdrBytes.buffer);
+    expirationView.setUint32(0, 3, false);
+    expirationView.setUint32(4, expirationLedger, false);
+    preimage.push(expirationXdrBytes);
+  }
 
-  const messageWithNonce = new Uint8Array(
-    message.length + nonceXdrBytes.length,
-  );
-  messageWithNonce.set(message, 0);
-  messageWithNonce.set(nonceXdrBytes, message.length);
+  // Nonce as a Soroban u64 value, nonce.to_xdr()
+  const nonceXdrBytes = new Uint8Array(12);
+  const nonceView = new DataView(nonceXdrBytes.buffer);
+  nonceView.setUint32(0, 5, false);
+  nonceView.setBigUint64(4, nonce, false);
+  preimage.push(nonceXdrBytes);
 
-  const messageHash = await sha256(messageWithNonce);
+  const messageHash = await sha256(concatBytes(preimage));
 
   return {
     message,
@@ -118,6 +133,20 @@ export async function createSEP53Message(
   };
 }
 
+/**
+ * Concatenate byte arrays
+ */
+function concatBytes(parts: Uint8Array[]): Uint8Array {
+  const totalLength = parts.reduce((sum, part) => sum + part.length, 0);
+  const bytes = new Uint8Array(totalLength);
+  let offset = 0;
+  for (const part of parts) {
+    bytes.set(part, offset);
+    offset += part.length;
+  }
+  return bytes;
+}
+
 /**
  * Format signature from NFC chip for Soroban
  * NFC chip returns (r, s, v) format